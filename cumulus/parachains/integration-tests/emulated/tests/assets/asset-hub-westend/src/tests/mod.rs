@@ -18,6 +18,7 @@ mod claim_assets;
 mod exchange_asset;
 mod fellowship_treasury;
 mod hybrid_transfers;
+mod remote_lock;
 mod reserve_transfer;
 mod reward_pool;
 mod send;
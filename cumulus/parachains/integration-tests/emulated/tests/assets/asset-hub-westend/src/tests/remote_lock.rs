@@ -0,0 +1,121 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests related to locking assets on Asset Hub in favour of other chains.
+
+use crate::imports::*;
+
+/// A PenpalA account locks its WND on Asset Hub in favour of PenpalA, which notes the lock once
+/// Asset Hub reports it back, and then unlocks it again from PenpalA.
+#[test]
+fn lock_and_unlock_wnd_on_asset_hub_from_penpal() {
+	let sender = PenpalASender::get();
+	let sender_on_ah = Location::new(
+		1,
+		[
+			Parachain(PenpalA::para_id().into()),
+			AccountId32Junction { network: None, id: sender.clone().into() },
+		],
+	);
+	let sender_ah_account = AssetHubWestend::sovereign_account_id_of(sender_on_ah);
+	let asset_hub_location = PenpalA::sibling_location_of(AssetHubWestend::para_id());
+	let lock_amount = ASSET_HUB_WESTEND_ED * 10_000;
+	let fee_amount = ASSET_HUB_WESTEND_ED * 1_000;
+	let locked_asset: Asset = (Parent, lock_amount).into();
+
+	// The account of the sender on Asset Hub holds the WND to lock and to pay for execution.
+	AssetHubWestend::fund_accounts(vec![(sender_ah_account.clone(), lock_amount * 10)]);
+	// The sender pays the delivery fees on PenpalA in WND.
+	PenpalA::mint_foreign_asset(
+		<PenpalA as Chain>::RuntimeOrigin::signed(PenpalAssetOwner::get()),
+		Location::parent(),
+		sender.clone(),
+		WESTEND_ED * 1_000,
+	);
+
+	// PenpalA requests Asset Hub to lock the WND.
+	PenpalA::execute_with(|| {
+		type RuntimeEvent = <PenpalA as Chain>::RuntimeEvent;
+		assert_ok!(<PenpalA as PenpalAPallet>::PolkadotXcm::lock_remote_asset(
+			<PenpalA as Chain>::RuntimeOrigin::signed(sender.clone()),
+			bx!(asset_hub_location.clone().into()),
+			bx!(locked_asset.clone().into()),
+			bx!((Parent, fee_amount).into()),
+		));
+		assert_expected_events!(
+			PenpalA,
+			vec![
+				RuntimeEvent::PolkadotXcm(
+					pallet_xcm::Event::RemoteLockRequested { locker, asset, .. }
+				) => {
+					locker: *locker == asset_hub_location,
+					asset: *asset == locked_asset,
+				},
+			]
+		);
+	});
+
+	// Asset Hub locks the WND and reports the lock back to PenpalA.
+	AssetHubWestend::execute_with(|| {
+		AssetHubWestend::assert_xcmp_queue_success(None);
+		assert_eq!(
+			<AssetHubWestend as Chain>::account_data_of(sender_ah_account.clone()).frozen,
+			lock_amount
+		);
+	});
+
+	// PenpalA notes the lock and unlocks it again.
+	PenpalA::execute_with(|| {
+		type RuntimeEvent = <PenpalA as Chain>::RuntimeEvent;
+		PenpalA::assert_xcmp_queue_success(None);
+		assert_expected_events!(
+			PenpalA,
+			vec![
+				RuntimeEvent::PolkadotXcm(
+					pallet_xcm::Event::RemoteLockNoted { locker, asset, .. }
+				) => {
+					locker: *locker == asset_hub_location,
+					asset: *asset == locked_asset,
+				},
+			]
+		);
+
+		assert_ok!(<PenpalA as PenpalAPallet>::PolkadotXcm::unlock_remote_asset(
+			<PenpalA as Chain>::RuntimeOrigin::signed(sender.clone()),
+			bx!(asset_hub_location.clone().into()),
+			bx!(locked_asset.clone().into()),
+		));
+		assert_expected_events!(
+			PenpalA,
+			vec![
+				RuntimeEvent::PolkadotXcm(
+					pallet_xcm::Event::RemoteUnlockRequested { locker, asset, .. }
+				) => {
+					locker: *locker == asset_hub_location,
+					asset: *asset == locked_asset,
+				},
+			]
+		);
+	});
+
+	// Asset Hub unlocks the WND.
+	AssetHubWestend::execute_with(|| {
+		AssetHubWestend::assert_xcmp_queue_success(None);
+		assert_eq!(
+			<AssetHubWestend as Chain>::account_data_of(sender_ah_account.clone()).frozen,
+			0
+		);
+	});
+}
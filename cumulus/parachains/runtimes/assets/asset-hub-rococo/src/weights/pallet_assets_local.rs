@@ -162,14 +162,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:1 w:1)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:1 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `280`
+		//  Measured:  `375`
 		//  Estimated: `3675`
-		// Minimum execution time: 22_893_000 picoseconds.
-		Weight::from_parts(23_480_000, 0)
+		// Minimum execution time: 25_763_000 picoseconds.
+		Weight::from_parts(26_350_000, 0)
 			.saturating_add(Weight::from_parts(0, 3675))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -200,14 +204,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:2 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `392`
+		//  Measured:  `559`
 		//  Estimated: `6208`
-		// Minimum execution time: 57_281_000 picoseconds.
-		Weight::from_parts(59_830_000, 0)
+		// Minimum execution time: 61_691_000 picoseconds.
+		Weight::from_parts(64_240_000, 0)
 			.saturating_add(Weight::from_parts(0, 6208))
-			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads(9))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -218,14 +226,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:2 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer_keep_alive() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `392`
+		//  Measured:  `559`
 		//  Estimated: `6208`
-		// Minimum execution time: 43_893_000 picoseconds.
-		Weight::from_parts(45_219_000, 0)
+		// Minimum execution time: 48_283_000 picoseconds.
+		Weight::from_parts(49_609_000, 0)
 			.saturating_add(Weight::from_parts(0, 6208))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -238,14 +250,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:1 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn force_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `392`
+		//  Measured:  `487`
 		//  Estimated: `6208`
-		// Minimum execution time: 57_213_000 picoseconds.
-		Weight::from_parts(59_097_000, 0)
+		// Minimum execution time: 60_143_000 picoseconds.
+		Weight::from_parts(62_027_000, 0)
 			.saturating_add(Weight::from_parts(0, 6208))
-			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `Assets::Asset` (r:1 w:0)
@@ -440,14 +456,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:2 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer_approved() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `562`
+		//  Measured:  `729`
 		//  Estimated: `6208`
-		// Minimum execution time: 81_974_000 picoseconds.
-		Weight::from_parts(83_937_000, 0)
+		// Minimum execution time: 86_444_000 picoseconds.
+		Weight::from_parts(88_407_000, 0)
 			.saturating_add(Weight::from_parts(0, 6208))
-			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads(10))
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -582,14 +602,60 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:2 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer_all() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `392`
+		//  Measured:  `559`
 		//  Estimated: `6208`
-		// Minimum execution time: 70_437_000 picoseconds.
-		Weight::from_parts(72_895_000, 0)
+		// Minimum execution time: 74_897_000 picoseconds.
+		Weight::from_parts(77_355_000, 0)
 			.saturating_add(Weight::from_parts(0, 6208))
-			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads(9))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:0 w:1)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn set_restricted() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `280`
+		//  Estimated: `3675`
+		// Minimum execution time: 12_637_000 picoseconds.
+		Weight::from_parts(13_075_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:0 w:1)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn add_to_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `280`
+		//  Estimated: `3675`
+		// Minimum execution time: 13_355_000 picoseconds.
+		Weight::from_parts(13_793_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:0 w:1)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn remove_from_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `280`
+		//  Estimated: `3675`
+		// Minimum execution time: 13_790_000 picoseconds.
+		Weight::from_parts(14_228_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
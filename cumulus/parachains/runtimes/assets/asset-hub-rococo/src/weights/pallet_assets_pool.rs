@@ -160,14 +160,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Account` (r:1 w:1)
 	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `PoolAssets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Allowlist` (r:1 w:0)
+	/// Proof: `PoolAssets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `280`
+		//  Measured:  `375`
 		//  Estimated: `3675`
-		// Minimum execution time: 22_877_000 picoseconds.
-		Weight::from_parts(23_846_000, 0)
+		// Minimum execution time: 25_747_000 picoseconds.
+		Weight::from_parts(26_716_000, 0)
 			.saturating_add(Weight::from_parts(0, 3675))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `PoolAssets::Asset` (r:1 w:1)
@@ -198,14 +202,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `PoolAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `PoolAssets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Allowlist` (r:2 w:0)
+	/// Proof: `PoolAssets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `430`
+		//  Measured:  `597`
 		//  Estimated: `6208`
-		// Minimum execution time: 62_125_000 picoseconds.
-		Weight::from_parts(63_860_000, 0)
+		// Minimum execution time: 66_535_000 picoseconds.
+		Weight::from_parts(68_270_000, 0)
 			.saturating_add(Weight::from_parts(0, 6208))
-			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads(9))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `PoolAssets::Asset` (r:1 w:1)
@@ -216,14 +224,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `PoolAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `PoolAssets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Allowlist` (r:2 w:0)
+	/// Proof: `PoolAssets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer_keep_alive() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `430`
+		//  Measured:  `597`
 		//  Estimated: `6208`
-		// Minimum execution time: 49_077_000 picoseconds.
-		Weight::from_parts(52_343_000, 0)
+		// Minimum execution time: 53_467_000 picoseconds.
+		Weight::from_parts(56_733_000, 0)
 			.saturating_add(Weight::from_parts(0, 6208))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `PoolAssets::Asset` (r:1 w:1)
@@ -236,14 +248,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `PoolAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `PoolAssets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Allowlist` (r:1 w:0)
+	/// Proof: `PoolAssets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn force_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `430`
+		//  Measured:  `525`
 		//  Estimated: `6208`
-		// Minimum execution time: 62_560_000 picoseconds.
-		Weight::from_parts(64_211_000, 0)
+		// Minimum execution time: 65_490_000 picoseconds.
+		Weight::from_parts(67_141_000, 0)
 			.saturating_add(Weight::from_parts(0, 6208))
-			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `PoolAssets::Asset` (r:1 w:0)
@@ -438,14 +454,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `PoolAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `PoolAssets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Allowlist` (r:2 w:0)
+	/// Proof: `PoolAssets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer_approved() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `600`
+		//  Measured:  `767`
 		//  Estimated: `6208`
-		// Minimum execution time: 81_618_000 picoseconds.
-		Weight::from_parts(84_984_000, 0)
+		// Minimum execution time: 86_088_000 picoseconds.
+		Weight::from_parts(89_454_000, 0)
 			.saturating_add(Weight::from_parts(0, 6208))
-			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads(10))
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `PoolAssets::Asset` (r:1 w:1)
@@ -576,14 +596,60 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `PoolAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `PoolAssets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Allowlist` (r:2 w:0)
+	/// Proof: `PoolAssets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer_all() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `430`
+		//  Measured:  `597`
 		//  Estimated: `6208`
-		// Minimum execution time: 75_603_000 picoseconds.
-		Weight::from_parts(77_765_000, 0)
+		// Minimum execution time: 80_063_000 picoseconds.
+		Weight::from_parts(82_225_000, 0)
 			.saturating_add(Weight::from_parts(0, 6208))
-			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads(9))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	/// Storage: `PoolAssets::Asset` (r:1 w:0)
+	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::RestrictedAssets` (r:0 w:1)
+	/// Proof: `PoolAssets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn set_restricted() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `280`
+		//  Estimated: `3675`
+		// Minimum execution time: 12_567_000 picoseconds.
+		Weight::from_parts(13_155_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `PoolAssets::Asset` (r:1 w:0)
+	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Allowlist` (r:0 w:1)
+	/// Proof: `PoolAssets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn add_to_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `280`
+		//  Estimated: `3675`
+		// Minimum execution time: 13_285_000 picoseconds.
+		Weight::from_parts(13_873_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `PoolAssets::Asset` (r:1 w:0)
+	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Allowlist` (r:0 w:1)
+	/// Proof: `PoolAssets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn remove_from_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `280`
+		//  Estimated: `3675`
+		// Minimum execution time: 13_720_000 picoseconds.
+		Weight::from_parts(14_308_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -116,7 +116,9 @@ use xcm::latest::prelude::{
 };
 
 use xcm_runtime_apis::{
-	dry_run::{CallDryRunEffects, Error as XcmDryRunApiError, XcmDryRunEffects},
+	dry_run::{
+		CallDryRunEffects, Error as XcmDryRunApiError, TracedDryRunEffects, XcmDryRunEffects,
+	},
 	fees::Error as XcmPaymentApiError,
 };
 
@@ -285,6 +287,7 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_local::WeightInfo<Runtime>;
 	type CallbackHandle = pallet_assets::AutoIncAssetId<Runtime, TrustBackedAssetsInstance>;
+	type TransferRestrictions = ();
 	type AssetAccountDeposit = AssetAccountDeposit;
 	type RemoveItemsLimit = ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -330,6 +333,7 @@ impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_pool::WeightInfo<Runtime>;
 	type CallbackHandle = ();
+	type TransferRestrictions = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -443,6 +447,8 @@ impl pallet_asset_conversion::Config for Runtime {
 	type PalletId = AssetConversionPalletId;
 	type MaxSwapPathLength = ConstU32<3>;
 	type MintMinLiquidity = ConstU128<100>;
+	type MaxPriceObservations = ConstU32<24>;
+	type PriceObservationPeriod = ConstU32<HOURS>;
 	type WeightInfo = weights::pallet_asset_conversion::WeightInfo<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = assets_common::benchmarks::AssetPairFactory<
@@ -555,6 +561,7 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_foreign::WeightInfo<Runtime>;
 	type CallbackHandle = ();
+	type TransferRestrictions = ();
 	type AssetAccountDeposit = ForeignAssetsAssetAccountDeposit;
 	type RemoveItemsLimit = frame_support::traits::ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1657,6 +1664,17 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_asset_conversion::AssetConversionOracleApi<
+		Block,
+		xcm::v5::Location,
+		BlockNumber,
+	> for Runtime
+	{
+		fn time_weighted_average_price(asset1: xcm::v5::Location, asset2: xcm::v5::Location, window: BlockNumber) -> Option<sp_runtime::FixedU128> {
+			AssetConversion::time_weighted_average_price(asset1, asset2, window)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
@@ -1738,6 +1756,14 @@ impl_runtime_apis! {
 		fn dry_run_xcm(origin_location: VersionedLocation, xcm: VersionedXcm<RuntimeCall>) -> Result<XcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
 			PolkadotXcm::dry_run_xcm::<Runtime, xcm_config::XcmRouter, RuntimeCall, xcm_config::XcmConfig>(origin_location, xcm)
 		}
+
+		fn dry_run_call_traced(origin: OriginCaller, call: RuntimeCall, result_xcms_version: XcmVersion) -> Result<TracedDryRunEffects<CallDryRunEffects<RuntimeEvent>>, XcmDryRunApiError> {
+			PolkadotXcm::dry_run_call_traced::<Runtime, xcm_config::XcmRouter, OriginCaller, RuntimeCall>(origin, call, result_xcms_version)
+		}
+
+		fn dry_run_xcm_traced(origin_location: VersionedLocation, xcm: VersionedXcm<RuntimeCall>) -> Result<TracedDryRunEffects<XcmDryRunEffects<RuntimeEvent>>, XcmDryRunApiError> {
+			PolkadotXcm::dry_run_xcm_traced::<Runtime, xcm_config::XcmRouter, RuntimeCall, xcm_config::XcmConfig>(origin_location, xcm)
+		}
 	}
 
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
@@ -1761,6 +1787,22 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_runtime_apis::transfer_routes::XcmTransferRoutesApi<Block> for Runtime {
+		fn transfer_routes(asset: VersionedAsset, destination: VersionedLocation) -> Result<
+			Vec<xcm_runtime_apis::transfer_routes::TransferRoute>,
+			xcm_runtime_apis::transfer_routes::Error
+		> {
+			use xcm_runtime_apis::fees::runtime_decl_for_xcm_payment_api::XcmPaymentApiV1;
+			PolkadotXcm::transfer_routes(asset, destination, |weight, asset_id| {
+				<Runtime as XcmPaymentApiV1<Block>>::query_weight_to_asset_fee(
+					weight,
+					asset_id.clone().into(),
+				)
+				.ok()
+			})
+		}
+	}
+
 	impl xcm_runtime_apis::authorized_aliases::AuthorizedAliasersApi<Block> for Runtime {
 		fn authorized_aliasers(target: VersionedLocation) -> Result<
 			Vec<xcm_runtime_apis::authorized_aliases::OriginAliaser>,
@@ -2268,7 +2310,15 @@ impl_runtime_apis! {
 				}
 
 				fn unlockable_asset() -> Result<(Location, Location, Asset), BenchmarkError> {
-					Err(BenchmarkError::Skip)
+					// The relay chain is a trusted locker, so it can also be the unlocker of the
+					// native assets locked here.
+					let (account, owner) = pallet_xcm_benchmarks::account_and_location::<Runtime>(1);
+					assert_ok!(<Balances as fungible::Mutate<_>>::mint_into(
+						&account,
+						ExistentialDeposit::get() + (1_000 * UNITS)
+					));
+					let asset: Asset = (AssetId(WestendLocation::get()), 100 * UNITS).into();
+					Ok((WestendLocation::get(), owner, asset))
 				}
 
 				fn export_message_origin_and_destination(
@@ -164,14 +164,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Account` (r:1 w:1)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `ForeignAssets::RestrictedAssets` (`max_values`: None, `max_size`: Some(618), added: 3093, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Allowlist` (r:1 w:0)
+	/// Proof: `ForeignAssets::Allowlist` (`max_values`: None, `max_size`: Some(666), added: 3141, mode: `MaxEncodedLen`)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `242`
+		//  Measured:  `337`
 		//  Estimated: `4273`
-		// Minimum execution time: 23_549_000 picoseconds.
-		Weight::from_parts(24_293_000, 0)
+		// Minimum execution time: 26_419_000 picoseconds.
+		Weight::from_parts(27_163_000, 0)
 			.saturating_add(Weight::from_parts(0, 4273))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
@@ -202,14 +206,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(685), added: 3160, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `ForeignAssets::RestrictedAssets` (`max_values`: None, `max_size`: Some(618), added: 3093, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Allowlist` (r:2 w:0)
+	/// Proof: `ForeignAssets::Allowlist` (`max_values`: None, `max_size`: Some(666), added: 3141, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `356`
+		//  Measured:  `523`
 		//  Estimated: `7404`
-		// Minimum execution time: 59_905_000 picoseconds.
-		Weight::from_parts(61_874_000, 0)
+		// Minimum execution time: 64_315_000 picoseconds.
+		Weight::from_parts(66_284_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads(9))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
@@ -220,14 +228,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `ForeignAssets::RestrictedAssets` (`max_values`: None, `max_size`: Some(618), added: 3093, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Allowlist` (r:2 w:0)
+	/// Proof: `ForeignAssets::Allowlist` (`max_values`: None, `max_size`: Some(666), added: 3141, mode: `MaxEncodedLen`)
 	fn transfer_keep_alive() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `356`
+		//  Measured:  `523`
 		//  Estimated: `7404`
-		// Minimum execution time: 45_868_000 picoseconds.
-		Weight::from_parts(47_239_000, 0)
+		// Minimum execution time: 50_258_000 picoseconds.
+		Weight::from_parts(51_629_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
@@ -240,14 +252,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(685), added: 3160, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `ForeignAssets::RestrictedAssets` (`max_values`: None, `max_size`: Some(618), added: 3093, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Allowlist` (r:1 w:0)
+	/// Proof: `ForeignAssets::Allowlist` (`max_values`: None, `max_size`: Some(666), added: 3141, mode: `MaxEncodedLen`)
 	fn force_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `356`
+		//  Measured:  `451`
 		//  Estimated: `7404`
-		// Minimum execution time: 60_020_000 picoseconds.
-		Weight::from_parts(62_424_000, 0)
+		// Minimum execution time: 62_950_000 picoseconds.
+		Weight::from_parts(65_354_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
@@ -436,14 +452,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(685), added: 3160, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `ForeignAssets::RestrictedAssets` (`max_values`: None, `max_size`: Some(618), added: 3093, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Allowlist` (r:2 w:0)
+	/// Proof: `ForeignAssets::Allowlist` (`max_values`: None, `max_size`: Some(666), added: 3141, mode: `MaxEncodedLen`)
 	fn transfer_approved() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `526`
+		//  Measured:  `693`
 		//  Estimated: `7404`
-		// Minimum execution time: 80_737_000 picoseconds.
-		Weight::from_parts(82_408_000, 0)
+		// Minimum execution time: 85_207_000 picoseconds.
+		Weight::from_parts(86_878_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads(10))
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
@@ -578,14 +598,60 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(685), added: 3160, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `ForeignAssets::RestrictedAssets` (`max_values`: None, `max_size`: Some(618), added: 3093, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Allowlist` (r:2 w:0)
+	/// Proof: `ForeignAssets::Allowlist` (`max_values`: None, `max_size`: Some(666), added: 3141, mode: `MaxEncodedLen`)
 	fn transfer_all() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `356`
+		//  Measured:  `523`
 		//  Estimated: `7404`
-		// Minimum execution time: 74_949_000 picoseconds.
-		Weight::from_parts(75_726_000, 0)
+		// Minimum execution time: 79_409_000 picoseconds.
+		Weight::from_parts(80_186_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads(9))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::RestrictedAssets` (r:0 w:1)
+	/// Proof: `ForeignAssets::RestrictedAssets` (`max_values`: None, `max_size`: Some(618), added: 3093, mode: `MaxEncodedLen`)
+	fn set_restricted() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `242`
+		//  Estimated: `4273`
+		// Minimum execution time: 12_500_000 picoseconds.
+		Weight::from_parts(13_175_000, 0)
+			.saturating_add(Weight::from_parts(0, 4273))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Allowlist` (r:0 w:1)
+	/// Proof: `ForeignAssets::Allowlist` (`max_values`: None, `max_size`: Some(666), added: 3141, mode: `MaxEncodedLen`)
+	fn add_to_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `242`
+		//  Estimated: `4273`
+		// Minimum execution time: 13_218_000 picoseconds.
+		Weight::from_parts(13_893_000, 0)
+			.saturating_add(Weight::from_parts(0, 4273))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Allowlist` (r:0 w:1)
+	/// Proof: `ForeignAssets::Allowlist` (`max_values`: None, `max_size`: Some(666), added: 3141, mode: `MaxEncodedLen`)
+	fn remove_from_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `242`
+		//  Estimated: `4273`
+		// Minimum execution time: 13_653_000 picoseconds.
+		Weight::from_parts(14_328_000, 0)
+			.saturating_add(Weight::from_parts(0, 4273))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -245,16 +245,16 @@ impl<Call> XcmWeightInfo<Call> for AssetHubWestendXcmWeight<Call> {
 		Weight::MAX
 	}
 	fn lock_asset(_: &Asset, _: &Location) -> Weight {
-		Weight::MAX
+		XcmGeneric::<Runtime>::lock_asset()
 	}
 	fn unlock_asset(_: &Asset, _: &Location) -> Weight {
-		Weight::MAX
+		XcmGeneric::<Runtime>::unlock_asset()
 	}
 	fn note_unlockable(_: &Asset, _: &Location) -> Weight {
-		Weight::MAX
+		XcmGeneric::<Runtime>::note_unlockable()
 	}
 	fn request_unlock(_: &Asset, _: &Location) -> Weight {
-		Weight::MAX
+		XcmGeneric::<Runtime>::request_unlock()
 	}
 	fn set_fees_mode(_: &bool) -> Weight {
 		XcmGeneric::<Runtime>::set_fees_mode()
@@ -371,6 +371,86 @@ impl<T: frame_system::Config> WeightInfo<T> {
 		// Minimum execution time: 642_000 picoseconds.
 		Weight::from_parts(691_000, 0)
 	}
+	// Storage: `PolkadotXcm::LockedFungibles` (r:1 w:1)
+	// Proof: `PolkadotXcm::LockedFungibles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::UpwardDeliveryFeeFactor` (r:1 w:0)
+	// Proof: `ParachainSystem::UpwardDeliveryFeeFactor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	pub fn lock_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `311`
+		//  Estimated: `6196`
+		// Minimum execution time: 129_418_000 picoseconds.
+		Weight::from_parts(132_683_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: `PolkadotXcm::LockedFungibles` (r:1 w:1)
+	// Proof: `PolkadotXcm::LockedFungibles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	pub fn unlock_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `446`
+		//  Estimated: `4764`
+		// Minimum execution time: 31_205_000 picoseconds.
+		Weight::from_parts(32_177_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: `PolkadotXcm::RemoteLockedFungibles` (r:1 w:1)
+	// Proof: `PolkadotXcm::RemoteLockedFungibles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `PolkadotXcm::PendingRemoteLocks` (r:0 w:1)
+	// Proof: `PolkadotXcm::PendingRemoteLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	pub fn note_unlockable() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `242`
+		//  Estimated: `3707`
+		// Minimum execution time: 23_842_000 picoseconds.
+		Weight::from_parts(24_516_000, 3707)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `PolkadotXcm::RemoteLockedFungibles` (r:1 w:1)
+	// Proof: `PolkadotXcm::RemoteLockedFungibles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::UpwardDeliveryFeeFactor` (r:1 w:0)
+	// Proof: `ParachainSystem::UpwardDeliveryFeeFactor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	pub fn request_unlock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `396`
+		//  Estimated: `6196`
+		// Minimum execution time: 117_904_000 picoseconds.
+		Weight::from_parts(120_331_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 	pub fn unpaid_execution() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -373,4 +373,71 @@ impl<T: frame_system::Config> pallet_xcm::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `PolkadotXcm::RateLimitOverrides` (r:0 w:1)
+	/// Proof: `PolkadotXcm::RateLimitOverrides` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::RateLimitAllowances` (r:0 w:1)
+	/// Proof: `PolkadotXcm::RateLimitAllowances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn force_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_944_000 picoseconds.
+		Weight::from_parts(4_176_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `PolkadotXcm::PendingRemoteLocks` (r:9 w:1)
+	/// Proof: `PolkadotXcm::PendingRemoteLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::UpwardDeliveryFeeFactor` (r:1 w:0)
+	/// Proof: `ParachainSystem::UpwardDeliveryFeeFactor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn lock_remote_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1268`
+		//  Estimated: `4733`
+		// Minimum execution time: 40_637_000 picoseconds.
+		Weight::from_parts(42_590_000, 0)
+			.saturating_add(Weight::from_parts(0, 4733))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `PolkadotXcm::RemoteLockedFungibles` (r:1 w:1)
+	/// Proof: `PolkadotXcm::RemoteLockedFungibles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::UpwardDeliveryFeeFactor` (r:1 w:0)
+	/// Proof: `ParachainSystem::UpwardDeliveryFeeFactor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn unlock_remote_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `420`
+		//  Estimated: `3885`
+		// Minimum execution time: 58_469_000 picoseconds.
+		Weight::from_parts(61_313_000, 0)
+			.saturating_add(Weight::from_parts(0, 3885))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `PolkadotXcm::PendingRemoteLocks` (r:1 w:1)
+	/// Proof: `PolkadotXcm::PendingRemoteLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_remote_lock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `367`
+		//  Estimated: `3832`
+		// Minimum execution time: 14_112_000 picoseconds.
+		Weight::from_parts(14_695_000, 0)
+			.saturating_add(Weight::from_parts(0, 3832))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
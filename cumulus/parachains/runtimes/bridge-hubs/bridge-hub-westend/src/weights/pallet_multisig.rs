@@ -177,4 +177,52 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::PersistentMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::PersistentMultisigs` (`max_values`: None, `max_size`: Some(3282), added: 5757, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 100]`.
+	fn create_persistent_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `161 + s * (33 ±0)`
+		//  Estimated: `6747`
+		// Minimum execution time: 28_616_000 picoseconds.
+		Weight::from_parts(30_794_592, 0)
+			.saturating_add(Weight::from_parts(0, 6747))
+			// Standard Error: 2_094
+			.saturating_add(Weight::from_parts(235_209, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::PersistentMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::PersistentMultisigs` (`max_values`: None, `max_size`: Some(3282), added: 5757, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 100]`.
+	fn set_signatories(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3443 + s * (33 ±0)`
+		//  Estimated: `6747`
+		// Minimum execution time: 55_166_000 picoseconds.
+		Weight::from_parts(57_917_433, 0)
+			.saturating_add(Weight::from_parts(0, 6747))
+			// Standard Error: 2_094
+			.saturating_add(Weight::from_parts(297_106, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::PersistentMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::PersistentMultisigs` (`max_values`: None, `max_size`: Some(3282), added: 5757, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn destroy_persistent_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3508`
+		//  Estimated: `8799`
+		// Minimum execution time: 77_515_000 picoseconds.
+		Weight::from_parts(79_578_824, 0)
+			.saturating_add(Weight::from_parts(0, 8799))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
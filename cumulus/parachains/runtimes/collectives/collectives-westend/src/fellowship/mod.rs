@@ -97,11 +97,13 @@ impl pallet_referenda::Config<FellowshipReferendaInstance> for Runtime {
 	>;
 	type CancelOrigin = Architects;
 	type KillOrigin = Masters;
+	type UpdateTracksOrigin = EnsureRoot<AccountId>;
 	type Slash = ToParentTreasury<WestendTreasuryAccount, LocationToAccountId, Runtime>;
 	type Votes = pallet_ranked_collective::Votes;
 	type Tally = pallet_ranked_collective::TallyOf<Runtime, FellowshipCollectiveInstance>;
 	type SubmissionDeposit = ConstU128<0>;
 	type MaxQueued = ConstU32<100>;
+	type MaxTracks = ConstU32<32>;
 	type UndecidingTimeout = ConstU32<{ 7 * DAYS }>;
 	type AlarmInterval = ConstU32<1>;
 	type Tracks = tracks::TracksInfo;
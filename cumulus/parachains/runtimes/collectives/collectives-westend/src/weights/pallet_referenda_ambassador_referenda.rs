@@ -58,14 +58,22 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:0 w:1)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::TrackOrigins` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::TrackOrigins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
 	fn submit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `254`
+		//  Measured:  `516`
 		//  Estimated: `159279`
-		// Minimum execution time: 26_280_000 picoseconds.
-		Weight::from_parts(27_583_000, 0)
+		// Minimum execution time: 30_410_000 picoseconds.
+		Weight::from_parts(31_713_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -74,14 +82,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_preparing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `365`
+		//  Measured:  `538`
 		//  Estimated: `317568`
-		// Minimum execution time: 51_856_000 picoseconds.
-		Weight::from_parts(53_756_000, 0)
+		// Minimum execution time: 54_126_000 picoseconds.
+		Weight::from_parts(56_026_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -94,14 +106,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1164`
+		//  Measured:  `1337`
 		//  Estimated: `159279`
-		// Minimum execution time: 110_259_000 picoseconds.
-		Weight::from_parts(129_048_000, 0)
+		// Minimum execution time: 112_529_000 picoseconds.
+		Weight::from_parts(131_318_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -114,14 +130,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_not_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1172`
+		//  Measured:  `1345`
 		//  Estimated: `159279`
-		// Minimum execution time: 113_384_000 picoseconds.
-		Weight::from_parts(125_747_000, 0)
+		// Minimum execution time: 115_654_000 picoseconds.
+		Weight::from_parts(128_017_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -134,14 +154,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `701`
+		//  Measured:  `874`
 		//  Estimated: `317568`
-		// Minimum execution time: 154_089_000 picoseconds.
-		Weight::from_parts(237_608_000, 0)
+		// Minimum execution time: 156_359_000 picoseconds.
+		Weight::from_parts(239_878_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -154,14 +178,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `600`
+		//  Measured:  `773`
 		//  Estimated: `317568`
-		// Minimum execution time: 69_683_000 picoseconds.
-		Weight::from_parts(71_743_000, 0)
+		// Minimum execution time: 71_953_000 picoseconds.
+		Weight::from_parts(74_013_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -226,14 +254,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorReferenda::DecidingCount` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_queue_empty() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `140`
+		//  Measured:  `313`
 		//  Estimated: `3636`
-		// Minimum execution time: 11_698_000 picoseconds.
-		Weight::from_parts(12_362_000, 0)
+		// Minimum execution time: 13_968_000 picoseconds.
+		Weight::from_parts(14_632_000, 0)
 			.saturating_add(Weight::from_parts(0, 3636))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `AmbassadorReferenda::TrackQueue` (r:1 w:1)
@@ -244,14 +276,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1411`
+		//  Measured:  `1584`
 		//  Estimated: `159279`
-		// Minimum execution time: 101_346_000 picoseconds.
-		Weight::from_parts(146_910_000, 0)
+		// Minimum execution time: 103_616_000 picoseconds.
+		Weight::from_parts(149_180_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AmbassadorReferenda::TrackQueue` (r:1 w:1)
@@ -262,42 +298,54 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1525`
+		//  Measured:  `1698`
 		//  Estimated: `159279`
-		// Minimum execution time: 102_013_000 picoseconds.
-		Weight::from_parts(110_210_000, 0)
+		// Minimum execution time: 104_283_000 picoseconds.
+		Weight::from_parts(112_480_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:0)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_insertion() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `935`
+		//  Measured:  `1108`
 		//  Estimated: `4365`
-		// Minimum execution time: 43_920_000 picoseconds.
-		Weight::from_parts(52_557_000, 0)
+		// Minimum execution time: 46_190_000 picoseconds.
+		Weight::from_parts(54_827_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:0)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_slide() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `935`
+		//  Measured:  `1108`
 		//  Estimated: `4365`
-		// Minimum execution time: 43_333_000 picoseconds.
-		Weight::from_parts(51_712_000, 0)
+		// Minimum execution time: 45_603_000 picoseconds.
+		Weight::from_parts(53_982_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -306,14 +354,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorReferenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `951`
+		//  Measured:  `1124`
 		//  Estimated: `4365`
-		// Minimum execution time: 51_743_000 picoseconds.
-		Weight::from_parts(61_818_000, 0)
+		// Minimum execution time: 54_013_000 picoseconds.
+		Weight::from_parts(64_088_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -322,54 +374,70 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorReferenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_not_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `959`
+		//  Measured:  `1132`
 		//  Estimated: `4365`
-		// Minimum execution time: 51_969_000 picoseconds.
-		Weight::from_parts(56_872_000, 0)
+		// Minimum execution time: 54_239_000 picoseconds.
+		Weight::from_parts(59_142_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_no_deposit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `262`
+		//  Measured:  `435`
 		//  Estimated: `159279`
-		// Minimum execution time: 23_590_000 picoseconds.
-		Weight::from_parts(24_523_000, 0)
+		// Minimum execution time: 25_860_000 picoseconds.
+		Weight::from_parts(26_793_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_preparing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `310`
+		//  Measured:  `483`
 		//  Estimated: `159279`
-		// Minimum execution time: 24_155_000 picoseconds.
-		Weight::from_parts(25_093_000, 0)
+		// Minimum execution time: 26_425_000 picoseconds.
+		Weight::from_parts(27_363_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_timed_out() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `208`
+		//  Measured:  `381`
 		//  Estimated: `4365`
-		// Minimum execution time: 15_623_000 picoseconds.
-		Weight::from_parts(16_278_000, 0)
+		// Minimum execution time: 17_893_000 picoseconds.
+		Weight::from_parts(18_548_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -380,14 +448,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `545`
+		//  Measured:  `718`
 		//  Estimated: `159279`
-		// Minimum execution time: 40_380_000 picoseconds.
-		Weight::from_parts(41_343_000, 0)
+		// Minimum execution time: 42_650_000 picoseconds.
+		Weight::from_parts(43_613_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -398,14 +470,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `646`
+		//  Measured:  `819`
 		//  Estimated: `159279`
-		// Minimum execution time: 80_025_000 picoseconds.
-		Weight::from_parts(88_956_000, 0)
+		// Minimum execution time: 82_295_000 picoseconds.
+		Weight::from_parts(91_226_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -414,14 +490,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `699`
+		//  Measured:  `872`
 		//  Estimated: `159279`
-		// Minimum execution time: 106_597_000 picoseconds.
-		Weight::from_parts(133_288_000, 0)
+		// Minimum execution time: 108_867_000 picoseconds.
+		Weight::from_parts(135_558_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -430,14 +510,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_end_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `682`
+		//  Measured:  `855`
 		//  Estimated: `159279`
-		// Minimum execution time: 126_869_000 picoseconds.
-		Weight::from_parts(143_083_000, 0)
+		// Minimum execution time: 129_139_000 picoseconds.
+		Weight::from_parts(145_353_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -446,14 +530,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_not_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `699`
+		//  Measured:  `872`
 		//  Estimated: `159279`
-		// Minimum execution time: 114_095_000 picoseconds.
-		Weight::from_parts(147_290_000, 0)
+		// Minimum execution time: 116_365_000 picoseconds.
+		Weight::from_parts(149_560_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -462,14 +550,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `703`
+		//  Measured:  `876`
 		//  Estimated: `159279`
-		// Minimum execution time: 70_631_000 picoseconds.
-		Weight::from_parts(81_326_000, 0)
+		// Minimum execution time: 72_901_000 picoseconds.
+		Weight::from_parts(83_596_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -480,14 +572,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Lookup` (r:1 w:1)
 	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_approved() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `703`
+		//  Measured:  `876`
 		//  Estimated: `317568`
-		// Minimum execution time: 121_161_000 picoseconds.
-		Weight::from_parts(157_050_000, 0)
+		// Minimum execution time: 123_431_000 picoseconds.
+		Weight::from_parts(159_320_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -496,14 +592,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_rejected() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `699`
+		//  Measured:  `872`
 		//  Estimated: `159279`
-		// Minimum execution time: 107_304_000 picoseconds.
-		Weight::from_parts(155_388_000, 0)
+		// Minimum execution time: 109_574_000 picoseconds.
+		Weight::from_parts(157_658_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:0)
@@ -538,4 +638,56 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:1)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::TrackOrigins` (r:1 w:1)
+	/// Proof: `AmbassadorReferenda::TrackOrigins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AmbassadorReferenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::CounterForStoredTracks` (r:1 w:1)
+	/// Proof: `AmbassadorReferenda::CounterForStoredTracks` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn insert_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `314`
+		//  Estimated: `4365`
+		// Minimum execution time: 23_318_000 picoseconds.
+		Weight::from_parts(24_475_000, 0)
+			.saturating_add(Weight::from_parts(0, 4365))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:1)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn update_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `314`
+		//  Estimated: `4365`
+		// Minimum execution time: 20_902_000 picoseconds.
+		Weight::from_parts(22_059_000, 0)
+			.saturating_add(Weight::from_parts(0, 4365))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AmbassadorReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::RetiredTracks` (r:1 w:1)
+	/// Proof: `AmbassadorReferenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	fn retire_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `314`
+		//  Estimated: `4365`
+		// Minimum execution time: 19_960_000 picoseconds.
+		Weight::from_parts(21_117_000, 0)
+			.saturating_add(Weight::from_parts(0, 4365))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -58,14 +58,22 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:0 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::TrackOrigins` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::TrackOrigins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
 	fn submit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `315`
+		//  Measured:  `577`
 		//  Estimated: `159279`
-		// Minimum execution time: 25_608_000 picoseconds.
-		Weight::from_parts(26_613_000, 0)
+		// Minimum execution time: 29_738_000 picoseconds.
+		Weight::from_parts(30_743_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -74,14 +82,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_preparing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `326`
+		//  Measured:  `499`
 		//  Estimated: `317568`
-		// Minimum execution time: 50_710_000 picoseconds.
-		Weight::from_parts(52_652_000, 0)
+		// Minimum execution time: 52_980_000 picoseconds.
+		Weight::from_parts(54_922_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -94,14 +106,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1964`
+		//  Measured:  `2137`
 		//  Estimated: `159279`
-		// Minimum execution time: 94_123_000 picoseconds.
-		Weight::from_parts(100_860_000, 0)
+		// Minimum execution time: 96_393_000 picoseconds.
+		Weight::from_parts(103_130_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -114,14 +130,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_not_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2005`
+		//  Measured:  `2178`
 		//  Estimated: `159279`
-		// Minimum execution time: 92_227_000 picoseconds.
-		Weight::from_parts(102_540_000, 0)
+		// Minimum execution time: 94_497_000 picoseconds.
+		Weight::from_parts(104_810_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -134,14 +154,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `762`
+		//  Measured:  `935`
 		//  Estimated: `317568`
-		// Minimum execution time: 136_440_000 picoseconds.
-		Weight::from_parts(158_720_000, 0)
+		// Minimum execution time: 138_710_000 picoseconds.
+		Weight::from_parts(160_990_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -154,14 +178,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `661`
+		//  Measured:  `834`
 		//  Estimated: `317568`
-		// Minimum execution time: 68_946_000 picoseconds.
-		Weight::from_parts(70_116_000, 0)
+		// Minimum execution time: 71_216_000 picoseconds.
+		Weight::from_parts(72_386_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -226,14 +254,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::DecidingCount` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_queue_empty() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `101`
+		//  Measured:  `274`
 		//  Estimated: `4277`
-		// Minimum execution time: 11_165_000 picoseconds.
-		Weight::from_parts(11_768_000, 0)
+		// Minimum execution time: 13_435_000 picoseconds.
+		Weight::from_parts(14_038_000, 0)
 			.saturating_add(Weight::from_parts(0, 4277))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
@@ -244,14 +276,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2350`
+		//  Measured:  `2523`
 		//  Estimated: `159279`
-		// Minimum execution time: 80_672_000 picoseconds.
-		Weight::from_parts(83_925_000, 0)
+		// Minimum execution time: 82_942_000 picoseconds.
+		Weight::from_parts(86_195_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
@@ -262,42 +298,54 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2350`
+		//  Measured:  `2523`
 		//  Estimated: `159279`
-		// Minimum execution time: 81_147_000 picoseconds.
-		Weight::from_parts(84_636_000, 0)
+		// Minimum execution time: 83_417_000 picoseconds.
+		Weight::from_parts(86_906_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:0)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_insertion() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1768`
+		//  Measured:  `1941`
 		//  Estimated: `4365`
-		// Minimum execution time: 45_304_000 picoseconds.
-		Weight::from_parts(53_869_000, 0)
+		// Minimum execution time: 47_574_000 picoseconds.
+		Weight::from_parts(56_139_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:0)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_slide() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1735`
+		//  Measured:  `1908`
 		//  Estimated: `4365`
-		// Minimum execution time: 43_802_000 picoseconds.
-		Weight::from_parts(51_495_000, 0)
+		// Minimum execution time: 46_072_000 picoseconds.
+		Weight::from_parts(53_765_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -306,14 +354,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipReferenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1751`
+		//  Measured:  `1924`
 		//  Estimated: `4365`
-		// Minimum execution time: 51_133_000 picoseconds.
-		Weight::from_parts(58_941_000, 0)
+		// Minimum execution time: 53_403_000 picoseconds.
+		Weight::from_parts(61_211_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -322,54 +374,70 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipReferenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_not_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1792`
+		//  Measured:  `1965`
 		//  Estimated: `4365`
-		// Minimum execution time: 52_734_000 picoseconds.
-		Weight::from_parts(58_840_000, 0)
+		// Minimum execution time: 55_004_000 picoseconds.
+		Weight::from_parts(61_110_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_no_deposit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `223`
+		//  Measured:  `396`
 		//  Estimated: `159279`
-		// Minimum execution time: 23_415_000 picoseconds.
-		Weight::from_parts(24_322_000, 0)
+		// Minimum execution time: 25_685_000 picoseconds.
+		Weight::from_parts(26_592_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_preparing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `271`
+		//  Measured:  `444`
 		//  Estimated: `159279`
-		// Minimum execution time: 23_298_000 picoseconds.
-		Weight::from_parts(24_469_000, 0)
+		// Minimum execution time: 25_568_000 picoseconds.
+		Weight::from_parts(26_739_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_timed_out() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `169`
+		//  Measured:  `342`
 		//  Estimated: `4365`
-		// Minimum execution time: 15_335_000 picoseconds.
-		Weight::from_parts(15_843_000, 0)
+		// Minimum execution time: 17_605_000 picoseconds.
+		Weight::from_parts(18_113_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -380,14 +448,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `606`
+		//  Measured:  `779`
 		//  Estimated: `159279`
-		// Minimum execution time: 37_153_000 picoseconds.
-		Weight::from_parts(38_139_000, 0)
+		// Minimum execution time: 39_423_000 picoseconds.
+		Weight::from_parts(40_409_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -398,14 +470,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `707`
+		//  Measured:  `880`
 		//  Estimated: `159279`
-		// Minimum execution time: 73_395_000 picoseconds.
-		Weight::from_parts(78_026_000, 0)
+		// Minimum execution time: 75_665_000 picoseconds.
+		Weight::from_parts(80_296_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -414,14 +490,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `758`
+		//  Measured:  `931`
 		//  Estimated: `159279`
-		// Minimum execution time: 107_223_000 picoseconds.
-		Weight::from_parts(127_463_000, 0)
+		// Minimum execution time: 109_493_000 picoseconds.
+		Weight::from_parts(129_733_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -430,14 +510,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_end_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `743`
+		//  Measured:  `916`
 		//  Estimated: `159279`
-		// Minimum execution time: 111_791_000 picoseconds.
-		Weight::from_parts(142_696_000, 0)
+		// Minimum execution time: 114_061_000 picoseconds.
+		Weight::from_parts(144_966_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -446,14 +530,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_not_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `758`
+		//  Measured:  `931`
 		//  Estimated: `159279`
-		// Minimum execution time: 105_650_000 picoseconds.
-		Weight::from_parts(131_614_000, 0)
+		// Minimum execution time: 107_920_000 picoseconds.
+		Weight::from_parts(133_884_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -462,14 +550,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `764`
+		//  Measured:  `937`
 		//  Estimated: `159279`
-		// Minimum execution time: 80_058_000 picoseconds.
-		Weight::from_parts(92_293_000, 0)
+		// Minimum execution time: 82_328_000 picoseconds.
+		Weight::from_parts(94_563_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -480,14 +572,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Lookup` (r:1 w:1)
 	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_approved() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `764`
+		//  Measured:  `937`
 		//  Estimated: `317568`
-		// Minimum execution time: 139_560_000 picoseconds.
-		Weight::from_parts(189_387_000, 0)
+		// Minimum execution time: 141_830_000 picoseconds.
+		Weight::from_parts(191_657_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -496,14 +592,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_rejected() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `760`
+		//  Measured:  `933`
 		//  Estimated: `159279`
-		// Minimum execution time: 127_944_000 picoseconds.
-		Weight::from_parts(187_713_000, 0)
+		// Minimum execution time: 130_214_000 picoseconds.
+		Weight::from_parts(189_983_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:0)
@@ -538,4 +638,56 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:1)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::TrackOrigins` (r:1 w:1)
+	/// Proof: `FellowshipReferenda::TrackOrigins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FellowshipReferenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::CounterForStoredTracks` (r:1 w:1)
+	/// Proof: `FellowshipReferenda::CounterForStoredTracks` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn insert_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `275`
+		//  Estimated: `4365`
+		// Minimum execution time: 23_006_000 picoseconds.
+		Weight::from_parts(23_646_000, 0)
+			.saturating_add(Weight::from_parts(0, 4365))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:1)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn update_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `275`
+		//  Estimated: `4365`
+		// Minimum execution time: 20_590_000 picoseconds.
+		Weight::from_parts(21_230_000, 0)
+			.saturating_add(Weight::from_parts(0, 4365))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::RetiredTracks` (r:1 w:1)
+	/// Proof: `FellowshipReferenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	fn retire_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `275`
+		//  Estimated: `4365`
+		// Minimum execution time: 19_648_000 picoseconds.
+		Weight::from_parts(20_288_000, 0)
+			.saturating_add(Weight::from_parts(0, 4365))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
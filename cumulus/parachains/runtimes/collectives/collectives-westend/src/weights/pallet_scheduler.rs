@@ -287,4 +287,192 @@ impl<T: frame_system::Config> pallet_scheduler::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	fn service_timed_agendas_base() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `30`
+		//  Estimated: `1489`
+		// Minimum execution time: 3_657_000 picoseconds.
+		Weight::from_parts(3_803_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 200]`.
+	fn service_timed_agenda_base(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76 + s * (177 ±0)`
+		//  Estimated: `159279`
+		// Minimum execution time: 4_335_000 picoseconds.
+		Weight::from_parts(652_490, 0)
+			.saturating_add(Weight::from_parts(0, 159279))
+			// Standard Error: 6_261
+			.saturating_add(Weight::from_parts(505_822, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 199]`.
+	fn schedule_at_time(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76 + s * (177 ±0)`
+		//  Estimated: `159279`
+		// Minimum execution time: 11_131_000 picoseconds.
+		Weight::from_parts(9_815_127, 0)
+			.saturating_add(Weight::from_parts(0, 159279))
+			// Standard Error: 4_777
+			.saturating_add(Weight::from_parts(480_266, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedLookup` (r:0 w:1)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 200]`.
+	fn cancel_timed(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76 + s * (177 ±0)`
+		//  Estimated: `159279`
+		// Minimum execution time: 17_237_000 picoseconds.
+		Weight::from_parts(11_083_199, 0)
+			.saturating_add(Weight::from_parts(0, 159279))
+			// Standard Error: 5_569
+			.saturating_add(Weight::from_parts(707_879, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:0)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:1)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 199]`.
+	fn schedule_named_at_time(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `467 + s * (179 ±0)`
+		//  Estimated: `159279`
+		// Minimum execution time: 14_435_000 picoseconds.
+		Weight::from_parts(15_995_475, 0)
+			.saturating_add(Weight::from_parts(0, 159279))
+			// Standard Error: 4_486
+			.saturating_add(Weight::from_parts(500_209, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:1)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 200]`.
+	fn cancel_named_timed(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `508 + s * (179 ±0)`
+		//  Estimated: `159279`
+		// Minimum execution time: 19_568_000 picoseconds.
+		Weight::from_parts(16_398_260, 0)
+			.saturating_add(Weight::from_parts(0, 159279))
+			// Standard Error: 4_753
+			.saturating_add(Weight::from_parts(700_992, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 200]`.
+	fn schedule_timed_retry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `117`
+		//  Estimated: `159279`
+		// Minimum execution time: 10_535_000 picoseconds.
+		Weight::from_parts(11_022_574, 0)
+			.saturating_add(Weight::from_parts(0, 159279))
+			// Standard Error: 294
+			.saturating_add(Weight::from_parts(28_445, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn set_retry_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `35480`
+		//  Estimated: `159279`
+		// Minimum execution time: 70_733_000 picoseconds.
+		Weight::from_parts(73_390_000, 0)
+			.saturating_add(Weight::from_parts(0, 159279))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:0)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn set_retry_named_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `36252`
+		//  Estimated: `159279`
+		// Minimum execution time: 78_863_000 picoseconds.
+		Weight::from_parts(80_810_000, 0)
+			.saturating_add(Weight::from_parts(0, 159279))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn cancel_retry_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `35492`
+		//  Estimated: `159279`
+		// Minimum execution time: 71_348_000 picoseconds.
+		Weight::from_parts(73_177_000, 0)
+			.saturating_add(Weight::from_parts(0, 159279))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:0)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn cancel_retry_named_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `36264`
+		//  Estimated: `159279`
+		// Minimum execution time: 78_998_000 picoseconds.
+		Weight::from_parts(80_991_000, 0)
+			.saturating_add(Weight::from_parts(0, 159279))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
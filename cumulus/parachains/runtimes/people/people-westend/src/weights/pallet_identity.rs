@@ -128,6 +128,8 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(804), added: 3279, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::SuperOf` (r:0 w:100)
 	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:0 w:20)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	fn clear_identity(r: u32, s: u32, ) -> Weight {
@@ -138,11 +140,12 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(31_822_941, 0)
 			.saturating_add(Weight::from_parts(0, 6723))
 			// Standard Error: 12_876
-			.saturating_add(Weight::from_parts(164_995, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(101_814, 0).saturating_mul(r.into()))
 			// Standard Error: 2_512
 			.saturating_add(Weight::from_parts(1_397_786, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
 	}
 	/// Storage: `Identity::Registrars` (r:1 w:0)
@@ -251,6 +254,8 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	/// Proof: `PolkadotXcm::ShouldRecordXcm` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Identity::SuperOf` (r:0 w:100)
 	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:0 w:20)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	fn kill_identity(r: u32, s: u32, ) -> Weight {
@@ -261,11 +266,12 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(90_816_486, 0)
 			.saturating_add(Weight::from_parts(0, 6723))
 			// Standard Error: 24_805
-			.saturating_add(Weight::from_parts(352_526, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(217_521, 0).saturating_mul(r.into()))
 			// Standard Error: 4_840
 			.saturating_add(Weight::from_parts(1_490_539, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
 			.saturating_add(Weight::from_parts(0, 6).saturating_mul(r.into()))
 			.saturating_add(Weight::from_parts(0, 32).saturating_mul(s.into()))
@@ -576,4 +582,58 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(804), added: 3279, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationNonce` (r:1 w:1)
+	/// Proof: `Identity::AttestationNonce` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:0 w:1)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 20]`.
+	fn provide_attestation(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `565 + r * (57 ±0)`
+		//  Estimated: `4269`
+		// Minimum execution time: 115_239_000 picoseconds.
+		Weight::from_parts(118_804_004, 0)
+			.saturating_add(Weight::from_parts(0, 4269))
+			// Standard Error: 2_148
+			.saturating_add(Weight::from_parts(114_592, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:1 w:1)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationNonce` (r:1 w:1)
+	/// Proof: `Identity::AttestationNonce` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 20]`.
+	fn revoke_attestation(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `259 + r * (57 ±0)`
+		//  Estimated: `3561`
+		// Minimum execution time: 16_682_000 picoseconds.
+		Weight::from_parts(17_198_629, 0)
+			.saturating_add(Weight::from_parts(0, 3561))
+			// Standard Error: 1_331
+			.saturating_add(Weight::from_parts(82_437, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Identity::AttestationsOf` (r:1 w:1)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(804), added: 3279, mode: `MaxEncodedLen`)
+	fn remove_stale_attestation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `659`
+		//  Estimated: `4269`
+		// Minimum execution time: 24_337_000 picoseconds.
+		Weight::from_parts(25_090_000, 0)
+			.saturating_add(Weight::from_parts(0, 4269))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -0,0 +1,293 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Initialise emulated chains from live chain state.
+//!
+//! Instead of building a chain from its genesis config, a [`Fork`] loads the state of a live
+//! chain, either from a `frame-remote-externalities` snapshot file or straight from a node, and
+//! turns it into the [`Storage`] expected by `decl_test_relay_chains!` and
+//! `decl_test_parachains!`:
+//!
+//! ```ignore
+//! decl_test_parachains! {
+//! 	pub struct AssetHubWestend {
+//! 		genesis = Fork::<asset_hub_westend_runtime::Block>::snapshot("ahw.snap").storage(),
+//! 		on_init = (),
+//! 		// ...
+//! 	}
+//! }
+//! ```
+//!
+//! Forked and genesis chains can be mixed freely in a network, messages are routed between them
+//! with the usual `decl_test_networks!` machinery.
+//!
+//! The emulator produces its own blocks on top of the forked state, so the storage items listed
+//! in [`DEFAULT_RESET_ITEMS`] are removed unless [`Fork::keep_consensus_state`] is used.
+
+use crate::{HashMap, LazyLock, Mutex, Storage};
+use remote_externalities::{Builder, Mode, OfflineConfig, OnlineConfig, SnapshotConfig, Transport};
+use sp_core::storage::{well_known_keys, ChildInfo, StorageChild};
+use sp_crypto_hashing::twox_128;
+use sp_runtime::traits::Block as BlockT;
+use std::{collections::BTreeMap, marker::PhantomData, path::PathBuf};
+
+/// `(pallet, storage item)` pairs removed from forked state by default.
+///
+/// They tie the live chain's block production to its own relay chain and clock, which the
+/// emulator doesn't follow: it starts over from its own relay block numbers, timestamps and
+/// message queues, and doesn't build on the unincluded segment of the live chain.
+pub const DEFAULT_RESET_ITEMS: &[(&str, &str)] = &[
+	("Timestamp", "Now"),
+	// Slots.
+	("Aura", "CurrentSlot"),
+	("AuraExt", "RelaySlotInfo"),
+	("Babe", "CurrentSlot"),
+	// Relay parent and unincluded segment.
+	("ParachainSystem", "LastRelayChainBlockNumber"),
+	("ParachainSystem", "ValidationData"),
+	("ParachainSystem", "RelayStateProof"),
+	("ParachainSystem", "RelevantMessagingState"),
+	("ParachainSystem", "UnincludedSegment"),
+	("ParachainSystem", "AggregatedUnincludedSegment"),
+	// Message queue chains and watermarks, which the emulator's relay chain starts over.
+	("ParachainSystem", "LastDmqMqcHead"),
+	("ParachainSystem", "LastHrmpMqcHeads"),
+	("ParachainSystem", "HrmpWatermark"),
+	// Validation code upgrades signalled by the live relay chain.
+	("ParachainSystem", "UpgradeGoAhead"),
+	("ParachainSystem", "UpgradeRestrictionSignal"),
+	("ParachainSystem", "PendingValidationCode"),
+];
+
+/// Loaded state, keyed by [`Fork::id`], so that every use of a `genesis` expression doesn't
+/// load it again.
+static FORKED_STATE: LazyLock<Mutex<HashMap<String, Storage>>> =
+	LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Where to load the live state from.
+#[derive(Clone, Debug)]
+pub enum ForkSource<Hash> {
+	/// A snapshot file created with `frame-remote-externalities`, e.g. through
+	/// `try-runtime create-snapshot`.
+	Snapshot(PathBuf),
+	/// A node, reachable at `uri`. The state is taken at block `at`, or at the latest finalized
+	/// block if `None`.
+	Node { uri: String, at: Option<Hash> },
+}
+
+/// The state of a live chain, ready to be used as the `genesis` of an emulated chain.
+pub struct Fork<B: BlockT> {
+	source: ForkSource<B::Hash>,
+	reset_items: Vec<(String, String)>,
+	_phantom: PhantomData<B>,
+}
+
+impl<B: BlockT> Fork<B> {
+	/// Fork the state stored in the snapshot file at `path`.
+	pub fn snapshot(path: impl Into<PathBuf>) -> Self {
+		Self::new(ForkSource::Snapshot(path.into()))
+	}
+
+	/// Fork the state of the node at `uri`, at its latest finalized block.
+	pub fn node(uri: impl Into<String>) -> Self {
+		Self::new(ForkSource::Node { uri: uri.into(), at: None })
+	}
+
+	/// Fork the state at block `hash` rather than at the latest finalized one.
+	///
+	/// Only relevant when forking from a node.
+	pub fn at(mut self, hash: B::Hash) -> Self {
+		if let ForkSource::Node { at, .. } = &mut self.source {
+			*at = Some(hash);
+		}
+		self
+	}
+
+	/// Also remove `pallet::item` from the forked state.
+	pub fn reset_item(mut self, pallet: &str, item: &str) -> Self {
+		self.reset_items.push((pallet.into(), item.into()));
+		self
+	}
+
+	/// Keep the storage items in [`DEFAULT_RESET_ITEMS`].
+	pub fn keep_consensus_state(mut self) -> Self {
+		self.reset_items.retain(|(pallet, item)| {
+			!DEFAULT_RESET_ITEMS.iter().any(|(p, i)| p == pallet && i == item)
+		});
+		self
+	}
+
+	/// Load the forked state.
+	///
+	/// Panics if it can't be loaded, as it is meant to be used in a `genesis` expression.
+	pub fn storage(&self) -> Storage {
+		let id = self.id();
+		let mut cache = FORKED_STATE.lock().unwrap();
+		let mut storage = match cache.get(&id) {
+			Some(storage) => storage.clone(),
+			None => {
+				let storage = self.load().unwrap_or_else(|e| panic!("failed to fork {id}: {e}"));
+				cache.insert(id, storage.clone());
+				storage
+			},
+		};
+		drop(cache);
+
+		remove_items(&mut storage, &self.reset_items);
+		storage
+	}
+
+	fn new(source: ForkSource<B::Hash>) -> Self {
+		let reset_items = DEFAULT_RESET_ITEMS
+			.iter()
+			.map(|(pallet, item)| (pallet.to_string(), item.to_string()))
+			.collect();
+		Self { source, reset_items, _phantom: PhantomData }
+	}
+
+	fn id(&self) -> String {
+		match &self.source {
+			ForkSource::Snapshot(path) => path.display().to_string(),
+			ForkSource::Node { uri, at: Some(at) } => format!("{uri}@{at:?}"),
+			ForkSource::Node { uri, at: None } => uri.clone(),
+		}
+	}
+
+	fn load(&self) -> Result<Storage, &'static str> {
+		let mode = match &self.source {
+			ForkSource::Snapshot(path) =>
+				Mode::Offline(OfflineConfig { state_snapshot: SnapshotConfig::new(path.clone()) }),
+			ForkSource::Node { uri, at } => Mode::Online(OnlineConfig {
+				at: *at,
+				transport: Transport::from(uri.clone()),
+				..Default::default()
+			}),
+		};
+
+		// `genesis` expressions are synchronous but may well be evaluated within a tokio runtime,
+		// e.g. in a `#[tokio::test]`, where blocking on another runtime panics. Load the state on
+		// a dedicated thread instead.
+		std::thread::scope(|scope| {
+			scope
+				.spawn(move || -> Result<Storage, &'static str> {
+					let runtime = tokio::runtime::Builder::new_current_thread()
+						.enable_all()
+						.build()
+						.map_err(|_| "failed to start a tokio runtime")?;
+					let mut ext = runtime.block_on(Builder::<B>::new().mode(mode).build())?;
+					Ok(ext.execute_with(dump_storage))
+				})
+				.join()
+				.map_err(|_| "the thread loading the state panicked")?
+		})
+	}
+}
+
+/// Remove the `(pallet, storage item)` pairs in `items` from `storage`.
+fn remove_items(storage: &mut Storage, items: &[(String, String)]) {
+	for (pallet, item) in items {
+		let key = [twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat();
+		storage.top.remove(&key);
+	}
+}
+
+/// Collect the whole state of the current externalities, child tries included.
+fn dump_storage() -> Storage {
+	let mut storage = Storage::default();
+	let mut key = Vec::new();
+	while let Some(next) = sp_io::storage::next_key(&key) {
+		if let Some(child_key) =
+			next.strip_prefix(well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX)
+		{
+			let child_info = ChildInfo::new_default(child_key);
+			let mut data = BTreeMap::new();
+			let mut child_entry = Vec::new();
+			while let Some(next_child) =
+				sp_io::default_child_storage::next_key(child_key, &child_entry)
+			{
+				if let Some(value) = sp_io::default_child_storage::get(child_key, &next_child) {
+					data.insert(next_child.clone(), value);
+				}
+				child_entry = next_child;
+			}
+			storage
+				.children_default
+				.insert(child_key.to_vec(), StorageChild { data, child_info });
+		} else if let Some(value) = sp_io::storage::get(&next) {
+			storage.top.insert(next.clone(), value.to_vec());
+		}
+		key = next;
+	}
+	storage
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_runtime::testing::{Block as RawBlock, MockCallU64, TestXt};
+
+	type Block = RawBlock<TestXt<MockCallU64, ()>>;
+
+	const SNAPSHOT: &str = concat!(
+		env!("CARGO_MANIFEST_DIR"),
+		"/../../../substrate/utils/frame/remote-externalities/test_data/test.snap"
+	);
+
+	fn key(pallet: &str, item: &str) -> Vec<u8> {
+		[twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat()
+	}
+
+	#[test]
+	fn can_fork_snapshot() {
+		let storage = Fork::<Block>::snapshot(SNAPSHOT).storage();
+		assert!(!storage.top.is_empty());
+
+		// The state is cached and handed out again as is.
+		assert_eq!(Fork::<Block>::snapshot(SNAPSHOT).storage().top, storage.top);
+	}
+
+	#[tokio::test]
+	async fn can_fork_snapshot_within_tokio_runtime() {
+		// A different path to the same file, such that the state isn't taken from the cache.
+		let path = std::path::Path::new(SNAPSHOT).canonicalize().unwrap();
+		assert!(!Fork::<Block>::snapshot(path).storage().top.is_empty());
+	}
+
+	#[test]
+	fn reset_items_are_removed() {
+		let mut storage = Storage::default();
+		for (pallet, item) in DEFAULT_RESET_ITEMS {
+			storage.top.insert(key(pallet, item), vec![1]);
+		}
+		storage.top.insert(key("Balances", "TotalIssuance"), vec![1]);
+		storage.top.insert(key("Foo", "Bar"), vec![1]);
+
+		let fork = Fork::<Block>::snapshot(SNAPSHOT).reset_item("Foo", "Bar");
+		let mut forked = storage.clone();
+		remove_items(&mut forked, &fork.reset_items);
+		assert_eq!(
+			forked.top.into_keys().collect::<Vec<_>>(),
+			vec![key("Balances", "TotalIssuance")]
+		);
+
+		// Only the additional item is removed when keeping the consensus state.
+		let fork = fork.keep_consensus_state();
+		let mut forked = storage.clone();
+		remove_items(&mut forked, &fork.reset_items);
+		storage.top.remove(&key("Foo", "Bar"));
+		assert_eq!(forked.top, storage.top);
+	}
+}
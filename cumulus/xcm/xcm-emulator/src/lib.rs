@@ -86,6 +86,9 @@ pub use xcm::latest::prelude::{
 };
 pub use xcm_executor::traits::ConvertLocation;
 
+#[cfg(feature = "fork")]
+pub mod fork;
+
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
 thread_local! {
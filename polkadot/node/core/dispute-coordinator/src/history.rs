@@ -0,0 +1,97 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use std::collections::BTreeMap;
+
+use polkadot_node_primitives::{ParticipationOutcome, SlashingReport};
+use polkadot_primitives::{slashing::PendingSlashes, CandidateHash, SessionIndex};
+
+/// History of recent disputes, which is not part of the persisted dispute state.
+///
+/// Kept for reporting to node operators only, so it is lost on restart.
+#[derive(Default)]
+pub struct DisputeHistory {
+	/// The outcomes of our participations.
+	participations: BTreeMap<(SessionIndex, CandidateHash), ParticipationOutcome>,
+	/// Slashes pending for concluded disputes.
+	slashes: BTreeMap<(SessionIndex, CandidateHash), SlashingReport>,
+}
+
+impl DisputeHistory {
+	/// Note the outcome of our participation in a dispute.
+	pub fn note_participation(
+		&mut self,
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+		outcome: ParticipationOutcome,
+	) {
+		self.participations.insert((session, candidate_hash), outcome);
+	}
+
+	/// Note the slashes pending for a dispute, as returned by the runtime.
+	///
+	/// Whether the offence has already been reported is preserved.
+	pub fn note_pending_slashes(
+		&mut self,
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+		pending: &PendingSlashes,
+	) {
+		let reported = self
+			.slashes
+			.get(&(session, candidate_hash))
+			.map_or(false, |report| report.reported);
+		self.slashes.insert(
+			(session, candidate_hash),
+			SlashingReport {
+				kind: pending.kind,
+				offenders: pending.keys.keys().copied().collect(),
+				reported,
+			},
+		);
+	}
+
+	/// Note that we reported the offence of a dispute to the runtime.
+	pub fn note_slashes_reported(&mut self, session: SessionIndex, candidate_hash: CandidateHash) {
+		if let Some(report) = self.slashes.get_mut(&(session, candidate_hash)) {
+			report.reported = true;
+		}
+	}
+
+	/// Get the outcome of our participation in a dispute, if any.
+	pub fn participation(
+		&self,
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+	) -> Option<ParticipationOutcome> {
+		self.participations.get(&(session, candidate_hash)).copied()
+	}
+
+	/// Get the slashes pending for a dispute, if any.
+	pub fn slashing(
+		&self,
+		session: SessionIndex,
+		candidate_hash: CandidateHash,
+	) -> Option<SlashingReport> {
+		self.slashes.get(&(session, candidate_hash)).cloned()
+	}
+
+	/// Prune all history for sessions older than the given index.
+	pub fn prune_old(&mut self, oldest_index: SessionIndex) {
+		self.participations.retain(|(session, _), _| *session >= oldest_index);
+		self.slashes.retain(|(session, _), _| *session >= oldest_index);
+	}
+}
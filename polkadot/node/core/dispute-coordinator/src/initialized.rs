@@ -18,6 +18,7 @@
 
 use std::{
 	collections::{BTreeMap, VecDeque},
+	ops::Bound,
 	sync::Arc,
 };
 
@@ -29,8 +30,8 @@ use futures::{
 use sc_keystore::LocalKeystore;
 
 use polkadot_node_primitives::{
-	disputes::ValidCandidateVotes, CandidateVotes, DisputeStatus, SignedDisputeStatement,
-	Timestamp, DISPUTE_WINDOW,
+	disputes::ValidCandidateVotes, CandidateVotes, DisputeReport, DisputeStatus,
+	SignedDisputeStatement, Timestamp, DISPUTE_WINDOW, MAX_DISPUTE_REPORTS_PER_QUERY,
 };
 use polkadot_node_subsystem::{
 	messages::{
@@ -64,6 +65,7 @@ use crate::{
 
 use super::{
 	backend::Backend,
+	history::DisputeHistory,
 	make_dispute_message,
 	participation::{
 		self, Participation, ParticipationPriority, ParticipationRequest, ParticipationStatement,
@@ -104,6 +106,8 @@ pub(crate) struct Initialized {
 	/// Will be set to `true` if an error occurred during the last caching attempt
 	gaps_in_cache: bool,
 	spam_slots: SpamSlots,
+	/// Participation outcomes and slashes of recent disputes, for reporting.
+	history: DisputeHistory,
 	participation: Participation,
 	scraper: ChainScraper,
 	participation_receiver: WorkerMessageReceiver,
@@ -152,6 +156,7 @@ impl Initialized {
 			highest_session_seen,
 			gaps_in_cache,
 			spam_slots,
+			history: DisputeHistory::default(),
 			scraper,
 			participation,
 			participation_receiver,
@@ -243,6 +248,7 @@ impl Initialized {
 							candidate_receipt,
 							outcome,
 						} = self.participation.get_participation_result(ctx, msg).await?;
+						self.history.note_participation(session, candidate_hash, outcome);
 						if let Some(valid) = outcome.validity() {
 							gum::trace!(
 								target: LOG_TARGET,
@@ -365,6 +371,7 @@ impl Initialized {
 
 					db::v1::note_earliest_session(overlay_db, prune_up_to)?;
 					self.spam_slots.prune_old(prune_up_to);
+					self.history.prune_old(prune_up_to);
 					self.offchain_disabled_validators.prune_old(prune_up_to);
 				},
 				Ok(_) => { /* no new session => nothing to cache */ },
@@ -412,6 +419,7 @@ impl Initialized {
 				n_slashes = pending.keys.len(),
 				"Processing unapplied validator slashes",
 			);
+			self.history.note_pending_slashes(session_index, candidate_hash, &pending);
 
 			let pinned_hash = self.runtime_info.get_block_in_session(session_index);
 			let inclusions = self.scraper.get_blocks_including_candidate(&candidate_hash);
@@ -537,6 +545,7 @@ impl Initialized {
 						);
 					},
 					Ok(Some(())) => {
+						self.history.note_slashes_reported(session_index, candidate_hash);
 						gum::info!(
 							target: LOG_TARGET,
 							?session_index,
@@ -546,6 +555,7 @@ impl Initialized {
 						);
 					},
 					Ok(None) => {
+						self.history.note_slashes_reported(session_index, candidate_hash);
 						gum::debug!(
 							target: LOG_TARGET,
 							?session_index,
@@ -876,6 +886,50 @@ impl Initialized {
 						.collect(),
 				);
 			},
+			DisputeCoordinatorMessage::QueryDisputeReports { after, limit, tx } => {
+				gum::trace!(target: LOG_TARGET, "DisputeCoordinatorMessage::QueryDisputeReports");
+				let recent_disputes = if let Some(disputes) = overlay_db.load_recent_disputes()? {
+					disputes
+				} else {
+					BTreeMap::new()
+				};
+
+				// Every report loads the votes of the dispute, so only a page of them is built.
+				let limit = limit.min(MAX_DISPUTE_REPORTS_PER_QUERY) as usize;
+				let start = after.map_or(Bound::Unbounded, Bound::Excluded);
+				let mut reports = Vec::with_capacity(limit.min(recent_disputes.len()));
+				for (&(session, candidate_hash), &status) in
+					recent_disputes.range((start, Bound::Unbounded)).take(limit)
+				{
+					let votes: Option<CandidateVotes> =
+						overlay_db.load_candidate_votes(session, &candidate_hash)?.map(Into::into);
+					let para_id =
+						votes.as_ref().map(|votes| votes.candidate_receipt.descriptor.para_id());
+					let votes = votes
+						.map(|votes| {
+							let valid = votes.valid.raw().iter().map(|(index, (kind, _))| {
+								(*index, DisputeStatement::Valid(kind.clone()))
+							});
+							let invalid = votes.invalid.iter().map(|(index, (kind, _))| {
+								(*index, DisputeStatement::Invalid(*kind))
+							});
+							valid.chain(invalid).collect()
+						})
+						.unwrap_or_default();
+
+					reports.push(DisputeReport {
+						session,
+						candidate_hash,
+						para_id,
+						status,
+						votes,
+						participation: self.history.participation(session, candidate_hash),
+						slashing: self.history.slashing(session, candidate_hash),
+					});
+				}
+
+				let _ = tx.send(reports);
+			},
 			DisputeCoordinatorMessage::QueryCandidateVotes(query, tx) => {
 				gum::trace!(target: LOG_TARGET, "DisputeCoordinatorMessage::QueryCandidateVotes");
 				let mut query_output = Vec::new();
@@ -109,6 +109,9 @@ mod metrics;
 /// Status tracking of disputes (`DisputeStatus`).
 mod status;
 
+/// In-memory history of participations and slashes of recent disputes, for reporting.
+mod history;
+
 use crate::status::Clock;
 
 #[cfg(test)]
@@ -32,8 +32,8 @@ use futures::{
 use polkadot_node_subsystem_util::database::Database;
 
 use polkadot_node_primitives::{
-	DisputeMessage, DisputeStatus, SignedDisputeStatement, SignedFullStatement, Statement,
-	DISPUTE_WINDOW,
+	DisputeMessage, DisputeStatus, ParticipationOutcome, SignedDisputeStatement,
+	SignedFullStatement, Statement, DISPUTE_WINDOW, MAX_DISPUTE_REPORTS_PER_QUERY,
 };
 use polkadot_node_subsystem::{
 	messages::{
@@ -1090,6 +1090,114 @@ fn dispute_gets_confirmed_via_participation() {
 	});
 }
 
+#[test]
+fn dispute_reports_contain_votes_and_participation() {
+	test_harness(|mut test_state, mut virtual_overseer| {
+		Box::pin(async move {
+			let session = 1;
+
+			test_state.handle_resume_sync(&mut virtual_overseer, session).await;
+
+			let candidate_receipt = make_valid_candidate_receipt();
+			let candidate_hash = candidate_receipt.hash();
+
+			test_state
+				.activate_leaf_at_session(
+					&mut virtual_overseer,
+					session,
+					1,
+					vec![make_candidate_backed_event(candidate_receipt.clone())],
+				)
+				.await;
+
+			let (valid_vote, invalid_vote) = generate_opposing_votes_pair(
+				&test_state,
+				ValidatorIndex(3),
+				ValidatorIndex(1),
+				candidate_hash,
+				session,
+				VoteType::Explicit,
+			)
+			.await;
+
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::ImportStatements {
+						candidate_receipt: candidate_receipt.clone(),
+						session,
+						statements: vec![
+							(valid_vote, ValidatorIndex(3)),
+							(invalid_vote, ValidatorIndex(1)),
+						],
+						pending_confirmation: None,
+					},
+				})
+				.await;
+			handle_disabled_validators_queries(&mut virtual_overseer, Vec::new()).await;
+			handle_approval_vote_request(&mut virtual_overseer, &candidate_hash, HashMap::new())
+				.await;
+
+			participation_with_distribution(
+				&mut virtual_overseer,
+				&candidate_hash,
+				candidate_receipt.commitments_hash,
+			)
+			.await;
+
+			let (tx, rx) = oneshot::channel();
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::QueryDisputeReports {
+						after: None,
+						limit: MAX_DISPUTE_REPORTS_PER_QUERY,
+						tx,
+					},
+				})
+				.await;
+
+			let reports = rx.await.unwrap();
+			assert_eq!(reports.len(), 1);
+			let report = &reports[0];
+			assert_eq!(report.session, session);
+			assert_eq!(report.candidate_hash, candidate_hash);
+			assert_eq!(report.para_id, Some(candidate_receipt.descriptor.para_id()));
+			assert_eq!(report.status, DisputeStatus::Active);
+			assert_eq!(report.participation, Some(ParticipationOutcome::Valid));
+			assert_eq!(report.slashing, None);
+
+			// Our own valid vote got imported alongside the explicit ones.
+			let (valid, invalid): (Vec<_>, Vec<_>) = report
+				.votes
+				.iter()
+				.partition(|(_, statement)| matches!(statement, DisputeStatement::Valid(_)));
+			assert_eq!(valid.len(), 2);
+			assert!(valid.iter().any(|(index, _)| *index == ValidatorIndex(3)));
+			assert_eq!(
+				invalid.into_iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+				vec![ValidatorIndex(1)]
+			);
+
+			// Pages are bounded by the limit and start after the given dispute.
+			for (after, limit) in [(None, 0), (Some((session, candidate_hash)), 1)] {
+				let (tx, rx) = oneshot::channel();
+				virtual_overseer
+					.send(FromOrchestra::Communication {
+						msg: DisputeCoordinatorMessage::QueryDisputeReports { after, limit, tx },
+					})
+					.await;
+				assert!(rx.await.unwrap().is_empty());
+			}
+
+			virtual_overseer.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+
+			// No more messages expected:
+			assert!(virtual_overseer.try_recv().await.is_none());
+
+			test_state
+		})
+	});
+}
+
 #[test]
 fn dispute_gets_confirmed_at_byzantine_threshold() {
 	test_harness(|mut test_state, mut virtual_overseer| {
@@ -24,12 +24,15 @@ use polkadot_primitives::{
 use sc_executor_common::{
 	error::WasmError,
 	runtime_blob::RuntimeBlob,
-	wasm_runtime::{HeapAllocStrategy, WasmModule as _},
+	wasm_runtime::{HeapAllocStrategy, WasmModule},
 };
-use sc_executor_wasmtime::{Config, DeterministicStackLimit, Semantics, WasmtimeRuntime};
+use sc_executor_wasmtime::{Config, DeterministicStackLimit, Semantics};
 use sp_core::storage::{ChildInfo, TrackedStorageKey};
 use sp_externalities::MultiRemovalResults;
-use std::any::{Any, TypeId};
+use std::{
+	any::{Any, TypeId},
+	sync::OnceLock,
+};
 
 // Memory configuration
 //
@@ -45,6 +48,8 @@ use std::any::{Any, TypeId};
 // are used for these needs by default.
 const DEFAULT_HEAP_PAGES_ESTIMATE: u32 = 32;
 const EXTRA_HEAP_PAGES: u32 = 2048;
+// The size of a WASM page, which the memory limits are expressed in.
+const WASM_PAGE_SIZE: u64 = 64 * 1024;
 
 // VALUES OF THE DEFAULT CONFIGURATION SHOULD NEVER BE CHANGED
 // They are used as base values for the execution environment parametrization.
@@ -128,6 +133,9 @@ pub unsafe fn execute_artifact(
 
 /// Constructs the runtime for the given PVF, given the artifact bytes.
 ///
+/// If [`ExecutorParam::PolkaVm`] is set, the artifact is expected to be produced by
+/// [`prepare_polkavm`] rather than by [`prepare`].
+///
 /// # Safety
 ///
 /// The caller must ensure that the compiled artifact passed here was:
@@ -138,7 +146,14 @@ pub unsafe fn execute_artifact(
 pub unsafe fn create_runtime_from_artifact_bytes(
 	compiled_artifact_blob: &[u8],
 	executor_params: &ExecutorParams,
-) -> Result<WasmtimeRuntime, WasmError> {
+) -> Result<Box<dyn WasmModule>, WasmError> {
+	if executor_params.polkavm_enabled() {
+		return create_polkavm_runtime(
+			&prevalidate_polkavm(compiled_artifact_blob)?,
+			executor_params,
+		)
+	}
+
 	let mut config = DEFAULT_CONFIG.clone();
 	config.semantics = params_to_wasmtime_semantics(executor_params).0;
 
@@ -146,6 +161,7 @@ pub unsafe fn create_runtime_from_artifact_bytes(
 		compiled_artifact_blob,
 		config,
 	)
+	.map(|runtime| Box::new(runtime) as Box<dyn WasmModule>)
 }
 
 /// Takes the default config and overwrites any settings with existing executor parameters.
@@ -169,7 +185,9 @@ pub fn params_to_wasmtime_semantics(par: &ExecutorParams) -> (Semantics, Determi
 			ExecutorParam::WasmExtBulkMemory => sem.wasm_bulk_memory = true,
 			ExecutorParam::PrecheckingMaxMemory(_) |
 			ExecutorParam::PvfPrepTimeout(_, _) |
-			ExecutorParam::PvfExecTimeout(_, _) => (), /* Not used here */
+			ExecutorParam::PvfExecTimeout(_, _) |
+			ExecutorParam::PolkaVm |
+			ExecutorParam::PolkaVmGasLimit(_) => (), /* Not used here */
 		}
 	}
 	sem.deterministic_stack_limit = Some(stack_limit.clone());
@@ -194,6 +212,83 @@ pub fn prepare(
 	sc_executor_wasmtime::prepare_runtime_artifact(blob, &semantics)
 }
 
+/// Runs the prevalidation on the given PolkaVM code. Returns the parsed program blob if it
+/// succeeds.
+pub fn prevalidate_polkavm(code: &[u8]) -> Result<polkavm::ProgramBlob, WasmError> {
+	Ok(polkavm::ProgramBlob::parse(polkavm::ArcBytes::from(code))?)
+}
+
+/// Runs preparation on the given PolkaVM code. If successful, it returns the artifact which can
+/// then be used to pass into `Executor::execute` after writing it to the disk.
+///
+/// PolkaVM modules cannot be serialized, so the artifact is the program blob itself. It is
+/// compiled and linked against the host functions here to reject broken programs early, and
+/// compiled again on every execution.
+pub fn prepare_polkavm(
+	code: &[u8],
+	executor_params: &ExecutorParams,
+) -> Result<Vec<u8>, WasmError> {
+	let blob = prevalidate_polkavm(code)?;
+	create_polkavm_runtime(&blob, executor_params)?;
+	Ok(code.to_vec())
+}
+
+/// Returns the memory, in bytes, the given PolkaVM code needs for its data and stack.
+pub fn polkavm_static_memory(code: &[u8]) -> Result<u64, WasmError> {
+	let blob = prevalidate_polkavm(code)?;
+	let module =
+		polkavm::Module::from_blob(polkavm_engine()?, &polkavm::ModuleConfig::default(), blob)?;
+	Ok(sc_executor_polkavm::static_memory_size(&module))
+}
+
+/// Instantiates the PolkaVM module for the given program, metering gas if
+/// [`ExecutorParam::PolkaVmGasLimit`] is set and limiting its memory to
+/// [`polkavm_max_memory`].
+fn create_polkavm_runtime(
+	blob: &polkavm::ProgramBlob,
+	executor_params: &ExecutorParams,
+) -> Result<Box<dyn WasmModule>, WasmError> {
+	sc_executor_polkavm::create_runtime_with_engine::<HostFunctions>(
+		polkavm_engine()?,
+		blob,
+		executor_params.polkavm_gas_limit(),
+		Some(polkavm_max_memory(executor_params).try_into().unwrap_or(u32::MAX)),
+	)
+}
+
+/// Returns the maximum amount of memory, in bytes, a PVF may use under PolkaVM.
+///
+/// It's the maximum linear memory a Wasm PVF gets with the same executor params, so that
+/// [`ExecutorParam::MaxMemoryPages`] limits PVFs the same way regardless of the backend.
+fn polkavm_max_memory(executor_params: &ExecutorParams) -> u64 {
+	let max_pages = executor_params
+		.iter()
+		.find_map(|param| match param {
+			ExecutorParam::MaxMemoryPages(max_pages) => Some(*max_pages),
+			_ => None,
+		})
+		.unwrap_or(EXTRA_HEAP_PAGES);
+	u64::from(max_pages.saturating_add(DEFAULT_HEAP_PAGES_ESTIMATE)) * WASM_PAGE_SIZE
+}
+
+/// Returns the PolkaVM engine shared by all the PVFs prepared or executed by this process.
+///
+/// We're using the interpreter backend. The compiler backend runs programs in sandbox processes of
+/// its own, which the PVF workers are not allowed to spawn (see `worker::security`), and the
+/// interpreter executes and meters gas identically on every machine, regardless of the CPU.
+fn polkavm_engine() -> Result<&'static polkavm::Engine, WasmError> {
+	static ENGINE: OnceLock<Result<polkavm::Engine, String>> = OnceLock::new();
+
+	ENGINE
+		.get_or_init(|| {
+			let mut config = polkavm::Config::new();
+			config.set_backend(Some(polkavm::BackendKind::Interpreter));
+			polkavm::Engine::new(&config).map_err(|err| err.to_string())
+		})
+		.as_ref()
+		.map_err(|err| WasmError::Other(err.clone()))
+}
+
 /// Available host functions. We leave out:
 ///
 /// 1. storage related stuff (PVF doesn't have a notion of a persistent storage/trie)
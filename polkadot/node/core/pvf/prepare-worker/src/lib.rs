@@ -35,7 +35,9 @@ use nix::{
 	unistd::{ForkResult, Pid},
 };
 use polkadot_node_core_pvf_common::{
-	executor_interface::{prepare, prevalidate},
+	executor_interface::{
+		polkavm_static_memory, prepare, prepare_polkavm, prevalidate, prevalidate_polkavm,
+	},
 	worker::{pipe2_cloexec, PipeFd, WorkerInfo},
 };
 
@@ -309,6 +311,20 @@ fn prepare_artifact(pvf: PvfPrepData) -> Result<PrepareOutcome, PrepareError> {
 	.map_err(|e| PrepareError::CouldNotDecompressCodeBlob(e.to_string()))?;
 	let observed_wasm_code_len = raw_validation_code.len() as u32;
 
+	if pvf.executor_params().polkavm_enabled() {
+		if let Err(err) = prevalidate_polkavm(&raw_validation_code) {
+			return Err(PrepareError::Prevalidation(format!("{:?}", err)))
+		}
+
+		return match prepare_polkavm(&raw_validation_code, &pvf.executor_params()) {
+			Ok(compiled_artifact) => Ok(PrepareOutcome {
+				compiled_artifact: CompiledArtifact::new(compiled_artifact),
+				observed_wasm_code_len,
+			}),
+			Err(err) => Err(PrepareError::Preparation(format!("{:?}", err))),
+		}
+	}
+
 	let blob = match prevalidate(&raw_validation_code) {
 		Err(err) => return Err(PrepareError::Prevalidation(format!("{:?}", err))),
 		Ok(b) => b,
@@ -328,6 +344,18 @@ fn runtime_construction_check(
 	artifact_bytes: &[u8],
 	executor_params: &ExecutorParams,
 ) -> Result<(), PrepareError> {
+	// PolkaVM allocates the memory of a program when it's instantiated rather than when it's
+	// compiled, so the memory tracker doesn't account for it while pre-checking.
+	if let Some(max_memory) = executor_params.prechecking_max_memory() {
+		if executor_params.polkavm_enabled() {
+			let static_memory = polkavm_static_memory(artifact_bytes)
+				.map_err(|err| PrepareError::RuntimeConstruction(format!("{:?}", err)))?;
+			if static_memory > max_memory {
+				return Err(PrepareError::OutOfMemory)
+			}
+		}
+	}
+
 	// SAFETY: We just compiled this artifact.
 	let result = unsafe { create_runtime_from_artifact_bytes(artifact_bytes, executor_params) };
 	result
@@ -0,0 +1,200 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! PVF host integration tests checking the PolkaVM execution backend against the Wasmtime one.
+//!
+//! The test parachains are only built as PolkaVM programs if the workspace is built with
+//! `SUBSTRATE_RUNTIME_TARGET=riscv`, which the `test-pvf-polkavm` CI job does. The tests executing
+//! them pick the backend matching the blob, so that the same expectations are checked against
+//! whichever backend the blob is built for.
+
+use super::{TestHost, VALIDATION_CODE_BOMB_LIMIT};
+use assert_matches::assert_matches;
+use codec::{Decode, Encode};
+use polkadot_node_core_pvf::{InvalidCandidate, PrepareError, ValidationError};
+use polkadot_node_primitives::PoV;
+use polkadot_parachain_primitives::primitives::{
+	BlockData as GenericBlockData, HeadData as GenericHeadData,
+};
+use polkadot_primitives::{ExecutorParam, ExecutorParams, PersistedValidationData};
+use sp_core::H256;
+use test_parachain_adder::{hash_state, BlockData, HeadData};
+
+fn is_polkavm_blob(code: &[u8]) -> bool {
+	sp_maybe_compressed_blob::decompress(code, VALIDATION_CODE_BOMB_LIMIT as usize)
+		.unwrap()
+		.starts_with(b"PVM\0")
+}
+
+/// Returns the executor params selecting the backend the `code` has been built for.
+fn executor_params_for(code: &[u8], gas_limit: u64) -> ExecutorParams {
+	if is_polkavm_blob(code) {
+		ExecutorParams::from(
+			&[ExecutorParam::PolkaVm, ExecutorParam::PolkaVmGasLimit(gas_limit)][..],
+		)
+	} else {
+		ExecutorParams::default()
+	}
+}
+
+fn adder_candidate(add: u64) -> (HeadData, PersistedValidationData, PoV) {
+	let parent_head = HeadData { number: 0, parent_hash: [0; 32], post_state: hash_state(0) };
+	let block_data = BlockData { state: 0, add };
+	let pvd = PersistedValidationData {
+		parent_head: GenericHeadData(parent_head.encode()),
+		relay_parent_number: 1u32,
+		relay_parent_storage_root: H256::default(),
+		max_pov_size: 4096 * 1024,
+	};
+	let pov = PoV { block_data: GenericBlockData(block_data.encode()) };
+	(parent_head, pvd, pov)
+}
+
+/// Same as [`adder_candidate`], with `padding` bytes appended to the block data, which the adder
+/// ignores but which still have to be copied into the memory of the PVF.
+fn padded_adder_candidate(add: u64, padding: usize) -> (PersistedValidationData, PoV) {
+	let (_, pvd, mut pov) = adder_candidate(add);
+	pov.block_data.0.resize(pov.block_data.0.len() + padding, 0);
+	(pvd, pov)
+}
+
+#[tokio::test]
+async fn polkavm_backend_rejects_wasm_code() {
+	let host = TestHost::new().await;
+	let code = test_parachain_adder::wasm_binary_unwrap();
+	if is_polkavm_blob(code) {
+		return
+	}
+
+	let result = host
+		.precheck_pvf(code, ExecutorParams::from(&[ExecutorParam::PolkaVm][..]))
+		.await;
+
+	assert_matches!(result, Err(PrepareError::Prevalidation(_)));
+}
+
+#[tokio::test]
+async fn backends_agree_on_malformed_code() {
+	let host = TestHost::new().await;
+	let code =
+		sp_maybe_compressed_blob::compress(b"PVM\0garbage", VALIDATION_CODE_BOMB_LIMIT as usize)
+			.unwrap();
+
+	let wasmtime = host.precheck_pvf(&code, ExecutorParams::default()).await;
+	let polkavm = host
+		.precheck_pvf(&code, ExecutorParams::from(&[ExecutorParam::PolkaVm][..]))
+		.await;
+
+	assert_matches!(wasmtime, Err(PrepareError::Prevalidation(_)));
+	assert_matches!(polkavm, Err(PrepareError::Prevalidation(_)));
+}
+
+#[tokio::test]
+async fn adder_produces_same_head_on_either_backend() {
+	let host = TestHost::new().await;
+	let code = test_parachain_adder::wasm_binary_unwrap();
+	let (parent_head, pvd, pov) = adder_candidate(512);
+
+	let ret = host
+		.validate_candidate(code, pvd, pov, executor_params_for(code, u64::MAX), H256::default())
+		.await
+		.unwrap();
+
+	let new_head = HeadData::decode(&mut &ret.head_data.0[..]).unwrap();
+
+	assert_eq!(new_head.number, 1);
+	assert_eq!(new_head.parent_hash, parent_head.hash());
+	assert_eq!(new_head.post_state, hash_state(512));
+}
+
+#[tokio::test]
+async fn polkavm_running_out_of_gas_is_invalid() {
+	let host = TestHost::new().await;
+	let code = test_parachain_adder::wasm_binary_unwrap();
+	if !is_polkavm_blob(code) {
+		return
+	}
+	let (_, pvd, pov) = adder_candidate(512);
+
+	let result = host
+		.validate_candidate(code, pvd, pov, executor_params_for(code, 1), H256::default())
+		.await;
+
+	assert_matches!(
+		result,
+		Err(ValidationError::Invalid(InvalidCandidate::WorkerReportedInvalid(msg))) if msg.contains("out of gas")
+	);
+}
+
+#[tokio::test]
+async fn polkavm_prechecking_out_of_memory() {
+	let host = TestHost::new().await;
+	let code = test_parachain_adder::wasm_binary_unwrap();
+	if !is_polkavm_blob(code) {
+		return
+	}
+
+	let result = host
+		.precheck_pvf(
+			code,
+			ExecutorParams::from(
+				&[ExecutorParam::PolkaVm, ExecutorParam::PrecheckingMaxMemory(64 * 1024)][..],
+			),
+		)
+		.await;
+
+	assert_matches!(result, Err(PrepareError::OutOfMemory));
+}
+
+#[tokio::test]
+async fn polkavm_max_memory_pages_are_enforced() {
+	let host = TestHost::new().await;
+	let code = test_parachain_adder::wasm_binary_unwrap();
+	if !is_polkavm_blob(code) {
+		return
+	}
+	// More than the 33 pages, i.e. ~2 MiB, allowed by `MaxMemoryPages(1)`.
+	let padding = 3 * 1024 * 1024;
+
+	let (pvd, pov) = padded_adder_candidate(512, padding);
+	let result = host
+		.validate_candidate(
+			code,
+			pvd,
+			pov,
+			ExecutorParams::from(&[ExecutorParam::PolkaVm, ExecutorParam::MaxMemoryPages(1)][..]),
+			H256::default(),
+		)
+		.await;
+
+	assert_matches!(
+		result,
+		Err(ValidationError::Invalid(InvalidCandidate::WorkerReportedInvalid(msg))) if msg.contains("memory limit exceeded")
+	);
+
+	let (pvd, pov) = padded_adder_candidate(512, padding);
+	let result = host
+		.validate_candidate(
+			code,
+			pvd,
+			pov,
+			ExecutorParams::from(&[ExecutorParam::PolkaVm][..]),
+			H256::default(),
+		)
+		.await;
+
+	assert_matches!(result, Ok(_));
+}
@@ -42,6 +42,12 @@ enum NemesisVariant {
 	DisputeFinalizedCandidates(DisputeFinalizedCandidatesOptions),
 	/// Spam many request statements instead of sending a single one.
 	SpamStatementRequests(SpamStatementRequestsOptions),
+	/// Withhold availability chunks and data of backed candidates.
+	WithholdAvailabilityChunks(WithholdAvailabilityChunksOptions),
+	/// Delay approval assignments and votes to induce no-shows.
+	DelayApprovalAssignments(DelayApprovalAssignmentsOptions),
+	/// Sign and distribute conflicting availability bitfields.
+	EquivocateBitfields(EquivocateBitfieldsOptions),
 }
 
 #[derive(Debug, Parser)]
@@ -105,6 +111,28 @@ impl MalusCli {
 
 				polkadot_cli::run_node(cli, SpamStatementRequests { spam_factor }, finality_delay)?
 			},
+			NemesisVariant::WithholdAvailabilityChunks(opts) => {
+				let WithholdAvailabilityChunksOptions { percentage, cli } = opts;
+
+				polkadot_cli::run_node(
+					cli,
+					WithholdAvailabilityChunks { percentage },
+					finality_delay,
+				)?
+			},
+			NemesisVariant::DelayApprovalAssignments(opts) => {
+				let DelayApprovalAssignmentsOptions { delay_ms, mut cli } = opts;
+				// The messages are held back on their way to approval distribution, which is
+				// replaced by approval-voting-parallel when enabled.
+				cli.run.enable_approval_voting_parallel = false;
+
+				polkadot_cli::run_node(cli, DelayApprovalAssignments { delay_ms }, finality_delay)?
+			},
+			NemesisVariant::EquivocateBitfields(opts) => {
+				let EquivocateBitfieldsOptions { cli } = opts;
+
+				polkadot_cli::run_node(cli, EquivocateBitfields, finality_delay)?
+			},
 		}
 		Ok(())
 	}
@@ -244,4 +272,58 @@ mod tests {
 			assert!(opts.cli.run.base.bob);
 		});
 	}
+
+	#[test]
+	fn withhold_availability_chunks_works() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"withhold-availability-chunks",
+			"--percentage",
+			"50",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::WithholdAvailabilityChunks(opts),
+			..
+		} => {
+			assert_eq!(opts.percentage, 50);
+			assert!(opts.cli.run.base.bob);
+		});
+	}
+
+	#[test]
+	fn delay_approval_assignments_delay_works() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"delay-approval-assignments",
+			"--delay-ms",
+			"12000",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::DelayApprovalAssignments(opts),
+			..
+		} => {
+			assert_eq!(opts.delay_ms, 12000);
+			assert!(opts.cli.run.base.bob);
+		});
+	}
+
+	#[test]
+	fn equivocate_bitfields_works() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"equivocate-bitfields",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::EquivocateBitfields(run),
+			..
+		} => {
+			assert!(run.cli.run.base.bob);
+		});
+	}
 }
@@ -0,0 +1,212 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node variant that delays its approval assignments and votes.
+//!
+//! This malus variant checks candidates honestly, but holds back the assignments and approvals
+//! issued by approval voting before handing them over to approval distribution. If the delay is
+//! long enough, the other validators consider the node a no-show and need to cover for it by
+//! triggering more assignments.
+//!
+//! Approval-voting-parallel is always disabled for this variant, since it takes over the
+//! messages otherwise sent to approval distribution.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use codec::Encode;
+use futures_timer::Delay;
+use polkadot_cli::{
+	service::{
+		AuxStore, Error, ExtendedOverseerGenArgs, Handle, Overseer, OverseerConnector, OverseerGen,
+		OverseerGenArgs, OverseerHandle,
+	},
+	validator_overseer_builder, Cli,
+};
+use polkadot_node_subsystem::SpawnGlue;
+use polkadot_node_subsystem_types::{ChainApiBackend, RuntimeApiSubsystemClient};
+use sp_core::traits::SpawnNamed;
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use std::{
+	collections::HashSet,
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+/// Wraps around approval distribution and replaces it.
+#[derive(Clone)]
+struct ApprovalDelayer<Spawner> {
+	spawner: Spawner,
+	/// Used to hand the delayed messages back to approval distribution.
+	overseer_handle: Handle,
+	/// How long to hold back the messages.
+	delay: Duration,
+	/// The encoded messages which have already been held back and must be let through.
+	delayed: Arc<Mutex<HashSet<Vec<u8>>>>,
+}
+
+impl<Spawner> ApprovalDelayer<Spawner>
+where
+	Spawner: overseer::gen::Spawner + Clone + 'static,
+{
+	/// Returns `true` if the message with the given encoding was already held back, otherwise
+	/// holds it back and sends it again once the delay elapses.
+	fn delay_once(
+		&self,
+		encoded: Vec<u8>,
+		msg: impl FnOnce() -> ApprovalDistributionMessage,
+	) -> bool {
+		let mut delayed = self.delayed.lock().expect("poisoned lock");
+		if delayed.remove(&encoded) {
+			return true
+		}
+		delayed.insert(encoded);
+
+		let msg = msg();
+		let delay = self.delay;
+		let mut overseer_handle = self.overseer_handle.clone();
+		self.spawner.spawn(
+			"malus-delay-approval",
+			Some("malus"),
+			Box::pin(async move {
+				Delay::new(delay).await;
+				overseer_handle.send_msg(msg, "malus").await;
+			}),
+		);
+		false
+	}
+}
+
+impl<Sender, Spawner> MessageInterceptor<Sender> for ApprovalDelayer<Spawner>
+where
+	Sender: overseer::ApprovalDistributionSenderTrait + Clone + Send + 'static,
+	Spawner: overseer::gen::Spawner + Clone + 'static,
+{
+	type Message = ApprovalDistributionMessage;
+
+	/// Intercept the assignments and approvals issued by approval voting and distribute them
+	/// only after the delay elapsed.
+	fn intercept_incoming(
+		&self,
+		_subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg: ApprovalDistributionMessage::DistributeAssignment(cert, claimed_candidates),
+			} => {
+				let encoded = (&cert, &claimed_candidates).encode();
+				let block_hash = cert.block_hash;
+				let already_delayed = self.delay_once(encoded, || {
+					ApprovalDistributionMessage::DistributeAssignment(
+						cert.clone(),
+						claimed_candidates.clone(),
+					)
+				});
+				if already_delayed {
+					Some(FromOrchestra::Communication {
+						msg: ApprovalDistributionMessage::DistributeAssignment(
+							cert,
+							claimed_candidates,
+						),
+					})
+				} else {
+					gum::info!(target: MALUS, ?block_hash, "😈 Delaying approval assignment.");
+					None
+				}
+			},
+			FromOrchestra::Communication {
+				msg: ApprovalDistributionMessage::DistributeApproval(vote),
+			} => {
+				let encoded = vote.encode();
+				let block_hash = vote.block_hash;
+				let already_delayed = self.delay_once(encoded, || {
+					ApprovalDistributionMessage::DistributeApproval(vote.clone())
+				});
+				if already_delayed {
+					Some(FromOrchestra::Communication {
+						msg: ApprovalDistributionMessage::DistributeApproval(vote),
+					})
+				} else {
+					gum::info!(target: MALUS, ?block_hash, "😈 Delaying approval vote.");
+					None
+				}
+			},
+			FromOrchestra::Communication { msg } => Some(FromOrchestra::Communication { msg }),
+			FromOrchestra::Signal(signal) => Some(FromOrchestra::Signal(signal)),
+		}
+	}
+}
+
+//----------------------------------------------------------------------------------
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct DelayApprovalAssignmentsOptions {
+	/// How long to delay the approval assignments and votes, in milliseconds.
+	#[clap(long, ignore_case = true, default_value_t = 30000, value_parser = clap::value_parser!(u64).range(0..=600000))]
+	pub delay_ms: u64,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// DelayApprovalAssignments implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct DelayApprovalAssignments {
+	/// How long to delay the approval assignments and votes, in milliseconds.
+	pub delay_ms: u64,
+}
+
+impl OverseerGen for DelayApprovalAssignments {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+		ext_args: Option<ExtendedOverseerGenArgs>,
+	) -> Result<(Overseer<SpawnGlue<Spawner>, Arc<RuntimeClient>>, OverseerHandle), Error>
+	where
+		RuntimeClient: RuntimeApiSubsystemClient + ChainApiBackend + AuxStore + 'static,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			"😈 Started Malus node that delays approval assignments and votes by {}ms.",
+			&self.delay_ms,
+		);
+
+		let approval_delayer = ApprovalDelayer {
+			spawner: SpawnGlue(args.spawner.clone()),
+			overseer_handle: Handle::new(connector.handle()),
+			delay: Duration::from_millis(self.delay_ms),
+			delayed: Default::default(),
+		};
+
+		validator_overseer_builder(
+			args,
+			ext_args.expect("Extended arguments required to build validator overseer are provided"),
+		)?
+		.replace_approval_distribution(move |ad_subsystem| {
+			InterceptedSubsystem::new(ad_subsystem, approval_delayer)
+		})
+		.build_with_connector(connector)
+		.map_err(|e| e.into())
+	}
+}
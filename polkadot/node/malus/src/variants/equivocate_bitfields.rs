@@ -0,0 +1,204 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node variant that equivocates on its availability bitfields.
+//!
+//! For every bitfield issued by bitfield signing, this malus variant signs a second bitfield for
+//! the same relay parent with all availability bits inverted and hands both of them over to
+//! bitfield distribution. The conflicting bitfield reaches the local provisioner and all peers
+//! which have not yet received the honest one, so different parts of the network end up with
+//! different views on which candidates are available.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	service::{
+		AuxStore, Error, ExtendedOverseerGenArgs, Handle, Overseer, OverseerConnector, OverseerGen,
+		OverseerGenArgs, OverseerHandle,
+	},
+	validator_overseer_builder, Cli,
+};
+use polkadot_node_subsystem::SpawnGlue;
+use polkadot_node_subsystem_types::{ChainApiBackend, OverseerSignal, RuntimeApiSubsystemClient};
+use polkadot_node_subsystem_util::Validator;
+use polkadot_primitives::{AvailabilityBitfield, Hash};
+use sp_core::traits::SpawnNamed;
+use sp_keystore::KeystorePtr;
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use std::{
+	collections::HashSet,
+	sync::{Arc, Mutex},
+};
+
+/// Wraps around bitfield distribution and replaces it.
+#[derive(Clone)]
+struct BitfieldEquivocator<Spawner> {
+	spawner: Spawner,
+	keystore: KeystorePtr,
+	/// Used to hand the conflicting bitfields back to bitfield distribution.
+	overseer_handle: Handle,
+	/// The relay parents we already equivocated on.
+	equivocated: Arc<Mutex<HashSet<Hash>>>,
+}
+
+impl<Sender, Spawner> MessageInterceptor<Sender> for BitfieldEquivocator<Spawner>
+where
+	Sender: overseer::BitfieldDistributionSenderTrait + Clone + Send + 'static,
+	Spawner: overseer::gen::Spawner + Clone + 'static,
+{
+	type Message = BitfieldDistributionMessage;
+
+	/// Intercept our own bitfields and distribute a conflicting one next to each of them.
+	fn intercept_incoming(
+		&self,
+		subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg: BitfieldDistributionMessage::DistributeBitfield(relay_parent, signed_bitfield),
+			} => {
+				// The conflicting bitfield comes back through here and must be let through.
+				if !self.equivocated.lock().expect("poisoned lock").insert(relay_parent) {
+					return Some(FromOrchestra::Communication {
+						msg: BitfieldDistributionMessage::DistributeBitfield(
+							relay_parent,
+							signed_bitfield,
+						),
+					})
+				}
+
+				let conflicting = AvailabilityBitfield(!signed_bitfield.payload().0.clone());
+				let keystore = self.keystore.clone();
+				let mut sender = subsystem_sender.clone();
+				let mut overseer_handle = self.overseer_handle.clone();
+				self.spawner.spawn(
+					"malus-equivocate-bitfield",
+					Some("malus"),
+					Box::pin(async move {
+						let validator = Validator::new(relay_parent, keystore.clone(), &mut sender);
+						let validator = match validator.await {
+							Ok(validator) => validator,
+							Err(err) => {
+								gum::info!(
+									target: MALUS,
+									?relay_parent,
+									?err,
+									"😈 Failed to fetch our validator data.",
+								);
+								return
+							},
+						};
+
+						let conflicting = match validator.sign(keystore, conflicting) {
+							Ok(Some(conflicting)) => conflicting,
+							_ => {
+								gum::info!(
+									target: MALUS,
+									?relay_parent,
+									"😈 Failed to sign conflicting bitfield.",
+								);
+								return
+							},
+						};
+
+						gum::info!(
+							target: MALUS,
+							?relay_parent,
+							validator_index = ?validator.index(),
+							"😈 Distributing conflicting bitfield.",
+						);
+						overseer_handle
+							.send_msg(
+								BitfieldDistributionMessage::DistributeBitfield(
+									relay_parent,
+									conflicting,
+								),
+								"malus",
+							)
+							.await;
+					}),
+				);
+
+				Some(FromOrchestra::Communication {
+					msg: BitfieldDistributionMessage::DistributeBitfield(
+						relay_parent,
+						signed_bitfield,
+					),
+				})
+			},
+			FromOrchestra::Communication { msg } => Some(FromOrchestra::Communication { msg }),
+			FromOrchestra::Signal(OverseerSignal::ActiveLeaves(update)) => {
+				let mut equivocated = self.equivocated.lock().expect("poisoned lock");
+				for deactivated in &update.deactivated {
+					equivocated.remove(deactivated);
+				}
+				Some(FromOrchestra::Signal(OverseerSignal::ActiveLeaves(update)))
+			},
+			FromOrchestra::Signal(signal) => Some(FromOrchestra::Signal(signal)),
+		}
+	}
+}
+
+//----------------------------------------------------------------------------------
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct EquivocateBitfieldsOptions {
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// EquivocateBitfields implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct EquivocateBitfields;
+
+impl OverseerGen for EquivocateBitfields {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+		ext_args: Option<ExtendedOverseerGenArgs>,
+	) -> Result<(Overseer<SpawnGlue<Spawner>, Arc<RuntimeClient>>, OverseerHandle), Error>
+	where
+		RuntimeClient: RuntimeApiSubsystemClient + ChainApiBackend + AuxStore + 'static,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(target: MALUS, "😈 Started Malus node that equivocates on bitfields.");
+
+		let ext_args =
+			ext_args.expect("Extended arguments required to build validator overseer are provided");
+
+		let bitfield_equivocator = BitfieldEquivocator {
+			spawner: SpawnGlue(args.spawner.clone()),
+			keystore: ext_args.keystore.clone(),
+			overseer_handle: Handle::new(connector.handle()),
+			equivocated: Default::default(),
+		};
+
+		validator_overseer_builder(args, ext_args)?
+			.replace_bitfield_distribution(move |bd_subsystem| {
+				InterceptedSubsystem::new(bd_subsystem, bitfield_equivocator)
+			})
+			.build_with_connector(connector)
+			.map_err(|e| e.into())
+	}
+}
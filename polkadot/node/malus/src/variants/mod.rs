@@ -18,18 +18,24 @@
 
 mod back_garbage_candidate;
 mod common;
+mod delay_approval_assignments;
 mod dispute_finalized_candidates;
 mod dispute_valid_candidates;
+mod equivocate_bitfields;
 mod spam_statement_requests;
 mod suggest_garbage_candidate;
 mod support_disabled;
+mod withhold_availability_chunks;
 
 pub(crate) use self::{
 	back_garbage_candidate::{BackGarbageCandidateOptions, BackGarbageCandidates},
+	delay_approval_assignments::{DelayApprovalAssignments, DelayApprovalAssignmentsOptions},
 	dispute_finalized_candidates::{DisputeFinalizedCandidates, DisputeFinalizedCandidatesOptions},
 	dispute_valid_candidates::{DisputeAncestorOptions, DisputeValidCandidates},
+	equivocate_bitfields::{EquivocateBitfields, EquivocateBitfieldsOptions},
 	spam_statement_requests::{SpamStatementRequests, SpamStatementRequestsOptions},
 	suggest_garbage_candidate::{SuggestGarbageCandidateOptions, SuggestGarbageCandidates},
 	support_disabled::{SupportDisabled, SupportDisabledOptions},
+	withhold_availability_chunks::{WithholdAvailabilityChunks, WithholdAvailabilityChunksOptions},
 };
 pub(crate) use common::*;
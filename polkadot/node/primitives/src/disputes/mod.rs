@@ -33,6 +33,11 @@ use polkadot_primitives::{
 /// `DisputeMessage` and related types.
 mod message;
 pub use message::{DisputeMessage, Error as DisputeMessageCheckError, UncheckedDisputeMessage};
+/// `DisputeReport` and related types.
+mod report;
+pub use report::{
+	DisputeReport, ParticipationOutcome, SlashingReport, MAX_DISPUTE_REPORTS_PER_QUERY,
+};
 mod status;
 pub use status::{dispute_is_inactive, DisputeStatus, Timestamp, ACTIVE_DURATION_SECS};
 
@@ -0,0 +1,88 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use polkadot_primitives::{
+	slashing::SlashingOffenceKind, CandidateHash, DisputeStatement, Id as ParaId, SessionIndex,
+	ValidatorIndex,
+};
+
+use super::DisputeStatus;
+
+/// The maximum number of [`DisputeReport`]s returned by a single query.
+///
+/// Every report requires loading the votes of the dispute from the database, so larger queries
+/// have to be paginated.
+pub const MAX_DISPUTE_REPORTS_PER_QUERY: u32 = 100;
+
+/// Outcome of the local node's participation in a dispute.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParticipationOutcome {
+	/// Candidate was found to be valid.
+	Valid,
+	/// Candidate was found to be invalid.
+	Invalid,
+	/// Candidate was found to be unavailable.
+	Unavailable,
+	/// Something went wrong (bug), details can be found in the logs.
+	Error,
+}
+
+impl ParticipationOutcome {
+	/// If validation was successful, get whether the candidate was valid or invalid.
+	pub fn validity(self) -> Option<bool> {
+		match self {
+			Self::Valid => Some(true),
+			Self::Invalid => Some(false),
+			Self::Unavailable | Self::Error => None,
+		}
+	}
+}
+
+/// Slashes pending for the losing side of a concluded dispute.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlashingReport {
+	/// Whether the offence is for backing or voting for an invalid candidate, or voting against a
+	/// valid one.
+	pub kind: SlashingOffenceKind,
+	/// The validators to be slashed.
+	pub offenders: Vec<ValidatorIndex>,
+	/// Whether the offence has been reported to the runtime, either by this node or by another
+	/// one.
+	pub reported: bool,
+}
+
+/// Evidence and history of a dispute, as known to the dispute coordinator.
+///
+/// Intended for node operators investigating disputes.
+#[derive(Clone, Debug)]
+pub struct DisputeReport {
+	/// The session the candidate appeared in.
+	pub session: SessionIndex,
+	/// The disputed candidate.
+	pub candidate_hash: CandidateHash,
+	/// The para the candidate belongs to, `None` if the candidate receipt is not known.
+	pub para_id: Option<ParaId>,
+	/// The current status of the dispute.
+	pub status: DisputeStatus,
+	/// All votes imported for the candidate.
+	///
+	/// A validator may appear twice, if it voted on both sides.
+	pub votes: Vec<(ValidatorIndex, DisputeStatement)>,
+	/// The outcome of this node's participation, if it participated since it was started.
+	pub participation: Option<ParticipationOutcome>,
+	/// Slashes pending for the dispute, if this node has seen any since it was started.
+	pub slashing: Option<SlashingReport>,
+}
@@ -52,9 +52,10 @@ pub mod approval;
 /// Disputes related types.
 pub mod disputes;
 pub use disputes::{
-	dispute_is_inactive, CandidateVotes, DisputeMessage, DisputeMessageCheckError, DisputeStatus,
-	InvalidDisputeVote, SignedDisputeStatement, Timestamp, UncheckedDisputeMessage,
-	ValidDisputeVote, ACTIVE_DURATION_SECS,
+	dispute_is_inactive, CandidateVotes, DisputeMessage, DisputeMessageCheckError, DisputeReport,
+	DisputeStatus, InvalidDisputeVote, ParticipationOutcome, SignedDisputeStatement,
+	SlashingReport, Timestamp, UncheckedDisputeMessage, ValidDisputeVote, ACTIVE_DURATION_SECS,
+	MAX_DISPUTE_REPORTS_PER_QUERY,
 };
 
 /// The current node version, which takes the basic SemVer form `<major>.<minor>.<patch>`.
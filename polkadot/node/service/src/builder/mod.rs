@@ -150,8 +150,14 @@ where
 			SelectRelayChain::new_longest_chain(basics.backend.clone())
 		};
 
-		let partial_components =
-			new_partial::<SelectRelayChain<_>>(&mut config, basics, select_chain)?;
+		// Only validators run the dispute coordinator queried by the disputes RPC.
+		let rpc_overseer_handle = config.role.is_authority().then(|| overseer_handle.clone());
+		let partial_components = new_partial::<SelectRelayChain<_>>(
+			&mut config,
+			basics,
+			select_chain,
+			rpc_overseer_handle,
+		)?;
 
 		let net_config = sc_network::config::FullNetworkConfiguration::<_, _, Network>::new(
 			&config.network,
@@ -456,5 +456,13 @@ sp_api::impl_runtime_apis! {
 		fn dry_run_xcm(_: VersionedLocation, _: VersionedXcm<()>) -> Result<xcm_runtime_apis::dry_run::XcmDryRunEffects<()>, xcm_runtime_apis::dry_run::Error> {
 			unimplemented!()
 		}
+
+		fn dry_run_call_traced(_: (), _: (), _: XcmVersion) -> Result<xcm_runtime_apis::dry_run::TracedDryRunEffects<xcm_runtime_apis::dry_run::CallDryRunEffects<()>>, xcm_runtime_apis::dry_run::Error> {
+			unimplemented!()
+		}
+
+		fn dry_run_xcm_traced(_: VersionedLocation, _: VersionedXcm<()>) -> Result<xcm_runtime_apis::dry_run::TracedDryRunEffects<xcm_runtime_apis::dry_run::XcmDryRunEffects<()>>, xcm_runtime_apis::dry_run::Error> {
+			unimplemented!()
+		}
 	}
 }
@@ -20,7 +20,9 @@
 use clap::Parser;
 use color_eyre::eyre;
 use colored::Colorize;
-use polkadot_subsystem_bench::{approval, availability, configuration, statement};
+use polkadot_subsystem_bench::{
+	approval, availability, collation, configuration, disputes, statement,
+};
 use pyroscope::PyroscopeAgent;
 use pyroscope_pprofrs::{pprof_backend, PprofConfig};
 use serde::{Deserialize, Serialize};
@@ -42,6 +44,10 @@ pub enum TestObjective {
 	ApprovalVoting(approval::ApprovalsOptions),
 	// Benchmark the statement-distribution subsystem
 	StatementDistribution,
+	/// Benchmark the dispute-coordinator and dispute-distribution subsystems.
+	DisputeCoordinator(disputes::DisputesOptions),
+	/// Benchmark the validator side of the collator-protocol subsystem.
+	CollatorProtocol(collation::CollatorProtocolOptions),
 }
 
 impl std::fmt::Display for TestObjective {
@@ -54,6 +60,8 @@ impl std::fmt::Display for TestObjective {
 				Self::DataAvailabilityWrite => "DataAvailabilityWrite",
 				Self::ApprovalVoting(_) => "ApprovalVoting",
 				Self::StatementDistribution => "StatementDistribution",
+				Self::DisputeCoordinator(_) => "DisputeCoordinator",
+				Self::CollatorProtocol(_) => "CollatorProtocol",
 			}
 		)
 	}
@@ -169,6 +177,16 @@ impl BenchCli {
 					env.runtime()
 						.block_on(statement::benchmark_statement_distribution(&mut env, &state))
 				},
+				TestObjective::DisputeCoordinator(ref options) => {
+					let state = disputes::TestState::new(&test_config, options);
+					let mut env = disputes::prepare_test(&state, true);
+					env.runtime().block_on(disputes::benchmark_disputes(&mut env, &state))
+				},
+				TestObjective::CollatorProtocol(ref options) => {
+					let state = collation::TestState::new(&test_config, options);
+					let mut env = collation::prepare_test(&state, true);
+					env.runtime().block_on(collation::benchmark_collator_protocol(&mut env, &state))
+				},
 			};
 			println!("\n{}\n{}", benchmark_name.purple(), usage);
 		}
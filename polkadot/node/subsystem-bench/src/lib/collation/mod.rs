@@ -0,0 +1,268 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A benchmark of the validator side of the collator-protocol subsystem fetching the collations
+//! advertised by many collators, with the claim queue bounding how many are fetched per block.
+
+use crate::{
+	dummy_builder,
+	environment::{TestEnvironment, TestEnvironmentDependencies},
+	mock::{
+		candidate_backing::MockCandidateBacking,
+		chain_api::{ChainApiState, MockChainApi},
+		network_bridge::{MockNetworkBridgeRx, MockNetworkBridgeTx},
+		prospective_parachains::MockProspectiveParachains,
+		runtime_api::{MockRuntimeApi, MockRuntimeApiCoreState},
+		AlwaysSupportsParachains,
+	},
+	network::{new_network, NetworkEmulatorHandle, NetworkInterface, NetworkInterfaceReceiver},
+	usage::BenchmarkUsage,
+};
+use colored::Colorize;
+use itertools::Itertools;
+use polkadot_collator_protocol::{CollatorProtocolSubsystem, ProtocolSide};
+use polkadot_node_metrics::metrics::Metrics;
+use polkadot_node_network_protocol::{
+	peer_set::CollationVersion, v2 as protocol_v2, CollationProtocols, ObservedRole, OurView,
+};
+use polkadot_node_subsystem::messages::{AllMessages, CollatorProtocolMessage, NetworkBridgeEvent};
+use polkadot_overseer::{
+	Handle as OverseerHandle, Overseer, OverseerConnector, OverseerMetrics, SpawnGlue,
+};
+use polkadot_primitives::{AuthorityDiscoveryId, ValidatorId};
+use sc_keystore::LocalKeystore;
+use sc_network_types::PeerId;
+use sc_service::SpawnTaskHandle;
+use serde::{Deserialize, Serialize};
+use sp_core::Pair;
+use sp_keystore::{Keystore, KeystorePtr};
+use sp_runtime::RuntimeAppPublic;
+use std::{
+	sync::Arc,
+	time::{Duration, Instant},
+};
+pub use test_state::TestState;
+
+mod test_state;
+
+const LOG_TARGET: &str = "subsystem-bench::collation";
+
+/// Parameters specific to the collator protocol benchmark
+#[derive(Debug, Clone, Serialize, Deserialize, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct CollatorProtocolOptions {
+	#[clap(short, long, default_value_t = 10)]
+	/// The number of collators advertising a collation for the para of the node in each block.
+	pub n_collators: usize,
+	#[clap(short = 'q', long, default_value_t = 3)]
+	/// The length of the claim queue, i.e. the number of collations fetched in each block.
+	pub claim_queue_len: usize,
+}
+
+fn make_keystore() -> KeystorePtr {
+	let keystore: KeystorePtr = Arc::new(LocalKeystore::in_memory());
+	Keystore::sr25519_generate_new(&*keystore, ValidatorId::ID, Some("//Node0"))
+		.expect("Insert key into keystore");
+	Keystore::sr25519_generate_new(&*keystore, AuthorityDiscoveryId::ID, Some("//Node0"))
+		.expect("Insert key into keystore");
+	keystore
+}
+
+fn build_overseer(
+	state: &TestState,
+	network: NetworkEmulatorHandle,
+	network_interface: NetworkInterface,
+	network_receiver: NetworkInterfaceReceiver,
+	dependencies: &TestEnvironmentDependencies,
+) -> (Overseer<SpawnGlue<SpawnTaskHandle>, AlwaysSupportsParachains>, OverseerHandle) {
+	let overseer_connector = OverseerConnector::with_event_capacity(64000);
+	let overseer_metrics = OverseerMetrics::try_register(&dependencies.registry).unwrap();
+	let spawn_task_handle = dependencies.task_manager.spawn_handle();
+	let mock_runtime_api = MockRuntimeApi::new(
+		state.config.clone(),
+		state.test_authorities.clone(),
+		state.candidate_receipts.clone(),
+		Default::default(),
+		Default::default(),
+		test_state::SESSION_INDEX,
+		MockRuntimeApiCoreState::Scheduled,
+	)
+	.with_claim_queue_len(state.options.claim_queue_len);
+	let chain_api_state = ChainApiState { block_headers: state.block_headers.clone() };
+	let mock_chain_api = MockChainApi::new(chain_api_state);
+	let mock_prospective_parachains = MockProspectiveParachains::new(state.pvd.clone());
+	let mock_candidate_backing = MockCandidateBacking::new(
+		state.config.clone(),
+		state.node_pair(),
+		state.pvd.clone(),
+		state.own_backing_group.clone(),
+		state.committed_candidate_receipts(),
+	);
+	let collator_protocol = CollatorProtocolSubsystem::new(ProtocolSide::Validator {
+		keystore: make_keystore(),
+		eviction_policy: Default::default(),
+		metrics: Metrics::try_register(&dependencies.registry).unwrap(),
+	});
+	let network_bridge_tx = MockNetworkBridgeTx::new(
+		network,
+		network_interface.subsystem_sender(),
+		state.test_authorities.clone(),
+	);
+	let network_bridge_rx = MockNetworkBridgeRx::new(network_receiver, None, false);
+
+	let dummy = dummy_builder!(spawn_task_handle, overseer_metrics)
+		.replace_runtime_api(|_| mock_runtime_api)
+		.replace_chain_api(|_| mock_chain_api)
+		.replace_prospective_parachains(|_| mock_prospective_parachains)
+		.replace_candidate_backing(|_| mock_candidate_backing)
+		.replace_collator_protocol(|_| collator_protocol)
+		.replace_network_bridge_tx(|_| network_bridge_tx)
+		.replace_network_bridge_rx(|_| network_bridge_rx);
+	let (overseer, raw_handle) = dummy.build_with_connector(overseer_connector).unwrap();
+	let overseer_handle = OverseerHandle::new(raw_handle);
+
+	(overseer, overseer_handle)
+}
+
+pub fn prepare_test(state: &TestState, with_prometheus_endpoint: bool) -> TestEnvironment {
+	let dependencies = TestEnvironmentDependencies::default();
+	let (network, network_interface, network_receiver) = new_network(
+		&state.config,
+		&dependencies,
+		&state.test_authorities,
+		vec![Arc::new(state.clone())],
+	);
+	let (overseer, overseer_handle) =
+		build_overseer(state, network.clone(), network_interface, network_receiver, &dependencies);
+
+	TestEnvironment::new(
+		dependencies,
+		state.config.clone(),
+		network,
+		overseer,
+		overseer_handle,
+		state.test_authorities.clone(),
+		with_prometheus_endpoint,
+	)
+}
+
+fn network_bridge_update(
+	event: NetworkBridgeEvent<polkadot_node_network_protocol::CollatorProtocolMessage>,
+) -> AllMessages {
+	AllMessages::CollatorProtocol(CollatorProtocolMessage::NetworkBridgeUpdate(event))
+}
+
+fn peer_message(peer_id: PeerId, message: protocol_v2::CollatorProtocolMessage) -> AllMessages {
+	network_bridge_update(NetworkBridgeEvent::PeerMessage(peer_id, CollationProtocols::V2(message)))
+}
+
+pub async fn benchmark_collator_protocol(
+	env: &mut TestEnvironment,
+	state: &TestState,
+) -> BenchmarkUsage {
+	// The collators are emulated by the peers connected to the node, so that the collations are
+	// fetched over the emulated network.
+	let collators = state
+		.test_authorities
+		.validator_authority_id
+		.iter()
+		.enumerate()
+		.filter_map(|(i, id)| if env.network().is_peer_connected(id) { Some(i) } else { None })
+		.take(state.options.n_collators)
+		.collect_vec();
+	assert_eq!(
+		collators.len(),
+		state.options.n_collators,
+		"Not enough peers connected to the node to emulate the collators"
+	);
+
+	let config = env.config().clone();
+
+	env.metrics().set_n_validators(config.n_validators);
+	env.metrics().set_n_cores(config.n_cores);
+
+	let test_start = Instant::now();
+	for block_info in state.block_infos.iter() {
+		let block_num = block_info.number as usize;
+		gum::info!(target: LOG_TARGET, "Current block {}/{} {:?}", block_num, config.num_blocks, block_info.hash);
+		env.metrics().set_current_block(block_num);
+		env.import_block(block_info.clone()).await;
+		env.send_message(network_bridge_update(NetworkBridgeEvent::OurViewChange(OurView::new(
+			[block_info.hash],
+			0,
+		))))
+		.await;
+
+		// Collators can only declare once the para is assigned to the core of the node.
+		if block_num == 1 {
+			for (collator_index, &peer_index) in collators.iter().enumerate() {
+				let peer_id = state.test_authorities.peer_ids[peer_index];
+				let collator_pair = &state.collator_pairs[collator_index];
+				env.send_message(network_bridge_update(NetworkBridgeEvent::PeerConnected(
+					peer_id,
+					ObservedRole::Full,
+					CollationVersion::V2.into(),
+					None,
+				)))
+				.await;
+				env.send_message(peer_message(
+					peer_id,
+					protocol_v2::CollatorProtocolMessage::Declare(
+						collator_pair.public(),
+						state.own_para,
+						collator_pair.sign(&protocol_v2::declare_signature_payload(&peer_id)),
+					),
+				))
+				.await;
+			}
+		}
+
+		let advertisements =
+			state.advertisements.get(&block_info.hash).expect("Generated at test start");
+		for (&peer_index, candidate_hash) in collators.iter().zip(advertisements) {
+			env.send_message(peer_message(
+				state.test_authorities.peer_ids[peer_index],
+				protocol_v2::CollatorProtocolMessage::AdvertiseCollation {
+					relay_parent: block_info.hash,
+					candidate_hash: *candidate_hash,
+					parent_head_data_hash: state.pvd.parent_head.hash(),
+				},
+			))
+			.await;
+		}
+
+		let expected_fetches = state.expected_fetches_per_block();
+		loop {
+			let fetched = state.fetched_count(&block_info.hash);
+			if fetched >= expected_fetches {
+				break
+			}
+			gum::debug!(target: LOG_TARGET, "Waiting for collations to be fetched: {}/{}", fetched, expected_fetches);
+			tokio::time::sleep(Duration::from_millis(50)).await;
+		}
+	}
+
+	let duration: u128 = test_start.elapsed().as_millis();
+	gum::info!(target: LOG_TARGET, "All blocks processed in {}", format!("{:?}ms", duration).cyan());
+	gum::info!(target: LOG_TARGET,
+		"Avg block time: {}",
+		format!("{} ms", test_start.elapsed().as_millis() / env.config().num_blocks as u128).red()
+	);
+
+	env.stop().await;
+	env.collect_resource_usage(&["collator-protocol"], false)
+}
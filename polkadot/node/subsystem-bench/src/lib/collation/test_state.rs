@@ -0,0 +1,244 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	configuration::{TestAuthorities, TestConfiguration},
+	mock::runtime_api::session_info_for_peers,
+	network::{HandleNetworkMessage, NetworkMessage},
+	NODE_UNDER_TEST,
+};
+use codec::Encode;
+use polkadot_node_network_protocol::request_response::{v2::CollationFetchingResponse, Requests};
+use polkadot_node_primitives::{BlockData, PoV};
+use polkadot_node_subsystem_test_helpers::mock::new_block_import_info;
+use polkadot_overseer::BlockInfo;
+use polkadot_primitives::{
+	vstaging::{
+		CandidateReceiptV2 as CandidateReceipt,
+		CommittedCandidateReceiptV2 as CommittedCandidateReceipt, MutateDescriptorV2,
+	},
+	BlockNumber, CandidateHash, CollatorPair, CoreIndex, Hash, Header, Id, PersistedValidationData,
+	ValidatorIndex, ValidatorPair,
+};
+use polkadot_primitives_test_helpers::{
+	dummy_candidate_receipt_v2, dummy_committed_candidate_receipt_v2, dummy_head_data, dummy_pvd,
+};
+use sc_network::ProtocolName;
+use sp_core::{Pair, H256};
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+};
+
+use super::CollatorProtocolOptions;
+
+pub const SESSION_INDEX: u32 = 0;
+
+#[derive(Clone)]
+pub struct TestState {
+	// Full test config
+	pub config: TestConfiguration,
+	// Collator protocol specific options
+	pub options: CollatorProtocolOptions,
+	// Authority keys for the network emulation.
+	pub test_authorities: TestAuthorities,
+	// Relay chain block infos
+	pub block_infos: Vec<BlockInfo>,
+	// Relay chain block headers
+	pub block_headers: HashMap<H256, Header>,
+	// Map from generated candidate receipts, one per core, used to build the claim queue
+	pub candidate_receipts: HashMap<H256, Vec<CandidateReceipt>>,
+	// PersistedValidationData, we use one for all candidates
+	pub pvd: PersistedValidationData,
+	// The core our backing group is assigned to
+	pub own_core: CoreIndex,
+	// The para scheduled on our core
+	pub own_para: Id,
+	// The indices of the validators in our backing group
+	pub own_backing_group: Vec<ValidatorIndex>,
+	// Keys of the emulated collators
+	pub collator_pairs: Vec<CollatorPair>,
+	// Collations advertised in each block, indexed by collator
+	pub advertisements: HashMap<H256, Vec<CandidateHash>>,
+	// Generated collations by candidate hash
+	pub collations: HashMap<CandidateHash, (CommittedCandidateReceipt, PoV)>,
+	// Tracks which collations were fetched by the node under test
+	pub fetched_tracker: HashMap<CandidateHash, Arc<AtomicBool>>,
+}
+
+impl TestState {
+	pub fn new(config: &TestConfiguration, options: &CollatorProtocolOptions) -> Self {
+		assert!(options.n_collators > 0, "At least one collator must advertise collations");
+		assert!(options.claim_queue_len > 0, "The claim queue can not be empty");
+
+		let test_authorities = config.generate_authorities();
+		let groups = session_info_for_peers(config, &test_authorities).validator_groups;
+		// Groups are not rotated during the test, so each group backs the core with its index.
+		let (own_group_index, own_backing_group) = groups
+			.iter()
+			.enumerate()
+			.find(|(_, group)| group.contains(&ValidatorIndex(NODE_UNDER_TEST)))
+			.map(|(index, group)| (index, group.clone()))
+			.expect("The node under test is in a backing group");
+		assert!(own_group_index < config.n_cores, "The backing group of the node has no core");
+		let own_core = CoreIndex(own_group_index as u32);
+
+		let mut state = Self {
+			config: config.clone(),
+			options: options.clone(),
+			test_authorities,
+			block_infos: (1..=config.num_blocks).map(generate_block_info).collect(),
+			block_headers: Default::default(),
+			candidate_receipts: Default::default(),
+			pvd: dummy_pvd(dummy_head_data(), 0),
+			own_core,
+			own_para: Id::new(own_core.0 + 1),
+			own_backing_group,
+			collator_pairs: (0..options.n_collators)
+				.map(|index| {
+					CollatorPair::from_string(&format!("//Collator{index}"), None).unwrap()
+				})
+				.collect(),
+			advertisements: Default::default(),
+			collations: Default::default(),
+			fetched_tracker: Default::default(),
+		};
+
+		state.block_headers = state.block_infos.iter().map(generate_block_header).collect();
+
+		let pov_sizes = config.pov_sizes();
+		for block_info in state.block_infos.iter() {
+			for core_idx in 0..config.n_cores {
+				let mut receipt = dummy_candidate_receipt_v2(block_info.hash);
+				receipt.descriptor.set_para_id(Id::new(core_idx as u32 + 1));
+				receipt.descriptor.set_core_index(CoreIndex(core_idx as u32));
+				receipt.descriptor.set_session_index(SESSION_INDEX);
+				state.candidate_receipts.entry(block_info.hash).or_default().push(receipt);
+			}
+
+			for collator_idx in 0..options.n_collators {
+				let pov_size = pov_sizes[collator_idx % pov_sizes.len()];
+				// Prefix the block data with the collator index so that each collation is unique.
+				let mut block_data = (collator_idx as u32).encode();
+				block_data.resize(pov_size.max(block_data.len()), 0);
+				let pov = PoV { block_data: BlockData(block_data) };
+
+				let mut receipt = dummy_committed_candidate_receipt_v2(block_info.hash);
+				receipt.descriptor.set_para_id(state.own_para);
+				receipt.descriptor.set_core_index(state.own_core);
+				receipt.descriptor.set_session_index(SESSION_INDEX);
+				receipt.descriptor.set_persisted_validation_data_hash(state.pvd.hash());
+				receipt.descriptor.set_pov_hash(pov.hash());
+
+				let candidate_hash = receipt.hash();
+				state.advertisements.entry(block_info.hash).or_default().push(candidate_hash);
+				state.fetched_tracker.insert(candidate_hash, Arc::new(AtomicBool::new(false)));
+				state.collations.insert(candidate_hash, (receipt, pov));
+			}
+		}
+
+		state
+	}
+
+	/// Returns the pair of the node under test, used to sign the `Seconded` statements.
+	pub fn node_pair(&self) -> ValidatorPair {
+		self.test_authorities
+			.validator_pairs
+			.get(NODE_UNDER_TEST as usize)
+			.expect("The node under test has keys")
+			.clone()
+	}
+
+	/// Returns the number of collations the node under test is expected to fetch in each block,
+	/// bounded by the claims its core has in the claim queue.
+	pub fn expected_fetches_per_block(&self) -> usize {
+		self.options.claim_queue_len.min(self.options.n_collators)
+	}
+
+	/// Returns the number of collations advertised in the given block which have been fetched.
+	pub fn fetched_count(&self, block_hash: &H256) -> usize {
+		self.advertisements
+			.get(block_hash)
+			.expect("Generated at test start")
+			.iter()
+			.filter(|candidate_hash| {
+				self.fetched_tracker
+					.get(candidate_hash)
+					.expect("Generated at test start")
+					.load(Ordering::SeqCst)
+			})
+			.count()
+	}
+
+	/// Returns the committed receipts of all the generated collations.
+	pub fn committed_candidate_receipts(
+		&self,
+	) -> HashMap<CandidateHash, CommittedCandidateReceipt> {
+		self.collations
+			.iter()
+			.map(|(candidate_hash, (receipt, _))| (*candidate_hash, receipt.clone()))
+			.collect()
+	}
+}
+
+fn generate_block_info(block_num: usize) -> BlockInfo {
+	new_block_import_info(Hash::repeat_byte(block_num as u8), block_num as BlockNumber)
+}
+
+fn generate_block_header(info: &BlockInfo) -> (H256, Header) {
+	(
+		info.hash,
+		Header {
+			digest: Default::default(),
+			number: info.number,
+			parent_hash: info.parent_hash,
+			extrinsics_root: Default::default(),
+			state_root: Default::default(),
+		},
+	)
+}
+
+#[async_trait::async_trait]
+impl HandleNetworkMessage for TestState {
+	async fn handle(
+		&self,
+		message: NetworkMessage,
+		_node_sender: &mut futures::channel::mpsc::UnboundedSender<NetworkMessage>,
+	) -> Option<NetworkMessage> {
+		match message {
+			NetworkMessage::RequestFromNode(_authority_id, Requests::CollationFetchingV2(req)) => {
+				let candidate_hash = req.payload.candidate_hash;
+				let (receipt, pov) = self
+					.collations
+					.get(&candidate_hash)
+					.expect("Only generated collations are advertised");
+				self.fetched_tracker
+					.get(&candidate_hash)
+					.expect("Generated at test start")
+					.store(true, Ordering::SeqCst);
+
+				let response =
+					CollationFetchingResponse::Collation(receipt.to_plain(), pov.clone());
+				let _ = req.pending_response.send(Ok((response.encode(), ProtocolName::from(""))));
+				None
+			},
+			_ => Some(message),
+		}
+	}
+}
@@ -0,0 +1,231 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A benchmark of the dispute-coordinator and dispute-distribution subsystems handling a storm of
+//! disputes raised by the emulated validators.
+
+use crate::{
+	dummy_builder,
+	environment::{TestEnvironment, TestEnvironmentDependencies, GENESIS_HASH},
+	mock::{
+		approval_voting_parallel::MockApprovalVotingParallel,
+		authority_discovery::MockAuthorityDiscovery,
+		availability_recovery::MockAvailabilityRecovery,
+		candidate_validation::MockCandidateValidation,
+		chain_api::{ChainApiState, MockChainApi},
+		network_bridge::{MockNetworkBridgeRx, MockNetworkBridgeTx},
+		runtime_api::{MockRuntimeApi, MockRuntimeApiCoreState},
+		AlwaysSupportsParachains,
+	},
+	network::{new_network, NetworkEmulatorHandle, NetworkInterface, NetworkInterfaceReceiver},
+	usage::BenchmarkUsage,
+};
+use codec::Encode;
+use colored::Colorize;
+use itertools::Itertools;
+use polkadot_dispute_distribution::DisputeDistributionSubsystem;
+use polkadot_node_core_dispute_coordinator::{Config, DisputeCoordinatorSubsystem};
+use polkadot_node_metrics::metrics::Metrics;
+use polkadot_node_network_protocol::request_response::{IncomingRequest, ReqProtocolNames};
+use polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter;
+use polkadot_overseer::{
+	Handle as OverseerHandle, Overseer, OverseerConnector, OverseerMetrics, SpawnGlue,
+};
+use polkadot_primitives::{AuthorityDiscoveryId, Block, Hash, ValidatorId};
+use sc_keystore::LocalKeystore;
+use sc_service::SpawnTaskHandle;
+use serde::{Deserialize, Serialize};
+use sp_keystore::Keystore;
+use sp_runtime::RuntimeAppPublic;
+use std::{
+	sync::Arc,
+	time::{Duration, Instant},
+};
+pub use test_state::TestState;
+
+mod test_state;
+
+const LOG_TARGET: &str = "subsystem-bench::disputes";
+
+/// Parameters specific to the disputes benchmark
+#[derive(Debug, Clone, Serialize, Deserialize, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct DisputesOptions {
+	#[clap(short, long, default_value_t = 10)]
+	/// The number of candidates disputed in each block, at most `n_cores`.
+	pub n_disputes: usize,
+	#[clap(short = 'v', long, default_value_t = 1)]
+	/// The number of emulated validators sending a valid vote for each dispute.
+	pub n_votes: usize,
+}
+
+fn make_keystore() -> Arc<LocalKeystore> {
+	let keystore = Arc::new(LocalKeystore::in_memory());
+	Keystore::sr25519_generate_new(&*keystore, ValidatorId::ID, Some("//Node0"))
+		.expect("Insert key into keystore");
+	Keystore::sr25519_generate_new(&*keystore, AuthorityDiscoveryId::ID, Some("//Node0"))
+		.expect("Insert key into keystore");
+	keystore
+}
+
+fn build_overseer(
+	state: &TestState,
+	network: NetworkEmulatorHandle,
+	network_interface: NetworkInterface,
+	network_receiver: NetworkInterfaceReceiver,
+	dependencies: &TestEnvironmentDependencies,
+) -> (Overseer<SpawnGlue<SpawnTaskHandle>, AlwaysSupportsParachains>, OverseerHandle) {
+	let overseer_connector = OverseerConnector::with_event_capacity(64000);
+	let overseer_metrics = OverseerMetrics::try_register(&dependencies.registry).unwrap();
+	let spawn_task_handle = dependencies.task_manager.spawn_handle();
+	let mock_runtime_api = MockRuntimeApi::new(
+		state.config.clone(),
+		state.test_authorities.clone(),
+		state.candidate_receipts.clone(),
+		state.candidate_events.clone(),
+		Default::default(),
+		test_state::SESSION_INDEX,
+		MockRuntimeApiCoreState::Occupied,
+	);
+	let chain_api_state = ChainApiState { block_headers: state.block_headers.clone() };
+	let mock_chain_api = MockChainApi::new(chain_api_state);
+	let (dispute_req_receiver, dispute_req_cfg) = IncomingRequest::get_config_receiver::<
+		Block,
+		sc_network::NetworkWorker<Block, Hash>,
+	>(&ReqProtocolNames::new(GENESIS_HASH, None));
+	let keystore = make_keystore();
+	let db = DbAdapter::new(kvdb_memorydb::create(1), &[]);
+	let dispute_coordinator = DisputeCoordinatorSubsystem::new(
+		Arc::new(db),
+		Config { col_dispute_data: 0 },
+		keystore.clone(),
+		Metrics::try_register(&dependencies.registry).unwrap(),
+		true,
+	);
+	let dispute_distribution = DisputeDistributionSubsystem::new(
+		keystore,
+		dispute_req_receiver,
+		MockAuthorityDiscovery::new(state.test_authorities.peer_id_to_authority.clone()),
+		Metrics::try_register(&dependencies.registry).unwrap(),
+	);
+	let network_bridge_tx = MockNetworkBridgeTx::new(
+		network,
+		network_interface.subsystem_sender(),
+		state.test_authorities.clone(),
+	);
+	let network_bridge_rx =
+		MockNetworkBridgeRx::new(network_receiver, Some(dispute_req_cfg), false);
+
+	let dummy = dummy_builder!(spawn_task_handle, overseer_metrics)
+		.replace_runtime_api(|_| mock_runtime_api)
+		.replace_chain_api(|_| mock_chain_api)
+		.replace_availability_recovery(|_| MockAvailabilityRecovery::new())
+		.replace_candidate_validation(|_| MockCandidateValidation::new())
+		.replace_approval_voting_parallel(|_| MockApprovalVotingParallel::new())
+		.replace_dispute_coordinator(|_| dispute_coordinator)
+		.replace_dispute_distribution(|_| dispute_distribution)
+		.replace_network_bridge_tx(|_| network_bridge_tx)
+		.replace_network_bridge_rx(|_| network_bridge_rx);
+	let (overseer, raw_handle) = dummy.build_with_connector(overseer_connector).unwrap();
+	let overseer_handle = OverseerHandle::new(raw_handle);
+
+	(overseer, overseer_handle)
+}
+
+pub fn prepare_test(state: &TestState, with_prometheus_endpoint: bool) -> TestEnvironment {
+	let dependencies = TestEnvironmentDependencies::default();
+	let (network, network_interface, network_receiver) = new_network(
+		&state.config,
+		&dependencies,
+		&state.test_authorities,
+		vec![Arc::new(state.clone())],
+	);
+	let (overseer, overseer_handle) =
+		build_overseer(state, network.clone(), network_interface, network_receiver, &dependencies);
+
+	TestEnvironment::new(
+		dependencies,
+		state.config.clone(),
+		network,
+		overseer,
+		overseer_handle,
+		state.test_authorities.clone(),
+		with_prometheus_endpoint,
+	)
+}
+
+pub async fn benchmark_disputes(env: &mut TestEnvironment, state: &TestState) -> BenchmarkUsage {
+	let connected_validators = state
+		.test_authorities
+		.validator_authority_id
+		.iter()
+		.enumerate()
+		.filter_map(|(i, id)| if env.network().is_peer_connected(id) { Some(i) } else { None })
+		.collect_vec();
+
+	let config = env.config().clone();
+
+	env.metrics().set_n_validators(config.n_validators);
+	env.metrics().set_n_cores(config.n_cores);
+
+	// The responses are only awaited by the network emulator, keep the receivers alive until
+	// the end of the test.
+	let mut pending_responses = vec![];
+
+	let test_start = Instant::now();
+	for block_info in state.block_infos.iter() {
+		let block_num = block_info.number as usize;
+		gum::info!(target: LOG_TARGET, "Current block {}/{} {:?}", block_num, config.num_blocks, block_info.hash);
+		env.metrics().set_current_block(block_num);
+		env.import_block(block_info.clone()).await;
+
+		for (voter, request) in state.dispute_requests(&block_info.hash, &connected_validators) {
+			let (pending_response, response_receiver) = futures::channel::oneshot::channel();
+			let authority_id = &state.test_authorities.validator_authority_id[voter];
+			env.network()
+				.send_request_from_peer(
+					authority_id,
+					sc_network::config::IncomingRequest {
+						peer: state.test_authorities.peer_ids[voter],
+						payload: request.encode(),
+						pending_response,
+					},
+				)
+				.expect("Voters are connected");
+			pending_responses.push(response_receiver);
+		}
+
+		loop {
+			if state.votes_received(&block_info.hash, &connected_validators) {
+				break
+			}
+			gum::debug!(target: LOG_TARGET, "Waiting for the votes of the node to be distributed");
+			tokio::time::sleep(Duration::from_millis(50)).await;
+		}
+	}
+
+	let duration: u128 = test_start.elapsed().as_millis();
+	gum::info!(target: LOG_TARGET, "All blocks processed in {}", format!("{:?}ms", duration).cyan());
+	gum::info!(target: LOG_TARGET,
+		"Avg block time: {}",
+		format!("{} ms", test_start.elapsed().as_millis() / env.config().num_blocks as u128).red()
+	);
+
+	env.stop().await;
+	drop(pending_responses);
+	env.collect_resource_usage(&["dispute-coordinator", "dispute-distribution"], false)
+}
@@ -0,0 +1,256 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	configuration::{TestAuthorities, TestConfiguration},
+	mock::runtime_api::session_info_for_peers,
+	network::{HandleNetworkMessage, NetworkMessage},
+};
+use codec::Encode;
+use itertools::Itertools;
+use polkadot_node_network_protocol::request_response::{
+	v1::{DisputeRequest, DisputeResponse},
+	Requests,
+};
+use polkadot_node_primitives::{InvalidDisputeVote, UncheckedDisputeMessage, ValidDisputeVote};
+use polkadot_node_subsystem_test_helpers::mock::new_block_import_info;
+use polkadot_overseer::BlockInfo;
+use polkadot_primitives::{
+	vstaging::{CandidateEvent, CandidateReceiptV2 as CandidateReceipt, MutateDescriptorV2},
+	BlockNumber, CandidateHash, CoreIndex, ExplicitDisputeStatement, GroupIndex, Hash, Header, Id,
+	InvalidDisputeStatementKind, SessionInfo, ValidDisputeStatementKind, ValidatorIndex,
+	ValidatorSignature,
+};
+use polkadot_primitives_test_helpers::{dummy_candidate_receipt_v2, dummy_head_data};
+use sc_network::ProtocolName;
+use sp_core::{Pair, H256};
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+};
+
+use super::DisputesOptions;
+
+pub const SESSION_INDEX: u32 = 0;
+
+#[derive(Clone)]
+pub struct TestState {
+	// Full test config
+	pub config: TestConfiguration,
+	// Dispute specific options
+	pub options: DisputesOptions,
+	// Authority keys for the network emulation.
+	pub test_authorities: TestAuthorities,
+	// Relay chain block infos
+	pub block_infos: Vec<BlockInfo>,
+	// Relay chain block headers
+	pub block_headers: HashMap<H256, Header>,
+	// Map from generated candidate receipts
+	pub candidate_receipts: HashMap<H256, Vec<CandidateReceipt>>,
+	// Candidates included per block
+	pub candidate_events: HashMap<H256, Vec<CandidateEvent>>,
+	// Session info
+	pub session_info: SessionInfo,
+	// Tracks which validators received the vote of the node under test for a disputed candidate
+	pub votes_tracker: HashMap<CandidateHash, Vec<Arc<AtomicBool>>>,
+}
+
+impl TestState {
+	pub fn new(config: &TestConfiguration, options: &DisputesOptions) -> Self {
+		assert!(
+			options.n_disputes <= config.n_cores,
+			"Only candidates included on the available cores can be disputed"
+		);
+		assert!(options.n_votes > 0, "At least one validator must vote in each dispute");
+
+		let test_authorities = config.generate_authorities();
+		let session_info = session_info_for_peers(config, &test_authorities);
+		let mut state = Self {
+			config: config.clone(),
+			options: options.clone(),
+			test_authorities,
+			block_infos: (1..=config.num_blocks).map(generate_block_info).collect(),
+			block_headers: Default::default(),
+			candidate_receipts: Default::default(),
+			candidate_events: Default::default(),
+			session_info,
+			votes_tracker: Default::default(),
+		};
+
+		state.block_headers = state.block_infos.iter().map(generate_block_header).collect();
+
+		for block_info in state.block_infos.iter() {
+			for core_idx in 0..config.n_cores {
+				let mut receipt = dummy_candidate_receipt_v2(block_info.hash);
+				receipt.descriptor.set_para_id(Id::new(core_idx as u32 + 1));
+				receipt.descriptor.set_core_index(CoreIndex(core_idx as u32));
+				receipt.descriptor.set_session_index(SESSION_INDEX);
+
+				state.candidate_events.entry(block_info.hash).or_default().push(
+					CandidateEvent::CandidateIncluded(
+						receipt.clone(),
+						dummy_head_data(),
+						CoreIndex(core_idx as u32),
+						GroupIndex(core_idx as u32),
+					),
+				);
+				if core_idx < options.n_disputes {
+					state.votes_tracker.insert(
+						receipt.hash(),
+						(0..config.n_validators)
+							.map(|_| Arc::new(AtomicBool::new(false)))
+							.collect(),
+					);
+				}
+				state.candidate_receipts.entry(block_info.hash).or_default().push(receipt);
+			}
+		}
+
+		state
+	}
+
+	/// Returns the candidates disputed in the given block.
+	pub fn disputed_candidates(&self, block_hash: &H256) -> &[CandidateReceipt] {
+		let candidates = self.candidate_receipts.get(block_hash).expect("Generated at test start");
+		&candidates[..self.options.n_disputes]
+	}
+
+	/// Creates the requests the emulated validators send to initiate disputes for the candidates
+	/// of the given block, along with the index of the sending validator.
+	///
+	/// Each dispute is raised by a different validator voting against the candidate, the
+	/// following `n_votes` validators vote for it, each of them sending a request.
+	pub fn dispute_requests(
+		&self,
+		block_hash: &H256,
+		connected_validators: &[usize],
+	) -> Vec<(usize, DisputeRequest)> {
+		assert!(
+			self.options.n_votes < connected_validators.len(),
+			"Not enough connected validators to vote in disputes"
+		);
+
+		let mut voters = connected_validators.iter().cycle();
+		self.disputed_candidates(block_hash)
+			.iter()
+			.flat_map(|candidate| {
+				let candidate_hash = candidate.hash();
+				let disputer = *voters.next().expect("cycle is infinite; qed");
+				let invalid_vote = InvalidDisputeVote {
+					validator_index: ValidatorIndex(disputer as u32),
+					signature: self.sign_explicit(false, candidate_hash, disputer),
+					kind: InvalidDisputeStatementKind::Explicit,
+				};
+
+				voters
+					.by_ref()
+					.take(self.options.n_votes)
+					.map(|&voter| {
+						let valid_vote = ValidDisputeVote {
+							validator_index: ValidatorIndex(voter as u32),
+							signature: self.sign_explicit(true, candidate_hash, voter),
+							kind: ValidDisputeStatementKind::Explicit,
+						};
+						let request = DisputeRequest(UncheckedDisputeMessage {
+							candidate_receipt: candidate.clone(),
+							session_index: SESSION_INDEX,
+							invalid_vote: invalid_vote.clone(),
+							valid_vote,
+						});
+						(voter, request)
+					})
+					.collect_vec()
+			})
+			.collect()
+	}
+
+	/// Returns `true` if all the `validators` received the vote of the node under test for each
+	/// of the disputed candidates of the given block.
+	pub fn votes_received(&self, block_hash: &H256, validators: &[usize]) -> bool {
+		self.disputed_candidates(block_hash).iter().all(|candidate| {
+			let tracker =
+				self.votes_tracker.get(&candidate.hash()).expect("Generated at test start");
+			validators.iter().all(|&index| tracker[index].load(Ordering::SeqCst))
+		})
+	}
+
+	fn sign_explicit(
+		&self,
+		valid: bool,
+		candidate_hash: CandidateHash,
+		validator_index: usize,
+	) -> ValidatorSignature {
+		let payload = ExplicitDisputeStatement { valid, candidate_hash, session: SESSION_INDEX }
+			.signing_payload();
+		let pair = self
+			.test_authorities
+			.validator_pairs
+			.get(validator_index)
+			.expect("All validators have keys");
+
+		pair.sign(&payload[..])
+	}
+}
+
+fn generate_block_info(block_num: usize) -> BlockInfo {
+	new_block_import_info(Hash::repeat_byte(block_num as u8), block_num as BlockNumber)
+}
+
+fn generate_block_header(info: &BlockInfo) -> (H256, Header) {
+	(
+		info.hash,
+		Header {
+			digest: Default::default(),
+			number: info.number,
+			parent_hash: info.parent_hash,
+			extrinsics_root: Default::default(),
+			state_root: Default::default(),
+		},
+	)
+}
+
+#[async_trait::async_trait]
+impl HandleNetworkMessage for TestState {
+	async fn handle(
+		&self,
+		message: NetworkMessage,
+		_node_sender: &mut futures::channel::mpsc::UnboundedSender<NetworkMessage>,
+	) -> Option<NetworkMessage> {
+		match message {
+			NetworkMessage::RequestFromNode(authority_id, Requests::DisputeSendingV1(req)) => {
+				let index = self
+					.test_authorities
+					.validator_authority_id
+					.iter()
+					.position(|v| v == &authority_id)
+					.unwrap();
+				let candidate_hash = req.payload.0.candidate_receipt.hash();
+				if let Some(tracker) = self.votes_tracker.get(&candidate_hash) {
+					tracker[index].store(true, Ordering::SeqCst);
+				}
+
+				let _ = req
+					.pending_response
+					.send(Ok((DisputeResponse::Confirmed.encode(), ProtocolName::from(""))));
+				None
+			},
+			_ => Some(message),
+		}
+	}
+}
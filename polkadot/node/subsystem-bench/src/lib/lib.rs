@@ -19,8 +19,10 @@ pub const NODE_UNDER_TEST: u32 = 0;
 
 pub mod approval;
 pub mod availability;
+pub mod collation;
 pub mod configuration;
 pub(crate) mod display;
+pub mod disputes;
 pub(crate) mod environment;
 pub(crate) mod keyring;
 pub(crate) mod mock;
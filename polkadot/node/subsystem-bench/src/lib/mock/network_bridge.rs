@@ -39,6 +39,7 @@ const LOG_TARGET: &str = "subsystem-bench::network-bridge";
 const ALLOWED_PROTOCOLS: &[&str] = &[
 	"/ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff/req_chunk/2",
 	"/ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff/req_attested_candidate/2",
+	"/ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff/send_dispute/1",
 ];
 
 /// A mock of the network bridge tx subsystem.
@@ -172,6 +173,12 @@ impl MockNetworkBridgeTx {
 							}
 						}
 					},
+					NetworkBridgeTxMessage::SendCollationMessage(..) |
+					NetworkBridgeTxMessage::SendCollationMessages(..) |
+					NetworkBridgeTxMessage::DisconnectPeer(..) => {
+						// The collation peer set is not emulated, collators talk to the node
+						// directly via the overseer.
+					},
 					message => unimplemented!("Unexpected network bridge message {:?}", message),
 				},
 			}
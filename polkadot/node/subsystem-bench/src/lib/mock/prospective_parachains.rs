@@ -21,13 +21,16 @@ use polkadot_node_subsystem::{
 	messages::ProspectiveParachainsMessage, overseer, SpawnedSubsystem, SubsystemError,
 };
 use polkadot_node_subsystem_types::OverseerSignal;
-use polkadot_primitives::Hash;
+use polkadot_primitives::{Hash, PersistedValidationData};
 
-pub struct MockProspectiveParachains {}
+pub struct MockProspectiveParachains {
+	// PersistedValidationData, we use one for all candidates
+	pvd: PersistedValidationData,
+}
 
 impl MockProspectiveParachains {
-	pub fn new() -> Self {
-		Self {}
+	pub fn new(pvd: PersistedValidationData) -> Self {
+		Self { pvd }
 	}
 }
 
@@ -64,6 +67,9 @@ impl MockProspectiveParachains {
 						)
 						.unwrap();
 					},
+					ProspectiveParachainsMessage::GetProspectiveValidationData(_req, tx) => {
+						tx.send(Some(self.pvd.clone())).unwrap();
+					},
 					_ => {
 						unimplemented!("Unexpected chain-api message")
 					},
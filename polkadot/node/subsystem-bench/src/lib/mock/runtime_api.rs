@@ -118,6 +118,16 @@ impl MockRuntimeApi {
 		}
 	}
 
+	/// Assigns each para to its core for the next `claim_queue_len` blocks, allowing more than
+	/// one candidate per parachain to be built on the same relay parent.
+	pub fn with_claim_queue_len(mut self, claim_queue_len: usize) -> Self {
+		for paras in self.state.claim_queue.values_mut() {
+			let para_id = *paras.front().expect("Each core has a para assigned");
+			*paras = std::iter::repeat(para_id).take(claim_queue_len).collect();
+		}
+		self
+	}
+
 	fn session_info(&self) -> SessionInfo {
 		session_info_for_peers(&self.config, &self.state.authorities)
 	}
@@ -330,6 +340,19 @@ impl MockRuntimeApi {
 						RuntimeApiMessage::Request(_parent, RuntimeApiRequest::ClaimQueue(tx)) => {
 							tx.send(Ok(self.state.claim_queue.clone())).unwrap();
 						},
+						RuntimeApiMessage::Request(
+							_parent,
+							RuntimeApiRequest::FetchOnChainVotes(tx),
+						) => {
+							// No disputes are ever concluded on chain.
+							tx.send(Ok(None)).unwrap();
+						},
+						RuntimeApiMessage::Request(
+							_parent,
+							RuntimeApiRequest::UnappliedSlashes(tx),
+						) => {
+							tx.send(Ok(vec![])).unwrap();
+						},
 						// Long term TODO: implement more as needed.
 						message => {
 							unimplemented!("Unexpected runtime-api message: {:?}", message)
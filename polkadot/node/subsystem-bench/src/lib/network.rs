@@ -1025,8 +1025,15 @@ impl RequestExt for Requests {
 					None
 				}
 			},
+			Requests::DisputeSendingV1(request) => {
+				if let Recipient::Authority(authority_id) = &request.peer {
+					Some(authority_id)
+				} else {
+					None
+				}
+			},
 			// Requested by PeerId
-			Requests::AttestedCandidateV2(_) => None,
+			Requests::AttestedCandidateV2(_) | Requests::CollationFetchingV2(_) => None,
 			request => {
 				unimplemented!("RequestAuthority not implemented for {:?}", request)
 			},
@@ -1039,6 +1046,10 @@ impl RequestExt for Requests {
 				Recipient::Authority(_) => None,
 				Recipient::Peer(peer_id) => Some(peer_id),
 			},
+			Requests::CollationFetchingV2(request) => match &request.peer {
+				Recipient::Authority(_) => None,
+				Recipient::Peer(peer_id) => Some(peer_id),
+			},
 			request => {
 				unimplemented!("peer_id() is not implemented for {:?}", request)
 			},
@@ -1050,6 +1061,8 @@ impl RequestExt for Requests {
 			Requests::ChunkFetching(outgoing_request) => outgoing_request.pending_response,
 			Requests::AvailableDataFetchingV1(outgoing_request) =>
 				outgoing_request.pending_response,
+			Requests::DisputeSendingV1(outgoing_request) => outgoing_request.pending_response,
+			Requests::CollationFetchingV2(outgoing_request) => outgoing_request.pending_response,
 			_ => unimplemented!("unsupported request type"),
 		}
 	}
@@ -1063,6 +1076,10 @@ impl RequestExt for Requests {
 				std::mem::replace(&mut outgoing_request.pending_response, new_sender),
 			Requests::AttestedCandidateV2(outgoing_request) =>
 				std::mem::replace(&mut outgoing_request.pending_response, new_sender),
+			Requests::DisputeSendingV1(outgoing_request) =>
+				std::mem::replace(&mut outgoing_request.pending_response, new_sender),
+			Requests::CollationFetchingV2(outgoing_request) =>
+				std::mem::replace(&mut outgoing_request.pending_response, new_sender),
 			_ => unimplemented!("unsupported request type"),
 		}
 	}
@@ -1075,6 +1092,9 @@ impl RequestExt for Requests {
 				outgoing_request.payload.encoded_size(),
 			Requests::AttestedCandidateV2(outgoing_request) =>
 				outgoing_request.payload.encoded_size(),
+			Requests::DisputeSendingV1(outgoing_request) => outgoing_request.payload.encoded_size(),
+			Requests::CollationFetchingV2(outgoing_request) =>
+				outgoing_request.payload.encoded_size(),
 			_ => unimplemented!("received an unexpected request"),
 		}
 	}
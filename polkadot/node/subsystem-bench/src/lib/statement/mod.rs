@@ -96,7 +96,7 @@ fn build_overseer(
 	);
 	let chain_api_state = ChainApiState { block_headers: state.block_headers.clone() };
 	let mock_chain_api = MockChainApi::new(chain_api_state);
-	let mock_prospective_parachains = MockProspectiveParachains::new();
+	let mock_prospective_parachains = MockProspectiveParachains::new(state.pvd.clone());
 	let mock_candidate_backing = MockCandidateBacking::new(
 		state.config.clone(),
 		state
@@ -107,6 +107,12 @@ fn build_overseer(
 			.clone(),
 		state.pvd.clone(),
 		state.own_backing_group.clone(),
+		state
+			.commited_candidate_receipts
+			.values()
+			.flatten()
+			.map(|receipt| (receipt.hash(), receipt.clone()))
+			.collect(),
 	);
 	let (candidate_req_receiver, candidate_req_cfg) =
 		IncomingRequest::get_config_receiver::<Block, sc_network::NetworkWorker<Block, Hash>>(
@@ -37,7 +37,7 @@ use polkadot_node_primitives::{
 		v2::{CandidateBitfield, IndirectAssignmentCertV2, IndirectSignedApprovalVoteV2},
 	},
 	AvailableData, BabeEpoch, BlockWeight, CandidateVotes, CollationGenerationConfig,
-	CollationSecondedSignal, DisputeMessage, DisputeStatus, ErasureChunk, PoV,
+	CollationSecondedSignal, DisputeMessage, DisputeReport, DisputeStatus, ErasureChunk, PoV,
 	SignedDisputeStatement, SignedFullStatement, SignedFullStatementWithPVD, SubmitCollationParams,
 	ValidationResult,
 };
@@ -322,6 +322,20 @@ pub enum DisputeCoordinatorMessage {
 	/// Fetch a list of all active disputes that the coordinator is aware of.
 	/// These disputes are either not yet concluded or recently concluded.
 	ActiveDisputes(oneshot::Sender<Vec<(SessionIndex, CandidateHash, DisputeStatus)>>),
+	/// Fetch reports of recent disputes, as returned by `RecentDisputes`, ordered by session and
+	/// candidate hash.
+	///
+	/// Reports contain the votes, as well as this node's participation outcome and pending
+	/// slashes, for investigating disputes. They are returned in pages of at most
+	/// `MAX_DISPUTE_REPORTS_PER_QUERY` reports.
+	QueryDisputeReports {
+		/// Only disputes after this one are returned, e.g. the last one of the previous page.
+		after: Option<(SessionIndex, CandidateHash)>,
+		/// The maximum number of reports to return, capped at `MAX_DISPUTE_REPORTS_PER_QUERY`.
+		limit: u32,
+		/// The channel to send the reports to.
+		tx: oneshot::Sender<Vec<DisputeReport>>,
+	},
 	/// Get candidate votes for a candidate.
 	QueryCandidateVotes(
 		Vec<(SessionIndex, CandidateHash)>,
@@ -68,6 +68,8 @@ pub enum MalusType {
 	None,
 	/// Submit the same collations to all assigned cores.
 	DuplicateCollations,
+	/// Submit collations with a PoV exceeding the maximum PoV size.
+	OversizedCollations,
 }
 
 #[allow(missing_docs)]
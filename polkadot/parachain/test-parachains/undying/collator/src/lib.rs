@@ -398,11 +398,33 @@ impl Collator {
 		}
 	}
 
+	/// Submit the same collation to all cores assigned to the parachain.
 	pub fn send_same_collations_to_all_assigned_cores(
+		&self,
+		full_node: &NewFull,
+		overseer_handle: Handle,
+		para_id: ParaId,
+	) {
+		self.send_malicious_collations(full_node, overseer_handle, para_id, false)
+	}
+
+	/// Submit collations with a PoV exceeding the maximum PoV size to one of the cores assigned
+	/// to the parachain.
+	pub fn send_oversized_collations(
+		&self,
+		full_node: &NewFull,
+		overseer_handle: Handle,
+		para_id: ParaId,
+	) {
+		self.send_malicious_collations(full_node, overseer_handle, para_id, true)
+	}
+
+	fn send_malicious_collations(
 		&self,
 		full_node: &NewFull,
 		mut overseer_handle: Handle,
 		para_id: ParaId,
+		oversized_pov: bool,
 	) {
 		let client = full_node.client.clone();
 
@@ -415,7 +437,7 @@ impl Collator {
 			.spawn("malus-undying-collator", None, async move {
 				// Subscribe to relay chain block import notifications. In each iteration, build a
 				// collation in response to a block import notification and submits it to all cores
-				// assigned to the parachain.
+				// assigned to the parachain, or to one of them if the PoV is oversized.
 				let mut import_notifications = client.import_notification_stream();
 
 				while let Some(notification) = import_notifications.next().await {
@@ -452,7 +474,7 @@ impl Collator {
 						continue;
 					}
 
-					if scheduled_cores.len() == 1 {
+					if scheduled_cores.len() == 1 && !oversized_pov {
 						log::info!(
 							target: LOG_TARGET,
 							"Malus collator configured with duplicate collations, but only 1 core assigned. \
@@ -539,17 +561,32 @@ impl Collator {
 
 					// Apply compression to the block data.
 					let pov = {
-						let pov = collation.proof_of_validity.into_compressed();
+						let mut pov = collation.proof_of_validity.into_compressed();
 						let encoded_size = pov.encoded_size();
 						let max_pov_size = validation_data.max_pov_size as usize;
 
+						// Pad the PoV past the maximum size, validators must reject it.
+						if oversized_pov {
+							if encoded_size <= max_pov_size {
+								pov.block_data.0.resize(
+									pov.block_data.0.len() + max_pov_size + 1 - encoded_size,
+									0,
+								);
+							}
+							log::info!(
+								target: LOG_TARGET,
+								"Submitting PoV of size {} exceeding maximum size of {max_pov_size}",
+								pov.encoded_size(),
+							);
+						}
+
 						// As long as `POV_BOMB_LIMIT` is at least `max_pov_size`, this ensures
 						// that honest collators never produce a PoV which is uncompressed.
 						//
 						// As such, honest collators never produce an uncompressed PoV which starts
 						// with a compression magic number, which would lead validators to
 						// reject the collation.
-						if encoded_size > max_pov_size {
+						if encoded_size > max_pov_size && !oversized_pov {
 							log::error!(
 								target: LOG_TARGET,
 								"PoV size {encoded_size} exceeded maximum size of {max_pov_size}",
@@ -610,8 +647,11 @@ impl Collator {
 						hrmp_watermark: collation.hrmp_watermark,
 					};
 
-					// Submit the same collation to all assigned cores.
-					for core_index in &scheduled_cores {
+					// Submit the same collation to all assigned cores. An oversized collation
+					// is enough to be submitted to a single core.
+					let target_cores =
+						if oversized_pov { &scheduled_cores[..1] } else { &scheduled_cores[..] };
+					for core_index in target_cores {
 						let candidate_receipt = CandidateReceiptV2 {
 							descriptor: CandidateDescriptorV2::new(
 								para_id,
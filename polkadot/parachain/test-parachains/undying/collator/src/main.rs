@@ -154,12 +154,16 @@ fn main() -> Result<()> {
 
 				// If the collator is configured to behave maliciously, simulate the specified
 				// malicious behavior.
-				if cli.run.malus_type == MalusType::DuplicateCollations {
-					collator.send_same_collations_to_all_assigned_cores(
-						&full_node,
-						overseer_handle,
-						para_id,
-					);
+				match cli.run.malus_type {
+					MalusType::None => {},
+					MalusType::DuplicateCollations => collator
+						.send_same_collations_to_all_assigned_cores(
+							&full_node,
+							overseer_handle,
+							para_id,
+						),
+					MalusType::OversizedCollations =>
+						collator.send_oversized_collations(&full_node, overseer_handle, para_id),
 				}
 
 				Ok(full_node.task_manager)
@@ -120,6 +120,16 @@ pub enum ExecutorParam {
 	/// Enables WASM bulk memory proposal
 	#[codec(index = 7)]
 	WasmExtBulkMemory,
+	/// Prepares and executes PVFs with PolkaVM instead of Wasmtime. The validation code must be a
+	/// PolkaVM program blob. [`ExecutorParam::MaxMemoryPages`] limits the memory of the program
+	/// as it limits the linear memory of a Wasm PVF.
+	#[codec(index = 8)]
+	PolkaVm,
+	/// Amount of gas a single PVF execution is allowed to consume under PolkaVM. Running out of
+	/// gas fails the execution deterministically, unlike hitting the execution timeout.
+	/// Only valid together with [`ExecutorParam::PolkaVm`]; a valid value is non-zero.
+	#[codec(index = 9)]
+	PolkaVmGasLimit(u64),
 }
 
 /// Possible inconsistencies of executor params.
@@ -242,6 +252,8 @@ impl ExecutorParams {
 				PvfPrepTimeout(..) => Some(param),
 				PvfExecTimeout(..) => None,
 				WasmExtBulkMemory => Some(param),
+				PolkaVm => Some(param),
+				PolkaVmGasLimit(..) => None,
 			})
 			.for_each(|p| enc.extend(p.encode()));
 
@@ -282,6 +294,21 @@ impl ExecutorParams {
 		None
 	}
 
+	/// Returns `true` if PVFs are to be prepared and executed with PolkaVM
+	pub fn polkavm_enabled(&self) -> bool {
+		self.0.iter().any(|param| matches!(param, ExecutorParam::PolkaVm))
+	}
+
+	/// Returns a PolkaVM execution gas limit, if any
+	pub fn polkavm_gas_limit(&self) -> Option<u64> {
+		for param in &self.0 {
+			if let ExecutorParam::PolkaVmGasLimit(limit) = param {
+				return Some(*limit)
+			}
+		}
+		None
+	}
+
 	/// Check params coherence.
 	pub fn check_consistency(&self) -> Result<(), ExecutorParamError> {
 		use ExecutorParam::*;
@@ -325,6 +352,8 @@ impl ExecutorParams {
 					PvfExecKind::Approval => "PvfExecKind::Approval",
 				},
 				WasmExtBulkMemory => "WasmExtBulkMemory",
+				PolkaVm => "PolkaVm",
+				PolkaVmGasLimit(_) => "PolkaVmGasLimit",
 			};
 
 			match *param {
@@ -359,9 +388,21 @@ impl ExecutorParams {
 				WasmExtBulkMemory => {
 					check!(param_ident, 1);
 				},
+
+				PolkaVm => {
+					check!(param_ident, 1);
+				},
+
+				PolkaVmGasLimit(val) => {
+					check!(param_ident, val, val == 0);
+				},
 			}
 		}
 
+		if seen.contains_key("PolkaVmGasLimit") && !seen.contains_key("PolkaVm") {
+			return Err(IncompatibleValues("PolkaVmGasLimit", "PolkaVm"))
+		}
+
 		if let (Some(lm), Some(nm)) = (
 			seen.get("StackLogicalMax").or(Some(&(DEFAULT_LOGICAL_STACK_MAX as u64))),
 			seen.get("StackNativeMax").or(Some(&(DEFAULT_NATIVE_STACK_MAX as u64))),
@@ -429,6 +470,8 @@ fn ensure_prep_hash_changes() {
 			PvfExecTimeout(PvfExecKind::Backing, 0),
 			PvfExecTimeout(PvfExecKind::Approval, 0),
 			WasmExtBulkMemory,
+			PolkaVm,
+			PolkaVmGasLimit(0),
 		][..],
 	);
 
@@ -452,6 +495,8 @@ fn ensure_prep_hash_changes() {
 			PvfExecTimeout(_, _) => continue,
 			WasmExtBulkMemory =>
 				(ExecutorParams::default(), ExecutorParams::from(&[WasmExtBulkMemory][..])),
+			PolkaVm => (ExecutorParams::default(), ExecutorParams::from(&[PolkaVm][..])),
+			PolkaVmGasLimit(_) => continue,
 		};
 
 		assert_ne!(ep1.prep_hash(), ep2.prep_hash());
@@ -0,0 +1,279 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC api for inspecting recent disputes, backed by the dispute coordinator.
+
+use futures::channel::oneshot;
+use jsonrpsee::{
+	core::async_trait,
+	proc_macros::rpc,
+	types::{ErrorObject, ErrorObjectOwned},
+	Extensions,
+};
+use serde::{Deserialize, Serialize};
+
+use polkadot_node_primitives::{
+	DisputeReport, DisputeStatus, ParticipationOutcome, SlashingReport, Timestamp,
+	MAX_DISPUTE_REPORTS_PER_QUERY,
+};
+use polkadot_node_subsystem_types::messages::DisputeCoordinatorMessage;
+use polkadot_overseer::Handle;
+use polkadot_primitives::{
+	slashing::SlashingOffenceKind, CandidateHash, DisputeStatement, Hash, SessionIndex,
+	ValidDisputeStatementKind,
+};
+use sc_rpc_api::{check_if_safe, UnsafeRpcError};
+
+const DISPUTES_ERROR: i32 = 9100;
+
+/// Provides rpc methods for inspecting the disputes known to the node.
+#[rpc(client, server)]
+pub trait DisputesApi {
+	/// Returns the disputes of recent sessions, ordered by session and candidate hash, with their
+	/// votes, the outcome of this node's participation and the slashes pending for the losing
+	/// side.
+	///
+	/// At most `limit` disputes are returned, 100 at most and by default. The next page is
+	/// fetched by passing the last dispute returned as `after`.
+	///
+	/// Participation outcomes and slashes are only known for disputes seen since the node was
+	/// started.
+	#[method(name = "disputes_recentDisputes", with_extensions)]
+	async fn recent_disputes(
+		&self,
+		after: Option<DisputeId>,
+		limit: Option<u32>,
+	) -> Result<Vec<Dispute>, Error>;
+}
+
+/// Provides RPC methods for inspecting disputes.
+pub struct Disputes {
+	/// A handle to the overseer, `None` if the node doesn't run the dispute coordinator.
+	overseer_handle: Option<Handle>,
+}
+
+impl Disputes {
+	/// Creates a new instance of the Disputes Rpc handler.
+	pub fn new(overseer_handle: Option<Handle>) -> Self {
+		Self { overseer_handle }
+	}
+}
+
+#[async_trait]
+impl DisputesApiServer for Disputes {
+	async fn recent_disputes(
+		&self,
+		ext: &Extensions,
+		after: Option<DisputeId>,
+		limit: Option<u32>,
+	) -> Result<Vec<Dispute>, Error> {
+		check_if_safe(ext)?;
+
+		let mut overseer_handle = self.overseer_handle.clone().ok_or(Error::NotAvailable)?;
+		let (tx, rx) = oneshot::channel();
+		overseer_handle
+			.send_msg(
+				DisputeCoordinatorMessage::QueryDisputeReports {
+					after: after.map(|id| (id.session, CandidateHash(id.candidate_hash))),
+					limit: limit.unwrap_or(MAX_DISPUTE_REPORTS_PER_QUERY),
+					tx,
+				},
+				"DisputesRpc",
+			)
+			.await;
+		let reports = rx.await.map_err(|_| Error::Canceled)?;
+
+		Ok(reports.into_iter().map(Into::into).collect())
+	}
+}
+
+/// Identifies a dispute, e.g. the last one of a page of disputes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisputeId {
+	/// The session the candidate appeared in.
+	pub session: SessionIndex,
+	/// The hash of the disputed candidate.
+	pub candidate_hash: Hash,
+}
+
+/// A dispute as known to the node.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Dispute {
+	/// The session the candidate appeared in.
+	pub session: SessionIndex,
+	/// The hash of the disputed candidate.
+	pub candidate_hash: Hash,
+	/// The para the candidate belongs to, if the candidate receipt is known.
+	pub para_id: Option<u32>,
+	/// The current status of the dispute.
+	pub status: Status,
+	/// All votes on the candidate.
+	pub votes: Vec<Vote>,
+	/// The outcome of this node's participation, if any.
+	pub participation: Option<Participation>,
+	/// The slashes pending for the losing side, if any.
+	pub slashing: Option<Slashing>,
+}
+
+impl From<DisputeReport> for Dispute {
+	fn from(report: DisputeReport) -> Self {
+		Self {
+			session: report.session,
+			candidate_hash: report.candidate_hash.0,
+			para_id: report.para_id.map(Into::into),
+			status: report.status.into(),
+			votes: report
+				.votes
+				.into_iter()
+				.map(|(validator_index, statement)| Vote::new(validator_index.0, statement))
+				.collect(),
+			participation: report.participation.map(Into::into),
+			slashing: report.slashing.map(Into::into),
+		}
+	}
+}
+
+/// The status of a dispute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Status {
+	/// The dispute is active and unconcluded.
+	Active,
+	/// The dispute has been confirmed, but not concluded yet.
+	Confirmed,
+	/// The dispute concluded in favor of the candidate at the given UNIX timestamp.
+	ConcludedFor(Timestamp),
+	/// The dispute concluded against the candidate at the given UNIX timestamp.
+	ConcludedAgainst(Timestamp),
+}
+
+impl From<DisputeStatus> for Status {
+	fn from(status: DisputeStatus) -> Self {
+		match status {
+			DisputeStatus::Active => Self::Active,
+			DisputeStatus::Confirmed => Self::Confirmed,
+			DisputeStatus::ConcludedFor(since) => Self::ConcludedFor(since),
+			DisputeStatus::ConcludedAgainst(since) => Self::ConcludedAgainst(since),
+		}
+	}
+}
+
+/// A vote of a validator on the disputed candidate.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Vote {
+	/// The index of the validator in the session.
+	pub validator_index: u32,
+	/// Whether the validator voted for the validity of the candidate.
+	pub valid: bool,
+	/// The kind of statement the vote was derived from, e.g. `backingSeconded` or `explicit`.
+	pub kind: String,
+}
+
+impl Vote {
+	fn new(validator_index: u32, statement: DisputeStatement) -> Self {
+		let (valid, kind) = match statement {
+			DisputeStatement::Valid(kind) => (
+				true,
+				match kind {
+					ValidDisputeStatementKind::Explicit => "explicit",
+					ValidDisputeStatementKind::BackingSeconded(_) => "backingSeconded",
+					ValidDisputeStatementKind::BackingValid(_) => "backingValid",
+					ValidDisputeStatementKind::ApprovalChecking => "approvalChecking",
+					ValidDisputeStatementKind::ApprovalCheckingMultipleCandidates(_) =>
+						"approvalCheckingMultipleCandidates",
+				},
+			),
+			DisputeStatement::Invalid(_) => (false, "explicit"),
+		};
+		Self { validator_index, valid, kind: kind.into() }
+	}
+}
+
+/// The outcome of this node's participation in a dispute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Participation {
+	/// The candidate was found to be valid.
+	Valid,
+	/// The candidate was found to be invalid.
+	Invalid,
+	/// The candidate could not be recovered.
+	Unavailable,
+	/// Validation failed for reasons unrelated to the candidate, see the logs.
+	Error,
+}
+
+impl From<ParticipationOutcome> for Participation {
+	fn from(outcome: ParticipationOutcome) -> Self {
+		match outcome {
+			ParticipationOutcome::Valid => Self::Valid,
+			ParticipationOutcome::Invalid => Self::Invalid,
+			ParticipationOutcome::Unavailable => Self::Unavailable,
+			ParticipationOutcome::Error => Self::Error,
+		}
+	}
+}
+
+/// The slashes pending for the losing side of a dispute.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Slashing {
+	/// Whether the offenders voted for an invalid candidate, or against a valid one.
+	pub for_invalid: bool,
+	/// The indices of the validators to be slashed.
+	pub offenders: Vec<u32>,
+	/// Whether the offence has been reported to the runtime.
+	pub reported: bool,
+}
+
+impl From<SlashingReport> for Slashing {
+	fn from(report: SlashingReport) -> Self {
+		Self {
+			for_invalid: report.kind == SlashingOffenceKind::ForInvalid,
+			offenders: report.offenders.into_iter().map(|index| index.0).collect(),
+			reported: report.reported,
+		}
+	}
+}
+
+/// Errors encountered by the RPC
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// The node doesn't run the dispute coordinator.
+	#[error("Disputes are only available on validator nodes")]
+	NotAvailable,
+	/// The dispute coordinator didn't answer the request.
+	#[error("Request to the dispute coordinator was canceled")]
+	Canceled,
+	/// Call to an unsafe RPC was denied.
+	#[error(transparent)]
+	UnsafeRpcCalled(#[from] UnsafeRpcError),
+}
+
+impl From<Error> for ErrorObjectOwned {
+	fn from(error: Error) -> Self {
+		match error {
+			Error::NotAvailable =>
+				ErrorObject::owned(DISPUTES_ERROR + 1, error.to_string(), None::<()>),
+			Error::Canceled =>
+				ErrorObject::owned(DISPUTES_ERROR + 2, error.to_string(), None::<()>),
+			Error::UnsafeRpcCalled(e) => e.into(),
+		}
+	}
+}
@@ -38,6 +38,8 @@ use sp_consensus_babe::BabeApi;
 use sp_consensus_beefy::AuthorityIdBound;
 use sp_keystore::KeystorePtr;
 
+pub mod disputes;
+
 /// A type representing all RPC extensions.
 pub type RpcExtension = RpcModule<()>;
 
@@ -73,6 +75,12 @@ pub struct BeefyDeps<AuthorityId: AuthorityIdBound> {
 	pub subscription_executor: sc_rpc::SubscriptionTaskExecutor,
 }
 
+/// Dependencies for inspecting disputes.
+pub struct DisputesDeps {
+	/// A handle to the overseer, `None` if the node doesn't run the dispute coordinator.
+	pub overseer_handle: Option<polkadot_overseer::Handle>,
+}
+
 /// Full client dependencies
 pub struct FullDeps<C, P, SC, B, AuthorityId: AuthorityIdBound> {
 	/// The client instance to use.
@@ -89,13 +97,25 @@ pub struct FullDeps<C, P, SC, B, AuthorityId: AuthorityIdBound> {
 	pub grandpa: GrandpaDeps<B>,
 	/// BEEFY specific dependencies.
 	pub beefy: BeefyDeps<AuthorityId>,
+	/// Dispute specific dependencies.
+	pub disputes: DisputesDeps,
 	/// Backend used by the node.
 	pub backend: Arc<B>,
 }
 
 /// Instantiate all RPC extensions.
 pub fn create_full<C, P, SC, B, AuthorityId>(
-	FullDeps { client, pool, select_chain, chain_spec, babe, grandpa, beefy, backend }: FullDeps<
+	FullDeps {
+		client,
+		pool,
+		select_chain,
+		chain_spec,
+		babe,
+		grandpa,
+		beefy,
+		disputes,
+		backend,
+	}: FullDeps<
 		C,
 		P,
 		SC,
@@ -123,6 +143,7 @@ where
 	AuthorityId: AuthorityIdBound,
 	<AuthorityId as RuntimeAppPublic>::Signature: Send + Sync,
 {
+	use disputes::{Disputes, DisputesApiServer};
 	use mmr_rpc::{Mmr, MmrApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use sc_consensus_babe_rpc::{Babe, BabeApiServer};
@@ -180,5 +201,7 @@ where
 		.into_rpc(),
 	)?;
 
+	io.merge(Disputes::new(disputes.overseer_handle).into_rpc())?;
+
 	Ok(io)
 }
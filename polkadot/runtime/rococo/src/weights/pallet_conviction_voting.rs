@@ -63,14 +63,16 @@ impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInf
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:1 w:0)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
 	fn vote_new() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `13408`
 		//  Estimated: `42428`
-		// Minimum execution time: 152_060_000 picoseconds.
-		Weight::from_parts(162_731_000, 0)
+		// Minimum execution time: 155_466_000 picoseconds.
+		Weight::from_parts(166_137_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -87,14 +89,16 @@ impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInf
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:1 w:0)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
 	fn vote_existing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `14129`
 		//  Estimated: `83866`
-		// Minimum execution time: 176_385_000 picoseconds.
-		Weight::from_parts(184_428_000, 0)
+		// Minimum execution time: 179_902_000 picoseconds.
+		Weight::from_parts(187_945_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
@@ -105,14 +109,16 @@ impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInf
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:1 w:0)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
 	fn remove_vote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `13919`
 		//  Estimated: `83866`
-		// Minimum execution time: 142_873_000 picoseconds.
-		Weight::from_parts(150_271_000, 0)
+		// Minimum execution time: 146_161_000 picoseconds.
+		Weight::from_parts(153_559_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
@@ -201,4 +207,109 @@ impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:0)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::Forwarding` (r:2 w:0)
+	/// Proof: `ConvictionVoting::Forwarding` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverridesFor` (r:2 w:1)
+	/// Proof: `ConvictionVoting::OverridesFor` (`max_values`: None, `max_size`: Some(27190), added: 29665, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:2 w:1)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
+	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:2 w:2)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:0 w:1)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 1]`.
+	fn override_vote(_d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `29884`
+		//  Estimated: `113582`
+		// Minimum execution time: 190_535_000 picoseconds.
+		Weight::from_parts(196_428_000, 0)
+			.saturating_add(Weight::from_parts(0, 113582))
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:1)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverridesFor` (r:2 w:1)
+	/// Proof: `ConvictionVoting::OverridesFor` (`max_values`: None, `max_size`: Some(27190), added: 29665, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::Forwarding` (r:2 w:0)
+	/// Proof: `ConvictionVoting::Forwarding` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:2 w:1)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:2 w:2)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:0 w:1)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 1]`.
+	fn remove_override(_d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `29774`
+		//  Estimated: `113582`
+		// Minimum execution time: 157_402_000 picoseconds.
+		Weight::from_parts(162_271_000, 0)
+			.saturating_add(Weight::from_parts(0, 113582))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `ConvictionVoting::VotingFor` (r:4 w:4)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::Forwarding` (r:2 w:1)
+	/// Proof: `ConvictionVoting::Forwarding` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::DelegationDepth` (r:2 w:2)
+	/// Proof: `ConvictionVoting::DelegationDepth` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverridesFor` (r:1 w:0)
+	/// Proof: `ConvictionVoting::OverridesFor` (`max_values`: None, `max_size`: Some(27190), added: 29665, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:1 w:0)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1024 w:1024)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
+	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 512]`.
+	/// The range of component `d` is `[1, 1]`.
+	fn redelegate(r: u32, _d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3308 + r * (730 ±0)`
+		//  Estimated: `113582 + r * (6822 ±0)`
+		// Minimum execution time: 1_697_045_000 picoseconds.
+		Weight::from_parts(1_749_221_530, 0)
+			.saturating_add(Weight::from_parts(0, 113582))
+			// Standard Error: 121_806
+			.saturating_add(Weight::from_parts(43_317_466, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(9))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 6822).saturating_mul(r.into()))
+	}
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:0)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:1 w:1)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	fn clear_overridden_delegations() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1032`
+		//  Estimated: `4401`
+		// Minimum execution time: 36_455_000 picoseconds.
+		Weight::from_parts(37_583_000, 0)
+			.saturating_add(Weight::from_parts(0, 4401))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
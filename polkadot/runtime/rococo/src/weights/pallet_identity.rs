@@ -129,6 +129,8 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::SuperOf` (r:0 w:100)
 	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:0 w:20)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	fn clear_identity(r: u32, s: u32, ) -> Weight {
@@ -139,11 +141,12 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(60_250_804, 0)
 			.saturating_add(Weight::from_parts(0, 11003))
 			// Standard Error: 20_548
-			.saturating_add(Weight::from_parts(114_534, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(70_680, 0).saturating_mul(r.into()))
 			// Standard Error: 4_009
 			.saturating_add(Weight::from_parts(1_459_737, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
 	}
 	/// Storage: `Identity::Registrars` (r:1 w:0)
@@ -248,6 +251,8 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::SuperOf` (r:0 w:100)
 	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:0 w:20)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	fn kill_identity(r: u32, s: u32, ) -> Weight {
@@ -258,11 +263,12 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(76_370_397, 0)
 			.saturating_add(Weight::from_parts(0, 11003))
 			// Standard Error: 20_402
-			.saturating_add(Weight::from_parts(114_391, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(70_592, 0).saturating_mul(r.into()))
 			// Standard Error: 3_981
 			.saturating_add(Weight::from_parts(1_456_205, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
 	}
 	/// Storage: `Identity::IdentityOf` (r:1 w:0)
@@ -567,4 +573,58 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationNonce` (r:1 w:1)
+	/// Proof: `Identity::AttestationNonce` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:0 w:1)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 20]`.
+	fn provide_attestation(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7101 + r * (57 ±0)`
+		//  Estimated: `11003`
+		// Minimum execution time: 192_669_000 picoseconds.
+		Weight::from_parts(198_628_354, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			// Standard Error: 10_095
+			.saturating_add(Weight::from_parts(64_928, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:1 w:1)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationNonce` (r:1 w:1)
+	/// Proof: `Identity::AttestationNonce` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 20]`.
+	fn revoke_attestation(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `259 + r * (57 ±0)`
+		//  Estimated: `3561`
+		// Minimum execution time: 16_735_000 picoseconds.
+		Weight::from_parts(17_252_681, 0)
+			.saturating_add(Weight::from_parts(0, 3561))
+			// Standard Error: 1_377
+			.saturating_add(Weight::from_parts(73_824, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Identity::AttestationsOf` (r:1 w:1)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	fn remove_stale_attestation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7195`
+		//  Estimated: `11003`
+		// Minimum execution time: 65_498_000 picoseconds.
+		Weight::from_parts(67_524_000, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -59,14 +59,22 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:0 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::TrackOrigins` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::TrackOrigins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
 	fn submit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `293`
+		//  Measured:  `555`
 		//  Estimated: `42428`
-		// Minimum execution time: 27_354_000 picoseconds.
-		Weight::from_parts(28_808_000, 0)
+		// Minimum execution time: 31_484_000 picoseconds.
+		Weight::from_parts(32_938_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -75,14 +83,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_preparing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `404`
+		//  Measured:  `577`
 		//  Estimated: `83866`
-		// Minimum execution time: 51_005_000 picoseconds.
-		Weight::from_parts(52_816_000, 0)
+		// Minimum execution time: 53_275_000 picoseconds.
+		Weight::from_parts(55_086_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -95,14 +107,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2042`
+		//  Measured:  `2215`
 		//  Estimated: `42428`
-		// Minimum execution time: 98_664_000 picoseconds.
-		Weight::from_parts(103_183_000, 0)
+		// Minimum execution time: 100_934_000 picoseconds.
+		Weight::from_parts(105_453_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -115,14 +131,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_not_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2083`
+		//  Measured:  `2256`
 		//  Estimated: `42428`
-		// Minimum execution time: 96_671_000 picoseconds.
-		Weight::from_parts(101_538_000, 0)
+		// Minimum execution time: 98_941_000 picoseconds.
+		Weight::from_parts(103_808_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -135,14 +155,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `740`
+		//  Measured:  `913`
 		//  Estimated: `83866`
-		// Minimum execution time: 149_229_000 picoseconds.
-		Weight::from_parts(168_217_000, 0)
+		// Minimum execution time: 151_499_000 picoseconds.
+		Weight::from_parts(170_487_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -155,14 +179,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `605`
+		//  Measured:  `778`
 		//  Estimated: `83866`
-		// Minimum execution time: 66_033_000 picoseconds.
-		Weight::from_parts(67_523_000, 0)
+		// Minimum execution time: 68_303_000 picoseconds.
+		Weight::from_parts(69_793_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -227,14 +255,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::DecidingCount` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_queue_empty() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `140`
+		//  Measured:  `313`
 		//  Estimated: `4277`
-		// Minimum execution time: 12_107_000 picoseconds.
-		Weight::from_parts(12_512_000, 0)
+		// Minimum execution time: 14_377_000 picoseconds.
+		Weight::from_parts(14_782_000, 0)
 			.saturating_add(Weight::from_parts(0, 4277))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
@@ -245,14 +277,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2342`
+		//  Measured:  `2515`
 		//  Estimated: `42428`
-		// Minimum execution time: 88_251_000 picoseconds.
-		Weight::from_parts(92_301_000, 0)
+		// Minimum execution time: 90_521_000 picoseconds.
+		Weight::from_parts(94_571_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
@@ -263,42 +299,54 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2328`
+		//  Measured:  `2501`
 		//  Estimated: `42428`
-		// Minimum execution time: 86_613_000 picoseconds.
-		Weight::from_parts(90_422_000, 0)
+		// Minimum execution time: 88_883_000 picoseconds.
+		Weight::from_parts(92_692_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:0)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_insertion() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1807`
+		//  Measured:  `1980`
 		//  Estimated: `4365`
-		// Minimum execution time: 42_093_000 picoseconds.
-		Weight::from_parts(44_027_000, 0)
+		// Minimum execution time: 44_363_000 picoseconds.
+		Weight::from_parts(46_297_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:0)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_slide() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1774`
+		//  Measured:  `1947`
 		//  Estimated: `4365`
-		// Minimum execution time: 39_115_000 picoseconds.
-		Weight::from_parts(43_478_000, 0)
+		// Minimum execution time: 41_385_000 picoseconds.
+		Weight::from_parts(45_748_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -307,14 +355,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipReferenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1790`
+		//  Measured:  `1963`
 		//  Estimated: `4365`
-		// Minimum execution time: 49_205_000 picoseconds.
-		Weight::from_parts(51_648_000, 0)
+		// Minimum execution time: 51_475_000 picoseconds.
+		Weight::from_parts(53_918_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -323,54 +375,70 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipReferenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_not_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1831`
+		//  Measured:  `2004`
 		//  Estimated: `4365`
-		// Minimum execution time: 47_692_000 picoseconds.
-		Weight::from_parts(51_756_000, 0)
+		// Minimum execution time: 49_962_000 picoseconds.
+		Weight::from_parts(54_026_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_no_deposit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `301`
+		//  Measured:  `474`
 		//  Estimated: `42428`
-		// Minimum execution time: 24_114_000 picoseconds.
-		Weight::from_parts(25_083_000, 0)
+		// Minimum execution time: 26_384_000 picoseconds.
+		Weight::from_parts(27_353_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_preparing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `349`
+		//  Measured:  `522`
 		//  Estimated: `42428`
-		// Minimum execution time: 23_863_000 picoseconds.
-		Weight::from_parts(25_274_000, 0)
+		// Minimum execution time: 26_133_000 picoseconds.
+		Weight::from_parts(27_544_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_timed_out() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `208`
+		//  Measured:  `381`
 		//  Estimated: `4365`
-		// Minimum execution time: 15_202_000 picoseconds.
-		Weight::from_parts(15_831_000, 0)
+		// Minimum execution time: 17_472_000 picoseconds.
+		Weight::from_parts(18_101_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -381,14 +449,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `550`
+		//  Measured:  `723`
 		//  Estimated: `42428`
-		// Minimum execution time: 37_649_000 picoseconds.
-		Weight::from_parts(38_973_000, 0)
+		// Minimum execution time: 39_919_000 picoseconds.
+		Weight::from_parts(41_243_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -399,14 +471,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `685`
+		//  Measured:  `858`
 		//  Estimated: `42428`
-		// Minimum execution time: 77_633_000 picoseconds.
-		Weight::from_parts(80_573_000, 0)
+		// Minimum execution time: 79_903_000 picoseconds.
+		Weight::from_parts(82_843_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -415,14 +491,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `736`
+		//  Measured:  `909`
 		//  Estimated: `42428`
-		// Minimum execution time: 108_648_000 picoseconds.
-		Weight::from_parts(124_320_000, 0)
+		// Minimum execution time: 110_918_000 picoseconds.
+		Weight::from_parts(126_590_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -431,14 +511,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_end_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `721`
+		//  Measured:  `894`
 		//  Estimated: `42428`
-		// Minimum execution time: 107_357_000 picoseconds.
-		Weight::from_parts(134_687_000, 0)
+		// Minimum execution time: 109_627_000 picoseconds.
+		Weight::from_parts(136_957_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -447,14 +531,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_not_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `736`
+		//  Measured:  `909`
 		//  Estimated: `42428`
-		// Minimum execution time: 114_998_000 picoseconds.
-		Weight::from_parts(128_601_000, 0)
+		// Minimum execution time: 117_268_000 picoseconds.
+		Weight::from_parts(130_871_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -463,14 +551,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `742`
+		//  Measured:  `915`
 		//  Estimated: `42428`
-		// Minimum execution time: 69_703_000 picoseconds.
-		Weight::from_parts(72_382_000, 0)
+		// Minimum execution time: 71_973_000 picoseconds.
+		Weight::from_parts(74_652_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -481,14 +573,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Lookup` (r:1 w:1)
 	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_approved() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `742`
+		//  Measured:  `915`
 		//  Estimated: `83866`
-		// Minimum execution time: 117_256_000 picoseconds.
-		Weight::from_parts(141_643_000, 0)
+		// Minimum execution time: 119_526_000 picoseconds.
+		Weight::from_parts(143_913_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -497,14 +593,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_rejected() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `738`
+		//  Measured:  `911`
 		//  Estimated: `42428`
-		// Minimum execution time: 106_442_000 picoseconds.
-		Weight::from_parts(128_451_000, 0)
+		// Minimum execution time: 108_712_000 picoseconds.
+		Weight::from_parts(130_721_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:0)
@@ -539,4 +639,56 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:1)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::TrackOrigins` (r:1 w:1)
+	/// Proof: `FellowshipReferenda::TrackOrigins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FellowshipReferenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::CounterForStoredTracks` (r:1 w:1)
+	/// Proof: `FellowshipReferenda::CounterForStoredTracks` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn insert_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `4365`
+		// Minimum execution time: 24_336_000 picoseconds.
+		Weight::from_parts(25_244_000, 0)
+			.saturating_add(Weight::from_parts(0, 4365))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:1)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn update_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `4365`
+		// Minimum execution time: 21_920_000 picoseconds.
+		Weight::from_parts(22_828_000, 0)
+			.saturating_add(Weight::from_parts(0, 4365))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FellowshipReferenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::StoredTracks` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::RetiredTracks` (r:1 w:1)
+	/// Proof: `FellowshipReferenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	fn retire_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `351`
+		//  Estimated: `4365`
+		// Minimum execution time: 20_978_000 picoseconds.
+		Weight::from_parts(21_886_000, 0)
+			.saturating_add(Weight::from_parts(0, 4365))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -57,14 +57,22 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:0 w:1)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::TrackOrigins` (r:1 w:0)
+	/// Proof: `Referenda::TrackOrigins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `Referenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
 	fn submit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `186`
+		//  Measured:  `448`
 		//  Estimated: `42428`
-		// Minimum execution time: 36_161_000 picoseconds.
-		Weight::from_parts(37_541_000, 0)
+		// Minimum execution time: 40_291_000 picoseconds.
+		Weight::from_parts(41_671_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -73,14 +81,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_preparing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `439`
+		//  Measured:  `612`
 		//  Estimated: `83866`
-		// Minimum execution time: 50_258_000 picoseconds.
-		Weight::from_parts(51_573_000, 0)
+		// Minimum execution time: 52_528_000 picoseconds.
+		Weight::from_parts(53_843_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -93,14 +105,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3226`
+		//  Measured:  `3399`
 		//  Estimated: `42428`
-		// Minimum execution time: 67_889_000 picoseconds.
-		Weight::from_parts(69_418_000, 0)
+		// Minimum execution time: 70_159_000 picoseconds.
+		Weight::from_parts(71_688_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -113,14 +129,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_not_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3246`
+		//  Measured:  `3419`
 		//  Estimated: `42428`
-		// Minimum execution time: 67_397_000 picoseconds.
-		Weight::from_parts(69_064_000, 0)
+		// Minimum execution time: 69_667_000 picoseconds.
+		Weight::from_parts(71_334_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -131,14 +151,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `439`
+		//  Measured:  `612`
 		//  Estimated: `83866`
-		// Minimum execution time: 59_530_000 picoseconds.
-		Weight::from_parts(61_636_000, 0)
+		// Minimum execution time: 61_800_000 picoseconds.
+		Weight::from_parts(63_906_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -149,14 +173,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `439`
+		//  Measured:  `612`
 		//  Estimated: `83866`
-		// Minimum execution time: 58_673_000 picoseconds.
-		Weight::from_parts(59_873_000, 0)
+		// Minimum execution time: 60_943_000 picoseconds.
+		Weight::from_parts(62_143_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -221,14 +249,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Referenda::TrackQueue` (`max_values`: None, `max_size`: Some(2012), added: 4487, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::DecidingCount` (r:1 w:1)
 	/// Proof: `Referenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_queue_empty() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `102`
+		//  Measured:  `275`
 		//  Estimated: `5477`
-		// Minimum execution time: 11_032_000 picoseconds.
-		Weight::from_parts(11_405_000, 0)
+		// Minimum execution time: 13_302_000 picoseconds.
+		Weight::from_parts(13_675_000, 0)
 			.saturating_add(Weight::from_parts(0, 5477))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Referenda::TrackQueue` (r:1 w:1)
@@ -237,14 +269,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3116`
+		//  Measured:  `3289`
 		//  Estimated: `42428`
-		// Minimum execution time: 46_541_000 picoseconds.
-		Weight::from_parts(47_840_000, 0)
+		// Minimum execution time: 48_811_000 picoseconds.
+		Weight::from_parts(50_110_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Referenda::TrackQueue` (r:1 w:1)
@@ -253,42 +289,54 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3116`
+		//  Measured:  `3289`
 		//  Estimated: `42428`
-		// Minimum execution time: 48_312_000 picoseconds.
-		Weight::from_parts(49_940_000, 0)
+		// Minimum execution time: 50_582_000 picoseconds.
+		Weight::from_parts(52_210_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:0)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::TrackQueue` (r:1 w:1)
 	/// Proof: `Referenda::TrackQueue` (`max_values`: None, `max_size`: Some(2012), added: 4487, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_insertion() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2939`
+		//  Measured:  `3112`
 		//  Estimated: `5477`
-		// Minimum execution time: 22_797_000 picoseconds.
-		Weight::from_parts(23_534_000, 0)
+		// Minimum execution time: 25_067_000 picoseconds.
+		Weight::from_parts(25_804_000, 0)
 			.saturating_add(Weight::from_parts(0, 5477))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:0)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::TrackQueue` (r:1 w:1)
 	/// Proof: `Referenda::TrackQueue` (`max_values`: None, `max_size`: Some(2012), added: 4487, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_slide() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2939`
+		//  Measured:  `3112`
 		//  Estimated: `5477`
-		// Minimum execution time: 22_528_000 picoseconds.
-		Weight::from_parts(23_292_000, 0)
+		// Minimum execution time: 24_798_000 picoseconds.
+		Weight::from_parts(25_562_000, 0)
 			.saturating_add(Weight::from_parts(0, 5477))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -297,14 +345,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Referenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::TrackQueue` (r:1 w:1)
 	/// Proof: `Referenda::TrackQueue` (`max_values`: None, `max_size`: Some(2012), added: 4487, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2943`
+		//  Measured:  `3116`
 		//  Estimated: `5477`
-		// Minimum execution time: 28_813_000 picoseconds.
-		Weight::from_parts(30_051_000, 0)
+		// Minimum execution time: 31_083_000 picoseconds.
+		Weight::from_parts(32_321_000, 0)
 			.saturating_add(Weight::from_parts(0, 5477))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -313,54 +365,70 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Referenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::TrackQueue` (r:1 w:1)
 	/// Proof: `Referenda::TrackQueue` (`max_values`: None, `max_size`: Some(2012), added: 4487, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_not_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2963`
+		//  Measured:  `3136`
 		//  Estimated: `5477`
-		// Minimum execution time: 28_198_000 picoseconds.
-		Weight::from_parts(29_080_000, 0)
+		// Minimum execution time: 30_468_000 picoseconds.
+		Weight::from_parts(31_350_000, 0)
 			.saturating_add(Weight::from_parts(0, 5477))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_no_deposit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `299`
+		//  Measured:  `472`
 		//  Estimated: `42428`
-		// Minimum execution time: 23_048_000 picoseconds.
-		Weight::from_parts(24_004_000, 0)
+		// Minimum execution time: 25_318_000 picoseconds.
+		Weight::from_parts(26_274_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_preparing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `347`
+		//  Measured:  `520`
 		//  Estimated: `42428`
-		// Minimum execution time: 23_198_000 picoseconds.
-		Weight::from_parts(24_029_000, 0)
+		// Minimum execution time: 25_468_000 picoseconds.
+		Weight::from_parts(26_299_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_timed_out() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `206`
+		//  Measured:  `379`
 		//  Estimated: `4401`
-		// Minimum execution time: 14_495_000 picoseconds.
-		Weight::from_parts(15_167_000, 0)
+		// Minimum execution time: 16_765_000 picoseconds.
+		Weight::from_parts(17_437_000, 0)
 			.saturating_add(Weight::from_parts(0, 4401))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -369,14 +437,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Referenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `347`
+		//  Measured:  `520`
 		//  Estimated: `42428`
-		// Minimum execution time: 30_480_000 picoseconds.
-		Weight::from_parts(31_982_000, 0)
+		// Minimum execution time: 32_750_000 picoseconds.
+		Weight::from_parts(34_252_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -385,70 +457,90 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Referenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `347`
+		//  Measured:  `520`
 		//  Estimated: `42428`
-		// Minimum execution time: 32_934_000 picoseconds.
-		Weight::from_parts(33_999_000, 0)
+		// Minimum execution time: 35_204_000 picoseconds.
+		Weight::from_parts(36_269_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `400`
+		//  Measured:  `573`
 		//  Estimated: `42428`
-		// Minimum execution time: 27_875_000 picoseconds.
-		Weight::from_parts(28_645_000, 0)
+		// Minimum execution time: 30_145_000 picoseconds.
+		Weight::from_parts(30_915_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_end_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `383`
+		//  Measured:  `556`
 		//  Estimated: `42428`
-		// Minimum execution time: 28_150_000 picoseconds.
-		Weight::from_parts(28_967_000, 0)
+		// Minimum execution time: 30_420_000 picoseconds.
+		Weight::from_parts(31_237_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_not_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `400`
+		//  Measured:  `573`
 		//  Estimated: `42428`
-		// Minimum execution time: 27_692_000 picoseconds.
-		Weight::from_parts(28_559_000, 0)
+		// Minimum execution time: 29_962_000 picoseconds.
+		Weight::from_parts(30_829_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `404`
+		//  Measured:  `577`
 		//  Estimated: `42428`
-		// Minimum execution time: 25_836_000 picoseconds.
-		Weight::from_parts(26_852_000, 0)
+		// Minimum execution time: 28_106_000 picoseconds.
+		Weight::from_parts(29_122_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -457,28 +549,36 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Lookup` (r:1 w:1)
 	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_approved() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `404`
+		//  Measured:  `577`
 		//  Estimated: `83866`
-		// Minimum execution time: 38_301_000 picoseconds.
-		Weight::from_parts(39_972_000, 0)
+		// Minimum execution time: 40_571_000 picoseconds.
+		Weight::from_parts(42_242_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_rejected() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `400`
+		//  Measured:  `573`
 		//  Estimated: `42428`
-		// Minimum execution time: 27_978_000 picoseconds.
-		Weight::from_parts(28_811_000, 0)
+		// Minimum execution time: 30_248_000 picoseconds.
+		Weight::from_parts(31_081_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:0)
@@ -513,4 +613,56 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:1)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::TrackOrigins` (r:1 w:1)
+	/// Proof: `Referenda::TrackOrigins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Referenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `Referenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::CounterForStoredTracks` (r:1 w:1)
+	/// Proof: `Referenda::CounterForStoredTracks` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn insert_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `349`
+		//  Estimated: `4401`
+		// Minimum execution time: 23_427_000 picoseconds.
+		Weight::from_parts(24_535_000, 0)
+			.saturating_add(Weight::from_parts(0, 4401))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `Referenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:1)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn update_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `349`
+		//  Estimated: `4401`
+		// Minimum execution time: 21_011_000 picoseconds.
+		Weight::from_parts(22_119_000, 0)
+			.saturating_add(Weight::from_parts(0, 4401))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::RetiredTracks` (r:1 w:1)
+	/// Proof: `Referenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	fn retire_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `349`
+		//  Estimated: `4401`
+		// Minimum execution time: 20_069_000 picoseconds.
+		Weight::from_parts(21_177_000, 0)
+			.saturating_add(Weight::from_parts(0, 4401))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
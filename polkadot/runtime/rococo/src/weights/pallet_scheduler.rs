@@ -288,4 +288,192 @@ impl<T: frame_system::Config> pallet_scheduler::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	fn service_timed_agendas_base() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `69`
+		//  Estimated: `1489`
+		// Minimum execution time: 4_408_000 picoseconds.
+		Weight::from_parts(4_723_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 50]`.
+	fn service_timed_agenda_base(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `116 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 4_789_000 picoseconds.
+		Weight::from_parts(8_177_405, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 1_630
+			.saturating_add(Weight::from_parts(349_930, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 49]`.
+	fn schedule_at_time(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `116 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 12_009_000 picoseconds.
+		Weight::from_parts(15_253_217, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 1_770
+			.saturating_add(Weight::from_parts(402_617, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedLookup` (r:0 w:1)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 50]`.
+	fn cancel_timed(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `116 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 17_471_000 picoseconds.
+		Weight::from_parts(17_840_084, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 3_141
+			.saturating_add(Weight::from_parts(581_579, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:0)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:1)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 49]`.
+	fn schedule_named_at_time(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `293 + s * (185 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 15_635_000 picoseconds.
+		Weight::from_parts(20_871_297, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 4_083
+			.saturating_add(Weight::from_parts(449_239, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:1)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 50]`.
+	fn cancel_named_timed(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `319 + s * (185 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 20_631_000 picoseconds.
+		Weight::from_parts(22_129_643, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 2_057
+			.saturating_add(Weight::from_parts(615_161, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 50]`.
+	fn schedule_timed_retry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `156`
+		//  Estimated: `42428`
+		// Minimum execution time: 11_472_000 picoseconds.
+		Weight::from_parts(12_021_769, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 696
+			.saturating_add(Weight::from_parts(27_300, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn set_retry_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8966`
+		//  Estimated: `42428`
+		// Minimum execution time: 28_031_000 picoseconds.
+		Weight::from_parts(29_185_000, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:0)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn set_retry_named_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9644`
+		//  Estimated: `42428`
+		// Minimum execution time: 34_902_000 picoseconds.
+		Weight::from_parts(36_691_000, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn cancel_retry_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8978`
+		//  Estimated: `42428`
+		// Minimum execution time: 27_013_000 picoseconds.
+		Weight::from_parts(28_502_000, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:0)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn cancel_retry_named_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9656`
+		//  Estimated: `42428`
+		// Minimum execution time: 34_538_000 picoseconds.
+		Weight::from_parts(35_510_000, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
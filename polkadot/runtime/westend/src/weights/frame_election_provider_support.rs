@@ -81,4 +81,21 @@ impl<T: frame_system::Config> frame_election_provider_support::WeightInfo for We
 			// Standard Error: 14_450_583
 			.saturating_add(Weight::from_parts(1_581_504_003, 0).saturating_mul(d.into()))
 	}
+	/// The range of component `v` is `[1000, 2000]`.
+	/// The range of component `t` is `[500, 1000]`.
+	/// The range of component `d` is `[5, 16]`.
+	fn stv(v: u32, t: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_286_114_000 picoseconds.
+		Weight::from_parts(12_417_389_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 9_417_528
+			.saturating_add(Weight::from_parts(231_618_402, 0).saturating_mul(v.into()))
+			// Standard Error: 18_835_057
+			.saturating_add(Weight::from_parts(486_027_331, 0).saturating_mul(t.into()))
+			// Standard Error: 856_149_310
+			.saturating_add(Weight::from_parts(33_108_624_117, 0).saturating_mul(d.into()))
+	}
 }
@@ -56,28 +56,40 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
 	/// Storage: Referenda ReferendumInfoFor (r:0 w:1)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda TrackOrigins (r:1 w:0)
+	/// Proof: Referenda TrackOrigins (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: Referenda RetiredTracks (r:1 w:0)
+	/// Proof: Referenda RetiredTracks (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
 	fn submit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `186`
+		//  Measured:  `448`
 		//  Estimated: `42428`
-		// Minimum execution time: 39_146_000 picoseconds.
-		Weight::from_parts(40_383_000, 0)
+		// Minimum execution time: 43_276_000 picoseconds.
+		Weight::from_parts(44_513_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:2 w:2)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn place_decision_deposit_preparing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `439`
+		//  Measured:  `612`
 		//  Estimated: `83866`
-		// Minimum execution time: 51_385_000 picoseconds.
-		Weight::from_parts(52_701_000, 0)
+		// Minimum execution time: 53_655_000 picoseconds.
+		Weight::from_parts(54_971_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -88,14 +100,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Referenda TrackQueue (max_values: None, max_size: Some(2012), added: 4487, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn place_decision_deposit_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3196`
+		//  Measured:  `3369`
 		//  Estimated: `42428`
-		// Minimum execution time: 70_018_000 picoseconds.
-		Weight::from_parts(75_868_000, 0)
+		// Minimum execution time: 72_288_000 picoseconds.
+		Weight::from_parts(78_138_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -106,14 +122,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Referenda TrackQueue (max_values: None, max_size: Some(2012), added: 4487, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn place_decision_deposit_not_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3216`
+		//  Measured:  `3389`
 		//  Estimated: `42428`
-		// Minimum execution time: 69_311_000 picoseconds.
-		Weight::from_parts(72_425_000, 0)
+		// Minimum execution time: 71_581_000 picoseconds.
+		Weight::from_parts(74_695_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -124,14 +144,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:2 w:2)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn place_decision_deposit_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `439`
+		//  Measured:  `612`
 		//  Estimated: `83866`
-		// Minimum execution time: 64_385_000 picoseconds.
-		Weight::from_parts(66_178_000, 0)
+		// Minimum execution time: 66_655_000 picoseconds.
+		Weight::from_parts(68_448_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -142,14 +166,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:2 w:2)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn place_decision_deposit_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `439`
+		//  Measured:  `612`
 		//  Estimated: `83866`
-		// Minimum execution time: 62_200_000 picoseconds.
-		Weight::from_parts(63_782_000, 0)
+		// Minimum execution time: 64_470_000 picoseconds.
+		Weight::from_parts(66_052_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -210,14 +238,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Referenda TrackQueue (max_values: None, max_size: Some(2012), added: 4487, mode: MaxEncodedLen)
 	/// Storage: Referenda DecidingCount (r:1 w:1)
 	/// Proof: Referenda DecidingCount (max_values: None, max_size: Some(14), added: 2489, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn one_fewer_deciding_queue_empty() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `102`
+		//  Measured:  `275`
 		//  Estimated: `5477`
-		// Minimum execution time: 8_987_000 picoseconds.
-		Weight::from_parts(9_431_000, 0)
+		// Minimum execution time: 11_257_000 picoseconds.
+		Weight::from_parts(11_701_000, 0)
 			.saturating_add(Weight::from_parts(0, 5477))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: Referenda TrackQueue (r:1 w:1)
@@ -228,14 +260,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn one_fewer_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3116`
+		//  Measured:  `3289`
 		//  Estimated: `42428`
-		// Minimum execution time: 55_344_000 picoseconds.
-		Weight::from_parts(58_026_000, 0)
+		// Minimum execution time: 57_614_000 picoseconds.
+		Weight::from_parts(60_296_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda TrackQueue (r:1 w:1)
@@ -246,42 +282,54 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn one_fewer_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3116`
+		//  Measured:  `3289`
 		//  Estimated: `42428`
-		// Minimum execution time: 57_003_000 picoseconds.
-		Weight::from_parts(60_347_000, 0)
+		// Minimum execution time: 59_273_000 picoseconds.
+		Weight::from_parts(62_617_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:0)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
 	/// Storage: Referenda TrackQueue (r:1 w:1)
 	/// Proof: Referenda TrackQueue (max_values: None, max_size: Some(2012), added: 4487, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn nudge_referendum_requeued_insertion() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2939`
+		//  Measured:  `3112`
 		//  Estimated: `5477`
-		// Minimum execution time: 23_001_000 picoseconds.
-		Weight::from_parts(24_812_000, 0)
+		// Minimum execution time: 25_271_000 picoseconds.
+		Weight::from_parts(27_082_000, 0)
 			.saturating_add(Weight::from_parts(0, 5477))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:0)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
 	/// Storage: Referenda TrackQueue (r:1 w:1)
 	/// Proof: Referenda TrackQueue (max_values: None, max_size: Some(2012), added: 4487, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn nudge_referendum_requeued_slide() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2939`
+		//  Measured:  `3112`
 		//  Estimated: `5477`
-		// Minimum execution time: 23_299_000 picoseconds.
-		Weight::from_parts(24_465_000, 0)
+		// Minimum execution time: 25_569_000 picoseconds.
+		Weight::from_parts(26_735_000, 0)
 			.saturating_add(Weight::from_parts(0, 5477))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -290,14 +338,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Referenda DecidingCount (max_values: None, max_size: Some(14), added: 2489, mode: MaxEncodedLen)
 	/// Storage: Referenda TrackQueue (r:1 w:1)
 	/// Proof: Referenda TrackQueue (max_values: None, max_size: Some(2012), added: 4487, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn nudge_referendum_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2943`
+		//  Measured:  `3116`
 		//  Estimated: `5477`
-		// Minimum execution time: 28_223_000 picoseconds.
-		Weight::from_parts(29_664_000, 0)
+		// Minimum execution time: 30_493_000 picoseconds.
+		Weight::from_parts(31_934_000, 0)
 			.saturating_add(Weight::from_parts(0, 5477))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -306,54 +358,70 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Referenda DecidingCount (max_values: None, max_size: Some(14), added: 2489, mode: MaxEncodedLen)
 	/// Storage: Referenda TrackQueue (r:1 w:1)
 	/// Proof: Referenda TrackQueue (max_values: None, max_size: Some(2012), added: 4487, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn nudge_referendum_not_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2963`
+		//  Measured:  `3136`
 		//  Estimated: `5477`
-		// Minimum execution time: 27_474_000 picoseconds.
-		Weight::from_parts(29_072_000, 0)
+		// Minimum execution time: 29_744_000 picoseconds.
+		Weight::from_parts(31_342_000, 0)
 			.saturating_add(Weight::from_parts(0, 5477))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn nudge_referendum_no_deposit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `299`
+		//  Measured:  `472`
 		//  Estimated: `42428`
-		// Minimum execution time: 24_405_000 picoseconds.
-		Weight::from_parts(25_184_000, 0)
+		// Minimum execution time: 26_675_000 picoseconds.
+		Weight::from_parts(27_454_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn nudge_referendum_preparing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `347`
+		//  Measured:  `520`
 		//  Estimated: `42428`
-		// Minimum execution time: 24_572_000 picoseconds.
-		Weight::from_parts(25_287_000, 0)
+		// Minimum execution time: 26_842_000 picoseconds.
+		Weight::from_parts(27_557_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn nudge_referendum_timed_out() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `206`
+		//  Measured:  `379`
 		//  Estimated: `4401`
-		// Minimum execution time: 16_042_000 picoseconds.
-		Weight::from_parts(16_610_000, 0)
+		// Minimum execution time: 18_312_000 picoseconds.
+		Weight::from_parts(18_880_000, 0)
 			.saturating_add(Weight::from_parts(0, 4401))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -364,14 +432,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn nudge_referendum_begin_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `347`
+		//  Measured:  `520`
 		//  Estimated: `42428`
-		// Minimum execution time: 33_639_000 picoseconds.
-		Weight::from_parts(34_749_000, 0)
+		// Minimum execution time: 35_909_000 picoseconds.
+		Weight::from_parts(37_019_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -382,14 +454,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn nudge_referendum_begin_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `347`
+		//  Measured:  `520`
 		//  Estimated: `42428`
-		// Minimum execution time: 36_467_000 picoseconds.
-		Weight::from_parts(37_693_000, 0)
+		// Minimum execution time: 38_737_000 picoseconds.
+		Weight::from_parts(39_963_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -398,14 +474,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn nudge_referendum_begin_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `400`
+		//  Measured:  `573`
 		//  Estimated: `42428`
-		// Minimum execution time: 29_857_000 picoseconds.
-		Weight::from_parts(30_840_000, 0)
+		// Minimum execution time: 32_127_000 picoseconds.
+		Weight::from_parts(33_110_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -414,14 +494,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn nudge_referendum_end_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `383`
+		//  Measured:  `556`
 		//  Estimated: `42428`
-		// Minimum execution time: 31_028_000 picoseconds.
-		Weight::from_parts(32_154_000, 0)
+		// Minimum execution time: 33_298_000 picoseconds.
+		Weight::from_parts(34_424_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -430,14 +514,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn nudge_referendum_continue_not_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `400`
+		//  Measured:  `573`
 		//  Estimated: `42428`
-		// Minimum execution time: 28_594_000 picoseconds.
-		Weight::from_parts(29_092_000, 0)
+		// Minimum execution time: 30_864_000 picoseconds.
+		Weight::from_parts(31_362_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -446,14 +534,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn nudge_referendum_continue_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `404`
+		//  Measured:  `577`
 		//  Estimated: `42428`
-		// Minimum execution time: 27_246_000 picoseconds.
-		Weight::from_parts(28_003_000, 0)
+		// Minimum execution time: 29_516_000 picoseconds.
+		Weight::from_parts(30_273_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -464,14 +556,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
 	/// Storage: Scheduler Lookup (r:1 w:1)
 	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn nudge_referendum_approved() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `404`
+		//  Measured:  `577`
 		//  Estimated: `83866`
-		// Minimum execution time: 43_426_000 picoseconds.
-		Weight::from_parts(44_917_000, 0)
+		// Minimum execution time: 45_696_000 picoseconds.
+		Weight::from_parts(47_187_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -480,14 +576,18 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
 	fn nudge_referendum_rejected() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `400`
+		//  Measured:  `573`
 		//  Estimated: `42428`
-		// Minimum execution time: 30_285_000 picoseconds.
-		Weight::from_parts(31_575_000, 0)
+		// Minimum execution time: 32_555_000 picoseconds.
+		Weight::from_parts(33_845_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:0)
@@ -520,4 +620,56 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:1)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: Referenda TrackOrigins (r:1 w:1)
+	/// Proof: Referenda TrackOrigins (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Referenda RetiredTracks (r:1 w:0)
+	/// Proof: Referenda RetiredTracks (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: Referenda CounterForStoredTracks (r:1 w:1)
+	/// Proof: Referenda CounterForStoredTracks (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn insert_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `350`
+		//  Estimated: `4401`
+		// Minimum execution time: 20_438_000 picoseconds.
+		Weight::from_parts(21_039_000, 0)
+			.saturating_add(Weight::from_parts(0, 4401))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda RetiredTracks (r:1 w:0)
+	/// Proof: Referenda RetiredTracks (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:1)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	fn update_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `350`
+		//  Estimated: `4401`
+		// Minimum execution time: 18_022_000 picoseconds.
+		Weight::from_parts(18_623_000, 0)
+			.saturating_add(Weight::from_parts(0, 4401))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Referenda UseStoredTracks (r:1 w:0)
+	/// Proof: Referenda UseStoredTracks (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Referenda StoredTracks (r:1 w:0)
+	/// Proof: Referenda StoredTracks (max_values: None, max_size: Some(121), added: 2596, mode: MaxEncodedLen)
+	/// Storage: Referenda RetiredTracks (r:1 w:1)
+	/// Proof: Referenda RetiredTracks (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	fn retire_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `350`
+		//  Estimated: `4401`
+		// Minimum execution time: 17_080_000 picoseconds.
+		Weight::from_parts(17_681_000, 0)
+			.saturating_add(Weight::from_parts(0, 4401))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
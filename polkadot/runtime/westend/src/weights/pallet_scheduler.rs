@@ -288,4 +288,192 @@ impl<T: frame_system::Config> pallet_scheduler::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	fn service_timed_agendas_base() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `68`
+		//  Estimated: `1489`
+		// Minimum execution time: 4_441_000 picoseconds.
+		Weight::from_parts(4_623_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 50]`.
+	fn service_timed_agenda_base(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `115 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 4_664_000 picoseconds.
+		Weight::from_parts(11_471_926, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 3_126
+			.saturating_add(Weight::from_parts(360_894, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 49]`.
+	fn schedule_at_time(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `115 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 12_204_000 picoseconds.
+		Weight::from_parts(18_541_327, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 3_093
+			.saturating_add(Weight::from_parts(407_367, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedLookup` (r:0 w:1)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 50]`.
+	fn cancel_timed(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `115 + s * (177 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 20_962_000 picoseconds.
+		Weight::from_parts(21_273_113, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 1_869
+			.saturating_add(Weight::from_parts(593_800, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:0)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:1)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 49]`.
+	fn schedule_named_at_time(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `292 + s * (185 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 15_672_000 picoseconds.
+		Weight::from_parts(23_639_859, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 4_219
+			.saturating_add(Weight::from_parts(467_925, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:1)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 50]`.
+	fn cancel_named_timed(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318 + s * (185 ±0)`
+		//  Estimated: `42428`
+		// Minimum execution time: 23_671_000 picoseconds.
+		Weight::from_parts(24_871_802, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 1_859
+			.saturating_add(Weight::from_parts(629_450, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 50]`.
+	fn schedule_timed_retry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `155`
+		//  Estimated: `42428`
+		// Minimum execution time: 14_919_000 picoseconds.
+		Weight::from_parts(15_402_950, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			// Standard Error: 751
+			.saturating_add(Weight::from_parts(30_479, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn set_retry_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8965`
+		//  Estimated: `42428`
+		// Minimum execution time: 30_185_000 picoseconds.
+		Weight::from_parts(31_937_000, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:0)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn set_retry_named_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9643`
+		//  Estimated: `42428`
+		// Minimum execution time: 38_375_000 picoseconds.
+		Weight::from_parts(39_701_000, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn cancel_retry_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8977`
+		//  Estimated: `42428`
+		// Minimum execution time: 29_797_000 picoseconds.
+		Weight::from_parts(30_298_000, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:0)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn cancel_retry_named_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9655`
+		//  Estimated: `42428`
+		// Minimum execution time: 37_375_000 picoseconds.
+		Weight::from_parts(38_679_000, 0)
+			.saturating_add(Weight::from_parts(0, 42428))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -0,0 +1,273 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Re-validate a parachain candidate locally, e.g. to investigate a dispute.
+//!
+//! The candidate is prepared and executed by the same PVF host and workers the validators use,
+//! with the given executor parameters. The outcome is reported together with the timings and
+//! memory statistics collected by the host. See the README for how to use it.
+
+use clap::Parser;
+use codec::Decode;
+use polkadot_node_core_pvf::{
+	get_worker_version, start, Config, Metrics, PrepareJobKind, Priority, PvfPrepData,
+	EXECUTE_BINARY_NAME, PREPARE_BINARY_NAME,
+};
+use polkadot_node_metrics::metrics::Metrics as _;
+use polkadot_node_primitives::PoV;
+use polkadot_node_subsystem::messages::PvfExecKind;
+use polkadot_primitives::{
+	executor_params::{DEFAULT_APPROVAL_EXECUTION_TIMEOUT, DEFAULT_LENIENT_PREPARATION_TIMEOUT},
+	ExecutorParams, PersistedValidationData, PvfExecKind as RuntimePvfExecKind, PvfPrepKind,
+	MAX_CODE_SIZE,
+};
+use prometheus_endpoint::Registry;
+use std::{fs, path::PathBuf, process::ExitCode, sync::Arc, time::Instant};
+use tracing::level_filters::LevelFilter;
+
+/// Exit code if the candidate could not be prepared.
+const PREPARATION_FAILED: u8 = 2;
+/// Exit code if the candidate was prepared but is invalid.
+const VALIDATION_FAILED: u8 = 3;
+
+/// Tool for re-validating a parachain candidate locally with the PVF workers.
+///
+/// All the inputs are read from files containing either the raw bytes or the `0x`-prefixed hex
+/// of the data, e.g. as returned by the RPC.
+///
+/// Exits with 0 if the candidate is valid, 2 if it could not be prepared, 3 if it's invalid and 1
+/// on any other error, e.g. malformed inputs.
+#[derive(Parser)]
+struct Cli {
+	/// The path to the validation code, compressed or not.
+	#[arg(long)]
+	validation_code: PathBuf,
+
+	/// The path to the SCALE-encoded `PersistedValidationData` of the candidate.
+	#[arg(long)]
+	pvd: PathBuf,
+
+	/// The path to the SCALE-encoded `PoV` of the candidate, e.g. as recovered from the
+	/// availability store.
+	#[arg(long)]
+	pov: PathBuf,
+
+	/// The path to the SCALE-encoded `ExecutorParams` of the session the candidate was validated
+	/// in. The default executor parameters are used if omitted.
+	#[arg(long)]
+	executor_params: Option<PathBuf>,
+
+	/// The directory containing the `polkadot-prepare-worker` and `polkadot-execute-worker`
+	/// binaries. Defaults to the directory of this binary.
+	#[arg(long)]
+	workers_path: Option<PathBuf>,
+
+	/// The directory to store the prepared artifact in. A temporary directory is used if omitted.
+	#[arg(long)]
+	cache_path: Option<PathBuf>,
+
+	/// The limit for decompressing the validation code, as returned by the
+	/// `validation_code_bomb_limit` runtime API.
+	#[arg(long, default_value_t = MAX_CODE_SIZE * 10)]
+	validation_code_bomb_limit: u32,
+
+	/// Run the workers in secure validator mode, failing if any of the security features are
+	/// unavailable.
+	#[arg(long)]
+	secure_validator_mode: bool,
+}
+
+/// Reads the file at `path`, decoding it from hex if it's `0x`-prefixed.
+fn read_input(path: &PathBuf, what: &str) -> anyhow::Result<Vec<u8>> {
+	let bytes = fs::read(path).map_err(|error| {
+		tracing::error!(%error, path = %path.display(), "Failed to read {what}");
+		anyhow::anyhow!("Failed to read {what}")
+	})?;
+
+	match std::str::from_utf8(&bytes).map(str::trim) {
+		Ok(hex) if hex.starts_with("0x") => array_bytes::hex2bytes(hex).map_err(|error| {
+			tracing::error!(?error, path = %path.display(), "Failed to decode {what} from hex");
+			anyhow::anyhow!("Failed to decode {what} from hex")
+		}),
+		_ => Ok(bytes),
+	}
+}
+
+fn decode_input<T: Decode>(path: &PathBuf, what: &str) -> anyhow::Result<T> {
+	T::decode(&mut &read_input(path, what)?[..]).map_err(|error| {
+		tracing::error!(%error, "Failed to decode {what}");
+		anyhow::anyhow!("Failed to decode {what}")
+	})
+}
+
+/// Returns the sum of the samples of the given histogram, if anything has been observed.
+fn histogram_sum(registry: &Registry, name: &str) -> Option<f64> {
+	registry
+		.gather()
+		.into_iter()
+		.find(|family| family.get_name() == name)
+		.and_then(|family| family.get_metric().first().map(|metric| metric.get_histogram().clone()))
+		.filter(|histogram| histogram.get_sample_count() > 0)
+		.map(|histogram| histogram.get_sample_sum())
+}
+
+fn report_stats(registry: &Registry) {
+	for (name, description, unit) in [
+		("polkadot_pvf_preparation_time", "Preparation time in worker", "s"),
+		("polkadot_pvf_preparation_max_rss", "Preparation max RSS", "KiB"),
+		("polkadot_pvf_preparation_max_resident", "Preparation max resident memory", "KiB"),
+		("polkadot_pvf_preparation_max_allocated", "Preparation max allocated memory", "KiB"),
+		("polkadot_pvf_preparation_peak_tracked_allocation", "Preparation peak allocation", "KiB"),
+		("polkadot_pvf_execution_time", "Execution time in worker", "s"),
+	] {
+		if let Some(value) = histogram_sum(registry, name) {
+			tracing::info!("{description}: {value}{unit}");
+		}
+	}
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<ExitCode> {
+	let _ = tracing_subscriber::fmt()
+		.with_env_filter(
+			tracing_subscriber::EnvFilter::from_default_env()
+				.add_directive(LevelFilter::INFO.into()),
+		)
+		.with_writer(std::io::stderr)
+		.try_init();
+
+	let cli = Cli::parse();
+
+	let validation_code = read_input(&cli.validation_code, "validation code")?;
+	let pvd: PersistedValidationData = decode_input(&cli.pvd, "`PersistedValidationData`")?;
+	let pov: PoV = decode_input(&cli.pov, "`PoV`")?;
+	let executor_params: ExecutorParams = match cli.executor_params {
+		Some(ref path) => decode_input(path, "`ExecutorParams`")?,
+		None => ExecutorParams::default(),
+	};
+
+	let workers_path = match cli.workers_path {
+		Some(path) => path,
+		None => std::env::current_exe()?
+			.parent()
+			.ok_or_else(|| anyhow::anyhow!("Failed to determine the workers path"))?
+			.to_path_buf(),
+	};
+	let prepare_worker_path = workers_path.join(PREPARE_BINARY_NAME);
+	let execute_worker_path = workers_path.join(EXECUTE_BINARY_NAME);
+
+	// The workers refuse to run for a host of a different version, so we pose as the version the
+	// workers were built for. This allows re-validating with the workers of the release the
+	// dispute happened on.
+	let worker_version = get_worker_version(&prepare_worker_path).map_err(|error| {
+		tracing::error!(%error, path = %prepare_worker_path.display(), "Failed to run prepare worker");
+		anyhow::anyhow!("Failed to run prepare worker")
+	})?;
+	tracing::info!(%worker_version, path = %workers_path.display(), "Using PVF workers");
+
+	let temp_cache_dir = tempfile::tempdir()?;
+	let cache_path = cli.cache_path.unwrap_or_else(|| temp_cache_dir.path().to_path_buf());
+
+	let registry = Registry::new();
+	let metrics = Metrics::try_register(&registry)?;
+
+	let config = Config::new(
+		cache_path,
+		Some(worker_version),
+		cli.secure_validator_mode,
+		prepare_worker_path,
+		execute_worker_path,
+		1,
+		1,
+		1,
+	);
+	let (mut host, task) =
+		start(config, metrics).await.map_err(|error| anyhow::anyhow!("{error}"))?;
+	tokio::spawn(task);
+	tracing::info!(security_status = ?host.security_status, "Started PVF host");
+
+	let prep_timeout = executor_params
+		.pvf_prep_timeout(PvfPrepKind::Prepare)
+		.unwrap_or(DEFAULT_LENIENT_PREPARATION_TIMEOUT);
+	// Disputes are concluded with the approval timeout.
+	let exec_timeout = executor_params
+		.pvf_exec_timeout(RuntimePvfExecKind::Approval)
+		.unwrap_or(DEFAULT_APPROVAL_EXECUTION_TIMEOUT);
+
+	let pvf = PvfPrepData::from_code(
+		validation_code,
+		executor_params,
+		prep_timeout,
+		PrepareJobKind::Compilation,
+		cli.validation_code_bomb_limit,
+	);
+	tracing::info!(code_hash = ?pvf.code_hash(), executor_params_hash = ?pvf.executor_params().hash(), "Starting preparation");
+
+	let start = Instant::now();
+	let (result_tx, result_rx) = futures::channel::oneshot::channel();
+	host.precheck_pvf(pvf.clone(), result_tx)
+		.await
+		.map_err(|error| anyhow::anyhow!(error))?;
+	let prepare_result = result_rx.await?;
+	tracing::info!("Preparation took {}ms", start.elapsed().as_millis());
+
+	if let Err(error) = prepare_result {
+		tracing::error!(%error, "Preparation failed");
+		report_stats(&registry);
+		return Ok(ExitCode::from(PREPARATION_FAILED))
+	}
+
+	tracing::info!("Starting validation");
+
+	let start = Instant::now();
+	let (result_tx, result_rx) = futures::channel::oneshot::channel();
+	host.execute_pvf(
+		pvf,
+		exec_timeout,
+		Arc::new(pvd),
+		Arc::new(pov),
+		Priority::Critical,
+		PvfExecKind::Dispute,
+		result_tx,
+	)
+	.await
+	.map_err(|error| anyhow::anyhow!(error))?;
+	let result = result_rx.await?;
+	tracing::info!("Validation took {}ms", start.elapsed().as_millis());
+
+	let exit_code = match result {
+		Ok(result) => {
+			tracing::info!(
+				head_data_hash = ?result.head_data.hash(),
+				new_validation_code = result.new_validation_code.is_some(),
+				upward_messages = result.upward_messages.len(),
+				horizontal_messages = result.horizontal_messages.len(),
+				processed_downward_messages = result.processed_downward_messages,
+				hrmp_watermark = result.hrmp_watermark,
+				"Validation was successful",
+			);
+			ExitCode::SUCCESS
+		},
+		Err(error) => {
+			tracing::error!(%error, "Validation failed");
+			ExitCode::from(VALIDATION_FAILED)
+		},
+	};
+
+	report_stats(&registry);
+
+	Ok(exit_code)
+}
@@ -0,0 +1,108 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runs the tool against the adder test parachain and checks its exit code.
+
+use codec::Encode;
+use polkadot_node_core_pvf::testing::build_workers_and_get_paths;
+use polkadot_node_primitives::PoV;
+use polkadot_parachain_primitives::primitives::{BlockData as GenericBlockData, HeadData};
+use polkadot_primitives::PersistedValidationData;
+use std::{fs, path::Path, process::Command};
+use test_parachain_adder::{hash_state, BlockData, HeadData as AdderHeadData};
+
+/// Writes the inputs of an adder candidate to `dir`, with the block data given by `block_data`.
+fn write_inputs(dir: &Path, validation_code: &[u8], block_data: Vec<u8>) {
+	let parent_head = AdderHeadData { number: 0, parent_hash: [0; 32], post_state: hash_state(0) };
+	let pvd = PersistedValidationData {
+		parent_head: HeadData(parent_head.encode()),
+		relay_parent_number: 1u32,
+		relay_parent_storage_root: Default::default(),
+		max_pov_size: 4096 * 1024,
+	};
+	let pov = PoV { block_data: GenericBlockData(block_data) };
+
+	fs::write(dir.join("code.wasm"), validation_code).unwrap();
+	// The persisted validation data is given as hex, as returned by the RPC.
+	fs::write(dir.join("pvd.hex"), array_bytes::bytes2hex("0x", pvd.encode())).unwrap();
+	fs::write(dir.join("pov.bin"), pov.encode()).unwrap();
+}
+
+/// Runs the tool on the inputs in `dir` and returns its exit code.
+fn revalidate(dir: &Path) -> Option<i32> {
+	let (prepare_worker_path, _) = build_workers_and_get_paths();
+
+	Command::new(env!("CARGO_BIN_EXE_polkadot-pvf-revalidate"))
+		.arg("--validation-code")
+		.arg(dir.join("code.wasm"))
+		.arg("--pvd")
+		.arg(dir.join("pvd.hex"))
+		.arg("--pov")
+		.arg(dir.join("pov.bin"))
+		.arg("--workers-path")
+		.arg(prepare_worker_path.parent().unwrap())
+		.arg("--cache-path")
+		.arg(dir.join("cache"))
+		.status()
+		.unwrap()
+		.code()
+}
+
+#[test]
+fn valid_candidate_exits_with_success() {
+	let dir = tempfile::tempdir().unwrap();
+	write_inputs(
+		dir.path(),
+		test_parachain_adder::wasm_binary_unwrap(),
+		BlockData { state: 0, add: 512 }.encode(),
+	);
+
+	assert_eq!(revalidate(dir.path()), Some(0));
+}
+
+#[test]
+fn invalid_candidate_exits_with_failure() {
+	let dir = tempfile::tempdir().unwrap();
+	// The adder panics if the block data doesn't build on the parent head.
+	write_inputs(
+		dir.path(),
+		test_parachain_adder::wasm_binary_unwrap(),
+		BlockData { state: 1, add: 512 }.encode(),
+	);
+
+	assert_eq!(revalidate(dir.path()), Some(3));
+}
+
+#[test]
+fn unpreparable_code_exits_with_failure() {
+	let dir = tempfile::tempdir().unwrap();
+	write_inputs(dir.path(), b"not a wasm module", BlockData { state: 0, add: 512 }.encode());
+
+	assert_eq!(revalidate(dir.path()), Some(2));
+}
+
+#[test]
+fn malformed_inputs_exit_with_failure() {
+	let dir = tempfile::tempdir().unwrap();
+	write_inputs(
+		dir.path(),
+		test_parachain_adder::wasm_binary_unwrap(),
+		BlockData { state: 0, add: 512 }.encode(),
+	);
+	fs::write(dir.path().join("pvd.hex"), "0xnot hex").unwrap();
+
+	assert_eq!(revalidate(dir.path()), Some(1));
+}
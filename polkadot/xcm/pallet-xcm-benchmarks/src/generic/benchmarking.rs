@@ -881,18 +881,16 @@ mod benchmarks {
 
 	#[benchmark]
 	fn request_unlock() -> Result<(), BenchmarkError> {
-		use xcm_executor::traits::{AssetLock, Enact};
+		use xcm_executor::traits::AssetLock;
 
 		let (locker, owner, asset) = T::unlockable_asset()?;
 
-		// We first place the asset in lock first...
-		<T::XcmConfig as xcm_executor::Config>::AssetLocker::prepare_lock(
+		// We first note the asset as locked on `locker`...
+		<T::XcmConfig as xcm_executor::Config>::AssetLocker::note_unlockable(
 			locker.clone(),
 			asset.clone(),
 			owner.clone(),
 		)
-		.map_err(|_| BenchmarkError::Skip)?
-		.enact()
 		.map_err(|_| BenchmarkError::Skip)?;
 
 		let (expected_fees_mode, expected_assets_in_holding) =
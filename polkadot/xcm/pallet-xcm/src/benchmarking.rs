@@ -388,6 +388,26 @@ mod benchmarks {
 		_(RawOrigin::Root, true)
 	}
 
+	#[benchmark]
+	fn force_rate_limit() {
+		let location = Location::from(Parent);
+		let limit =
+			RateLimit { max_weight: Weight::from_parts(1, 1), max_messages: 1, refill_period: 1 };
+		RateLimitAllowances::<T>::insert(
+			VersionedLocation::from(location.clone()),
+			RateLimitBucket::full(&limit, 0),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, Box::new(VersionedLocation::from(location.clone())), Some(limit));
+
+		assert_eq!(
+			RateLimitOverrides::<T>::get(VersionedLocation::from(location.clone())),
+			Some(limit)
+		);
+		assert!(!RateLimitAllowances::<T>::contains_key(VersionedLocation::from(location)));
+	}
+
 	#[benchmark]
 	fn migrate_supported_version() {
 		let old_version = XCM_VERSION - 1;
@@ -680,6 +700,79 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn lock_remote_asset() -> Result<(), BenchmarkError> {
+		let (origin, origin_location, locker) = remote_lock_setup::<T>()?;
+		let account = crate::Pallet::<T>::lock_owner_account(&origin_location)
+			.map_err(|_| BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))?;
+		// Worst case: the origin already has `MaxLockers - 1` pending requests.
+		for i in 1..T::MaxLockers::get() {
+			let asset: Asset = (Location::new(0, [GeneralIndex(i.into())]), 1u128).into();
+			PendingRemoteLocks::<T>::insert(
+				&account,
+				(VersionedLocation::from(locker.clone()), VersionedAsset::from(asset)),
+				BlockNumberFor::<T>::zero(),
+			);
+		}
+		let asset: Asset = (Here, 1_000u128).into();
+
+		#[extrinsic_call]
+		_(
+			origin,
+			Box::new(locker.clone().into()),
+			Box::new(asset.clone().into()),
+			Box::new(asset.clone().into()),
+		);
+
+		assert!(PendingRemoteLocks::<T>::contains_key(
+			&account,
+			(VersionedLocation::from(locker), VersionedAsset::from(asset))
+		));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unlock_remote_asset() -> Result<(), BenchmarkError> {
+		let (origin, origin_location, locker) = remote_lock_setup::<T>()?;
+		let account = crate::Pallet::<T>::lock_owner_account(&origin_location)
+			.map_err(|_| BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))?;
+		let mut owner = origin_location;
+		owner.remove_network_id();
+		let asset: Asset = (Here, 1_000u128).into();
+		let key = (XCM_VERSION, account, VersionedAssetId::from(asset.id.clone()));
+		RemoteLockedFungibles::<T>::insert(
+			&key,
+			RemoteLockedFungibleRecord {
+				amount: 1_000,
+				owner: owner.into(),
+				locker: locker.clone().into(),
+				consumers: BoundedVec::default(),
+			},
+		);
+
+		#[extrinsic_call]
+		_(origin, Box::new(locker.into()), Box::new(asset.into()));
+
+		assert!(!RemoteLockedFungibles::<T>::contains_key(&key));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_remote_lock() -> Result<(), BenchmarkError> {
+		let (origin, origin_location, locker) = remote_lock_setup::<T>()?;
+		let account = crate::Pallet::<T>::lock_owner_account(&origin_location)
+			.map_err(|_| BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))?;
+		let asset: Asset = (Here, 1_000u128).into();
+		let key = (VersionedLocation::from(locker.clone()), VersionedAsset::from(asset.clone()));
+		PendingRemoteLocks::<T>::insert(&account, &key, BlockNumberFor::<T>::zero());
+
+		#[extrinsic_call]
+		_(origin, Box::new(locker.into()), Box::new(asset.into()));
+
+		assert!(!PendingRemoteLocks::<T>::contains_key(&account, &key));
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext_with_balances(Vec::new()),
@@ -687,6 +780,27 @@ mod benchmarks {
 	);
 }
 
+/// A funded signed origin, its location and a reachable locker for the remote lock benchmarks.
+fn remote_lock_setup<T: Config>(
+) -> Result<(RawOrigin<T::AccountId>, Location, Location), BenchmarkError> {
+	let error = BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX));
+	let who: T::AccountId = whitelisted_caller();
+	let origin = RawOrigin::Signed(who.clone());
+	let origin_location =
+		T::SendXcmOrigin::try_origin(origin.clone().into()).map_err(|_| error.clone())?;
+	let locker = T::reachable_dest().ok_or(error)?;
+
+	// Give some multiple of ED
+	let balance = T::ExistentialDeposit::get() * 1000u32.into();
+	let _ =
+		<pallet_balances::Pallet<T> as frame_support::traits::Currency<_>>::make_free_balance_be(
+			&who, balance,
+		);
+	T::DeliveryHelper::ensure_successful_delivery(&origin_location, &locker, FeeReason::ChargeFees);
+
+	Ok((origin, origin_location, locker))
+}
+
 pub mod helpers {
 	use super::*;
 	pub fn native_teleport_as_asset_transfer<T>(
@@ -57,7 +57,8 @@ use sp_runtime::{
 use xcm::{latest::QueryResponseInfo, prelude::*};
 use xcm_builder::{
 	ExecuteController, ExecuteControllerWeightInfo, InspectMessageQueues, QueryController,
-	QueryControllerWeightInfo, SendController, SendControllerWeightInfo,
+	QueryControllerWeightInfo, RateLimit, RateLimitBucket, RateLimitBuckets, RateLimits,
+	SendController, SendControllerWeightInfo,
 };
 use xcm_executor::{
 	traits::{
@@ -66,12 +67,17 @@ use xcm_executor::{
 		QueryHandler, QueryResponseStatus, RecordXcm, TransactAsset, TransferType,
 		VersionChangeNotifier, WeightBounds, XcmAssetTransfers,
 	},
-	AssetsInHolding,
+	AssetsInHolding, InstructionTrace,
 };
 use xcm_runtime_apis::{
 	authorized_aliases::{Error as AuthorizedAliasersApiError, OriginAliaser},
-	dry_run::{CallDryRunEffects, Error as XcmDryRunApiError, XcmDryRunEffects},
+	dry_run::{
+		CallDryRunEffects, Error as XcmDryRunApiError, TracedDryRunEffects, XcmDryRunEffects,
+		XcmTraceStep,
+	},
 	fees::Error as XcmPaymentApiError,
+	remote_locks::{Error as RemoteLocksApiError, RemoteLock, RemoteLockStatus},
+	transfer_routes::{Error as XcmTransferRoutesApiError, RouteHop, TransferRoute},
 	trusted_query::Error as TrustedQueryApiError,
 };
 
@@ -101,6 +107,10 @@ pub trait WeightInfo {
 	fn claim_assets() -> Weight;
 	fn add_authorized_alias() -> Weight;
 	fn remove_authorized_alias() -> Weight;
+	fn force_rate_limit() -> Weight;
+	fn lock_remote_asset() -> Weight;
+	fn unlock_remote_asset() -> Weight;
+	fn cancel_remote_lock() -> Weight;
 }
 
 /// fallback implementation
@@ -193,6 +203,22 @@ impl WeightInfo for TestWeightInfo {
 	fn remove_authorized_alias() -> Weight {
 		Weight::from_parts(100_000, 0)
 	}
+
+	fn force_rate_limit() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
+
+	fn lock_remote_asset() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
+
+	fn unlock_remote_asset() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
+
+	fn cancel_remote_lock() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
 }
 
 #[derive(Clone, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
@@ -580,6 +606,25 @@ pub mod pallet {
 		AliasAuthorizationRemoved { aliaser: Location, target: Location },
 		/// `target` removed all alias authorizations.
 		AliasesAuthorizationsRemoved { target: Location },
+		/// The rate limit of XCM executed from `location` was set, or removed if `None`.
+		RateLimitSet { location: Location, limit: Option<RateLimit> },
+		/// `owner` requested `locker` to lock `asset` in favour of this chain.
+		RemoteLockRequested { owner: Location, locker: Location, asset: Asset, message_id: XcmHash },
+		/// `locker` reported that it locked `asset` of `owner` in favour of this chain.
+		RemoteLockNoted { owner: Location, locker: Location, asset: Asset },
+		/// `owner` requested `locker` to unlock `asset`, which was locked in favour of this chain.
+		RemoteUnlockRequested {
+			owner: Location,
+			locker: Location,
+			asset: Asset,
+			message_id: XcmHash,
+		},
+		/// The remote lock of `asset` on `locker` started being used by a local consumer.
+		RemoteLockConsumed { owner: T::AccountId, locker: Location, asset: Asset },
+		/// A local consumer stopped using the remote lock of `asset` on `locker`.
+		RemoteLockReleased { owner: T::AccountId, locker: Location, asset: Asset },
+		/// `owner` dropped its unconfirmed request to `locker` to lock `asset`.
+		RemoteLockCancelled { owner: Location, locker: Location, asset: Asset },
 	}
 
 	#[pallet::origin]
@@ -679,6 +724,9 @@ pub mod pallet {
 		/// The alias to remove authorization for was not found.
 		#[codec(index = 27)]
 		AliasNotFound,
+		/// The remote lock operation could not be prepared.
+		#[codec(index = 28)]
+		RemoteLockFailed,
 	}
 
 	impl<T: Config> From<SendError> for Error<T> {
@@ -691,6 +739,18 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> From<xcm_executor::traits::LockError> for Error<T> {
+		fn from(e: xcm_executor::traits::LockError) -> Self {
+			use xcm_executor::traits::LockError::*;
+			match e {
+				NotLocked | NotEnoughLocked | WouldClobber => Error::<T>::LockNotFound,
+				InUse => Error::<T>::InUse,
+				BadOwner => Error::<T>::AccountNotSovereign,
+				_ => Error::<T>::RemoteLockFailed,
+			}
+		}
+	}
+
 	impl<T: Config> From<AssetTransferError> for Error<T> {
 		fn from(e: AssetTransferError) -> Self {
 			match e {
@@ -863,6 +923,47 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, TypeInfo, MaxEncodedLen)]
+	pub struct RemoteLockedNonFungibleRecord<ConsumerIdentifier> {
+		/// The owner of the locked asset.
+		pub owner: VersionedLocation,
+		/// The location which holds the original lock.
+		pub locker: VersionedLocation,
+		/// The local consumer of the remote lock, if any. A non-fungible asset can only be used by
+		/// one consumer at a time.
+		pub consumer: Option<ConsumerIdentifier>,
+	}
+
+	/// Non-fungible assets which we know are locked on a remote chain.
+	#[pallet::storage]
+	pub(super) type RemoteLockedNonFungibles<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, XcmVersion>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, VersionedAssetId>,
+			NMapKey<Blake2_128Concat, AssetInstance>,
+		),
+		RemoteLockedNonFungibleRecord<T::RemoteLockConsumerIdentifier>,
+		OptionQuery,
+	>;
+
+	/// Remote locks requested through `lock_remote_asset`, and the block they were requested at,
+	/// which the locker didn't confirm yet.
+	///
+	/// An account may have up to `MaxLockers` pending requests, which it can drop with
+	/// `cancel_remote_lock` if the locker never confirms them.
+	#[pallet::storage]
+	pub(super) type PendingRemoteLocks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		(VersionedLocation, VersionedAsset),
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
 	/// Fungible assets which we know are locked on this chain.
 	#[pallet::storage]
 	pub(super) type LockedFungibles<T: Config> = StorageMap<
@@ -896,6 +997,22 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type RecordedXcm<T: Config> = StorageValue<_, Xcm<()>>;
 
+	/// Whether or not a per-instruction trace of locally executed XCMs should be recorded.
+	/// Like [`ShouldRecordXcm`], this is meant to be used in runtime APIs only.
+	///
+	/// Only relevant if this pallet is being used as the [`xcm_executor::traits::RecordXcm`]
+	/// implementation in the XCM executor configuration.
+	#[pallet::storage]
+	pub(crate) type ShouldTraceXcm<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// If [`ShouldTraceXcm`] is set to true, the state of the executor after every locally
+	/// executed instruction is appended here.
+	///
+	/// Only relevant if this pallet is being used as the [`xcm_executor::traits::RecordXcm`]
+	/// implementation in the XCM executor configuration.
+	#[pallet::storage]
+	pub(crate) type RecordedTrace<T: Config> = StorageValue<_, Vec<InstructionTrace>, ValueQuery>;
+
 	/// Map of authorized aliasers of local origins. Each local location can authorize a list of
 	/// other locations to alias into it. Each aliaser is only valid until its inner `expiry`
 	/// block number.
@@ -908,6 +1025,28 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Rate limits of XCM executed from specific locations, set by governance.
+	///
+	/// Only relevant if this pallet is part of the `Limits` of an
+	/// [`xcm_builder::RateLimitedBarrier`]. They take precedence over the other limits if it comes
+	/// first in there.
+	#[pallet::storage]
+	pub(super) type RateLimitOverrides<T: Config> =
+		StorageMap<_, Blake2_128Concat, VersionedLocation, RateLimit, OptionQuery>;
+
+	/// Remaining allowance of rate limited locations.
+	///
+	/// Only relevant if this pallet is used as the `Buckets` of an
+	/// [`xcm_builder::RateLimitedBarrier`]. Allowances which are whole again are pruned a few at a
+	/// time as messages are executed.
+	#[pallet::storage]
+	pub(super) type RateLimitAllowances<T: Config> =
+		StorageMap<_, Blake2_128Concat, VersionedLocation, RateLimitBucket, OptionQuery>;
+
+	/// The raw key of [`RateLimitAllowances`] from which to continue pruning, if any.
+	#[pallet::storage]
+	pub(super) type RateLimitPruneCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		#[serde(skip)]
@@ -1698,6 +1837,195 @@ pub mod pallet {
 				Err(Error::<T>::AliasNotFound.into())
 			}
 		}
+
+		/// Set or remove the rate limit of XCM executed from `location`, overriding any other
+		/// limit applying to it. Its remaining allowance is reset.
+		///
+		/// - `origin`: Must be an origin specified by AdminOrigin.
+		/// - `location`: The origin of the XCM to rate limit.
+		/// - `limit`: The new limit, or `None` to fall back to the other limits applying to
+		///   `location`.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::force_rate_limit())]
+		pub fn force_rate_limit(
+			origin: OriginFor<T>,
+			location: Box<VersionedLocation>,
+			limit: Option<RateLimit>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let location: Location = (*location).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let versioned_location = VersionedLocation::from(location.clone());
+			RateLimitOverrides::<T>::set(&versioned_location, limit);
+			RateLimitAllowances::<T>::remove(&versioned_location);
+			Self::deposit_event(Event::RateLimitSet { location, limit });
+			Ok(())
+		}
+
+		/// Request `locker` to lock `asset` of the origin in favour of this chain.
+		///
+		/// The asset must be held on `locker` by the origin's location there. Once `locker`
+		/// reports the lock back, the asset is tracked as locked in favour of this chain and can
+		/// be used by local consumers, see [`Pallet::consume_remote_lock`].
+		///
+		/// - `origin`: Must be capable of sending XCM.
+		/// - `locker`: The location holding the asset, e.g. Asset Hub.
+		/// - `asset`: The fungible or non-fungible asset to lock, from the context of this chain.
+		/// - `fees`: The asset used to pay for execution on `locker`, from the context of this
+		///   chain. Any surplus is deposited back to the origin's location on `locker`.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::lock_remote_asset())]
+		pub fn lock_remote_asset(
+			origin: OriginFor<T>,
+			locker: Box<VersionedLocation>,
+			asset: Box<VersionedAsset>,
+			fees: Box<VersionedAsset>,
+		) -> DispatchResult {
+			let origin_location = T::SendXcmOrigin::ensure_origin(origin)?;
+			let interior: Junctions =
+				origin_location.clone().try_into().map_err(|_| Error::<T>::InvalidOrigin)?;
+			let locker: Location = (*locker).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let asset: Asset = (*asset).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let fees: Asset = (*fees).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let account = Self::lock_owner_account(&origin_location)?;
+			let pending_key =
+				(VersionedLocation::from(locker.clone()), VersionedAsset::from(asset.clone()));
+			let max_pending = T::MaxLockers::get() as usize;
+			ensure!(
+				PendingRemoteLocks::<T>::contains_key(&account, &pending_key) ||
+					PendingRemoteLocks::<T>::iter_key_prefix(&account).take(max_pending).count() <
+						max_pending,
+				Error::<T>::TooManyLocks
+			);
+
+			let context = T::UniversalLocation::get();
+			let reanchor = |location: Location| {
+				location.reanchored(&locker, &context).map_err(|_| Error::<T>::BadLocation)
+			};
+			let unlocker = reanchor(Location::here())?;
+			let beneficiary = reanchor(origin_location.clone())?;
+			let remote_asset = asset
+				.clone()
+				.reanchored(&locker, &context)
+				.map_err(|_| Error::<T>::BadLocation)?;
+			let remote_fees =
+				fees.reanchored(&locker, &context).map_err(|_| Error::<T>::BadLocation)?;
+			let message = Xcm(vec![
+				WithdrawAsset(remote_fees.clone().into()),
+				BuyExecution { fees: remote_fees, weight_limit: Unlimited },
+				LockAsset { asset: remote_asset, unlocker },
+				RefundSurplus,
+				DepositAsset { assets: AllCounted(1).into(), beneficiary },
+			]);
+			let message_id = Self::send_xcm(interior, locker.clone(), message).map_err(|error| {
+				tracing::error!(target: "xcm::pallet_xcm::lock_remote_asset", ?error, ?locker, "XCM send failed with error");
+				Error::<T>::from(error)
+			})?;
+
+			PendingRemoteLocks::<T>::insert(
+				&account,
+				pending_key,
+				frame_system::Pallet::<T>::block_number(),
+			);
+			Self::deposit_event(Event::RemoteLockRequested {
+				owner: origin_location,
+				locker,
+				asset,
+				message_id,
+			});
+			Ok(())
+		}
+
+		/// Request `locker` to unlock `asset` of the origin, previously locked in favour of this
+		/// chain.
+		///
+		/// Fails if the remote lock is still used by a local consumer. The delivery fees of the
+		/// unlock request are paid by the origin.
+		///
+		/// - `origin`: Must be capable of sending XCM.
+		/// - `locker`: The location holding the lock.
+		/// - `asset`: The locked asset, from the context of this chain. Fungible locks can be
+		///   partially unlocked.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::unlock_remote_asset())]
+		pub fn unlock_remote_asset(
+			origin: OriginFor<T>,
+			locker: Box<VersionedLocation>,
+			asset: Box<VersionedAsset>,
+		) -> DispatchResult {
+			let origin_location = T::SendXcmOrigin::ensure_origin(origin)?;
+			let locker: Location = (*locker).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let asset: Asset = (*asset).try_into().map_err(|()| Error::<T>::BadVersion)?;
+
+			let ticket = <Self as xcm_executor::traits::AssetLock>::prepare_reduce_unlockable(
+				locker.clone(),
+				asset.clone(),
+				origin_location.clone(),
+			)
+			.map_err(Error::<T>::from)?;
+
+			let context = T::UniversalLocation::get();
+			let remote_asset = asset
+				.clone()
+				.reanchored(&locker, &context)
+				.map_err(|_| Error::<T>::BadLocation)?;
+			let target = origin_location
+				.clone()
+				.reanchored(&locker, &context)
+				.map_err(|_| Error::<T>::BadLocation)?;
+			let message = Xcm(vec![UnlockAsset { asset: remote_asset, target }]);
+			let (send_ticket, price) =
+				validate_send::<T::XcmRouter>(locker.clone(), message).map_err(Error::<T>::from)?;
+			Self::charge_fees(origin_location.clone(), price).map_err(|error| {
+				tracing::error!(
+					target: "xcm::pallet_xcm::unlock_remote_asset",
+					?error, ?origin_location, "Unable to charge fee",
+				);
+				Error::<T>::FeesNotMet
+			})?;
+			xcm_executor::traits::Enact::enact(ticket).map_err(Error::<T>::from)?;
+			let message_id = T::XcmRouter::deliver(send_ticket).map_err(Error::<T>::from)?;
+
+			Self::deposit_event(Event::RemoteUnlockRequested {
+				owner: origin_location,
+				locker,
+				asset,
+				message_id,
+			});
+			Ok(())
+		}
+
+		/// Drop the request of the origin to lock `asset` on `locker`, which `locker` didn't
+		/// confirm.
+		///
+		/// Meant for requests which failed on `locker`. Nothing is sent to `locker`, so a lock it
+		/// confirms later is still tracked.
+		///
+		/// - `origin`: Must be capable of sending XCM.
+		/// - `locker`: The location the lock was requested from.
+		/// - `asset`: The asset requested to be locked, as given to `lock_remote_asset`.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::cancel_remote_lock())]
+		pub fn cancel_remote_lock(
+			origin: OriginFor<T>,
+			locker: Box<VersionedLocation>,
+			asset: Box<VersionedAsset>,
+		) -> DispatchResult {
+			let origin_location = T::SendXcmOrigin::ensure_origin(origin)?;
+			let locker: Location = (*locker).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let asset: Asset = (*asset).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let account = Self::lock_owner_account(&origin_location)?;
+			PendingRemoteLocks::<T>::take(
+				&account,
+				(VersionedLocation::from(locker.clone()), VersionedAsset::from(asset.clone())),
+			)
+			.ok_or(Error::<T>::LockNotFound)?;
+			Self::deposit_event(Event::RemoteLockCancelled {
+				owner: origin_location,
+				locker,
+				asset,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -2901,6 +3229,83 @@ impl<T: Config> Pallet<T> {
 		Ok(XcmDryRunEffects { forwarded_xcms, emitted_events: events, execution_result: result })
 	}
 
+	/// Like [`Self::dry_run_call`], but also returns the state of the XCM executor after every
+	/// instruction executed locally.
+	///
+	/// Meant to be used in the `xcm_runtime_apis::dry_run::DryRunApi` runtime API.
+	pub fn dry_run_call_traced<Runtime, Router, OriginCaller, RuntimeCall>(
+		origin: OriginCaller,
+		call: RuntimeCall,
+		result_xcms_version: XcmVersion,
+	) -> Result<
+		TracedDryRunEffects<CallDryRunEffects<<Runtime as frame_system::Config>::RuntimeEvent>>,
+		XcmDryRunApiError,
+	>
+	where
+		Runtime: crate::Config,
+		Router: InspectMessageQueues,
+		RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>,
+		<RuntimeCall as Dispatchable>::RuntimeOrigin: From<OriginCaller>,
+	{
+		crate::Pallet::<Runtime>::set_trace_xcm(true);
+		let effects = Self::dry_run_call::<Runtime, Router, OriginCaller, RuntimeCall>(
+			origin,
+			call,
+			result_xcms_version,
+		);
+		crate::Pallet::<Runtime>::set_trace_xcm(false);
+		let trace = crate::Pallet::<Runtime>::take_trace();
+		Ok(TracedDryRunEffects {
+			effects: effects?,
+			trace: Self::convert_trace(result_xcms_version, trace)?,
+		})
+	}
+
+	/// Like [`Self::dry_run_xcm`], but also returns the state of the XCM executor after every
+	/// executed instruction.
+	///
+	/// The trace is expressed in the same version as `xcm`.
+	/// Meant to be used in the `xcm_runtime_apis::dry_run::DryRunApi` runtime API.
+	pub fn dry_run_xcm_traced<Runtime, Router, RuntimeCall: Decode + GetDispatchInfo, XcmConfig>(
+		origin_location: VersionedLocation,
+		xcm: VersionedXcm<RuntimeCall>,
+	) -> Result<
+		TracedDryRunEffects<XcmDryRunEffects<<Runtime as frame_system::Config>::RuntimeEvent>>,
+		XcmDryRunApiError,
+	>
+	where
+		Runtime: frame_system::Config,
+		Router: InspectMessageQueues,
+		XcmConfig: xcm_executor::Config<RuntimeCall = RuntimeCall>,
+	{
+		let xcm_version = xcm.identify_version();
+		XcmConfig::XcmRecorder::set_trace_xcm(true);
+		let effects =
+			Self::dry_run_xcm::<Runtime, Router, RuntimeCall, XcmConfig>(origin_location, xcm);
+		XcmConfig::XcmRecorder::set_trace_xcm(false);
+		let trace = XcmConfig::XcmRecorder::take_trace();
+		Ok(TracedDryRunEffects {
+			effects: effects?,
+			trace: Self::convert_trace(xcm_version, trace)?,
+		})
+	}
+
+	fn convert_trace(
+		xcm_version: XcmVersion,
+		trace: Vec<InstructionTrace>,
+	) -> Result<Vec<XcmTraceStep>, XcmDryRunApiError> {
+		trace
+			.into_iter()
+			.map(|step| XcmTraceStep::from_instruction_trace(step, xcm_version))
+			.collect::<Result<Vec<_>, _>>()
+			.inspect_err(|error| {
+				tracing::error!(
+					target: "xcm::DryRunApi::convert_trace",
+					?error, "Trace version conversion failed with error"
+				);
+			})
+	}
+
 	fn convert_xcms(
 		xcm_version: XcmVersion,
 		xcms: Vec<VersionedXcm<()>>,
@@ -2988,6 +3393,134 @@ impl<T: Config> Pallet<T> {
 			})
 	}
 
+	/// Returns the routes for transferring `asset` to `destination`, one per transfer type
+	/// supported by the trusted reserves and teleporters of the runtime.
+	///
+	/// The programs of every route are built like `transfer_assets_using_type_and_then` would
+	/// build them, with `asset` paying for the fees on every hop. `weight_to_fee` converts the
+	/// weight of the local program to a fee in the given asset, if it's accepted for paying fees.
+	///
+	/// Meant to be used in the `xcm_runtime_apis::transfer_routes::XcmTransferRoutesApi` runtime
+	/// API.
+	pub fn transfer_routes(
+		asset: VersionedAsset,
+		destination: VersionedLocation,
+		weight_to_fee: impl Fn(Weight, &AssetId) -> Option<u128>,
+	) -> Result<Vec<TransferRoute>, XcmTransferRoutesApiError> {
+		let version = asset.identify_version();
+		let asset: Asset = asset.try_into().map_err(|e| {
+			tracing::debug!(
+				target: "xcm::pallet_xcm::transfer_routes",
+				?e, "Failed to convert versioned asset",
+			);
+			XcmTransferRoutesApiError::VersionedConversionFailed
+		})?;
+		let dest: Location = destination.try_into().map_err(|e| {
+			tracing::debug!(
+				target: "xcm::pallet_xcm::transfer_routes",
+				?e, "Failed to convert versioned location",
+			);
+			XcmTransferRoutesApiError::VersionedConversionFailed
+		})?;
+		ensure!(matches!(asset.fun, Fungible(_)), XcmTransferRoutesApiError::NonFungibleAsset);
+
+		let asset_location = asset.id.0.chain_location();
+		let mut transfer_types = Vec::new();
+		if <T::XcmExecutor as XcmAssetTransfers>::IsTeleporter::contains(&asset, &dest) {
+			transfer_types.push(TransferType::Teleport);
+		}
+		if <T::XcmExecutor as XcmAssetTransfers>::IsReserve::contains(&asset, &dest) {
+			transfer_types.push(TransferType::DestinationReserve);
+		}
+		if asset_location == Location::here() ||
+			<T::XcmExecutor as XcmAssetTransfers>::IsTeleporter::contains(
+				&asset,
+				&asset_location,
+			) {
+			transfer_types.push(TransferType::LocalReserve);
+		} else if asset_location != dest &&
+			<T::XcmExecutor as XcmAssetTransfers>::IsReserve::contains(&asset, &asset_location)
+		{
+			transfer_types.push(TransferType::RemoteReserve(asset_location.into()));
+		}
+
+		Ok(transfer_types
+			.into_iter()
+			.filter_map(|transfer_type| {
+				Self::transfer_route(&asset, &dest, transfer_type, version, &weight_to_fee)
+			})
+			.collect())
+	}
+
+	/// Build the route of `asset` to `dest` using `transfer_type`, if the transfer is allowed.
+	fn transfer_route(
+		asset: &Asset,
+		dest: &Location,
+		transfer_type: TransferType,
+		version: XcmVersion,
+		weight_to_fee: &impl Fn(Weight, &AssetId) -> Option<u128>,
+	) -> Option<TransferRoute> {
+		// The programs don't depend on the sender and beneficiary, use a placeholder account.
+		let account: Location = AccountId32 { network: None, id: [0; 32] }.into();
+		let (mut local_xcm, remote_xcm) = Self::build_xcm_transfer_type(
+			account.clone(),
+			dest.clone(),
+			Either::Left(account),
+			vec![asset.clone()],
+			transfer_type.clone(),
+			FeesHandling::Batched { fees: asset.clone() },
+			Unlimited,
+		)
+		.inspect_err(|error| {
+			tracing::debug!(
+				target: "xcm::pallet_xcm::transfer_routes",
+				?error, ?transfer_type, "Transfer type not allowed",
+			);
+		})
+		.ok()?;
+		let local_weight = T::Weigher::weight(&mut local_xcm).ok()?;
+
+		// `(location, message, sent from here)` of every hop.
+		let hops = match remote_xcm {
+			Some(message) => vec![(dest.clone(), message, true)],
+			None => {
+				// The reserve forwards the transfer to the destination.
+				let (reserve, on_reserve) = Self::forwarded_xcm(&local_xcm)?;
+				let (_, on_dest) = Self::forwarded_xcm(&on_reserve)?;
+				vec![(reserve, on_reserve, true), (dest.clone(), on_dest, false)]
+			},
+		};
+		let hops = hops
+			.into_iter()
+			.map(|(location, message, sent_from_here)| {
+				let location = VersionedLocation::from(location).into_version(version).ok()?;
+				let message = VersionedXcm::from(message).into_version(version).ok()?;
+				let delivery_fees = sent_from_here
+					.then(|| Self::query_delivery_fees(location.clone(), message.clone()).ok())
+					.flatten();
+				Some(RouteHop { location, message, delivery_fees })
+			})
+			.collect::<Option<Vec<_>>>()?;
+
+		Some(TransferRoute {
+			transfer_type,
+			local_weight,
+			local_fee: weight_to_fee(local_weight, &asset.id),
+			hops,
+		})
+	}
+
+	/// The destination and program of the first instruction of `xcm` forwarding assets to another
+	/// chain.
+	fn forwarded_xcm<Call>(xcm: &Xcm<Call>) -> Option<(Location, Xcm<()>)> {
+		xcm.inner().iter().find_map(|instruction| match instruction {
+			InitiateReserveWithdraw { reserve, xcm, .. } => Some((reserve.clone(), xcm.clone())),
+			DepositReserveAsset { dest, xcm, .. } | InitiateTeleport { dest, xcm, .. } =>
+				Some((dest.clone(), xcm.clone())),
+			_ => None,
+		})
+	}
+
 	/// Given an Asset and a Location, returns if the provided location is a trusted reserve for the
 	/// given asset.
 	pub fn is_trusted_reserve(
@@ -3095,6 +3628,147 @@ impl<T: Config> Pallet<T> {
 		}))
 	}
 
+	/// Returns the assets of `owner` locked on remote chains in favour of this chain, including
+	/// the locks requested through `lock_remote_asset` and not confirmed yet.
+	pub fn remote_locks(owner: VersionedLocation) -> Result<Vec<RemoteLock>, RemoteLocksApiError> {
+		let desired_version = owner.identify_version();
+		let owner: Location = owner.try_into().map_err(|e| {
+			tracing::debug!(
+				target: "xcm::pallet_xcm::remote_locks",
+				?e, "Failed to convert versioned location",
+			);
+			RemoteLocksApiError::LocationVersionConversionFailed
+		})?;
+		let account =
+			Self::lock_owner_account(&owner).map_err(|_| RemoteLocksApiError::InvalidOwner)?;
+		let entry = |locker: VersionedLocation, asset: VersionedAsset, status| {
+			Some(RemoteLock {
+				locker: locker.into_version(desired_version).ok()?,
+				asset: asset.into_version(desired_version).ok()?,
+				status,
+			})
+		};
+
+		let requested =
+			PendingRemoteLocks::<T>::iter_prefix(&account).filter_map(|((locker, asset), at)| {
+				entry(locker, asset, RemoteLockStatus::Requested { at: at.saturated_into() })
+			});
+		let fungibles = RemoteLockedFungibles::<T>::iter_prefix((XCM_VERSION, account.clone()))
+			.filter_map(|(id, record)| {
+				let id: AssetId = id.try_into().ok()?;
+				let status = match record.amount_held() {
+					Some(_) => RemoteLockStatus::InUse,
+					None => RemoteLockStatus::Locked,
+				};
+				entry(record.locker, Asset::from((id, record.amount)).into(), status)
+			});
+		let non_fungibles =
+			RemoteLockedNonFungibles::<T>::iter_prefix((XCM_VERSION, account.clone())).filter_map(
+				|((id, instance), record)| {
+					let id: AssetId = id.try_into().ok()?;
+					let status = match record.consumer {
+						Some(_) => RemoteLockStatus::InUse,
+						None => RemoteLockStatus::Locked,
+					};
+					entry(record.locker, Asset::from((id, instance)).into(), status)
+				},
+			);
+		Ok(requested.chain(fungibles).chain(non_fungibles).collect())
+	}
+
+	/// Start using the remote lock of `asset` on `locker`, held by `owner`, as `consumer`.
+	///
+	/// Meant for pallets taking remotely locked assets as collateral: a remote lock can't be
+	/// unlocked while it has consumers. A fungible lock can have up to `MaxRemoteLockConsumers`
+	/// consumers, each holding up to the whole locked amount, and a non-fungible lock only one.
+	pub fn consume_remote_lock(
+		owner: &T::AccountId,
+		locker: Location,
+		asset: Asset,
+		consumer: T::RemoteLockConsumerIdentifier,
+	) -> DispatchResult {
+		let versioned_locker = VersionedLocation::from(locker.clone());
+		let id = VersionedAssetId::from(asset.id.clone());
+		match asset.fun {
+			Fungible(amount) => {
+				let key = (XCM_VERSION, owner.clone(), id);
+				RemoteLockedFungibles::<T>::try_mutate(&key, |record| {
+					let record = record.as_mut().ok_or(Error::<T>::LockNotFound)?;
+					ensure!(record.locker == versioned_locker, Error::<T>::LockNotFound);
+					ensure!(record.amount >= amount, Error::<T>::LowBalance);
+					ensure!(
+						record.consumers.iter().all(|(c, _)| *c != consumer),
+						Error::<T>::InUse
+					);
+					record
+						.consumers
+						.try_push((consumer, amount))
+						.map_err(|_| Error::<T>::TooManyLocks)
+				})?;
+			},
+			NonFungible(instance) => {
+				let key = (XCM_VERSION, owner.clone(), id, instance);
+				RemoteLockedNonFungibles::<T>::try_mutate(&key, |record| {
+					let record = record.as_mut().ok_or(Error::<T>::LockNotFound)?;
+					ensure!(record.locker == versioned_locker, Error::<T>::LockNotFound);
+					ensure!(record.consumer.is_none(), Error::<T>::InUse);
+					record.consumer = Some(consumer);
+					Ok::<_, Error<T>>(())
+				})?;
+			},
+		}
+		Self::deposit_event(Event::RemoteLockConsumed { owner: owner.clone(), locker, asset });
+		Ok(())
+	}
+
+	/// Stop using the remote lock of `asset` on `locker`, held by `owner`, as `consumer`.
+	///
+	/// Once a remote lock has no consumers left, `owner` can unlock it.
+	pub fn release_remote_lock(
+		owner: &T::AccountId,
+		locker: Location,
+		asset: Asset,
+		consumer: T::RemoteLockConsumerIdentifier,
+	) -> DispatchResult {
+		let versioned_locker = VersionedLocation::from(locker.clone());
+		let id = VersionedAssetId::from(asset.id.clone());
+		match asset.fun {
+			Fungible(_) => {
+				let key = (XCM_VERSION, owner.clone(), id);
+				RemoteLockedFungibles::<T>::try_mutate(&key, |record| {
+					let record = record.as_mut().ok_or(Error::<T>::LockNotFound)?;
+					ensure!(record.locker == versioned_locker, Error::<T>::LockNotFound);
+					let index = record
+						.consumers
+						.iter()
+						.position(|(c, _)| *c == consumer)
+						.ok_or(Error::<T>::LockNotFound)?;
+					record.consumers.remove(index);
+					Ok::<_, Error<T>>(())
+				})?;
+			},
+			NonFungible(instance) => {
+				let key = (XCM_VERSION, owner.clone(), id, instance);
+				RemoteLockedNonFungibles::<T>::try_mutate(&key, |record| {
+					let record = record.as_mut().ok_or(Error::<T>::LockNotFound)?;
+					ensure!(record.locker == versioned_locker, Error::<T>::LockNotFound);
+					ensure!(record.consumer == Some(consumer), Error::<T>::LockNotFound);
+					record.consumer = None;
+					Ok::<_, Error<T>>(())
+				})?;
+			},
+		}
+		Self::deposit_event(Event::RemoteLockReleased { owner: owner.clone(), locker, asset });
+		Ok(())
+	}
+
+	/// The account tracking the remote locks of `owner`.
+	fn lock_owner_account(owner: &Location) -> Result<T::AccountId, Error<T>> {
+		let mut owner = owner.clone();
+		owner.remove_network_id();
+		T::SovereignAccountOf::convert_location(&owner).ok_or(Error::<T>::AccountNotSovereign)
+	}
+
 	/// Create a new expectation of a query response with the querier being here.
 	fn do_new_query(
 		responder: impl Into<Location>,
@@ -3358,6 +4032,7 @@ impl<T: Config> xcm_executor::traits::Enact for UnlockTicket<T> {
 pub struct ReduceTicket<T: Config> {
 	key: (u32, T::AccountId, VersionedAssetId),
 	amount: u128,
+	instance: Option<AssetInstance>,
 	locker: VersionedLocation,
 	owner: VersionedLocation,
 }
@@ -3365,6 +4040,15 @@ pub struct ReduceTicket<T: Config> {
 impl<T: Config> xcm_executor::traits::Enact for ReduceTicket<T> {
 	fn enact(self) -> Result<(), xcm_executor::traits::LockError> {
 		use xcm_executor::traits::LockError::UnexpectedState;
+		if let Some(instance) = self.instance {
+			let (version, account, id) = self.key;
+			let key = (version, account, id, instance);
+			let record = RemoteLockedNonFungibles::<T>::get(&key).ok_or(UnexpectedState)?;
+			ensure!(self.locker == record.locker && self.owner == record.owner, UnexpectedState);
+			ensure!(record.consumer.is_none(), UnexpectedState);
+			RemoteLockedNonFungibles::<T>::remove(&key);
+			return Ok(())
+		}
 		let mut record = RemoteLockedFungibles::<T>::get(&self.key).ok_or(UnexpectedState)?;
 		ensure!(self.locker == record.locker && self.owner == record.owner, UnexpectedState);
 		let new_amount = record.amount.checked_sub(self.amount).ok_or(UnexpectedState)?;
@@ -3422,25 +4106,48 @@ impl<T: Config> xcm_executor::traits::AssetLock for Pallet<T> {
 	) -> Result<(), xcm_executor::traits::LockError> {
 		use xcm_executor::traits::LockError::*;
 		ensure!(T::TrustedLockers::contains(&locker, &asset), NotTrusted);
-		let amount = match asset.fun {
-			Fungible(a) => a,
-			NonFungible(_) => return Err(Unimplemented),
-		};
 		owner.remove_network_id();
 		let account = T::SovereignAccountOf::convert_location(&owner).ok_or(BadOwner)?;
-		let locker = locker.into();
-		let owner = owner.into();
-		let id: VersionedAssetId = asset.id.into();
-		let key = (XCM_VERSION, account, id);
-		let mut record =
-			RemoteLockedFungibleRecord { amount, owner, locker, consumers: BoundedVec::default() };
-		if let Some(old) = RemoteLockedFungibles::<T>::get(&key) {
-			// Make sure that the new record wouldn't clobber any old data.
-			ensure!(old.locker == record.locker && old.owner == record.owner, WouldClobber);
-			record.consumers = old.consumers;
-			record.amount = record.amount.max(old.amount);
-		}
-		RemoteLockedFungibles::<T>::insert(&key, record);
+		let versioned_locker: VersionedLocation = locker.clone().into();
+		let versioned_owner: VersionedLocation = owner.clone().into();
+		let id: VersionedAssetId = asset.id.clone().into();
+		match asset.fun {
+			Fungible(amount) => {
+				let key = (XCM_VERSION, account.clone(), id);
+				let mut record = RemoteLockedFungibleRecord {
+					amount,
+					owner: versioned_owner,
+					locker: versioned_locker.clone(),
+					consumers: BoundedVec::default(),
+				};
+				if let Some(old) = RemoteLockedFungibles::<T>::get(&key) {
+					// Make sure that the new record wouldn't clobber any old data.
+					ensure!(old.locker == record.locker && old.owner == record.owner, WouldClobber);
+					record.consumers = old.consumers;
+					record.amount = record.amount.max(old.amount);
+				}
+				RemoteLockedFungibles::<T>::insert(&key, record);
+			},
+			NonFungible(instance) => {
+				let key = (XCM_VERSION, account.clone(), id, instance);
+				let mut record = RemoteLockedNonFungibleRecord {
+					owner: versioned_owner,
+					locker: versioned_locker.clone(),
+					consumer: None,
+				};
+				if let Some(old) = RemoteLockedNonFungibles::<T>::get(&key) {
+					// Make sure that the new record wouldn't clobber any old data.
+					ensure!(old.locker == record.locker && old.owner == record.owner, WouldClobber);
+					record.consumer = old.consumer;
+				}
+				RemoteLockedNonFungibles::<T>::insert(&key, record);
+			},
+		}
+		PendingRemoteLocks::<T>::remove(
+			&account,
+			(versioned_locker, VersionedAsset::from(asset.clone())),
+		);
+		Self::deposit_event(Event::RemoteLockNoted { owner, locker, asset });
 		Ok(())
 	}
 
@@ -3450,16 +4157,23 @@ impl<T: Config> xcm_executor::traits::AssetLock for Pallet<T> {
 		mut owner: Location,
 	) -> Result<Self::ReduceTicket, xcm_executor::traits::LockError> {
 		use xcm_executor::traits::LockError::*;
-		let amount = match asset.fun {
-			Fungible(a) => a,
-			NonFungible(_) => return Err(Unimplemented),
-		};
 		owner.remove_network_id();
 		let sovereign_account = T::SovereignAccountOf::convert_location(&owner).ok_or(BadOwner)?;
 		let locker = locker.into();
 		let owner = owner.into();
 		let id: VersionedAssetId = asset.id.into();
 		let key = (XCM_VERSION, sovereign_account, id);
+		let amount = match asset.fun {
+			Fungible(a) => a,
+			NonFungible(instance) => {
+				let (version, account, id) = key.clone();
+				let record = RemoteLockedNonFungibles::<T>::get((version, account, id, instance))
+					.ok_or(NotLocked)?;
+				ensure!(locker == record.locker && owner == record.owner, WouldClobber);
+				ensure!(record.consumer.is_none(), InUse);
+				return Ok(ReduceTicket { key, amount: 1, instance: Some(instance), locker, owner })
+			},
+		};
 
 		let record = RemoteLockedFungibles::<T>::get(&key).ok_or(NotLocked)?;
 		// Make sure that the record contains what we expect and there's enough to unlock.
@@ -3469,7 +4183,7 @@ impl<T: Config> xcm_executor::traits::AssetLock for Pallet<T> {
 			record.amount_held().map_or(true, |h| record.amount.saturating_sub(amount) >= h),
 			InUse
 		);
-		Ok(ReduceTicket { key, amount, locker, owner })
+		Ok(ReduceTicket { key, amount, instance: None, locker, owner })
 	}
 }
 
@@ -3792,6 +4506,60 @@ impl<T: Config> CheckSuspension for Pallet<T> {
 	}
 }
 
+impl<T: Config> RateLimits for Pallet<T> {
+	fn rate_limit(origin: &Location) -> Option<RateLimit> {
+		RateLimitOverrides::<T>::get(VersionedLocation::from(origin.clone()))
+	}
+
+	fn weight() -> Weight {
+		T::DbWeight::get().reads(1)
+	}
+}
+
+/// Maximum number of allowances looked at by a single call to [`RateLimitBuckets::prune`].
+///
+/// More than one allowance is created per message at most, so this is enough to keep
+/// [`RateLimitAllowances`] bounded by the number of locations recently rate limited.
+const MAX_PRUNED_ALLOWANCES: u32 = 2;
+
+impl<T: Config> RateLimitBuckets for Pallet<T> {
+	fn bucket(origin: &Location) -> Option<RateLimitBucket> {
+		RateLimitAllowances::<T>::get(VersionedLocation::from(origin.clone()))
+	}
+
+	fn set_bucket(origin: &Location, bucket: RateLimitBucket) {
+		RateLimitAllowances::<T>::insert(VersionedLocation::from(origin.clone()), bucket);
+	}
+
+	fn prune(now: u32) {
+		let mut iter = match RateLimitPruneCursor::<T>::take() {
+			Some(cursor) => RateLimitAllowances::<T>::iter_from(cursor),
+			None => RateLimitAllowances::<T>::iter(),
+		};
+		let mut seen = 0;
+		let mut whole = Vec::new();
+		for (location, bucket) in iter.by_ref().take(MAX_PRUNED_ALLOWANCES as usize) {
+			seen += 1;
+			if bucket.full_at <= now {
+				whole.push(location);
+			}
+		}
+		// Start over from the beginning once the end of the map is reached.
+		if seen == MAX_PRUNED_ALLOWANCES {
+			RateLimitPruneCursor::<T>::put(iter.last_raw_key().to_vec());
+		}
+		for location in whole {
+			RateLimitAllowances::<T>::remove(location);
+		}
+	}
+
+	fn weight() -> Weight {
+		// Reading and storing the allowance, the cursor and the pruned allowances.
+		let accesses = 2 + MAX_PRUNED_ALLOWANCES as u64;
+		T::DbWeight::get().reads_writes(accesses, accesses)
+	}
+}
+
 impl<T: Config> RecordXcm for Pallet<T> {
 	fn should_record() -> bool {
 		ShouldRecordXcm::<T>::get()
@@ -3808,6 +4576,22 @@ impl<T: Config> RecordXcm for Pallet<T> {
 	fn record(xcm: Xcm<()>) {
 		RecordedXcm::<T>::put(xcm);
 	}
+
+	fn should_trace() -> bool {
+		ShouldTraceXcm::<T>::get()
+	}
+
+	fn set_trace_xcm(enabled: bool) {
+		ShouldTraceXcm::<T>::put(enabled);
+	}
+
+	fn take_trace() -> Vec<InstructionTrace> {
+		RecordedTrace::<T>::take()
+	}
+
+	fn trace(step: InstructionTrace) {
+		RecordedTrace::<T>::append(step);
+	}
 }
 
 /// Ensure that the origin `o` represents an XCM (`Transact`) origin.
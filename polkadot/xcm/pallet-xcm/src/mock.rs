@@ -38,9 +38,9 @@ use xcm_builder::{
 	AllowTopLevelPaidExecutionFrom, Case, ChildParachainAsNative, ChildParachainConvertsVia,
 	ChildSystemParachainAsSuperuser, DescribeAllTerminal, EnsureDecodableXcm, FixedRateOfFungible,
 	FixedWeightBounds, FrameTransactionalProcessor, FungibleAdapter, FungiblesAdapter,
-	HashedDescription, IsConcrete, MatchedConvertedConcreteId, NoChecking, SendXcmFeeToAccount,
-	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
-	XcmFeeManagerFromComponents,
+	HashedDescription, IsConcrete, MatchedConvertedConcreteId, NoChecking, RateLimitedBarrier,
+	RateLimitedWeightBounds, SendXcmFeeToAccount, SignedAccountId32AsNative, SignedToAccountId32,
+	SovereignSignedViaLocation, TakeWeightCredit, XcmFeeManagerFromComponents,
 };
 use xcm_executor::{
 	traits::{Identity, JustTry},
@@ -314,6 +314,7 @@ impl pallet_assets::Config for Test {
 	type Freezer = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
+	type TransferRestrictions = ();
 	type Extra = ();
 	type RemoveItemsLimit = ConstU32<5>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -478,12 +479,23 @@ impl Contains<Location> for XcmFeesNotWaivedLocations {
 	}
 }
 
-pub type Barrier = (
-	TakeWeightCredit,
-	AllowTopLevelPaidExecutionFrom<Everything>,
-	AllowKnownQueryResponses<XcmPallet>,
-	AllowSubscriptionsFrom<Everything>,
-);
+pub type Barrier = RateLimitedBarrier<
+	(
+		TakeWeightCredit,
+		AllowTopLevelPaidExecutionFrom<Everything>,
+		AllowKnownQueryResponses<XcmPallet>,
+		AllowSubscriptionsFrom<Everything>,
+	),
+	XcmPallet,
+	XcmPallet,
+	System,
+>;
+
+pub type Weigher = RateLimitedWeightBounds<
+	FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>,
+	XcmPallet,
+	XcmPallet,
+>;
 
 pub type XcmRouter =
 	EnsureDecodableXcm<(TestPaidForPara3000SendXcm, TestSendXcmErrX8, TestSendXcm)>;
@@ -505,7 +517,7 @@ impl xcm_executor::Config for XcmConfig {
 	);
 	type UniversalLocation = UniversalLocation;
 	type Barrier = Barrier;
-	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+	type Weigher = Weigher;
 	type Trader = FixedRateOfFungible<CurrencyPerSecondPerByte, ()>;
 	type ResponseHandler = XcmPallet;
 	type AssetTrap = XcmPallet;
@@ -564,20 +576,20 @@ impl pallet_xcm::Config for Test {
 	type XcmExecutor = XcmExecutor<XcmConfig>;
 	type XcmTeleportFilter = EverythingBut<XcmTeleportFiltered>;
 	type XcmReserveTransferFilter = Everything;
-	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+	type Weigher = Weigher;
 	type UniversalLocation = UniversalLocation;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
 	type AdvertisedXcmVersion = AdvertisedXcmVersion;
 	type AdminOrigin = EnsureRoot<AccountId>;
-	type TrustedLockers = ();
+	type TrustedLockers = Everything;
 	type SovereignAccountOf = AccountId32Aliases<(), AccountId32>;
 	type Currency = Balances;
 	type CurrencyMatcher = IsConcrete<RelayLocation>;
 	type MaxLockers = frame_support::traits::ConstU32<8>;
-	type MaxRemoteLockConsumers = frame_support::traits::ConstU32<0>;
-	type RemoteLockConsumerIdentifier = ();
+	type MaxRemoteLockConsumers = frame_support::traits::ConstU32<2>;
+	type RemoteLockConsumerIdentifier = u32;
 	type WeightInfo = TestWeightInfo;
 	type AuthorizedAliasConsideration =
 		HoldConsideration<AccountId, Balances, AuthorizeAliasHoldReason, ConvertDeposit>;
@@ -24,9 +24,10 @@ use crate::{
 	mock::*,
 	pallet::{LockedFungibles, RemoteLockedFungibles, SupportedVersion},
 	AssetTraps, AuthorizedAliasers, Config, CurrentMigration, Error, ExecuteControllerWeightInfo,
-	LatestVersionedLocation, MaxAuthorizedAliases, Pallet, Queries, QueryStatus, RecordedXcm,
-	RemoteLockedFungibleRecord, ShouldRecordXcm, VersionDiscoveryQueue, VersionMigrationStage,
-	VersionNotifiers, VersionNotifyTargets, WeightInfo,
+	LatestVersionedLocation, MaxAuthorizedAliases, Pallet, Queries, QueryStatus,
+	RateLimitAllowances, RecordedXcm, RemoteLockedFungibleRecord, ShouldRecordXcm,
+	VersionDiscoveryQueue, VersionMigrationStage, VersionNotifiers, VersionNotifyTargets,
+	WeightInfo,
 };
 use bounded_collections::BoundedVec;
 use frame_support::{
@@ -40,11 +41,12 @@ use sp_runtime::{
 	SaturatedConversion, TokenError,
 };
 use xcm::{latest::QueryResponseInfo, prelude::*};
-use xcm_builder::AllowKnownQueryResponses;
+use xcm_builder::{AllowKnownQueryResponses, RateLimit};
 use xcm_executor::{
-	traits::{Properties, QueryHandler, QueryResponseStatus, ShouldExecute},
+	traits::{AssetLock, Properties, QueryHandler, QueryResponseStatus, ShouldExecute},
 	XcmExecutor,
 };
+use xcm_runtime_apis::remote_locks::{RemoteLock, RemoteLockStatus};
 
 const ALICE: AccountId = AccountId::new([0u8; 32]);
 const BOB: AccountId = AccountId::new([1u8; 32]);
@@ -814,6 +816,115 @@ fn basic_subscription_works() {
 	});
 }
 
+#[test]
+fn force_rate_limit_works() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let remote: Location = Parachain(1000).into();
+		let limit =
+			RateLimit { max_weight: BaseXcmWeight::get(), max_messages: 1, refill_period: 10 };
+		let should_execute = || {
+			let mut message = Xcm::<()>(vec![SubscribeVersion {
+				query_id: 0,
+				max_response_weight: Weight::zero(),
+			}]);
+			Barrier::should_execute(
+				&remote,
+				message.inner_mut(),
+				BaseXcmWeight::get(),
+				&mut Properties { weight_credit: Weight::zero(), message_id: None },
+			)
+		};
+
+		// Not rate limited by default.
+		assert_ok!(should_execute());
+		assert_ok!(should_execute());
+
+		assert_noop!(
+			XcmPallet::force_rate_limit(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(remote.clone().into()),
+				Some(limit)
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(XcmPallet::force_rate_limit(
+			RuntimeOrigin::root(),
+			Box::new(remote.clone().into()),
+			Some(limit)
+		));
+		System::assert_last_event(RuntimeEvent::XcmPallet(crate::Event::RateLimitSet {
+			location: remote.clone(),
+			limit: Some(limit),
+		}));
+
+		assert_ok!(should_execute());
+		assert_eq!(should_execute(), Err(frame_support::traits::ProcessMessageError::Yield));
+		System::set_block_number(System::block_number() + 10);
+		assert_ok!(should_execute());
+		assert_eq!(should_execute(), Err(frame_support::traits::ProcessMessageError::Yield));
+
+		// Removing the limit also drops the remaining allowance.
+		assert_ok!(XcmPallet::force_rate_limit(
+			RuntimeOrigin::root(),
+			Box::new(remote.clone().into()),
+			None
+		));
+		assert!(RateLimitAllowances::<Test>::get(VersionedLocation::from(remote.clone())).is_none());
+		assert_ok!(should_execute());
+		assert_ok!(should_execute());
+	});
+}
+
+#[test]
+fn whole_rate_limit_allowances_are_pruned() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let limit =
+			RateLimit { max_weight: BaseXcmWeight::get(), max_messages: 1, refill_period: 10 };
+		let should_execute = |remote: &Location| {
+			let mut message = Xcm::<()>(vec![SubscribeVersion {
+				query_id: 0,
+				max_response_weight: Weight::zero(),
+			}]);
+			Barrier::should_execute(
+				remote,
+				message.inner_mut(),
+				BaseXcmWeight::get(),
+				&mut Properties { weight_credit: Weight::zero(), message_id: None },
+			)
+		};
+		let remotes: Vec<Location> = (1000..1005).map(|id| Parachain(id).into()).collect();
+		for remote in &remotes {
+			assert_ok!(XcmPallet::force_rate_limit(
+				RuntimeOrigin::root(),
+				Box::new(remote.clone().into()),
+				Some(limit)
+			));
+			assert_ok!(should_execute(remote));
+		}
+		// Nothing is pruned while the allowances are being refilled.
+		assert_eq!(RateLimitAllowances::<Test>::iter().count(), 5);
+
+		// Once they are whole again, a few of them are pruned with every message.
+		System::set_block_number(System::block_number() + 10);
+		assert_ok!(should_execute(&remotes[0]));
+		assert!(RateLimitAllowances::<Test>::iter().count() >= 3);
+		for _ in 0..4 {
+			let _ = should_execute(&remotes[0]);
+		}
+		assert_eq!(
+			RateLimitAllowances::<Test>::iter_keys().collect::<Vec<_>>(),
+			vec![VersionedLocation::from(remotes[0].clone())]
+		);
+
+		// Which doesn't change anything for the pruned locations.
+		assert_ok!(should_execute(&remotes[1]));
+		assert_eq!(
+			should_execute(&remotes[1]),
+			Err(frame_support::traits::ProcessMessageError::Yield)
+		);
+	});
+}
+
 #[test]
 fn subscriptions_increment_id() {
 	new_test_ext_with_balances(vec![]).execute_with(|| {
@@ -1743,3 +1854,209 @@ fn deliver_failure_with_expect_error() {
 		})
 	});
 }
+
+/// Test the lifecycle of a non-fungible asset locked on a remote chain in favour of this chain:
+/// lock request, lock confirmation by the locker, use as collateral and unlock.
+#[test]
+fn remote_non_fungible_lock_lifecycle_works() {
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let locker: Location = Parachain(1000).into();
+		let owner: Location = AccountId32 { network: None, id: ALICE.into() }.into();
+		let nft: Asset = ((Parachain(1000), PalletInstance(52), GeneralIndex(1)), Index(7)).into();
+		let remote_nft: Asset = ((PalletInstance(52), GeneralIndex(1)), Index(7)).into();
+		let remote_owner = Location::new(1, [AccountId32 { network: None, id: ALICE.into() }]);
+		let locks = || XcmPallet::remote_locks(owner.clone().into()).unwrap();
+
+		// request the lock
+		assert_ok!(XcmPallet::lock_remote_asset(
+			RuntimeOrigin::signed(ALICE),
+			Box::new(locker.clone().into()),
+			Box::new(nft.clone().into()),
+			Box::new((Here, FEE_AMOUNT).into()),
+		));
+		assert_eq!(
+			take_sent_xcm(),
+			vec![(
+				locker.clone(),
+				Xcm(vec![
+					DescendOrigin([AccountId32 { network: None, id: ALICE.into() }].into()),
+					WithdrawAsset((Parent, FEE_AMOUNT).into()),
+					buy_execution((Parent, FEE_AMOUNT)),
+					LockAsset { asset: remote_nft.clone(), unlocker: Parent.into() },
+					RefundSurplus,
+					DepositAsset {
+						assets: AllCounted(1).into(),
+						beneficiary: remote_owner.clone()
+					},
+				]),
+			)]
+		);
+		assert_eq!(
+			locks(),
+			vec![RemoteLock {
+				locker: locker.clone().into(),
+				asset: nft.clone().into(),
+				status: RemoteLockStatus::Requested { at: 1 },
+			}]
+		);
+
+		// the locker confirms it
+		assert_ok!(XcmPallet::note_unlockable(locker.clone(), nft.clone(), owner.clone()));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::XcmPallet(crate::Event::RemoteLockNoted {
+				owner: owner.clone(),
+				locker: locker.clone(),
+				asset: nft.clone(),
+			})
+		);
+		assert_eq!(
+			locks(),
+			vec![RemoteLock {
+				locker: locker.clone().into(),
+				asset: nft.clone().into(),
+				status: RemoteLockStatus::Locked,
+			}]
+		);
+
+		// it can only be used by one consumer, and can't be unlocked while in use
+		assert_ok!(XcmPallet::consume_remote_lock(&ALICE, locker.clone(), nft.clone(), 1));
+		assert_noop!(
+			XcmPallet::consume_remote_lock(&ALICE, locker.clone(), nft.clone(), 2),
+			Error::<Test>::InUse
+		);
+		assert_eq!(locks()[0].status, RemoteLockStatus::InUse);
+		assert_noop!(
+			XcmPallet::unlock_remote_asset(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(locker.clone().into()),
+				Box::new(nft.clone().into()),
+			),
+			Error::<Test>::InUse
+		);
+
+		// once released, it can be unlocked
+		assert_noop!(
+			XcmPallet::release_remote_lock(&ALICE, locker.clone(), nft.clone(), 2),
+			Error::<Test>::LockNotFound
+		);
+		assert_ok!(XcmPallet::release_remote_lock(&ALICE, locker.clone(), nft.clone(), 1));
+		assert_ok!(XcmPallet::unlock_remote_asset(
+			RuntimeOrigin::signed(ALICE),
+			Box::new(locker.clone().into()),
+			Box::new(nft.clone().into()),
+		));
+		assert_eq!(
+			take_sent_xcm(),
+			vec![(
+				locker.clone(),
+				Xcm(vec![UnlockAsset { asset: remote_nft, target: remote_owner }])
+			)]
+		);
+		assert!(locks().is_empty());
+		assert_noop!(
+			XcmPallet::unlock_remote_asset(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(locker.into()),
+				Box::new(nft.into()),
+			),
+			Error::<Test>::LockNotFound
+		);
+	});
+}
+
+/// Test consumers of a fungible asset locked on a remote chain in favour of this chain.
+#[test]
+fn remote_fungible_lock_consumers_work() {
+	new_test_ext_with_balances(vec![(ALICE, INITIAL_BALANCE)]).execute_with(|| {
+		let locker: Location = Parachain(1000).into();
+		let owner: Location = AccountId32 { network: None, id: ALICE.into() }.into();
+		let asset = |amount: u128| -> Asset { ((Parachain(1000), GeneralIndex(1)), amount).into() };
+		let unlock = |amount: u128| {
+			XcmPallet::unlock_remote_asset(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(locker.clone().into()),
+				Box::new(asset(amount).into()),
+			)
+		};
+		assert_ok!(XcmPallet::note_unlockable(locker.clone(), asset(1000), owner.clone()));
+
+		// every consumer can hold up to the whole locked amount
+		assert_noop!(
+			XcmPallet::consume_remote_lock(&ALICE, locker.clone(), asset(1001), 1),
+			Error::<Test>::LowBalance
+		);
+		assert_ok!(XcmPallet::consume_remote_lock(&ALICE, locker.clone(), asset(600), 1));
+		assert_ok!(XcmPallet::consume_remote_lock(&ALICE, locker.clone(), asset(800), 2));
+		assert_noop!(
+			XcmPallet::consume_remote_lock(&ALICE, locker.clone(), asset(100), 3),
+			Error::<Test>::TooManyLocks
+		);
+
+		// only the amount not held by any consumer can be unlocked
+		assert_noop!(unlock(300), Error::<Test>::InUse);
+		assert_ok!(XcmPallet::release_remote_lock(&ALICE, locker.clone(), asset(800), 2));
+		assert_ok!(unlock(300));
+		assert_eq!(
+			XcmPallet::remote_locks(owner.into()).unwrap(),
+			vec![RemoteLock {
+				locker: locker.into(),
+				asset: asset(700).into(),
+				status: RemoteLockStatus::InUse,
+			}]
+		);
+	});
+}
+
+/// Test that an account can only have a bounded number of unconfirmed lock requests, which it can
+/// cancel.
+#[test]
+fn pending_remote_locks_are_bounded_and_can_be_cancelled() {
+	new_test_ext_with_balances(vec![(ALICE, INITIAL_BALANCE)]).execute_with(|| {
+		let locker: Location = Parachain(1000).into();
+		let owner: Location = AccountId32 { network: None, id: ALICE.into() }.into();
+		let asset = |index: u128| -> Asset { ((Parachain(1000), GeneralIndex(index)), 10).into() };
+		let lock = |index: u128| {
+			XcmPallet::lock_remote_asset(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(locker.clone().into()),
+				Box::new(asset(index).into()),
+				Box::new((Here, FEE_AMOUNT).into()),
+			)
+		};
+		let cancel = |index: u128| {
+			XcmPallet::cancel_remote_lock(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(locker.clone().into()),
+				Box::new(asset(index).into()),
+			)
+		};
+
+		// up to `MaxLockers` requests can be pending
+		for index in 0..8 {
+			assert_ok!(lock(index));
+		}
+		assert_noop!(lock(8), Error::<Test>::TooManyLocks);
+		// requesting a pending lock again is fine
+		assert_ok!(lock(0));
+
+		// cancelling a request makes room for another one
+		assert_noop!(cancel(8), Error::<Test>::LockNotFound);
+		assert_ok!(cancel(0));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::XcmPallet(crate::Event::RemoteLockCancelled {
+				owner: owner.clone(),
+				locker: locker.clone(),
+				asset: asset(0),
+			})
+		);
+		assert_ok!(lock(8));
+
+		// confirmed requests don't count anymore
+		assert_ok!(XcmPallet::note_unlockable(locker.clone(), asset(1), owner.clone()));
+		assert_ok!(lock(9));
+		assert_eq!(XcmPallet::remote_locks(owner.into()).unwrap().len(), 9);
+	});
+}
@@ -28,7 +28,7 @@ use frame_support::{
 	},
 	PalletId,
 };
-use sp_core::{ConstU128, ConstU32, Get};
+use sp_core::{ConstU128, ConstU32, ConstU64, Get};
 use sp_runtime::{
 	traits::{AccountIdConversion, IdentityLookup, MaybeEquivalence, TryConvert, TryConvertInto},
 	BuildStorage, Permill,
@@ -146,6 +146,8 @@ impl pallet_asset_conversion::Config for Runtime {
 	type PalletId = AssetConversionPalletId;
 	type MaxSwapPathLength = ConstU32<3>;
 	type MintMinLiquidity = ConstU128<100>;
+	type MaxPriceObservations = ConstU32<10>;
+	type PriceObservationPeriod = ConstU64<10>;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
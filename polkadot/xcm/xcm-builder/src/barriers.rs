@@ -17,12 +17,15 @@
 //! Various implementations for `ShouldExecute`.
 
 use crate::{CreateMatcher, MatchXcm};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use core::{cell::Cell, marker::PhantomData, ops::ControlFlow, result::Result};
 use frame_support::{
 	ensure,
 	traits::{Contains, ContainsPair, Get, Nothing, ProcessMessageError},
 };
 use polkadot_parachain_primitives::primitives::IsSystem;
+use scale_info::TypeInfo;
+use sp_runtime::traits::{BlockNumberProvider, UniqueSaturatedInto};
 use xcm::prelude::*;
 use xcm_executor::traits::{CheckSuspension, DenyExecution, OnResponse, Properties, ShouldExecute};
 
@@ -279,6 +282,223 @@ where
 	}
 }
 
+/// How much an origin may have executed by [`RateLimitedBarrier`].
+///
+/// An origin may execute up to `max_weight` and `max_messages` in a burst, after which it has to
+/// wait for its allowance to be refilled, which takes `refill_period` blocks when it is empty.
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	PartialEq,
+	Eq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct RateLimit {
+	/// Maximum weight executed in a burst.
+	pub max_weight: Weight,
+	/// Maximum number of messages executed in a burst.
+	pub max_messages: u32,
+	/// Number of blocks needed to refill an empty allowance.
+	pub refill_period: u32,
+}
+
+impl RateLimit {
+	fn period(&self) -> u64 {
+		self.refill_period.max(1) as u64
+	}
+}
+
+/// The remaining allowance of an origin, as tracked by [`RateLimitedBarrier`].
+///
+/// Allowances are stored multiplied by [`RateLimit::refill_period`], so that each block refills
+/// exactly `max_weight` and `max_messages` and no fraction is lost to rounding.
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	PartialEq,
+	Eq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct RateLimitBucket {
+	/// Remaining weight, scaled by the refill period.
+	pub weight: Weight,
+	/// Remaining number of messages, scaled by the refill period.
+	pub messages: u64,
+	/// Block at which the allowance was last refilled.
+	pub updated_at: u32,
+	/// Block by which the allowance is whole again at the latest, after which the bucket can be
+	/// dropped without changing anything for its origin.
+	pub full_at: u32,
+}
+
+impl RateLimitBucket {
+	/// A bucket holding the whole allowance of `limit`.
+	pub fn full(limit: &RateLimit, now: u32) -> Self {
+		Self {
+			weight: limit.max_weight.saturating_mul(limit.period()),
+			messages: (limit.max_messages as u64).saturating_mul(limit.period()),
+			updated_at: now,
+			full_at: now,
+		}
+	}
+
+	/// Take the allowance for a message of `weight`, refilling the bucket up to `now` first.
+	///
+	/// Returns `false`, leaving the bucket untouched other than the refill, if there isn't enough
+	/// allowance left.
+	pub fn try_consume(&mut self, limit: &RateLimit, weight: Weight, now: u32) -> bool {
+		let full = Self::full(limit, now);
+		let elapsed = now.saturating_sub(self.updated_at) as u64;
+		self.weight = self
+			.weight
+			.saturating_add(limit.max_weight.saturating_mul(elapsed))
+			.min(full.weight);
+		self.messages = self
+			.messages
+			.saturating_add((limit.max_messages as u64).saturating_mul(elapsed))
+			.min(full.messages);
+		self.updated_at = now;
+
+		let weight = weight.saturating_mul(limit.period());
+		if self.weight.all_gte(weight) && self.messages >= limit.period() {
+			self.weight.saturating_reduce(weight);
+			self.messages -= limit.period();
+			self.full_at = now.saturating_add(limit.refill_period.max(1));
+			true
+		} else {
+			false
+		}
+	}
+}
+
+/// Provides the [`RateLimit`] applying to an origin, if any.
+pub trait RateLimits {
+	/// The limit applying to `origin`, or `None` if it isn't rate limited.
+	fn rate_limit(origin: &Location) -> Option<RateLimit>;
+
+	/// The worst-case weight of [`Self::rate_limit`].
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl RateLimits for Tuple {
+	fn rate_limit(origin: &Location) -> Option<RateLimit> {
+		for_tuples!( #(
+			if let Some(limit) = Tuple::rate_limit(origin) {
+				return Some(limit);
+			}
+		)* );
+		None
+	}
+
+	fn weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight.saturating_accrue(Tuple::weight()); )* );
+		weight
+	}
+}
+
+/// Rate limits every origin contained in `Pattern` to `Limit`.
+///
+/// Each matching origin gets its own allowance.
+pub struct RateLimitFor<Pattern, Limit>(PhantomData<(Pattern, Limit)>);
+impl<Pattern: Contains<Location>, Limit: Get<RateLimit>> RateLimits
+	for RateLimitFor<Pattern, Limit>
+{
+	fn rate_limit(origin: &Location) -> Option<RateLimit> {
+		Pattern::contains(origin).then(Limit::get)
+	}
+}
+
+/// Storage for the allowances tracked by [`RateLimitedBarrier`].
+pub trait RateLimitBuckets {
+	/// The current allowance of `origin`, if it has ever been rate limited.
+	fn bucket(origin: &Location) -> Option<RateLimitBucket>;
+	/// Store the allowance of `origin`.
+	fn set_bucket(origin: &Location, bucket: RateLimitBucket);
+
+	/// Drop some of the allowances which are whole again at `now`, as given by
+	/// [`RateLimitBucket::full_at`], so that storage doesn't grow with every origin ever seen.
+	///
+	/// Called once per rate limited message, so it should only do a bounded amount of work.
+	fn prune(_now: u32) {}
+
+	/// The worst-case weight of reading, storing and pruning allowances for one message.
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Barrier throttling the execution of messages allowed by `InnerBarrier`, using a token bucket
+/// per origin.
+///
+/// Origins rate limited by `Limits` may only have executed up to their [`RateLimit`] in weight and
+/// number of messages, with their allowance stored in `Buckets` and refilled over time, as given
+/// by `BlockNumber`. Once the allowance of an origin runs out, its messages are rejected with
+/// [`ProcessMessageError::Yield`] so that they are retried in a later block. Messages heavier
+/// than the whole allowance are rejected as overweight.
+///
+/// The storage accesses of `Limits` and `Buckets` are not part of the weight of the message; use
+/// [`RateLimitedWeightBounds`](crate::RateLimitedWeightBounds) as the weigher to charge for them.
+pub struct RateLimitedBarrier<InnerBarrier, Limits, Buckets, BlockNumber>(
+	PhantomData<(InnerBarrier, Limits, Buckets, BlockNumber)>,
+);
+impl<InnerBarrier, Limits, Buckets, BlockNumber> ShouldExecute
+	for RateLimitedBarrier<InnerBarrier, Limits, Buckets, BlockNumber>
+where
+	InnerBarrier: ShouldExecute,
+	Limits: RateLimits,
+	Buckets: RateLimitBuckets,
+	BlockNumber: BlockNumberProvider,
+{
+	fn should_execute<RuntimeCall>(
+		origin: &Location,
+		instructions: &mut [Instruction<RuntimeCall>],
+		max_weight: Weight,
+		properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		tracing::trace!(
+			target: "xcm::barriers",
+			?origin,
+			?instructions,
+			?max_weight,
+			?properties,
+			"RateLimitedBarrier"
+		);
+		InnerBarrier::should_execute(origin, instructions, max_weight, properties)?;
+		let Some(limit) = Limits::rate_limit(origin) else { return Ok(()) };
+		ensure!(max_weight.all_lte(limit.max_weight), ProcessMessageError::Overweight(max_weight));
+
+		let now = BlockNumber::current_block_number().unique_saturated_into();
+		let mut bucket =
+			Buckets::bucket(origin).unwrap_or_else(|| RateLimitBucket::full(&limit, now));
+		let allowed = bucket.try_consume(&limit, max_weight, now);
+		Buckets::set_bucket(origin, bucket);
+		Buckets::prune(now);
+		if !allowed {
+			tracing::debug!(
+				target: "xcm::barriers",
+				?origin, ?max_weight, ?bucket,
+				"RateLimitedBarrier: allowance exhausted, yielding"
+			);
+			return Err(ProcessMessageError::Yield)
+		}
+		Ok(())
+	}
+}
+
 /// Allows execution from any origin that is contained in `T` (i.e. `T::Contains(origin)`).
 ///
 /// Use only for executions from completely trusted origins, from which no permissionless messages
@@ -488,6 +708,37 @@ impl<T: Contains<Location>> ShouldExecute for AllowSubscriptionsFrom<T> {
 	}
 }
 
+/// Allows execution from `origin` if it is just a straight `NoteUnlockable` or `UnlockAsset`
+/// instruction, i.e. the notification of an asset `origin` locked in favour of this chain, or of
+/// an asset this chain locked in favour of `origin` being released.
+///
+/// The asset locker only accepts these notifications from the chain the asset is locked on, or in
+/// favour of, respectively.
+pub struct AllowLockNotificationsFrom<T>(PhantomData<T>);
+impl<T: Contains<Location>> ShouldExecute for AllowLockNotificationsFrom<T> {
+	fn should_execute<RuntimeCall>(
+		origin: &Location,
+		instructions: &mut [Instruction<RuntimeCall>],
+		max_weight: Weight,
+		properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		tracing::trace!(
+			target: "xcm::barriers",
+			?origin, ?instructions, ?max_weight, ?properties,
+			"AllowLockNotificationsFrom",
+		);
+		ensure!(T::contains(origin), ProcessMessageError::Unsupported);
+		instructions
+			.matcher()
+			.assert_remaining_insts(1)?
+			.match_next_inst(|inst| match inst {
+				NoteUnlockable { .. } | UnlockAsset { .. } => Ok(()),
+				_ => Err(ProcessMessageError::BadFormat),
+			})?;
+		Ok(())
+	}
+}
+
 /// Allows execution for the Relay Chain origin (represented as `Location::parent()`) if it is just
 /// a straight `HrmpNewChannelOpenRequest`, `HrmpChannelAccepted`, or `HrmpChannelClosing`
 /// instruction.
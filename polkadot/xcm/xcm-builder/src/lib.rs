@@ -42,10 +42,12 @@ pub use asset_exchange::SingleAssetExchangeAdapter;
 mod barriers;
 pub use barriers::{
 	AllowExplicitUnpaidExecutionFrom, AllowHrmpNotificationsFromRelayChain,
-	AllowKnownQueryResponses, AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom,
-	AllowUnpaidExecutionFrom, DenyRecursively, DenyReserveTransferToRelayChain, DenyThenTry,
-	IsChildSystemParachain, IsParentsOnly, IsSiblingSystemParachain, RespectSuspension,
-	TakeWeightCredit, TrailingSetTopicAsId, WithComputedOrigin,
+	AllowKnownQueryResponses, AllowLockNotificationsFrom, AllowSubscriptionsFrom,
+	AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, DenyRecursively,
+	DenyReserveTransferToRelayChain, DenyThenTry, IsChildSystemParachain, IsParentsOnly,
+	IsSiblingSystemParachain, RateLimit, RateLimitBucket, RateLimitBuckets, RateLimitFor,
+	RateLimitedBarrier, RateLimits, RespectSuspension, TakeWeightCredit, TrailingSetTopicAsId,
+	WithComputedOrigin,
 };
 
 mod controller;
@@ -101,7 +103,8 @@ pub use matcher::{CreateMatcher, MatchXcm, Matcher};
 
 mod nonfungibles_adapter;
 pub use nonfungibles_adapter::{
-	NonFungiblesAdapter, NonFungiblesMutateAdapter, NonFungiblesTransferAdapter,
+	NonFungiblesAdapter, NonFungiblesLockTicket, NonFungiblesLocker, NonFungiblesMutateAdapter,
+	NonFungiblesTransferAdapter, XCM_UNLOCKER_ATTRIBUTE,
 };
 
 mod nonfungible_adapter;
@@ -145,5 +148,6 @@ pub use universal_exports::{
 
 mod weight;
 pub use weight::{
-	FixedRateOfFungible, FixedWeightBounds, TakeRevenue, UsingComponents, WeightInfoBounds,
+	FixedRateOfFungible, FixedWeightBounds, RateLimitedWeightBounds, TakeRevenue, UsingComponents,
+	WeightInfoBounds,
 };
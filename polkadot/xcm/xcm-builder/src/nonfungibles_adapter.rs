@@ -17,14 +17,19 @@
 //! Adapters to work with [`frame_support::traits::tokens::nonfungibles`] through XCM.
 
 use crate::{AssetChecking, MintLocation};
-use core::{fmt::Debug, marker::PhantomData, result};
+use codec::Encode;
+use core::{convert::Infallible, fmt::Debug, marker::PhantomData, result};
 use frame_support::{
 	ensure,
-	traits::{tokens::nonfungibles, Get},
+	traits::{
+		tokens::{nonfungibles, nonfungibles_v2},
+		Get,
+	},
 };
 use xcm::latest::prelude::*;
 use xcm_executor::traits::{
-	ConvertLocation, Error as MatchError, MatchesNonFungibles, TransactAsset,
+	AssetLock, ConvertLocation, Enact, Error as MatchError, LockError, MatchesNonFungibles,
+	TransactAsset,
 };
 
 const LOG_TARGET: &str = "xcm::nonfungibles_adapter";
@@ -385,3 +390,132 @@ where
 		)
 	}
 }
+
+/// Key of the system attribute holding the unlocker of an item locked through
+/// [`NonFungiblesLocker`].
+pub const XCM_UNLOCKER_ATTRIBUTE: &[u8] = b"xcm:unlocker";
+
+/// [`AssetLock`] implementation that allows locking items of a [`nonfungibles_v2`] implementation
+/// in favour of a remote chain, the unlocker.
+///
+/// Only implements the locker's side: a locked item can't be transferred until the unlocker
+/// sends `UnlockAsset` back. The unlocker is kept in the item's [`XCM_UNLOCKER_ATTRIBUTE`] system
+/// attribute.
+pub struct NonFungiblesLocker<Assets, Matcher, AccountIdConverter, AccountId, ItemConfig>(
+	PhantomData<(Assets, Matcher, AccountIdConverter, AccountId, ItemConfig)>,
+);
+
+/// Ticket of [`NonFungiblesLocker`], locking or unlocking an item when enacted.
+pub struct NonFungiblesLockTicket<Assets, AccountId, ItemConfig>
+where
+	Assets: nonfungibles_v2::Inspect<AccountId>,
+{
+	collection: Assets::CollectionId,
+	item: Assets::ItemId,
+	unlocker: Option<Vec<u8>>,
+	_phantom: PhantomData<(AccountId, ItemConfig)>,
+}
+
+impl<Assets, AccountId, ItemConfig> Enact for NonFungiblesLockTicket<Assets, AccountId, ItemConfig>
+where
+	Assets: nonfungibles_v2::Transfer<AccountId> + nonfungibles_v2::Mutate<AccountId, ItemConfig>,
+{
+	fn enact(self) -> Result<(), LockError> {
+		match self.unlocker {
+			Some(unlocker) => {
+				Assets::disable_transfer(&self.collection, &self.item)
+					.map_err(|_| LockError::InUse)?;
+				Assets::set_attribute(
+					&self.collection,
+					&self.item,
+					XCM_UNLOCKER_ATTRIBUTE,
+					&unlocker,
+				)
+				.map_err(|_| LockError::UnexpectedState)
+			},
+			None => {
+				Assets::clear_attribute(&self.collection, &self.item, XCM_UNLOCKER_ATTRIBUTE)
+					.map_err(|_| LockError::UnexpectedState)?;
+				Assets::enable_transfer(&self.collection, &self.item)
+					.map_err(|_| LockError::UnexpectedState)
+			},
+		}
+	}
+}
+
+impl<Assets, Matcher, AccountIdConverter, AccountId, ItemConfig>
+	NonFungiblesLocker<Assets, Matcher, AccountIdConverter, AccountId, ItemConfig>
+where
+	Assets: nonfungibles_v2::Inspect<AccountId>,
+	Matcher: MatchesNonFungibles<Assets::CollectionId, Assets::ItemId>,
+	AccountIdConverter: ConvertLocation<AccountId>,
+	AccountId: Eq,
+{
+	/// Match `asset` and make sure it belongs to `owner`.
+	fn owned_item(
+		asset: &Asset,
+		owner: &Location,
+	) -> Result<(Assets::CollectionId, Assets::ItemId), LockError> {
+		let (collection, item) =
+			Matcher::matches_nonfungibles(asset).map_err(|_| LockError::UnknownAsset)?;
+		let who = AccountIdConverter::convert_location(owner).ok_or(LockError::BadOwner)?;
+		ensure!(Assets::owner(&collection, &item) == Some(who), LockError::AssetNotOwned);
+		Ok((collection, item))
+	}
+}
+
+impl<Assets, Matcher, AccountIdConverter, AccountId, ItemConfig> AssetLock
+	for NonFungiblesLocker<Assets, Matcher, AccountIdConverter, AccountId, ItemConfig>
+where
+	Assets: nonfungibles_v2::Transfer<AccountId> + nonfungibles_v2::Mutate<AccountId, ItemConfig>,
+	Matcher: MatchesNonFungibles<Assets::CollectionId, Assets::ItemId>,
+	AccountIdConverter: ConvertLocation<AccountId>,
+	AccountId: Eq,
+{
+	type LockTicket = NonFungiblesLockTicket<Assets, AccountId, ItemConfig>;
+	type UnlockTicket = NonFungiblesLockTicket<Assets, AccountId, ItemConfig>;
+	type ReduceTicket = Infallible;
+
+	fn prepare_lock(
+		unlocker: Location,
+		asset: Asset,
+		owner: Location,
+	) -> Result<Self::LockTicket, LockError> {
+		let (collection, item) = Self::owned_item(&asset, &owner)?;
+		ensure!(
+			Assets::system_attribute(&collection, Some(&item), XCM_UNLOCKER_ATTRIBUTE).is_none(),
+			LockError::InUse
+		);
+		ensure!(Assets::can_transfer(&collection, &item), LockError::InUse);
+		Ok(NonFungiblesLockTicket {
+			collection,
+			item,
+			unlocker: Some(unlocker.encode()),
+			_phantom: PhantomData,
+		})
+	}
+
+	fn prepare_unlock(
+		unlocker: Location,
+		asset: Asset,
+		owner: Location,
+	) -> Result<Self::UnlockTicket, LockError> {
+		let (collection, item) = Self::owned_item(&asset, &owner)?;
+		let locked_for = Assets::system_attribute(&collection, Some(&item), XCM_UNLOCKER_ATTRIBUTE)
+			.ok_or(LockError::NotLocked)?;
+		ensure!(locked_for == unlocker.encode(), LockError::NotLocked);
+		Ok(NonFungiblesLockTicket { collection, item, unlocker: None, _phantom: PhantomData })
+	}
+
+	fn note_unlockable(_: Location, _: Asset, _: Location) -> Result<(), LockError> {
+		Err(LockError::NotApplicable)
+	}
+
+	fn prepare_reduce_unlockable(
+		_: Location,
+		_: Asset,
+		_: Location,
+	) -> Result<Self::ReduceTicket, LockError> {
+		Err(LockError::NotApplicable)
+	}
+}
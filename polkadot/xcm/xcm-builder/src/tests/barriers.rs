@@ -827,6 +827,54 @@ fn allow_subscriptions_from_should_work() {
 	assert_should_execute(vec![UnsubscribeVersion], Location::parent(), Ok(()));
 }
 
+#[test]
+fn allow_lock_notifications_from_should_work() {
+	frame_support::parameter_types! {
+		pub Locker: Location = Location::new(1, [Parachain(1000)]);
+	}
+	let note: Instruction<()> =
+		NoteUnlockable { asset: (Parent, 100).into(), owner: Location::parent() };
+	let unlock: Instruction<()> =
+		UnlockAsset { asset: (Parent, 100).into(), target: Location::parent() };
+
+	// closure for (xcm, origin) testing with `AllowLockNotificationsFrom`
+	let assert_should_execute = |mut xcm: Vec<Instruction<()>>, origin: Location, expected| {
+		assert_eq!(
+			AllowLockNotificationsFrom::<frame_support::traits::Equals<Locker>>::should_execute(
+				&origin,
+				&mut xcm,
+				Weight::from_parts(10, 10),
+				&mut props(Weight::zero()),
+			),
+			expected
+		);
+	};
+
+	// invalid origin
+	assert_should_execute(
+		vec![note.clone()],
+		Location::parent(),
+		Err(ProcessMessageError::Unsupported),
+	);
+	// invalid XCM
+	assert_should_execute(
+		vec![note.clone(), ClearOrigin],
+		Locker::get(),
+		Err(ProcessMessageError::BadFormat),
+	);
+	assert_should_execute(vec![ClearOrigin], Locker::get(), Err(ProcessMessageError::BadFormat));
+
+	assert_should_execute(
+		vec![unlock.clone(), note.clone()],
+		Locker::get(),
+		Err(ProcessMessageError::BadFormat),
+	);
+
+	// ok
+	assert_should_execute(vec![note], Locker::get(), Ok(()));
+	assert_should_execute(vec![unlock], Locker::get(), Ok(()));
+}
+
 #[test]
 fn allow_hrmp_notifications_from_relay_chain_should_work() {
 	// closure for (xcm, origin) testing with `AllowHrmpNotificationsFromRelayChain`
@@ -1380,3 +1428,104 @@ fn assert_deny_instructions_recursively<Barrier: ShouldExecute>() {
 		Err(ProcessMessageError::StackLimitReached),
 	);
 }
+
+#[test]
+fn rate_limited_barrier_should_work() {
+	use frame_support::traits::{Contains, Everything};
+	use std::{cell::RefCell, collections::BTreeMap};
+
+	thread_local! {
+		static BUCKETS: RefCell<BTreeMap<Location, RateLimitBucket>> = RefCell::new(BTreeMap::new());
+		static BLOCK_NUMBER: RefCell<u32> = RefCell::new(1);
+	}
+
+	struct TestBuckets;
+	impl RateLimitBuckets for TestBuckets {
+		fn bucket(origin: &Location) -> Option<RateLimitBucket> {
+			BUCKETS.with(|b| b.borrow().get(origin).copied())
+		}
+		fn set_bucket(origin: &Location, bucket: RateLimitBucket) {
+			BUCKETS.with(|b| b.borrow_mut().insert(origin.clone(), bucket));
+		}
+		fn prune(now: u32) {
+			BUCKETS.with(|b| b.borrow_mut().retain(|_, bucket| bucket.full_at > now));
+		}
+	}
+
+	struct TestBlockNumber;
+	impl sp_runtime::traits::BlockNumberProvider for TestBlockNumber {
+		type BlockNumber = u32;
+		fn current_block_number() -> u32 {
+			BLOCK_NUMBER.with(|b| *b.borrow())
+		}
+	}
+	let set_block_number = |n: u32| BLOCK_NUMBER.with(|b| *b.borrow_mut() = n);
+
+	parameter_types! {
+		pub ParachainsLimit: RateLimit = RateLimit {
+			max_weight: Weight::from_parts(30, 30),
+			max_messages: 2,
+			refill_period: 4,
+		};
+	}
+	pub struct IsParachain;
+	impl Contains<Location> for IsParachain {
+		fn contains(l: &Location) -> bool {
+			matches!(l.unpack(), (1, [Parachain(_)]))
+		}
+	}
+
+	type Barrier = RateLimitedBarrier<
+		AllowUnpaidExecutionFrom<Everything>,
+		RateLimitFor<IsParachain, ParachainsLimit>,
+		TestBuckets,
+		TestBlockNumber,
+	>;
+
+	let para_1: Location = (Parent, Parachain(1)).into();
+	let para_2: Location = (Parent, Parachain(2)).into();
+	let weight = Weight::from_parts(10, 10);
+	let mut message = Xcm::<()>(vec![ClearOrigin]);
+	let mut should_execute = |origin: &Location, weight: Weight| {
+		Barrier::should_execute(origin, message.inner_mut(), weight, &mut props(Weight::zero()))
+	};
+
+	// The relay chain isn't rate limited.
+	for _ in 0..5 {
+		assert_eq!(should_execute(&Parent.into(), weight), Ok(()));
+	}
+	// Messages heavier than the whole allowance can never be executed.
+	assert_eq!(
+		should_execute(&para_1, Weight::from_parts(31, 10)),
+		Err(ProcessMessageError::Overweight(Weight::from_parts(31, 10)))
+	);
+
+	// A burst of two messages is allowed, then the origin has to wait.
+	assert_eq!(should_execute(&para_1, weight), Ok(()));
+	assert_eq!(should_execute(&para_1, weight), Ok(()));
+	assert_eq!(should_execute(&para_1, weight), Err(ProcessMessageError::Yield));
+	// Allowances are tracked per origin.
+	assert_eq!(should_execute(&para_2, Weight::from_parts(30, 30)), Ok(()));
+	assert_eq!(should_execute(&para_2, weight), Err(ProcessMessageError::Yield));
+
+	// Half the period refills one message for `para_1`, but not enough weight for `para_2`.
+	set_block_number(3);
+	assert_eq!(should_execute(&para_1, weight), Ok(()));
+	assert_eq!(should_execute(&para_1, weight), Err(ProcessMessageError::Yield));
+	assert_eq!(
+		should_execute(&para_2, Weight::from_parts(20, 20)),
+		Err(ProcessMessageError::Yield)
+	);
+	assert_eq!(should_execute(&para_2, weight), Ok(()));
+
+	// A whole period refills the allowance, but not beyond it.
+	set_block_number(100);
+	assert_eq!(should_execute(&para_1, weight), Ok(()));
+	assert_eq!(should_execute(&para_1, weight), Ok(()));
+	assert_eq!(should_execute(&para_1, weight), Err(ProcessMessageError::Yield));
+	// The allowance of `para_2` is whole again, so it was pruned.
+	assert_eq!(BUCKETS.with(|b| b.borrow().keys().cloned().collect::<Vec<_>>()), vec![para_1]);
+	// Which changes nothing for `para_2`.
+	assert_eq!(should_execute(&para_2, Weight::from_parts(30, 30)), Ok(()));
+	assert_eq!(should_execute(&para_2, weight), Err(ProcessMessageError::Yield));
+}
@@ -179,6 +179,40 @@ fn weight_bounds_should_respect_instructions_limit() {
 	);
 }
 
+#[test]
+fn rate_limited_weight_bounds_should_add_storage_accesses() {
+	struct Limits;
+	impl RateLimits for Limits {
+		fn rate_limit(_: &Location) -> Option<RateLimit> {
+			None
+		}
+		fn weight() -> Weight {
+			Weight::from_parts(1, 2)
+		}
+	}
+	struct Buckets;
+	impl RateLimitBuckets for Buckets {
+		fn bucket(_: &Location) -> Option<RateLimitBucket> {
+			None
+		}
+		fn set_bucket(_: &Location, _: RateLimitBucket) {}
+		fn weight() -> Weight {
+			Weight::from_parts(3, 4)
+		}
+	}
+	type Weigher = RateLimitedWeightBounds<<TestConfig as Config>::Weigher, (Limits, ()), Buckets>;
+
+	MaxInstructions::set(3);
+	// The storage accesses are added once per message.
+	let mut message = Xcm(vec![ClearOrigin; 2]);
+	assert_eq!(Weigher::weight(&mut message), Ok(Weight::from_parts(24, 26)));
+	// But not to single instructions.
+	assert_eq!(Weigher::instr_weight(&mut ClearOrigin), Ok(Weight::from_parts(10, 10)));
+	// And the limits of the inner weigher still apply.
+	let mut message = Xcm(vec![ClearOrigin; 4]);
+	assert_eq!(Weigher::weight(&mut message), Err(()));
+}
+
 #[test]
 fn weight_trader_tuple_should_work() {
 	let para_1: Location = Parachain(1).into();
@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
+use crate::{RateLimitBuckets, RateLimits};
 use codec::Decode;
 use core::{marker::PhantomData, result::Result};
 use frame_support::{
@@ -121,6 +122,28 @@ where
 	}
 }
 
+/// Weigher adding the storage accesses of [`RateLimitedBarrier`](crate::RateLimitedBarrier) to the
+/// weight of every message, as given by `Limits` and `Buckets`, on top of that of `Weigher`.
+pub struct RateLimitedWeightBounds<Weigher, Limits, Buckets>(
+	PhantomData<(Weigher, Limits, Buckets)>,
+);
+impl<C, Weigher, Limits, Buckets> WeightBounds<C>
+	for RateLimitedWeightBounds<Weigher, Limits, Buckets>
+where
+	Weigher: WeightBounds<C>,
+	Limits: RateLimits,
+	Buckets: RateLimitBuckets,
+{
+	fn weight(message: &mut Xcm<C>) -> Result<Weight, ()> {
+		Weigher::weight(message)?
+			.checked_add(&Limits::weight().saturating_add(Buckets::weight()))
+			.ok_or(())
+	}
+	fn instr_weight(instruction: &mut Instruction<C>) -> Result<Weight, ()> {
+		Weigher::instr_weight(instruction)
+	}
+}
+
 /// Function trait for handling some revenue. Similar to a negative imbalance (credit) handler, but
 /// for a `Asset`. Sensible implementations will deposit the asset in some known treasury or
 /// block-author account.
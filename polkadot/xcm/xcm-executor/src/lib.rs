@@ -40,7 +40,7 @@ use traits::{
 	XcmAssetTransfers,
 };
 
-pub use traits::RecordXcm;
+pub use traits::{InstructionTrace, RecordXcm};
 
 mod assets;
 pub use assets::AssetsInHolding;
@@ -825,6 +825,9 @@ impl<Config: config::Config> XcmExecutor<Config> {
 		for (i, mut instr) in xcm.0.into_iter().enumerate() {
 			match &mut result {
 				r @ Ok(()) => {
+					// Weigh the instruction up front since executing it consumes it.
+					let trace_weight = Config::XcmRecorder::should_trace()
+						.then(|| Config::Weigher::instr_weight(&mut instr).unwrap_or_default());
 					// Initialize the recursion count only the first time we hit this code in our
 					// potential recursive execution.
 					let inst_res = recursion_count::using_once(&mut 1, || {
@@ -848,6 +851,16 @@ impl<Config: config::Config> XcmExecutor<Config> {
 
 						self.process_instruction(instr)
 					});
+					if let Some(weight) = trace_weight {
+						Config::XcmRecorder::trace(InstructionTrace {
+							index: i as u32,
+							weight,
+							origin: self.context.origin.clone(),
+							holding: self.holding.clone().into(),
+							fees: self.fees.clone().into(),
+							error: inst_res.err(),
+						});
+					}
 					if let Err(error) = inst_res {
 						tracing::debug!(
 							target: "xcm::process",
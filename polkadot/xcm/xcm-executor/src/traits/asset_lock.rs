@@ -34,6 +34,17 @@ pub enum LockError {
 	InUse,
 }
 
+impl LockError {
+	/// Whether the error means the asset isn't handled at all, rather than that the operation
+	/// failed.
+	pub fn is_unsupported(&self) -> bool {
+		matches!(
+			self,
+			LockError::NotApplicable | LockError::UnknownAsset | LockError::Unimplemented
+		)
+	}
+}
+
 impl From<LockError> for XcmError {
 	fn from(e: LockError) -> XcmError {
 		use LockError::*;
@@ -138,3 +149,72 @@ impl AssetLock for () {
 		Err(LockError::NotApplicable)
 	}
 }
+
+/// Ticket of either [`AssetLock`] implementation of a pair.
+pub enum EitherTicket<L, R> {
+	Left(L),
+	Right(R),
+}
+
+impl<L: Enact, R: Enact> Enact for EitherTicket<L, R> {
+	fn enact(self) -> Result<(), LockError> {
+		match self {
+			EitherTicket::Left(ticket) => ticket.enact(),
+			EitherTicket::Right(ticket) => ticket.enact(),
+		}
+	}
+}
+
+/// Combines two [`AssetLock`] implementations, e.g. one for fungible and one for non-fungible
+/// assets.
+///
+/// `A` is tried first, `B` is only used for the assets `A` doesn't support, as told by
+/// [`LockError::is_unsupported`].
+impl<A: AssetLock, B: AssetLock> AssetLock for (A, B) {
+	type LockTicket = EitherTicket<A::LockTicket, B::LockTicket>;
+	type UnlockTicket = EitherTicket<A::UnlockTicket, B::UnlockTicket>;
+	type ReduceTicket = EitherTicket<A::ReduceTicket, B::ReduceTicket>;
+
+	fn prepare_lock(
+		unlocker: Location,
+		asset: Asset,
+		owner: Location,
+	) -> Result<Self::LockTicket, LockError> {
+		match A::prepare_lock(unlocker.clone(), asset.clone(), owner.clone()) {
+			Err(e) if e.is_unsupported() =>
+				B::prepare_lock(unlocker, asset, owner).map(EitherTicket::Right),
+			result => result.map(EitherTicket::Left),
+		}
+	}
+
+	fn prepare_unlock(
+		locker: Location,
+		asset: Asset,
+		owner: Location,
+	) -> Result<Self::UnlockTicket, LockError> {
+		match A::prepare_unlock(locker.clone(), asset.clone(), owner.clone()) {
+			Err(e) if e.is_unsupported() =>
+				B::prepare_unlock(locker, asset, owner).map(EitherTicket::Right),
+			result => result.map(EitherTicket::Left),
+		}
+	}
+
+	fn note_unlockable(locker: Location, asset: Asset, owner: Location) -> Result<(), LockError> {
+		match A::note_unlockable(locker.clone(), asset.clone(), owner.clone()) {
+			Err(e) if e.is_unsupported() => B::note_unlockable(locker, asset, owner),
+			result => result,
+		}
+	}
+
+	fn prepare_reduce_unlockable(
+		locker: Location,
+		asset: Asset,
+		owner: Location,
+	) -> Result<Self::ReduceTicket, LockError> {
+		match A::prepare_reduce_unlockable(locker.clone(), asset.clone(), owner.clone()) {
+			Err(e) if e.is_unsupported() =>
+				B::prepare_reduce_unlockable(locker, asset, owner).map(EitherTicket::Right),
+			result => result.map(EitherTicket::Left),
+		}
+	}
+}
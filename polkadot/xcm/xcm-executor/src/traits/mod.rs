@@ -23,7 +23,7 @@ pub use drop_assets::{ClaimAssets, DropAssets};
 mod asset_exchange;
 pub use asset_exchange::AssetExchange;
 mod asset_lock;
-pub use asset_lock::{AssetLock, Enact, LockError};
+pub use asset_lock::{AssetLock, EitherTicket, Enact, LockError};
 mod asset_transfer;
 pub use asset_transfer::{Error as AssetTransferError, TransferType, XcmAssetTransfers};
 mod export;
@@ -54,7 +54,7 @@ mod record_xcm;
 mod weight;
 pub use event_emitter::EventEmitter;
 
-pub use record_xcm::RecordXcm;
+pub use record_xcm::{InstructionTrace, RecordXcm};
 #[deprecated = "Use `sp_runtime::traits::` instead"]
 pub use sp_runtime::traits::{Identity, TryConvertInto as JustTry};
 pub use weight::{WeightBounds, WeightTrader};
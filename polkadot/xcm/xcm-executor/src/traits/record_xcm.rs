@@ -16,7 +16,33 @@
 
 //! Trait for recording XCMs and a dummy implementation.
 
-use xcm::latest::Xcm;
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_weights::Weight;
+use xcm::latest::{Assets, Error as XcmError, Location, Xcm};
+
+/// The state of the XCM executor right after executing a single instruction.
+///
+/// Only recorded when tracing is enabled through [`RecordXcm::set_trace_xcm`].
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct InstructionTrace {
+	/// Index of the instruction within the program being executed.
+	///
+	/// Instructions of nested programs (error handler, appendix, `SetErrorHandler` and
+	/// friends) are indexed relative to their own program.
+	pub index: u32,
+	/// Weight of the instruction, as given by the executor's weigher.
+	pub weight: Weight,
+	/// The origin after the instruction was executed.
+	pub origin: Option<Location>,
+	/// The holding register after the instruction was executed.
+	pub holding: Assets,
+	/// The fees register after the instruction was executed.
+	pub fees: Assets,
+	/// The error returned by the instruction, if any.
+	pub error: Option<XcmError>,
+}
 
 /// Trait for recording XCMs.
 pub trait RecordXcm {
@@ -29,6 +55,18 @@ pub trait RecordXcm {
 	fn recorded_xcm() -> Option<Xcm<()>>;
 	/// Record `xcm`.
 	fn record(xcm: Xcm<()>);
+	/// Whether or not we should record a per-instruction trace of executed XCMs.
+	fn should_trace() -> bool {
+		false
+	}
+	/// Enable or disable tracing.
+	fn set_trace_xcm(_enabled: bool) {}
+	/// Take the recorded trace, leaving an empty one behind.
+	fn take_trace() -> Vec<InstructionTrace> {
+		Vec::new()
+	}
+	/// Append `step` to the recorded trace.
+	fn trace(_step: InstructionTrace) {}
 }
 
 impl RecordXcm for () {
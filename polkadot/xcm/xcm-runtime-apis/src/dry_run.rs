@@ -21,7 +21,9 @@
 use alloc::vec::Vec;
 use codec::{Decode, Encode};
 use frame_support::pallet_prelude::{DispatchResultWithPostInfo, TypeInfo};
+use sp_weights::Weight;
 use xcm::prelude::*;
+use xcm_executor::InstructionTrace;
 
 /// Effects of dry-running an extrinsic.
 #[derive(Encode, Decode, Debug, TypeInfo)]
@@ -47,6 +49,58 @@ pub struct XcmDryRunEffects<Event> {
 	pub forwarded_xcms: Vec<(VersionedLocation, Vec<VersionedXcm<()>>)>,
 }
 
+/// The state of the XCM executor right after executing a single instruction.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct XcmTraceStep {
+	/// Index of the instruction within the program being executed.
+	///
+	/// Instructions of nested programs (error handler, appendix, ...) are indexed relative to
+	/// their own program and appear in the trace before the instruction that executed them.
+	pub index: u32,
+	/// Weight of the instruction, as given by the executor's weigher.
+	pub weight: Weight,
+	/// The origin after the instruction was executed.
+	pub origin: Option<VersionedLocation>,
+	/// The holding register after the instruction was executed.
+	pub holding: VersionedAssets,
+	/// The fees register after the instruction was executed.
+	pub fees: VersionedAssets,
+	/// The error returned by the instruction, if any.
+	pub error: Option<XcmError>,
+}
+
+impl XcmTraceStep {
+	/// Convert a step recorded by the executor into its versioned representation.
+	pub fn from_instruction_trace(
+		step: InstructionTrace,
+		version: XcmVersion,
+	) -> Result<Self, Error> {
+		let convert = || -> Result<Self, ()> {
+			Ok(Self {
+				index: step.index,
+				weight: step.weight,
+				origin: step
+					.origin
+					.map(|origin| VersionedLocation::from(origin).into_version(version))
+					.transpose()?,
+				holding: VersionedAssets::from(step.holding).into_version(version)?,
+				fees: VersionedAssets::from(step.fees).into_version(version)?,
+				error: step.error,
+			})
+		};
+		convert().map_err(|()| Error::VersionedConversionFailed)
+	}
+}
+
+/// Effects of a dry-run, along with the trace of every XCM instruction executed during it.
+#[derive(Encode, Decode, Debug, TypeInfo)]
+pub struct TracedDryRunEffects<Effects> {
+	/// The effects of the dry-run, as returned by the untraced version of the call.
+	pub effects: Effects,
+	/// Every instruction executed locally, in execution order.
+	pub trace: Vec<XcmTraceStep>,
+}
+
 sp_api::decl_runtime_apis! {
 	/// API for dry-running extrinsics and XCM programs to get the programs that need to be passed to the fees API.
 	///
@@ -57,7 +111,7 @@ sp_api::decl_runtime_apis! {
 	/// Calls or XCMs might fail when executed, this doesn't mean the result of these calls will be an `Err`.
 	/// In those cases, there might still be a valid result, with the execution error inside it.
 	/// The only reasons why these calls might return an error are listed in the [`Error`] enum.
-	#[api_version(2)]
+	#[api_version(3)]
 	pub trait DryRunApi<Call, Event, OriginCaller>
 	where
 		Call: Encode,
@@ -73,6 +127,14 @@ sp_api::decl_runtime_apis! {
 
 		/// Dry run XCM program
 		fn dry_run_xcm(origin_location: VersionedLocation, xcm: VersionedXcm<Call>) -> Result<XcmDryRunEffects<Event>, Error>;
+
+		/// Dry run call, additionally returning a trace of every XCM instruction executed locally.
+		#[api_version(3)]
+		fn dry_run_call_traced(origin: OriginCaller, call: Call, result_xcms_version: XcmVersion) -> Result<TracedDryRunEffects<CallDryRunEffects<Event>>, Error>;
+
+		/// Dry run XCM program, additionally returning a trace of every instruction executed.
+		#[api_version(3)]
+		fn dry_run_xcm_traced(origin_location: VersionedLocation, xcm: VersionedXcm<Call>) -> Result<TracedDryRunEffects<XcmDryRunEffects<Event>>, Error>;
 	}
 }
 
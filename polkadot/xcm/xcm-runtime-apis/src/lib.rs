@@ -30,6 +30,11 @@ pub mod dry_run;
 /// Fee estimation API.
 /// Given an XCM program, it will return the fees needed to execute it properly or send it.
 pub mod fees;
+/// Runtime APIs for querying assets locked on remote chains.
+pub mod remote_locks;
+/// Transfer routes API.
+/// Given an asset and a destination, it returns the supported transfer types and their fees.
+pub mod transfer_routes;
 /// Exposes runtime API for querying whether a Location is trusted as a reserve or teleporter for a
 /// given Asset.
 pub mod trusted_query;
@@ -0,0 +1,62 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains runtime APIs for querying assets locked on remote chains in favour of this chain.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::TypeInfo;
+use xcm::{VersionedAsset, VersionedLocation};
+
+/// Status of a remote lock.
+#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, TypeInfo)]
+pub enum RemoteLockStatus {
+	/// The lock was requested at block number `at`, the locker didn't confirm it yet.
+	Requested { at: u64 },
+	/// The asset is locked in favour of this chain.
+	Locked,
+	/// The asset is locked in favour of this chain and used by local consumers, so it can't be
+	/// unlocked.
+	InUse,
+}
+
+/// Entry of an asset locked on `locker` in favour of this chain.
+#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, TypeInfo)]
+pub struct RemoteLock {
+	pub locker: VersionedLocation,
+	pub asset: VersionedAsset,
+	pub status: RemoteLockStatus,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API for querying assets locked on remote chains in favour of this chain.
+	pub trait RemoteLocksApi {
+		/// Returns the remote locks of `owner`, both requested and confirmed ones.
+		fn remote_locks(owner: VersionedLocation) -> Result<Vec<RemoteLock>, Error>;
+	}
+}
+
+/// `RemoteLocksApi` Runtime APIs errors.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub enum Error {
+	/// Converting a location from one version to another failed.
+	#[codec(index = 0)]
+	LocationVersionConversionFailed,
+	/// The owner location can't be converted to a local account.
+	#[codec(index = 1)]
+	InvalidOwner,
+}
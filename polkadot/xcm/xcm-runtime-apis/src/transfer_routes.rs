@@ -0,0 +1,86 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for discovering how an asset can be transferred to a destination.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::TypeInfo;
+use sp_weights::Weight;
+use xcm::{VersionedAsset, VersionedAssets, VersionedLocation, VersionedXcm};
+use xcm_executor::traits::TransferType;
+
+/// A chain a transfer goes through after leaving the local chain.
+#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, TypeInfo)]
+pub struct RouteHop {
+	/// The chain, as seen from the local chain.
+	pub location: VersionedLocation,
+	/// The program forwarded to `location`.
+	///
+	/// Its execution weight and fees can be estimated with
+	/// [`XcmPaymentApi`](crate::fees::XcmPaymentApi) on `location` itself.
+	pub message: VersionedXcm<()>,
+	/// The fees for delivering `message` to `location`.
+	///
+	/// Only known for the hops the local chain sends to, the other ones are sent by the previous
+	/// hop.
+	pub delivery_fees: Option<VersionedAssets>,
+}
+
+/// A way of transferring an asset to a destination.
+#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, TypeInfo)]
+pub struct TransferRoute {
+	/// The transfer type to use with `pallet_xcm::transfer_assets_using_type_and_then`.
+	pub transfer_type: TransferType,
+	/// The weight of the program executed on the local chain.
+	pub local_weight: Weight,
+	/// `local_weight` converted to a fee in the transferred asset, if it's accepted for paying
+	/// fees on the local chain.
+	pub local_fee: Option<u128>,
+	/// The chains the transfer goes through, in order, ending with the destination.
+	pub hops: Vec<RouteHop>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API for discovering how assets can be transferred to a destination.
+	///
+	/// Based on the trusted reserves and teleporters of the runtime, it lists the transfer types
+	/// supported for a given asset and destination, together with a fee breakdown per hop.
+	pub trait XcmTransferRoutesApi {
+		/// Returns the routes for transferring `asset` from the local chain to `destination`.
+		///
+		/// Routes are built with `asset` paying for the fees on every hop, and returned in the
+		/// XCM version of `asset`. An empty list means `asset` can't be transferred to
+		/// `destination`.
+		///
+		/// # Arguments
+		/// * `asset`: `VersionedAsset`, including the amount to transfer.
+		/// * `destination`: `VersionedLocation`.
+		fn transfer_routes(asset: VersionedAsset, destination: VersionedLocation) -> Result<Vec<TransferRoute>, Error>;
+	}
+}
+
+/// `XcmTransferRoutesApi` Runtime APIs errors.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub enum Error {
+	/// Converting a versioned data structure from one version to another failed.
+	#[codec(index = 0)]
+	VersionedConversionFailed,
+	/// The asset is not fungible, only fungible assets are supported.
+	#[codec(index = 1)]
+	NonFungibleAsset,
+}
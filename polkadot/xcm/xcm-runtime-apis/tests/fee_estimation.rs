@@ -21,15 +21,15 @@ use frame_system::RawOrigin;
 use sp_api::ProvideRuntimeApi;
 use xcm::prelude::*;
 use xcm_runtime_apis::{
-	dry_run::{CallDryRunEffects, DryRunApi},
+	dry_run::{CallDryRunEffects, DryRunApi, XcmTraceStep},
 	fees::XcmPaymentApi,
 };
 
 mod mock;
 use mock::{
 	fake_message_hash, new_test_ext_with_balances, new_test_ext_with_balances_and_assets,
-	DeliveryFees, ExistentialDeposit, HereLocation, OriginCaller, RuntimeCall, RuntimeEvent,
-	TestClient,
+	BaseXcmWeight, DeliveryFees, ExistentialDeposit, HereLocation, OriginCaller, RuntimeCall,
+	RuntimeEvent, TestClient,
 };
 
 // Scenario: User `1` in the local chain (id 2000) wants to transfer assets to account `[0u8; 32]`
@@ -442,6 +442,93 @@ fn dry_run_xcm_versions() {
 	}
 }
 
+#[test]
+fn dry_run_xcm_traced_records_every_instruction() {
+	sp_tracing::init_for_tests();
+	let who = 1; // AccountId = u64.
+	let amount = 100u128;
+	let client = TestClient;
+	let runtime_api = client.runtime_api();
+	let xcm_to_weigh = Xcm::<RuntimeCall>::builder_unsafe()
+		.withdraw_asset((Here, amount))
+		.buy_execution((Here, amount), Unlimited)
+		.trap(7)
+		.build();
+	let xcm_weight = runtime_api
+		.query_xcm_weight(H256::zero(), VersionedXcm::from(xcm_to_weigh.clone().into()))
+		.unwrap()
+		.unwrap();
+	let execution_fees = runtime_api
+		.query_weight_to_asset_fee(
+			H256::zero(),
+			xcm_weight,
+			VersionedAssetId::from(AssetId(Here.into())),
+		)
+		.unwrap()
+		.unwrap();
+	let xcm = Xcm::<RuntimeCall>::builder_unsafe()
+		.withdraw_asset((Here, amount + execution_fees))
+		.buy_execution((Here, execution_fees), Unlimited)
+		.trap(7)
+		.build();
+	let balances = vec![(who, amount + execution_fees + ExistentialDeposit::get())];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let origin = Location::new(0, [AccountIndex64 { index: 1, network: None }]);
+		let traced = runtime_api
+			.dry_run_xcm_traced(
+				H256::zero(),
+				VersionedLocation::from(origin.clone()),
+				VersionedXcm::from(xcm),
+			)
+			.unwrap()
+			.unwrap();
+		assert!(matches!(
+			traced.effects.execution_result,
+			Outcome::Incomplete { error: XcmError::Trap(7), .. }
+		));
+		assert_eq!(
+			traced.trace.iter().map(|step| (step.index, step.error)).collect::<Vec<_>>(),
+			vec![(0, None), (1, None), (2, Some(XcmError::Trap(7)))]
+		);
+		assert!(traced.trace.iter().all(|step| step.weight == BaseXcmWeight::get()));
+		assert!(traced
+			.trace
+			.iter()
+			.all(|step| step.origin == Some(VersionedLocation::from(origin.clone()))));
+		assert_eq!(
+			traced.trace[0],
+			XcmTraceStep {
+				index: 0,
+				weight: BaseXcmWeight::get(),
+				origin: Some(VersionedLocation::from(origin.clone())),
+				holding: VersionedAssets::from(Assets::from((Here, amount + execution_fees))),
+				fees: VersionedAssets::from(Assets::new()),
+				error: None,
+			}
+		);
+
+		// Tracing is switched off again once the dry-run is over, so an untraced dry-run doesn't
+		// leak steps into the next traced one.
+		runtime_api
+			.dry_run_xcm(
+				H256::zero(),
+				VersionedLocation::from(origin.clone()),
+				VersionedXcm::from(xcm_to_weigh),
+			)
+			.unwrap()
+			.unwrap();
+		let next = runtime_api
+			.dry_run_xcm_traced(
+				H256::zero(),
+				VersionedLocation::from(origin),
+				VersionedXcm::from(Xcm::<RuntimeCall>::new()),
+			)
+			.unwrap()
+			.unwrap();
+		assert!(next.trace.is_empty());
+	});
+}
+
 #[test]
 fn calling_payment_api_with_a_lower_version_works() {
 	let transfer_amount = 100u128;
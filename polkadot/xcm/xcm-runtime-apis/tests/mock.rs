@@ -46,8 +46,12 @@ use xcm_executor::{
 
 use xcm_runtime_apis::{
 	conversions::{Error as LocationToAccountApiError, LocationToAccountApi},
-	dry_run::{CallDryRunEffects, DryRunApi, Error as XcmDryRunApiError, XcmDryRunEffects},
+	dry_run::{
+		CallDryRunEffects, DryRunApi, Error as XcmDryRunApiError, TracedDryRunEffects,
+		XcmDryRunEffects,
+	},
 	fees::{Error as XcmPaymentApiError, XcmPaymentApi},
+	transfer_routes::{Error as XcmTransferRoutesApiError, TransferRoute, XcmTransferRoutesApi},
 	trusted_query::{Error as TrustedQueryApiError, TrustedQueryApi},
 };
 
@@ -453,6 +457,14 @@ sp_api::mock_impl_runtime_apis! {
 		}
 	}
 
+	impl XcmTransferRoutesApi<Block> for RuntimeApi {
+		fn transfer_routes(asset: VersionedAsset, destination: VersionedLocation) -> Result<Vec<TransferRoute>, XcmTransferRoutesApiError> {
+			XcmPallet::transfer_routes(asset, destination, |weight, asset_id| {
+				(asset_id.0 == HereLocation::get()).then(|| WeightToFee::weight_to_fee(&weight))
+			})
+		}
+	}
+
 	impl LocationToAccountApi<Block, AccountId> for RuntimeApi {
 		fn convert_location(location: VersionedLocation) -> Result<AccountId, LocationToAccountApiError> {
 			let location = location.try_into().map_err(|_| LocationToAccountApiError::VersionedConversionFailed)?;
@@ -521,5 +533,17 @@ sp_api::mock_impl_runtime_apis! {
 		fn dry_run_xcm(origin_location: VersionedLocation, xcm: VersionedXcm<RuntimeCall>) -> Result<XcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
 			pallet_xcm::Pallet::<TestRuntime>::dry_run_xcm::<TestRuntime, XcmRouter, RuntimeCall, XcmConfig>(origin_location, xcm)
 		}
+
+		fn dry_run_call_traced(
+			origin: OriginCaller,
+			call: RuntimeCall,
+			result_xcms_version: XcmVersion,
+		) -> Result<TracedDryRunEffects<CallDryRunEffects<RuntimeEvent>>, XcmDryRunApiError> {
+			pallet_xcm::Pallet::<TestRuntime>::dry_run_call_traced::<TestRuntime, XcmRouter, OriginCaller, RuntimeCall>(origin, call, result_xcms_version)
+		}
+
+		fn dry_run_xcm_traced(origin_location: VersionedLocation, xcm: VersionedXcm<RuntimeCall>) -> Result<TracedDryRunEffects<XcmDryRunEffects<RuntimeEvent>>, XcmDryRunApiError> {
+			pallet_xcm::Pallet::<TestRuntime>::dry_run_xcm_traced::<TestRuntime, XcmRouter, RuntimeCall, XcmConfig>(origin_location, xcm)
+		}
 	}
 }
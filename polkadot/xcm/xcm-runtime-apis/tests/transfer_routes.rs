@@ -0,0 +1,99 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+mod mock;
+
+use frame_support::{sp_runtime::testing::H256, weights::WeightToFee as WeightToFeeT};
+use mock::*;
+use sp_api::ProvideRuntimeApi;
+use xcm::prelude::*;
+use xcm_executor::traits::TransferType;
+use xcm_runtime_apis::transfer_routes::{Error, XcmTransferRoutesApi};
+
+#[test]
+fn transfer_routes_of_native_asset() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		let client = TestClient {};
+		let runtime_api = client.runtime_api();
+		let asset_hub: Location = (Parent, Parachain(1000)).into();
+		let asset: Asset = (Here, 100u128).into();
+		let delivery_fees: Assets = (HereLocation::get(), DeliveryFees::get()).into();
+
+		let routes = runtime_api
+			.transfer_routes(H256::zero(), asset.into(), asset_hub.clone().into())
+			.unwrap()
+			.unwrap();
+
+		// The native token can be teleported to Asset Hub, or reserve-transferred with us as the
+		// reserve.
+		let transfer_types: Vec<_> = routes.iter().map(|r| r.transfer_type.clone()).collect();
+		assert_eq!(transfer_types, vec![TransferType::Teleport, TransferType::LocalReserve]);
+		for route in routes {
+			assert_eq!(route.local_fee, Some(WeightToFee::weight_to_fee(&route.local_weight)));
+			assert_eq!(route.hops.len(), 1);
+			let hop = &route.hops[0];
+			assert_eq!(hop.location, asset_hub.clone().into());
+			assert_eq!(hop.delivery_fees, Some(delivery_fees.clone().into()));
+			let message: Xcm<()> = hop.message.clone().try_into().unwrap();
+			match route.transfer_type {
+				TransferType::Teleport =>
+					assert!(matches!(message.0[0], ReceiveTeleportedAsset(_))),
+				_ => assert!(matches!(message.0[0], ReserveAssetDeposited(_))),
+			}
+		}
+	});
+}
+
+#[test]
+fn transfer_routes_of_foreign_asset() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		let client = TestClient {};
+		let runtime_api = client.runtime_api();
+		let asset: Asset = (Parent, 100u128).into();
+
+		// Asset Hub is the reserve of the relay token, which can't pay for fees here.
+		let routes = runtime_api
+			.transfer_routes(H256::zero(), asset.clone().into(), (Parent, Parachain(1000)).into())
+			.unwrap()
+			.unwrap();
+		assert_eq!(routes.len(), 1);
+		assert_eq!(routes[0].transfer_type, TransferType::DestinationReserve);
+		assert_eq!(routes[0].local_fee, None);
+		let message: Xcm<()> = routes[0].hops[0].message.clone().try_into().unwrap();
+		assert!(matches!(message.0[0], WithdrawAsset(_)));
+
+		// No known route to other chains.
+		let routes = runtime_api
+			.transfer_routes(H256::zero(), asset.into(), (Parent, Parachain(2000)).into())
+			.unwrap()
+			.unwrap();
+		assert!(routes.is_empty());
+	});
+}
+
+#[test]
+fn transfer_routes_of_non_fungible_asset_fail() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		let client = TestClient {};
+		let runtime_api = client.runtime_api();
+		let asset: Asset = (Here, Index(1)).into();
+
+		let result = runtime_api
+			.transfer_routes(H256::zero(), asset.into(), (Parent, Parachain(1000)).into())
+			.unwrap();
+		assert_eq!(result, Err(Error::NonFungibleAsset));
+	});
+}
@@ -0,0 +1,135 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Test that parachains progress and finality is not affected when a malus validator delays its
+// approval assignments and votes, as honest validators cover its no-shows.
+
+use anyhow::anyhow;
+
+use cumulus_zombienet_sdk_helpers::{assert_finality_lag, assert_finalized_para_throughput};
+use polkadot_primitives::Id as ParaId;
+use serde_json::json;
+use subxt::{OnlineClient, PolkadotConfig};
+use zombienet_sdk::NetworkConfigBuilder;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn delay_approval_assignments_test() -> Result<(), anyhow::Error> {
+	let _ = env_logger::try_init_from_env(
+		env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
+	);
+
+	let images = zombienet_sdk::environment::get_images_from_env();
+
+	let config = NetworkConfigBuilder::new()
+		.with_relaychain(|r| {
+			r.with_chain("rococo-local")
+				.with_default_command("polkadot")
+				.with_default_image(images.polkadot.as_str())
+				.with_default_args(vec![
+					("-lparachain=debug,parachain::approval-voting=trace").into()
+				])
+				.with_genesis_overrides(json!({
+					"configuration": {
+						"config": {
+							"needed_approvals": 2,
+							"scheduler_params": {
+								"max_validators_per_core": 5
+							}
+						}
+					}
+				}))
+				.with_node(|node| node.with_name("honest-0"))
+				.with_node(|node| node.with_name("honest-1"))
+				.with_node(|node| node.with_name("honest-2"))
+				.with_node(|node| node.with_name("honest-3"))
+				.with_node(|node| {
+					node.with_name("malus")
+						.with_image(
+							std::env::var("MALUS_IMAGE")
+								.unwrap_or("docker.io/paritypr/malus".to_string())
+								.as_str(),
+						)
+						.with_command("malus")
+						.with_subcommand("delay-approval-assignments")
+						.with_args(vec![
+							"--alice".into(),
+							"--delay-ms=30000".into(),
+							"--enable-approval-voting-parallel=false".into(),
+							"--insecure-validator-i-know-what-i-do".into(),
+							"-lMALUS=trace,parachain=debug".into(),
+						])
+				})
+		})
+		.with_parachain(|p| {
+			p.with_id(2000)
+				.with_default_command("undying-collator")
+				.cumulus_based(false)
+				.with_default_image(
+					std::env::var("COL_IMAGE")
+						.unwrap_or("docker.io/paritypr/colander:latest".to_string())
+						.as_str(),
+				)
+				.with_default_args(vec![("-lparachain=debug").into()])
+				.with_collator(|n| n.with_name("collator-2000"))
+		})
+		.build()
+		.map_err(|e| {
+			let errs = e.into_iter().map(|e| e.to_string()).collect::<Vec<_>>().join(" ");
+			anyhow!("config errs: {errs}")
+		})?;
+
+	let spawn_fn = zombienet_sdk::environment::get_spawn_fn();
+	let network = spawn_fn(config).await?;
+
+	let malus = network.get_node("malus")?;
+	let honest = network.get_node("honest-0")?;
+	let relay_client: OnlineClient<PolkadotConfig> = honest.wait_client().await?;
+	let _malus_client: OnlineClient<PolkadotConfig> = malus.wait_client().await?;
+
+	// Check authority status.
+	malus.assert("node_roles", 4.0).await?;
+	honest.assert("node_roles", 4.0).await?;
+
+	assert_finalized_para_throughput(
+		&relay_client,
+		15,
+		[(ParaId::from(2000), 8..16)].into_iter().collect(),
+	)
+	.await?;
+
+	// Ensure that malus is delaying its assignments and votes and that honest validators notice
+	// the no-shows.
+	malus
+		.wait_log_line_count_with_timeout("*Delaying approval assignment*", true, 1, 60u64)
+		.await?;
+	malus
+		.wait_log_line_count_with_timeout("*Delaying approval vote*", true, 1, 60u64)
+		.await?;
+	honest
+		.wait_metric_with_timeout(
+			"polkadot_parachain_approvals_observed_no_shows_total",
+			|v| v > 0.0,
+			60u64,
+		)
+		.await?;
+
+	// Honest validators still import the late approvals of malus, the last declared validator.
+	honest
+		.wait_log_line_count_with_timeout(
+			"*Importing approval vote validator_index=4 *",
+			true,
+			1,
+			90u64,
+		)
+		.await?;
+
+	// Late approvals must not lead to disputes.
+	honest.assert("polkadot_parachain_candidate_disputes_total", 0.0).await?;
+
+	// No-shows are covered by additional tranches, so finality only lags behind slightly.
+	assert_finality_lag(&relay_client, 10).await?;
+
+	log::info!("Test finished successfully");
+
+	Ok(())
+}
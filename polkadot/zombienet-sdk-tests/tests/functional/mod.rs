@@ -3,6 +3,10 @@
 
 mod approved_peer_mixed_validators;
 mod async_backing_6_seconds_rate;
+mod delay_approval_assignments;
 mod duplicate_collations;
+mod equivocate_bitfields;
+mod oversized_collations;
 mod spam_statement_distribution_requests;
 mod sync_backing;
+mod withhold_availability_chunks;
@@ -0,0 +1,105 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Test that a parachain using a malus undying collator, sending collations with a PoV exceeding
+// the maximum PoV size, does not break the relay chain and that blocks are included, backed by a
+// normal collator.
+
+use anyhow::anyhow;
+
+use cumulus_zombienet_sdk_helpers::assert_finalized_para_throughput;
+use polkadot_primitives::Id as ParaId;
+use subxt::{OnlineClient, PolkadotConfig};
+use zombienet_sdk::NetworkConfigBuilder;
+
+const VALIDATOR_COUNT: u8 = 3;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn oversized_collations_test() -> Result<(), anyhow::Error> {
+	let _ = env_logger::try_init_from_env(
+		env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
+	);
+
+	let images = zombienet_sdk::environment::get_images_from_env();
+
+	let config = NetworkConfigBuilder::new()
+		.with_relaychain(|r| {
+			let r = r
+				.with_chain("rococo-local")
+				.with_default_command("polkadot")
+				.with_default_image(images.polkadot.as_str())
+				.with_default_args(vec![("-lparachain=debug").into()])
+				// Have to set a `with_node` outside of the loop below, so that `r` has the right
+				// type.
+				.with_node(|node| node.with_name("validator-0"));
+
+			(1..VALIDATOR_COUNT)
+				.fold(r, |acc, i| acc.with_node(|node| node.with_name(&format!("validator-{i}"))))
+		})
+		.with_parachain(|p| {
+			p.with_id(2000)
+				.with_default_command("undying-collator")
+				.cumulus_based(false)
+				.with_default_image(
+					std::env::var("COL_IMAGE")
+						.unwrap_or("docker.io/paritypr/colander:latest".to_string())
+						.as_str(),
+				)
+				.with_collator(|n| {
+					n.with_name("normal-collator").with_args(vec![("-lparachain=debug").into()])
+				})
+				.with_collator(|n| {
+					n.with_name("malus-collator").with_args(vec![
+						("-lparachain=debug").into(),
+						("--malus-type=oversized-collations").into(),
+					])
+				})
+		})
+		.build()
+		.map_err(|e| {
+			let errs = e.into_iter().map(|e| e.to_string()).collect::<Vec<_>>().join(" ");
+			anyhow!("config errs: {errs}")
+		})?;
+
+	let spawn_fn = zombienet_sdk::environment::get_spawn_fn();
+	let network = spawn_fn(config).await?;
+
+	let relay_node = network.get_node("validator-0")?;
+	let relay_client: OnlineClient<PolkadotConfig> = relay_node.wait_client().await?;
+
+	// Ensure that the malus collator is submitting oversized collations.
+	network
+		.get_node("malus-collator")?
+		.wait_log_line_count_with_timeout("*exceeding maximum size*", true, 1, 120u64)
+		.await?;
+
+	// The oversized collations are rejected, the normal collator keeps the parachain progressing.
+	assert_finalized_para_throughput(
+		&relay_client,
+		15,
+		[(ParaId::from(2000), 10..16)].into_iter().collect(),
+	)
+	.await?;
+
+	// Verify that the oversized collations are rejected by the backing group. With a single core,
+	// all validators are in the backing group, but only those fetching a collation validate it, so
+	// it is enough for one of them to have rejected it. Since this check is performed after the
+	// para throughput check, the rejected collations should have already been counted.
+	let mut rejected = false;
+	for i in 0..VALIDATOR_COUNT {
+		let validator_node = network.get_node(&format!("validator-{i}"))?;
+		rejected |= validator_node
+			.wait_metric_with_timeout(
+				"polkadot_parachain_validation_requests_total{validity=\"invalid\"}",
+				|v| v > 0.0,
+				10u64,
+			)
+			.await
+			.is_ok();
+	}
+	assert!(rejected, "No validator rejected the oversized collations");
+
+	log::info!("Test finished successfully");
+
+	Ok(())
+}
@@ -510,6 +510,8 @@ impl pallet_scheduler::Config for Runtime {
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = Preimage;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type TimeProvider = Timestamp;
+	type MaxTimedAgendas = ConstU32<50>;
 }
 
 impl pallet_glutton::Config for Runtime {
@@ -1012,6 +1014,7 @@ impl pallet_conviction_voting::Config for Runtime {
 	type Currency = Balances;
 	type VoteLockingPeriod = VoteLockingPeriod;
 	type MaxVotes = ConstU32<512>;
+	type MaxDelegationDepth = ConstU32<4>;
 	type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, Self::AccountId>;
 	type Polls = Referenda;
 	type BlockNumberProvider = System;
@@ -1052,11 +1055,13 @@ impl pallet_referenda::Config for Runtime {
 	type SubmitOrigin = EnsureSigned<AccountId>;
 	type CancelOrigin = EnsureRoot<AccountId>;
 	type KillOrigin = EnsureRoot<AccountId>;
+	type UpdateTracksOrigin = EnsureRoot<AccountId>;
 	type Slash = ();
 	type Votes = pallet_conviction_voting::VotesOf<Runtime>;
 	type Tally = pallet_conviction_voting::TallyOf<Runtime>;
 	type SubmissionDeposit = SubmissionDeposit;
 	type MaxQueued = ConstU32<100>;
+	type MaxTracks = ConstU32<32>;
 	type UndecidingTimeout = UndecidingTimeout;
 	type AlarmInterval = AlarmInterval;
 	type Tracks = TracksInfo;
@@ -1073,11 +1078,13 @@ impl pallet_referenda::Config<pallet_referenda::Instance2> for Runtime {
 	type SubmitOrigin = EnsureSigned<AccountId>;
 	type CancelOrigin = EnsureRoot<AccountId>;
 	type KillOrigin = EnsureRoot<AccountId>;
+	type UpdateTracksOrigin = EnsureRoot<AccountId>;
 	type Slash = ();
 	type Votes = pallet_ranked_collective::Votes;
 	type Tally = pallet_ranked_collective::TallyOf<Runtime>;
 	type SubmissionDeposit = SubmissionDeposit;
 	type MaxQueued = ConstU32<100>;
+	type MaxTracks = ConstU32<32>;
 	type UndecidingTimeout = UndecidingTimeout;
 	type AlarmInterval = AlarmInterval;
 	type Tracks = TracksInfo;
@@ -1799,6 +1806,7 @@ impl pallet_assets::Config<Instance1> for Runtime {
 	type Freezer = ();
 	type Extra = ();
 	type CallbackHandle = ();
+	type TransferRestrictions = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1829,6 +1837,7 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
 	type CallbackHandle = ();
+	type TransferRestrictions = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -1875,6 +1884,8 @@ impl pallet_asset_conversion::Config for Runtime {
 	type WeightInfo = pallet_asset_conversion::weights::SubstrateWeight<Runtime>;
 	type MaxSwapPathLength = ConstU32<4>;
 	type MintMinLiquidity = MintMinLiquidity;
+	type MaxPriceObservations = ConstU32<24>;
+	type PriceObservationPeriod = ConstU32<HOURS>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -2868,6 +2879,11 @@ type Migrations = (
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
 	pallet_identity::migration::versioned::V0ToV1<Runtime, IDENTITY_MIGRATION_KEY_LIMIT>,
+	pallet_referenda::migration::stored_tracks::MigrateToStoredTracks<Runtime>,
+	pallet_referenda::migration::stored_tracks::MigrateToStoredTracks<
+		Runtime,
+		pallet_referenda::Instance2,
+	>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -3207,6 +3223,16 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_staking_runtime_api::StakingRewardsApi<Block, Balance, AccountId> for Runtime {
+		fn era_rewards(era: sp_staking::EraIndex, stash: AccountId) -> Vec<sp_staking::EraReward<AccountId, Balance>> {
+			Staking::api_era_rewards(era, stash)
+		}
+
+		fn estimate_yield(stake: Balance, targets: Vec<AccountId>) -> sp_staking::YieldEstimate<Balance> {
+			Staking::api_estimate_yield(stake, targets)
+		}
+	}
+
 	impl sp_consensus_babe::BabeApi<Block> for Runtime {
 		fn configuration() -> sp_consensus_babe::BabeConfiguration {
 			let epoch_config = Babe::epoch_config().unwrap_or(BABE_GENESIS_EPOCH_CONFIG);
@@ -3556,6 +3582,17 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_asset_conversion::AssetConversionOracleApi<
+		Block,
+		NativeOrWithId<u32>,
+		BlockNumber,
+	> for Runtime
+	{
+		fn time_weighted_average_price(asset1: NativeOrWithId<u32>, asset2: NativeOrWithId<u32>, window: BlockNumber) -> Option<sp_runtime::FixedU128> {
+			AssetConversion::time_weighted_average_price(asset1, asset2, window)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
 		for Runtime
 	{
@@ -3573,6 +3610,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_scheduler::runtime_api::SchedulerApi<Block, Moment, Hash> for Runtime {
+		fn upcoming_timed_tasks(max: u32) -> Vec<pallet_scheduler::TimedTaskInfo<Moment, Hash>> {
+			Scheduler::upcoming_timed_tasks(max)
+		}
+	}
+
+	impl pallet_referenda::runtime_api::ReferendaApi<Block, u16, BlockNumber> for Runtime {
+		fn referendum_projection(
+			index: pallet_referenda::ReferendumIndex,
+		) -> Option<pallet_referenda::ReferendumProjection<u16, BlockNumber>> {
+			Referenda::referendum_projection(index)
+		}
+	}
+
 	impl pallet_nfts_runtime_api::NftsApi<Block, AccountId, u32, u32> for Runtime {
 		fn owner(collection: u32, item: u32) -> Option<AccountId> {
 			<Nfts as Inspect<AccountId>>::owner(&collection, &item)
@@ -25,15 +25,25 @@ use sp_wasm_interface::{
 	Function, FunctionContext, HostFunctions, Pointer, Value, ValueType, WordSize,
 };
 
-#[repr(transparent)]
-pub struct InstancePre(polkavm::InstancePre<(), String>);
+pub struct InstancePre {
+	instance_pre: polkavm::InstancePre<(), String>,
+	gas_limit: Option<u64>,
+	max_heap_size: Option<u32>,
+}
 
-#[repr(transparent)]
-pub struct Instance(polkavm::Instance<(), String>);
+pub struct Instance {
+	instance: polkavm::Instance<(), String>,
+	gas_limit: Option<u64>,
+	max_heap_size: Option<u32>,
+}
 
 impl WasmModule for InstancePre {
 	fn new_instance(&self) -> Result<Box<dyn WasmInstance>, Error> {
-		Ok(Box::new(Instance(self.0.instantiate()?)))
+		Ok(Box::new(Instance {
+			instance: self.instance_pre.instantiate()?,
+			gas_limit: self.gas_limit,
+			max_heap_size: self.max_heap_size,
+		}))
 	}
 }
 
@@ -43,7 +53,7 @@ impl WasmInstance for Instance {
 		name: &str,
 		raw_data: &[u8],
 	) -> (Result<Vec<u8>, Error>, Option<AllocationStats>) {
-		let pc = match self.0.module().exports().find(|e| e.symbol() == name) {
+		let pc = match self.instance.module().exports().find(|e| e.symbol() == name) {
 			Some(export) => export.program_counter(),
 			None =>
 				return (
@@ -59,10 +69,18 @@ impl WasmInstance for Instance {
 			);
 		};
 
+		if self.max_heap_size.is_some_and(|max_heap_size| raw_data_length > max_heap_size) {
+			return (
+				Err(format!("call into the runtime method '{name}' failed: memory limit exceeded")
+					.into()),
+				None,
+			);
+		}
+
 		// TODO: This will leak guest memory; find a better solution.
 
 		// Make sure that the memory is cleared...
-		if let Err(err) = self.0.reset_memory() {
+		if let Err(err) = self.instance.reset_memory() {
 			return (
 				Err(format!(
 					"call into the runtime method '{name}' failed: reset memory failed: {err}"
@@ -73,7 +91,7 @@ impl WasmInstance for Instance {
 		}
 
 		// ... and allocate space for the input payload.
-		if let Err(err) = self.0.sbrk(raw_data_length) {
+		if let Err(err) = self.instance.sbrk(raw_data_length) {
 			return (
 				Err(format!(
 					"call into the runtime method '{name}' failed: reset memory failed: {err}"
@@ -85,13 +103,18 @@ impl WasmInstance for Instance {
 
 		// Grab the address of where the guest's heap starts; that's where we've just allocated
 		// the memory for the input payload.
-		let data_pointer = self.0.module().memory_map().heap_base();
+		let data_pointer = self.instance.module().memory_map().heap_base();
 
-		if let Err(err) = self.0.write_memory(data_pointer, raw_data) {
+		if let Err(err) = self.instance.write_memory(data_pointer, raw_data) {
 			return (Err(format!("call into the runtime method '{name}': failed to write the input payload into guest memory: {err}").into()), None);
 		}
 
-		match self.0.call_typed(&mut (), pc, (data_pointer, raw_data_length)) {
+		if let Some(gas_limit) = self.gas_limit {
+			// The gas limit is applied to every call separately.
+			self.instance.set_gas(gas_limit.try_into().unwrap_or(polkavm::Gas::MAX));
+		}
+
+		match self.instance.call_typed(&mut (), pc, (data_pointer, raw_data_length)) {
 			Ok(()) => {},
 			Err(CallError::Trap) =>
 				return (
@@ -108,12 +131,31 @@ impl WasmInstance for Instance {
 					Err(format!("call into the runtime method '{name}' failed: {err}").into()),
 					None,
 				),
-			Err(CallError::NotEnoughGas) => unreachable!("gas metering is never enabled"),
+			Err(CallError::NotEnoughGas) =>
+				return (
+					Err(format!("call into the runtime method '{name}' failed: out of gas").into()),
+					None,
+				),
 		};
 
-		let result_pointer = self.0.reg(Reg::A0);
-		let result_length = self.0.reg(Reg::A1);
-		let output = match self.0.read_memory(result_pointer as u32, result_length as u32) {
+		// PolkaVM can't cap the heap of a running program, so the limit is checked once the call
+		// returns; the result is the same either way.
+		if let Some(max_heap_size) = self.max_heap_size {
+			let heap_top = self.instance.sbrk(0).ok().flatten().unwrap_or(u32::MAX);
+			if heap_top.saturating_sub(data_pointer) > max_heap_size {
+				return (
+					Err(format!(
+						"call into the runtime method '{name}' failed: memory limit exceeded"
+					)
+					.into()),
+					None,
+				);
+			}
+		}
+
+		let result_pointer = self.instance.reg(Reg::A0);
+		let result_length = self.instance.reg(Reg::A1);
+		let output = match self.instance.read_memory(result_pointer as u32, result_length as u32) {
 			Ok(output) => output,
 			Err(error) => {
 				return (Err(format!("call into the runtime method '{name}' failed: failed to read the return payload: {error}").into()), None)
@@ -278,8 +320,46 @@ where
 		},
 	};
 
-	let module =
-		polkavm::Module::from_blob(&engine, &polkavm::ModuleConfig::default(), blob.clone())?;
+	create_runtime_with_engine::<H>(engine, blob, None, None)
+}
+
+/// Creates a runtime from the given blob using the given `engine`.
+///
+/// If `gas_limit` is set, the module is compiled with gas metering enabled and every call into
+/// the runtime fails once it consumes more than `gas_limit` gas.
+///
+/// If `memory_limit` is set, creating the runtime fails if the data and the stack of the program
+/// take more than `memory_limit` bytes, and every call into the runtime fails if they take more
+/// than `memory_limit` bytes together with the heap.
+pub fn create_runtime_with_engine<H>(
+	engine: &polkavm::Engine,
+	blob: &polkavm::ProgramBlob,
+	gas_limit: Option<u64>,
+	memory_limit: Option<u32>,
+) -> Result<Box<dyn WasmModule>, WasmError>
+where
+	H: HostFunctions,
+{
+	let mut module_config = polkavm::ModuleConfig::default();
+	if gas_limit.is_some() {
+		module_config.set_gas_metering(Some(polkavm::GasMeteringKind::Sync));
+	}
+
+	let module = polkavm::Module::from_blob(engine, &module_config, blob.clone())?;
+
+	let max_heap_size = match memory_limit {
+		Some(memory_limit) => {
+			let static_memory = static_memory_size(&module);
+			let Some(max_heap_size) = u64::from(memory_limit).checked_sub(static_memory) else {
+				return Err(WasmError::Other(format!(
+					"the program needs {static_memory} bytes of memory for its data and stack, \
+					 more than the limit of {memory_limit} bytes"
+				)));
+			};
+			Some(max_heap_size as u32)
+		},
+		None => None,
+	};
 
 	let mut linker = polkavm::Linker::new();
 
@@ -289,5 +369,14 @@ where
 		})?;
 	}
 	let instance_pre = linker.instantiate_pre(&module)?;
-	Ok(Box::new(InstancePre(instance_pre)))
+	Ok(Box::new(InstancePre { instance_pre, gas_limit, max_heap_size }))
+}
+
+/// Returns the memory, in bytes, the given module needs for its data and stack, i.e. before
+/// allocating anything on its heap.
+pub fn static_memory_size(module: &polkavm::Module) -> u64 {
+	let memory_map = module.memory_map();
+	u64::from(memory_map.ro_data_size()) +
+		u64::from(memory_map.rw_data_size()) +
+		u64::from(memory_map.stack_size())
 }
@@ -47,6 +47,9 @@ pub enum Error {
 		/// Maximum allowed value
 		max: u32,
 	},
+	/// The runtime could not dispatch a view function.
+	#[error("View function dispatch failed: {}", .0)]
+	ViewFunction(String),
 	/// Call to an unsafe RPC was denied.
 	#[error(transparent)]
 	UnsafeRpcCalled(#[from] crate::policy::UnsafeRpcError),
@@ -21,7 +21,7 @@
 use jsonrpsee::proc_macros::rpc;
 use sp_core::{
 	storage::{StorageChangeSet, StorageData, StorageKey},
-	Bytes,
+	Bytes, H256,
 };
 use sp_version::RuntimeVersion;
 
@@ -38,6 +38,18 @@ pub trait StateApi<Hash> {
 	#[method(name = "state_call", aliases = ["state_callAt"], blocking)]
 	fn call(&self, name: String, bytes: Bytes, hash: Option<Hash>) -> Result<Bytes, Error>;
 
+	/// Execute a pallet view function at a block's state.
+	///
+	/// `id` is the view function's `ViewFunctionId`, its prefix followed by its suffix, and
+	/// `input` the SCALE encoded arguments. Returns the SCALE encoded result.
+	#[method(name = "state_callViewFunction", blocking)]
+	fn call_view_function(
+		&self,
+		id: H256,
+		input: Bytes,
+		hash: Option<Hash>,
+	) -> Result<Bytes, Error>;
+
 	/// Returns the keys with prefix, leave empty to get all the keys.
 	#[method(name = "state_getKeys", blocking)]
 	#[deprecated(since = "2.0.0", note = "Please use `getKeysPaged` with proper paging support")]
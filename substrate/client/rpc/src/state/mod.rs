@@ -25,6 +25,7 @@ mod utils;
 mod tests;
 
 use crate::SubscriptionTaskExecutor;
+use codec::{Decode, Encode};
 use jsonrpsee::{core::async_trait, Extensions, PendingSubscriptionSink};
 use sc_client_api::{
 	Backend, BlockBackend, BlockchainEvents, ExecutorProvider, ProofProvider, StorageProvider,
@@ -33,10 +34,14 @@ use sc_rpc_api::{check_if_safe, DenyUnsafe};
 use sp_api::{CallApiAt, Metadata, ProvideRuntimeApi};
 use sp_blockchain::{HeaderBackend, HeaderMetadata};
 use sp_core::{
+	hexdisplay::HexDisplay,
 	storage::{PrefixedStorageKey, StorageChangeSet, StorageData, StorageKey},
-	Bytes,
+	Bytes, H256,
+};
+use sp_runtime::{
+	traits::Block as BlockT,
+	view_functions::{ViewFunctionDispatchError, ViewFunctionId},
 };
-use sp_runtime::traits::Block as BlockT;
 use sp_version::RuntimeVersion;
 use std::sync::Arc;
 
@@ -44,6 +49,23 @@ pub use sc_rpc_api::{child_state::*, state::*};
 
 const STORAGE_KEYS_PAGED_MAX_COUNT: u32 = 1000;
 
+/// The runtime API method through which view functions are executed.
+const EXECUTE_VIEW_FUNCTION: &str = "RuntimeViewFunction_execute_view_function";
+
+/// Describes why the runtime failed to dispatch a view function.
+fn view_function_error(e: ViewFunctionDispatchError) -> Error {
+	let details = match e {
+		ViewFunctionDispatchError::NotImplemented =>
+			"view functions are not implemented by the runtime".into(),
+		ViewFunctionDispatchError::NotFound(id) => format!(
+			"view function 0x{} not found",
+			HexDisplay::from(&<[u8; 32]>::from(id).as_ref())
+		),
+		ViewFunctionDispatchError::Codec => "failed to decode view function input".into(),
+	};
+	Error::ViewFunction(details)
+}
+
 /// State backend API.
 #[async_trait]
 pub trait StateBackend<Block: BlockT, Client>: Send + Sync + 'static
@@ -209,6 +231,19 @@ where
 		self.backend.call(block, method, data).map_err(Into::into)
 	}
 
+	fn call_view_function(
+		&self,
+		id: H256,
+		input: Bytes,
+		block: Option<Block::Hash>,
+	) -> Result<Bytes, Error> {
+		let call_data = (ViewFunctionId::from(id.0), input.0).encode();
+		let result = self.backend.call(block, EXECUTE_VIEW_FUNCTION.into(), call_data.into())?;
+		let result = Result::<Vec<u8>, ViewFunctionDispatchError>::decode(&mut &result[..])
+			.map_err(|e| Error::Client(Box::new(e)))?;
+		result.map(Into::into).map_err(view_function_error)
+	}
+
 	fn storage_keys(
 		&self,
 		key_prefix: StorageKey,
@@ -207,6 +207,39 @@ async fn should_call_contract() {
 	)
 }
 
+#[tokio::test]
+async fn should_call_view_function() {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let genesis_hash = client.genesis_hash();
+	let (client, _child) = new_full(client, test_executor());
+
+	// `ViewFunctionId` of `pallet_balances::Pallet::spendable_balance`.
+	let mut id = [0u8; 32];
+	id[..16].copy_from_slice(&sp_crypto_hashing::twox_128(b"Balances"));
+	id[16..].copy_from_slice(&sp_crypto_hashing::twox_128(
+		b"spendable_balance(T::AccountId) -> T::Balance",
+	));
+	let input = Sr25519Keyring::Alice.public().encode();
+
+	let result = client.call_view_function(H256(id), Bytes(input), Some(genesis_hash)).unwrap();
+	assert_eq!(
+		u64::decode(&mut &result[..]).unwrap(),
+		1000 * substrate_test_runtime_client::runtime::currency::DOLLARS
+	);
+}
+
+#[tokio::test]
+async fn should_fail_unknown_view_function() {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let genesis_hash = client.genesis_hash();
+	let (client, _child) = new_full(client, test_executor());
+
+	assert_matches!(
+		client.call_view_function(H256::zero(), Bytes(vec![]), Some(genesis_hash)),
+		Err(Error::ViewFunction(_))
+	)
+}
+
 #[tokio::test]
 async fn should_notify_about_storage_changes() {
 	let mut sub = {
@@ -476,7 +509,8 @@ async fn should_return_runtime_version() {
 		[\"0x37e397fc7c91f5e4\",2],[\"0xd2bc9897eed08f15\",3],[\"0x40fe3ad401f8959a\",6],\
 		[\"0xbc9d89904f5b923f\",1],[\"0xc6e9a76309f39b09\",2],[\"0xdd718d5cc53262d4\",1],\
 		[\"0xcbca25e39f142387\",2],[\"0xf78b278be53f454c\",2],[\"0xab3c0572291feb8b\",1],\
-		[\"0xed99c5acb25eedf5\",3],[\"0xfbc577b9d747efd6\",1]],\"transactionVersion\":1,\"systemVersion\":1,\
+		[\"0xed99c5acb25eedf5\",3],[\"0xfbc577b9d747efd6\",1],\
+		[\"0xccd9de6396c899ca\",1]],\"transactionVersion\":1,\"systemVersion\":1,\
 		\"stateVersion\":1}";
 
 	let runtime_version = api.runtime_version(None.into()).unwrap();
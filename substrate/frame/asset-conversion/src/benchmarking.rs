@@ -28,6 +28,7 @@ use frame_support::{
 		fungible::NativeOrWithId,
 		fungibles::{Create, Inspect, Mutate, Refund},
 	},
+	BoundedVec,
 };
 use frame_system::RawOrigin as SystemOrigin;
 use sp_core::Get;
@@ -166,6 +167,23 @@ where
 	(lp_token, liquidity1, liquidity2)
 }
 
+/// Fill the price observations of the pools of the given asset pairs and move to a block in which
+/// both their cumulative prices and a new observation are due, the worst case of
+/// [`Pallet::update_price_cumulative`].
+fn make_price_updates_due<T: Config>(pairs: &[(T::AssetKind, T::AssetKind)]) {
+	let now = frame_system::Pallet::<T>::block_number();
+	let cumulative = PriceCumulative { block: now, ..Default::default() };
+	for (asset1, asset2) in pairs {
+		let pool_id = T::PoolLocator::pool_id(asset1, asset2).unwrap();
+		PriceCumulatives::<T>::insert(&pool_id, cumulative);
+		PriceObservations::<T>::insert(
+			&pool_id,
+			BoundedVec::truncate_from(vec![cumulative; T::MaxPriceObservations::get() as usize]),
+		);
+	}
+	frame_system::Pallet::<T>::set_block_number(now + T::PriceObservationPeriod::get());
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -199,6 +217,34 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn create_pool_with_kind() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset1, asset2) = T::BenchmarkHelper::create_pair(0, 1);
+		create_asset::<T>(&caller, &asset1, T::Assets::minimum_balance(asset1.clone()), true);
+		create_asset::<T>(&caller, &asset2, T::Assets::minimum_balance(asset2.clone()), true);
+
+		let lp_token = AssetConversion::<T>::get_next_pool_asset_id();
+		create_fee_asset::<T>(&caller);
+		mint_setup_fee_asset::<T>(&caller, &asset1, &asset2, &lp_token);
+		let kind = PoolKind::StableSwap { amplification: MAX_AMPLIFICATION };
+
+		#[extrinsic_call]
+		_(
+			SystemOrigin::Signed(caller.clone()),
+			Box::new(asset1.clone()),
+			Box::new(asset2.clone()),
+			kind,
+		);
+
+		let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).unwrap();
+		let pool_account = T::PoolLocator::address(&pool_id).unwrap();
+		assert_eq!(PoolKinds::<T>::get(&pool_id), kind);
+		assert_last_event::<T>(
+			Event::PoolCreated { creator: caller, pool_account, pool_id, lp_token }.into(),
+		);
+	}
+
 	#[benchmark]
 	fn add_liquidity() {
 		let caller: T::AccountId = whitelisted_caller();
@@ -207,6 +253,7 @@ mod benchmarks {
 		create_fee_asset::<T>(&caller);
 		let (lp_token, liquidity1, liquidity2) =
 			create_asset_and_pool::<T>(&caller, &asset1, &asset2);
+		make_price_updates_due::<T>(&[(asset1.clone(), asset2.clone())]);
 
 		#[extrinsic_call]
 		_(
@@ -251,6 +298,7 @@ mod benchmarks {
 		));
 		let total_supply =
 			<T::PoolAssets as Inspect<T::AccountId>>::total_issuance(lp_token.clone());
+		make_price_updates_due::<T>(&[(asset1.clone(), asset2.clone())]);
 
 		#[extrinsic_call]
 		_(
@@ -272,6 +320,8 @@ mod benchmarks {
 		let mut swap_amount = T::Balance::one();
 		let mut path = vec![];
 
+		let mut pairs = vec![];
+
 		let caller: T::AccountId = whitelisted_caller();
 		create_fee_asset::<T>(&caller);
 		for n in 1..n {
@@ -295,7 +345,9 @@ mod benchmarks {
 				T::Balance::zero(),
 				caller.clone(),
 			));
+			pairs.push((asset1, asset2));
 		}
+		make_price_updates_due::<T>(&pairs);
 
 		let asset_in = *path.first().unwrap().clone();
 		assert_ok!(T::Assets::mint_into(
@@ -324,6 +376,8 @@ mod benchmarks {
 		let mut max_swap_amount = T::Balance::one();
 		let mut path = vec![];
 
+		let mut pairs = vec![];
+
 		let caller: T::AccountId = whitelisted_caller();
 		create_fee_asset::<T>(&caller);
 		for n in 1..n {
@@ -347,7 +401,9 @@ mod benchmarks {
 				T::Balance::zero(),
 				caller.clone(),
 			));
+			pairs.push((asset1, asset2));
 		}
+		make_price_updates_due::<T>(&pairs);
 
 		let asset_in = *path.first().unwrap().clone();
 		let asset_out = *path.last().unwrap().clone();
@@ -24,6 +24,8 @@
 //! This pallet allows you to:
 //!
 //!  - [create a liquidity pool](`Pallet::create_pool()`) for 2 assets
+//!  - [create a stable-swap liquidity pool](`Pallet::create_pool_with_kind()`) for 2 assets of
+//!    (nearly) the same value
 //!  - [provide the liquidity](`Pallet::add_liquidity()`) and receive back an LP token
 //!  - [exchange the LP token back to assets](`Pallet::remove_liquidity()`)
 //!  - [swap a specific amount of assets for another](`Pallet::swap_exact_tokens_for_tokens()`) if
@@ -34,6 +36,8 @@
 //!    a runtime call endpoint
 //!  - [query the size of a liquidity pool](`AssetConversionApi::get_reserves`) via a runtime api
 //!    endpoint.
+//!  - [query the time-weighted average price](`PriceOracle::time_weighted_average_price`) of a
+//!    pool, either on-chain or via the [`AssetConversionOracleApi`] runtime api endpoint.
 //!
 //! The `quote_price_exact_tokens_for_tokens` and `quote_price_tokens_for_exact_tokens` functions
 //! both take a path parameter of the route to take. If you want to swap from native asset to
@@ -88,7 +92,8 @@ use frame_support::{
 	},
 	PalletId,
 };
-use sp_core::Get;
+use sp_arithmetic::FixedU128;
+use sp_core::{Get, U256};
 use sp_runtime::{
 	traits::{
 		CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Ensure, IntegerSquareRoot, MaybeDisplay,
@@ -121,6 +126,7 @@ pub mod pallet {
 
 		/// A type used for calculations concerning the `Balance` type to avoid possible overflows.
 		type HigherPrecisionBalance: IntegerSquareRoot
+			+ Clone
 			+ One
 			+ Ensure
 			+ Unsigned
@@ -190,6 +196,17 @@ pub mod pallet {
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// The maximum number of price observations kept per pool.
+		///
+		/// Together with [`Config::PriceObservationPeriod`] this bounds the longest window over
+		/// which a time-weighted average price can be queried.
+		#[pallet::constant]
+		type MaxPriceObservations: Get<u32>;
+
+		/// The minimum number of blocks between two price observations of a pool.
+		#[pallet::constant]
+		type PriceObservationPeriod: Get<BlockNumberFor<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -209,6 +226,28 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NextPoolAssetId<T: Config> = StorageValue<_, T::PoolAssetId, OptionQuery>;
 
+	/// The kind of each pool. Pools without an entry are constant-product pools.
+	#[pallet::storage]
+	pub type PoolKinds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, PoolKind, ValueQuery>;
+
+	/// The cumulative prices of each pool as of the last block it was interacted with. Used to
+	/// derive time-weighted average prices.
+	#[pallet::storage]
+	pub type PriceCumulatives<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, PriceCumulative<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Snapshots of the [`PriceCumulatives`] of each pool, oldest first, taken at most once every
+	/// [`Config::PriceObservationPeriod`].
+	#[pallet::storage]
+	pub type PriceObservations<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		BoundedVec<PriceCumulative<BlockNumberFor<T>>, T::MaxPriceObservations>,
+		ValueQuery,
+	>;
+
 	// Pallet's events.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -349,6 +388,10 @@ pub mod pallet {
 		IncorrectPoolAssetId,
 		/// The destination account cannot exist with the swapped funds.
 		BelowMinimum,
+		/// The parameters of the pool kind are out of bounds.
+		InvalidPoolKind,
+		/// The invariant of a stable-swap pool could not be computed.
+		StableSwapDidNotConverge,
 	}
 
 	#[pallet::hooks]
@@ -364,10 +407,11 @@ pub mod pallet {
 	/// Pallet's callable functions.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Creates an empty liquidity pool and an associated new `lp_token` asset
-		/// (the id of which is returned in the `Event::PoolCreated` event).
+		/// Creates an empty constant-product liquidity pool and an associated new `lp_token`
+		/// asset (the id of which is returned in the `Event::PoolCreated` event).
 		///
-		/// Once a pool is created, someone may [`Pallet::add_liquidity`] to it.
+		/// Once a pool is created, someone may [`Pallet::add_liquidity`] to it. Use
+		/// [`Pallet::create_pool_with_kind`] to create a pool with another pricing curve.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::create_pool())]
 		pub fn create_pool(
@@ -376,51 +420,7 @@ pub mod pallet {
 			asset2: Box<T::AssetKind>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			ensure!(asset1 != asset2, Error::<T>::InvalidAssetPair);
-
-			// prepare pool_id
-			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
-				.map_err(|_| Error::<T>::InvalidAssetPair)?;
-			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
-
-			let pool_account =
-				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
-
-			// pay the setup fee
-			let fee =
-				Self::withdraw(T::PoolSetupFeeAsset::get(), &sender, T::PoolSetupFee::get(), true)?;
-			T::PoolSetupFeeTarget::on_unbalanced(fee);
-
-			if T::Assets::should_touch(*asset1.clone(), &pool_account) {
-				T::Assets::touch(*asset1, &pool_account, &sender)?
-			};
-
-			if T::Assets::should_touch(*asset2.clone(), &pool_account) {
-				T::Assets::touch(*asset2, &pool_account, &sender)?
-			};
-
-			let lp_token = NextPoolAssetId::<T>::get()
-				.or(T::PoolAssetId::initial_value())
-				.ok_or(Error::<T>::IncorrectPoolAssetId)?;
-			let next_lp_token_id = lp_token.increment().ok_or(Error::<T>::IncorrectPoolAssetId)?;
-			NextPoolAssetId::<T>::set(Some(next_lp_token_id));
-
-			T::PoolAssets::create(lp_token.clone(), pool_account.clone(), false, 1u32.into())?;
-			if T::PoolAssets::should_touch(lp_token.clone(), &pool_account) {
-				T::PoolAssets::touch(lp_token.clone(), &pool_account, &sender)?
-			};
-
-			let pool_info = PoolInfo { lp_token: lp_token.clone() };
-			Pools::<T>::insert(pool_id.clone(), pool_info);
-
-			Self::deposit_event(Event::PoolCreated {
-				creator: sender,
-				pool_id,
-				pool_account,
-				lp_token,
-			});
-
-			Ok(())
+			Self::do_create_pool(sender, asset1, asset2, PoolKind::ConstantProduct)
 		}
 
 		/// Provide liquidity into the pool of `asset1` and `asset2`.
@@ -505,6 +505,8 @@ pub mod pallet {
 				Error::<T>::AmountTwoLessThanMinimal
 			);
 
+			Self::update_price_cumulative(&pool_id, &asset1, &asset2, reserve1, reserve2);
+
 			T::Assets::transfer(*asset1, &sender, &pool_account, amount1, Preserve)?;
 			T::Assets::transfer(*asset2, &sender, &pool_account, amount2, Preserve)?;
 
@@ -608,6 +610,8 @@ pub mod pallet {
 				Polite,
 			)?;
 
+			Self::update_price_cumulative(&pool_id, &asset1, &asset2, reserve1, reserve2);
+
 			T::Assets::transfer(*asset1, &pool_account, &withdraw_to, amount1, Expendable)?;
 			T::Assets::transfer(*asset2, &pool_account, &withdraw_to, amount2, Expendable)?;
 
@@ -723,9 +727,84 @@ pub mod pallet {
 			Self::deposit_event(Event::Touched { pool_id, who });
 			Ok(Some(T::WeightInfo::touch(refunds_number)).into())
 		}
+
+		/// Creates an empty liquidity pool of the given `kind` and an associated new `lp_token`
+		/// asset (the id of which is returned in the `Event::PoolCreated` event).
+		///
+		/// Works like [`Pallet::create_pool`], except that the pricing curve of the pool can be
+		/// chosen. Swaps through the pool are routed like through any other pool.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::create_pool_with_kind())]
+		pub fn create_pool_with_kind(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			kind: PoolKind,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_create_pool(sender, asset1, asset2, kind)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Create a pool of `kind` for `asset1` and `asset2` on behalf of `sender`.
+		pub(crate) fn do_create_pool(
+			sender: T::AccountId,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			kind: PoolKind,
+		) -> DispatchResult {
+			ensure!(asset1 != asset2, Error::<T>::InvalidAssetPair);
+			ensure!(kind.is_valid(), Error::<T>::InvalidPoolKind);
+
+			// prepare pool_id
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
+
+			let pool_account =
+				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
+
+			// pay the setup fee
+			let fee =
+				Self::withdraw(T::PoolSetupFeeAsset::get(), &sender, T::PoolSetupFee::get(), true)?;
+			T::PoolSetupFeeTarget::on_unbalanced(fee);
+
+			if T::Assets::should_touch(*asset1.clone(), &pool_account) {
+				T::Assets::touch(*asset1, &pool_account, &sender)?
+			};
+
+			if T::Assets::should_touch(*asset2.clone(), &pool_account) {
+				T::Assets::touch(*asset2, &pool_account, &sender)?
+			};
+
+			let lp_token = NextPoolAssetId::<T>::get()
+				.or(T::PoolAssetId::initial_value())
+				.ok_or(Error::<T>::IncorrectPoolAssetId)?;
+			let next_lp_token_id = lp_token.increment().ok_or(Error::<T>::IncorrectPoolAssetId)?;
+			NextPoolAssetId::<T>::set(Some(next_lp_token_id));
+
+			T::PoolAssets::create(lp_token.clone(), pool_account.clone(), false, 1u32.into())?;
+			if T::PoolAssets::should_touch(lp_token.clone(), &pool_account) {
+				T::PoolAssets::touch(lp_token.clone(), &pool_account, &sender)?
+			};
+
+			let pool_info = PoolInfo { lp_token: lp_token.clone() };
+			Pools::<T>::insert(pool_id.clone(), pool_info);
+			if kind != PoolKind::ConstantProduct {
+				PoolKinds::<T>::insert(pool_id.clone(), kind);
+			}
+
+			Self::deposit_event(Event::PoolCreated {
+				creator: sender,
+				pool_id,
+				pool_account,
+				lp_token,
+			});
+
+			Ok(())
+		}
+
 		/// Swap exactly `amount_in` of asset `path[0]` for asset `path[1]`.
 		/// If an `amount_out_min` is specified, it will return an error if it is unable to acquire
 		/// the amount desired.
@@ -956,6 +1035,13 @@ pub mod pallet {
 			credit_in: CreditOf<T>,
 			path: &BalancePath<T>,
 		) -> Result<CreditOf<T>, (CreditOf<T>, DispatchError)> {
+			// Accrue the prices of all pools along the path before any of their reserves change.
+			for pair in path.windows(2) {
+				if let [(asset1, _), (asset2, _)] = pair {
+					Self::update_price_cumulative_of_pair(asset1, asset2);
+				}
+			}
+
 			let resolve_path = || -> Result<CreditOf<T>, DispatchError> {
 				for pos in 0..=path.len() {
 					if let Some([(asset1, _), (asset2, amount_out)]) = path.get(pos..=pos + 1) {
@@ -1025,7 +1111,7 @@ pub mod pallet {
 
 		/// Get the `owner`'s balance of `asset`, which could be the chain's native asset or another
 		/// fungible. Returns a value in the form of an `Balance`.
-		fn get_balance(owner: &T::AccountId, asset: T::AssetKind) -> T::Balance {
+		pub(crate) fn get_balance(owner: &T::AccountId, asset: T::AssetKind) -> T::Balance {
 			T::Assets::reducible_balance(asset, owner, Expendable, Polite)
 		}
 
@@ -1066,8 +1152,10 @@ pub mod pallet {
 					},
 				};
 				let (reserve_in, reserve_out) = Self::get_reserves(asset1.clone(), asset2.clone())?;
+				let kind = Self::pool_kind(asset1, &asset2);
 				balance_path.push((asset2, amount_in));
-				amount_in = Self::get_amount_in(&amount_in, &reserve_in, &reserve_out)?;
+				amount_in =
+					Self::get_amount_in_for_kind(&kind, &amount_in, &reserve_in, &reserve_out)?;
 			}
 			balance_path.reverse();
 
@@ -1092,8 +1180,10 @@ pub mod pallet {
 					},
 				};
 				let (reserve_in, reserve_out) = Self::get_reserves(asset1.clone(), asset2.clone())?;
+				let kind = Self::pool_kind(&asset1, asset2);
 				balance_path.push((asset1, amount_out));
-				amount_out = Self::get_amount_out(&amount_out, &reserve_in, &reserve_out)?;
+				amount_out =
+					Self::get_amount_out_for_kind(&kind, &amount_out, &reserve_in, &reserve_out)?;
 			}
 			Ok(balance_path)
 		}
@@ -1106,14 +1196,23 @@ pub mod pallet {
 			include_fee: bool,
 		) -> Option<T::Balance> {
 			let pool_account = T::PoolLocator::pool_address(&asset1, &asset2).ok()?;
+			let kind = Self::pool_kind(&asset1, &asset2);
 
 			let balance1 = Self::get_balance(&pool_account, asset1);
 			let balance2 = Self::get_balance(&pool_account, asset2);
 			if !balance1.is_zero() {
-				if include_fee {
-					Self::get_amount_out(&amount, &balance1, &balance2).ok()
-				} else {
-					Self::quote(&amount, &balance1, &balance2).ok()
+				match kind {
+					PoolKind::ConstantProduct if include_fee =>
+						Self::get_amount_out(&amount, &balance1, &balance2).ok(),
+					PoolKind::ConstantProduct => Self::quote(&amount, &balance1, &balance2).ok(),
+					PoolKind::StableSwap { amplification } => Self::get_stable_swap_amount_out(
+						&amount,
+						&balance1,
+						&balance2,
+						amplification,
+						if include_fee { T::LPFee::get() } else { 0 },
+					)
+					.ok(),
 				}
 			} else {
 				None
@@ -1128,14 +1227,23 @@ pub mod pallet {
 			include_fee: bool,
 		) -> Option<T::Balance> {
 			let pool_account = T::PoolLocator::pool_address(&asset1, &asset2).ok()?;
+			let kind = Self::pool_kind(&asset1, &asset2);
 
 			let balance1 = Self::get_balance(&pool_account, asset1);
 			let balance2 = Self::get_balance(&pool_account, asset2);
 			if !balance1.is_zero() {
-				if include_fee {
-					Self::get_amount_in(&amount, &balance1, &balance2).ok()
-				} else {
-					Self::quote(&amount, &balance2, &balance1).ok()
+				match kind {
+					PoolKind::ConstantProduct if include_fee =>
+						Self::get_amount_in(&amount, &balance1, &balance2).ok(),
+					PoolKind::ConstantProduct => Self::quote(&amount, &balance2, &balance1).ok(),
+					PoolKind::StableSwap { amplification } => Self::get_stable_swap_amount_in(
+						&amount,
+						&balance1,
+						&balance2,
+						amplification,
+						if include_fee { T::LPFee::get() } else { 0 },
+					)
+					.ok(),
 				}
 			} else {
 				None
@@ -1260,6 +1368,280 @@ pub mod pallet {
 			result.try_into().map_err(|_| Error::<T>::Overflow)
 		}
 
+		/// Returns the kind of the pool of `asset1` and `asset2`.
+		///
+		/// Falls back to [`PoolKind::ConstantProduct`] if the asset pair is not supported.
+		pub fn pool_kind(asset1: &T::AssetKind, asset2: &T::AssetKind) -> PoolKind {
+			T::PoolLocator::pool_id(asset1, asset2)
+				.map(PoolKinds::<T>::get)
+				.unwrap_or_default()
+		}
+
+		/// Calculates amount out for a pool of the given `kind`.
+		///
+		/// See [`Pallet::get_amount_out`].
+		pub fn get_amount_out_for_kind(
+			kind: &PoolKind,
+			amount_in: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			match kind {
+				PoolKind::ConstantProduct =>
+					Self::get_amount_out(amount_in, reserve_in, reserve_out),
+				PoolKind::StableSwap { amplification } => Self::get_stable_swap_amount_out(
+					amount_in,
+					reserve_in,
+					reserve_out,
+					*amplification,
+					T::LPFee::get(),
+				),
+			}
+		}
+
+		/// Calculates amount in for a pool of the given `kind`.
+		///
+		/// See [`Pallet::get_amount_in`].
+		pub fn get_amount_in_for_kind(
+			kind: &PoolKind,
+			amount_out: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			match kind {
+				PoolKind::ConstantProduct =>
+					Self::get_amount_in(amount_out, reserve_in, reserve_out),
+				PoolKind::StableSwap { amplification } => Self::get_stable_swap_amount_in(
+					amount_out,
+					reserve_in,
+					reserve_out,
+					*amplification,
+					T::LPFee::get(),
+				),
+			}
+		}
+
+		/// Calculates amount out of a stable-swap pool.
+		///
+		/// Given an input amount of an asset and pair reserves, returns the maximum output amount
+		/// of the other asset after taking `fee` (in 10ths of a percent) off the input.
+		pub(crate) fn get_stable_swap_amount_out(
+			amount_in: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+			amplification: u32,
+			fee: u32,
+		) -> Result<T::Balance, Error<T>> {
+			let amount_in = T::HigherPrecisionBalance::from(*amount_in);
+			let reserve_in = T::HigherPrecisionBalance::from(*reserve_in);
+			let reserve_out = T::HigherPrecisionBalance::from(*reserve_out);
+
+			if reserve_in.is_zero() || reserve_out.is_zero() {
+				return Err(Error::<T>::ZeroLiquidity)
+			}
+
+			let amount_in_with_fee = amount_in
+				.checked_mul(&(T::HigherPrecisionBalance::from(1000u32) - fee.into()))
+				.ok_or(Error::<T>::Overflow)?
+				.checked_div(&1000u32.into())
+				.ok_or(Error::<T>::Overflow)?;
+
+			let invariant = Self::stable_swap_invariant(&reserve_in, &reserve_out, amplification)?;
+			let new_reserve_in =
+				reserve_in.checked_add(&amount_in_with_fee).ok_or(Error::<T>::Overflow)?;
+			let new_reserve_out =
+				Self::stable_swap_reserve(&new_reserve_in, &invariant, amplification)?;
+
+			// round down in favour of the pool.
+			let result = reserve_out
+				.checked_sub(&new_reserve_out)
+				.and_then(|r| r.checked_sub(&One::one()))
+				.unwrap_or_else(Zero::zero);
+
+			result.try_into().map_err(|_| Error::<T>::Overflow)
+		}
+
+		/// Calculates amount in of a stable-swap pool.
+		///
+		/// Given an output amount of an asset and pair reserves, returns a required input amount
+		/// of the other asset, including `fee` (in 10ths of a percent).
+		pub(crate) fn get_stable_swap_amount_in(
+			amount_out: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+			amplification: u32,
+			fee: u32,
+		) -> Result<T::Balance, Error<T>> {
+			let amount_out = T::HigherPrecisionBalance::from(*amount_out);
+			let reserve_in = T::HigherPrecisionBalance::from(*reserve_in);
+			let reserve_out = T::HigherPrecisionBalance::from(*reserve_out);
+
+			if reserve_in.is_zero() || reserve_out.is_zero() {
+				Err(Error::<T>::ZeroLiquidity)?
+			}
+
+			if amount_out >= reserve_out {
+				Err(Error::<T>::AmountOutTooHigh)?
+			}
+
+			let invariant = Self::stable_swap_invariant(&reserve_in, &reserve_out, amplification)?;
+			let new_reserve_out =
+				reserve_out.checked_sub(&amount_out).ok_or(Error::<T>::Overflow)?;
+			let new_reserve_in =
+				Self::stable_swap_reserve(&new_reserve_out, &invariant, amplification)?;
+
+			// round up in favour of the pool.
+			let amount_in_without_fee = new_reserve_in
+				.checked_sub(&reserve_in)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_add(&One::one())
+				.ok_or(Error::<T>::Overflow)?;
+
+			let result = amount_in_without_fee
+				.checked_mul(&1000u32.into())
+				.ok_or(Error::<T>::Overflow)?
+				.checked_div(&(T::HigherPrecisionBalance::from(1000u32) - fee.into()))
+				.ok_or(Error::<T>::Overflow)?
+				.checked_add(&One::one())
+				.ok_or(Error::<T>::Overflow)?;
+
+			result.try_into().map_err(|_| Error::<T>::Overflow)
+		}
+
+		/// Computes the stable-swap invariant `D` of a pool with the reserves `x` and `y`.
+		///
+		/// `D` satisfies `4A(x + y) + D = 4AD + D³ / 4xy`, and is found with Newton's method.
+		fn stable_swap_invariant(
+			x: &T::HigherPrecisionBalance,
+			y: &T::HigherPrecisionBalance,
+			amplification: u32,
+		) -> Result<T::HigherPrecisionBalance, Error<T>> {
+			let two = T::HigherPrecisionBalance::from(2u32);
+			let three = T::HigherPrecisionBalance::from(3u32);
+			let ann = T::HigherPrecisionBalance::from(amplification)
+				.checked_mul(&4u32.into())
+				.ok_or(Error::<T>::Overflow)?;
+			let sum = x.checked_add(y).ok_or(Error::<T>::Overflow)?;
+			let ann_sum = ann.checked_mul(&sum).ok_or(Error::<T>::Overflow)?;
+			let two_x = x.checked_mul(&two).ok_or(Error::<T>::Overflow)?;
+			let two_y = y.checked_mul(&two).ok_or(Error::<T>::Overflow)?;
+
+			let mut d = x.checked_add(y).ok_or(Error::<T>::Overflow)?;
+			for _ in 0..STABLE_SWAP_MAX_ITERATIONS {
+				// d_p = D³ / 4xy
+				let d_p = d
+					.checked_mul(&d)
+					.and_then(|r| r.checked_div(&two_x))
+					.and_then(|r| r.checked_mul(&d))
+					.and_then(|r| r.checked_div(&two_y))
+					.ok_or(Error::<T>::Overflow)?;
+				// D = (Ann·S + 2·d_p)·D / ((Ann - 1)·D + 3·d_p)
+				let numerator = d_p
+					.checked_mul(&two)
+					.and_then(|r| r.checked_add(&ann_sum))
+					.and_then(|r| r.checked_mul(&d))
+					.ok_or(Error::<T>::Overflow)?;
+				let denominator = ann
+					.checked_sub(&One::one())
+					.and_then(|r| r.checked_mul(&d))
+					.and_then(|r| r.checked_add(&d_p.checked_mul(&three)?))
+					.ok_or(Error::<T>::Overflow)?;
+				let next = numerator.checked_div(&denominator).ok_or(Error::<T>::Overflow)?;
+				if Self::differ_at_most_by_one(&next, &d) {
+					return Ok(next)
+				}
+				d = next;
+			}
+			Err(Error::<T>::StableSwapDidNotConverge)
+		}
+
+		/// Computes the reserve `y` of one asset of a stable-swap pool with invariant `d`, given
+		/// the reserve `x` of the other asset.
+		fn stable_swap_reserve(
+			x: &T::HigherPrecisionBalance,
+			d: &T::HigherPrecisionBalance,
+			amplification: u32,
+		) -> Result<T::HigherPrecisionBalance, Error<T>> {
+			let two = T::HigherPrecisionBalance::from(2u32);
+			let ann = T::HigherPrecisionBalance::from(amplification)
+				.checked_mul(&4u32.into())
+				.ok_or(Error::<T>::Overflow)?;
+			// c = D³ / (4x·Ann)
+			let c = d
+				.checked_mul(d)
+				.and_then(|r| r.checked_div(&x.checked_mul(&two)?))
+				.and_then(|r| r.checked_mul(d))
+				.and_then(|r| r.checked_div(&ann.checked_mul(&two)?))
+				.ok_or(Error::<T>::Overflow)?;
+			// b = x + D / Ann
+			let b =
+				d.checked_div(&ann).and_then(|r| r.checked_add(x)).ok_or(Error::<T>::Overflow)?;
+
+			let mut y = d.clone();
+			for _ in 0..STABLE_SWAP_MAX_ITERATIONS {
+				// y = (y² + c) / (2y + b - D)
+				let numerator = y
+					.checked_mul(&y)
+					.and_then(|r| r.checked_add(&c))
+					.ok_or(Error::<T>::Overflow)?;
+				let denominator = y
+					.checked_mul(&two)
+					.and_then(|r| r.checked_add(&b))
+					.and_then(|r| r.checked_sub(d))
+					.ok_or(Error::<T>::Overflow)?;
+				let next = numerator.checked_div(&denominator).ok_or(Error::<T>::Overflow)?;
+				if Self::differ_at_most_by_one(&next, &y) {
+					return Ok(next)
+				}
+				y = next;
+			}
+			Err(Error::<T>::StableSwapDidNotConverge)
+		}
+
+		/// Computes the marginal price of the asset with reserve `x` of a stable-swap pool,
+		/// denominated in the asset with reserve `y`, as a numerator and a denominator.
+		///
+		/// Derived from the invariant, the price is `(t + y) / (t + x)` with `t = 16A(xy)² / D³`,
+		/// which tends to the constant product price `y / x` as the amplification tends to zero.
+		pub(crate) fn stable_swap_marginal_price(
+			x: &T::Balance,
+			y: &T::Balance,
+			amplification: u32,
+		) -> Result<(T::Balance, T::Balance), Error<T>> {
+			let x = T::HigherPrecisionBalance::from(*x);
+			let y = T::HigherPrecisionBalance::from(*y);
+			if x.is_zero() || y.is_zero() {
+				return Err(Error::<T>::ZeroLiquidity)
+			}
+
+			let d = Self::stable_swap_invariant(&x, &y, amplification)?;
+			// t = 16A·(xy / D)² / D
+			let xy_d =
+				x.checked_mul(&y).and_then(|r| r.checked_div(&d)).ok_or(Error::<T>::Overflow)?;
+			let t = xy_d
+				.checked_mul(&xy_d)
+				.and_then(|r| r.checked_div(&d))
+				.and_then(|r| r.checked_mul(&T::HigherPrecisionBalance::from(amplification)))
+				.and_then(|r| r.checked_mul(&16u32.into()))
+				.ok_or(Error::<T>::Overflow)?;
+			let numerator = t.checked_add(&y).ok_or(Error::<T>::Overflow)?;
+			let denominator = t.checked_add(&x).ok_or(Error::<T>::Overflow)?;
+
+			Ok((
+				numerator.try_into().map_err(|_| Error::<T>::Overflow)?,
+				denominator.try_into().map_err(|_| Error::<T>::Overflow)?,
+			))
+		}
+
+		/// Whether Newton's method has converged from `b` to `a`.
+		fn differ_at_most_by_one(
+			a: &T::HigherPrecisionBalance,
+			b: &T::HigherPrecisionBalance,
+		) -> bool {
+			let diff = if a > b { a.checked_sub(b) } else { b.checked_sub(a) };
+			diff.is_some_and(|diff| diff <= One::one())
+		}
+
 		/// Ensure that a path is valid.
 		fn validate_swap_path(path: &Vec<T::AssetKind>) -> Result<(), DispatchError> {
 			ensure!(path.len() >= 2, Error::<T>::InvalidPath);
@@ -1324,6 +1706,24 @@ sp_api::decl_runtime_apis! {
 		/// Returns the size of the liquidity pool for the given asset pair.
 		fn get_reserves(asset1: AssetId, asset2: AssetId) -> Option<(Balance, Balance)>;
 	}
+
+	/// This runtime api allows people to query the time-weighted average prices of the liquidity
+	/// pools.
+	pub trait AssetConversionOracleApi<AssetId, BlockNumber>
+	where
+		AssetId: Codec,
+		BlockNumber: Codec,
+	{
+		/// Provides the price of `asset1` denominated in `asset2`, averaged over at least the last
+		/// `window` blocks.
+		///
+		/// See [`PriceOracle::time_weighted_average_price`].
+		fn time_weighted_average_price(
+			asset1: AssetId,
+			asset2: AssetId,
+			window: BlockNumber,
+		) -> Option<FixedU128>;
+	}
 }
 
 sp_core::generate_feature_enabled_macro!(runtime_benchmarks_enabled, feature = "runtime-benchmarks", $);
@@ -18,6 +18,10 @@
 //! Traits and implementations for swap between the various asset classes.
 
 use super::*;
+use codec::Encode;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_arithmetic::FixedPointNumber;
+use sp_runtime::traits::UniqueSaturatedInto;
 
 /// Trait for providing methods to swap between the various asset classes.
 pub trait Swap<AccountId> {
@@ -143,6 +147,30 @@ pub trait QuotePrice {
 	) -> Option<Self::Balance>;
 }
 
+/// Trait providing time-weighted average prices (TWAP) of asset pairs.
+///
+/// Unlike the spot prices of [`QuotePrice`], an average price over a window of blocks can not be
+/// moved considerably by swaps made within a single block, which makes it suitable as a price feed
+/// for other pallets.
+pub trait PriceOracle {
+	/// Type representing the kind of assets for which the price is being queried.
+	type AssetKind;
+	/// The unit in which the window of the average is measured.
+	type Moment;
+
+	/// Returns the price of `asset1` denominated in `asset2`, averaged over at least the last
+	/// `window`.
+	///
+	/// The price of every block is the price which held at its beginning. If the pool does not
+	/// exist, has no liquidity or the price history does not cover the window, `None` is
+	/// returned.
+	fn time_weighted_average_price(
+		asset1: Self::AssetKind,
+		asset2: Self::AssetKind,
+		window: Self::Moment,
+	) -> Option<FixedU128>;
+}
+
 impl<T: Config> Swap<T::AccountId> for Pallet<T> {
 	type Balance = T::Balance;
 	type AssetKind = T::AssetKind;
@@ -262,3 +290,148 @@ impl<T: Config> QuotePrice for Pallet<T> {
 		Self::quote_price_tokens_for_exact_tokens(asset1, asset2, amount, include_fee)
 	}
 }
+
+impl<T: Config> PriceOracle for Pallet<T> {
+	type AssetKind = T::AssetKind;
+	type Moment = BlockNumberFor<T>;
+
+	fn time_weighted_average_price(
+		asset1: Self::AssetKind,
+		asset2: Self::AssetKind,
+		window: Self::Moment,
+	) -> Option<FixedU128> {
+		Self::time_weighted_average_price(asset1, asset2, window)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns the price of `asset1` denominated in `asset2`, averaged over at least the last
+	/// `window` blocks.
+	///
+	/// See [`PriceOracle::time_weighted_average_price`].
+	pub fn time_weighted_average_price(
+		asset1: T::AssetKind,
+		asset2: T::AssetKind,
+		window: BlockNumberFor<T>,
+	) -> Option<FixedU128> {
+		let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).ok()?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let since = now.checked_sub(&window)?;
+
+		let (reserve1, reserve2) = Self::get_reserves(asset1.clone(), asset2.clone()).ok()?;
+		let prices = Self::ordered_prices(&asset1, &asset2, reserve1, reserve2)?;
+		let current = PriceCumulatives::<T>::get(&pool_id)?.accrued(prices, now);
+		let observed = PriceObservations::<T>::get(&pool_id)
+			.into_iter()
+			.rev()
+			.find(|observation| observation.block <= since)?;
+
+		let elapsed: u128 = now.saturating_sub(observed.block).unique_saturated_into();
+		if elapsed.is_zero() {
+			return None
+		}
+		let (current, observed) = if Self::is_price_order(&asset1, &asset2) {
+			(current.price1_cumulative, observed.price1_cumulative)
+		} else {
+			(current.price2_cumulative, observed.price2_cumulative)
+		};
+		let average = current.overflowing_sub(observed).0 / U256::from(elapsed);
+		u128::try_from(average).ok().map(FixedU128::from_inner)
+	}
+
+	/// Accrue the prices of `asset1` and `asset2` which held since the cumulative prices of their
+	/// pool were last updated.
+	///
+	/// Must be called before the reserves of the pool change within a block.
+	pub(crate) fn update_price_cumulative_of_pair(asset1: &T::AssetKind, asset2: &T::AssetKind) {
+		let (Ok(pool_id), Ok(pool_account)) =
+			(T::PoolLocator::pool_id(asset1, asset2), T::PoolLocator::pool_address(asset1, asset2))
+		else {
+			return
+		};
+		let reserve1 = Self::get_balance(&pool_account, asset1.clone());
+		let reserve2 = Self::get_balance(&pool_account, asset2.clone());
+		Self::update_price_cumulative(&pool_id, asset1, asset2, reserve1, reserve2);
+	}
+
+	/// Accrue the prices implied by `reserve1` and `reserve2`, the reserves of `asset1` and
+	/// `asset2` which held since the cumulative prices of the pool `pool_id` were last updated.
+	///
+	/// Only the first call within a block has an effect, so that the price of a block can not be
+	/// manipulated by the swaps made within it. If the pool has no liquidity yet, tracking starts
+	/// from the current block without accruing any price.
+	pub(crate) fn update_price_cumulative(
+		pool_id: &T::PoolId,
+		asset1: &T::AssetKind,
+		asset2: &T::AssetKind,
+		reserve1: T::Balance,
+		reserve2: T::Balance,
+	) {
+		let now = frame_system::Pallet::<T>::block_number();
+		let maybe_prices = Self::ordered_prices(asset1, asset2, reserve1, reserve2);
+		let cumulative = match (PriceCumulatives::<T>::get(pool_id), maybe_prices) {
+			(Some(cumulative), _) if cumulative.block >= now => return,
+			(Some(cumulative), Some(prices)) => cumulative.accrued(prices, now),
+			(Some(cumulative), None) => PriceCumulative { block: now, ..cumulative },
+			(None, _) => PriceCumulative { block: now, ..Default::default() },
+		};
+		PriceCumulatives::<T>::insert(pool_id, cumulative);
+
+		PriceObservations::<T>::mutate(pool_id, |observations| {
+			let due = match observations.last() {
+				Some(last) => now >= last.block.saturating_add(T::PriceObservationPeriod::get()),
+				None => true,
+			};
+			if !due {
+				return
+			}
+			if observations.len() as u32 >= T::MaxPriceObservations::get() &&
+				!observations.is_empty()
+			{
+				observations.remove(0);
+			}
+			// can only fail if `MaxPriceObservations` is zero.
+			let _ = observations.try_push(cumulative);
+		});
+	}
+
+	/// Whether `asset1` is the first asset of its pool in the order of its [`PriceCumulative`].
+	fn is_price_order(asset1: &T::AssetKind, asset2: &T::AssetKind) -> bool {
+		asset1.encode() <= asset2.encode()
+	}
+
+	/// Returns the prices of the assets of a pool in the order of its [`PriceCumulative`], each
+	/// denominated in the other asset, or `None` if the pool has no liquidity.
+	///
+	/// The prices of a [`PoolKind::StableSwap`] pool are its marginal prices along the stable-swap
+	/// curve rather than the ratio of its reserves.
+	fn ordered_prices(
+		asset1: &T::AssetKind,
+		asset2: &T::AssetKind,
+		reserve1: T::Balance,
+		reserve2: T::Balance,
+	) -> Option<(FixedU128, FixedU128)> {
+		if reserve1.is_zero() || reserve2.is_zero() {
+			return None
+		}
+		let (reserve1, reserve2) = if Self::is_price_order(asset1, asset2) {
+			(reserve1, reserve2)
+		} else {
+			(reserve2, reserve1)
+		};
+		let (reserve1, reserve2) = match Self::pool_kind(asset1, asset2) {
+			PoolKind::ConstantProduct => (reserve1, reserve2),
+			PoolKind::StableSwap { amplification } => {
+				let (numerator, denominator) =
+					Self::stable_swap_marginal_price(&reserve1, &reserve2, amplification).ok()?;
+				(denominator, numerator)
+			},
+		};
+		let reserve1: u128 = reserve1.unique_saturated_into();
+		let reserve2: u128 = reserve2.unique_saturated_into();
+		Some((
+			FixedU128::saturating_from_rational(reserve2, reserve1),
+			FixedU128::saturating_from_rational(reserve1, reserve2),
+		))
+	}
+}
@@ -27,7 +27,7 @@ use frame_support::{
 		Get,
 	},
 };
-use sp_arithmetic::Permill;
+use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
 use sp_runtime::{DispatchError, TokenError};
 
 fn events() -> Vec<Event<Test>> {
@@ -2493,3 +2493,354 @@ fn swap_credit_invalid_path() {
 		assert_eq!(error, (expected_credit_in, Error::<Test>::InvalidPath.into()));
 	});
 }
+
+fn setup_price_oracle_pool() -> (NativeOrWithId<u32>, NativeOrWithId<u32>) {
+	let user = 1;
+	let token_1 = NativeOrWithId::Native;
+	let token_2 = NativeOrWithId::WithId(2);
+
+	create_tokens(user, vec![token_2.clone()]);
+	assert_ok!(AssetConversion::create_pool(
+		RuntimeOrigin::signed(user),
+		Box::new(token_1.clone()),
+		Box::new(token_2.clone())
+	));
+
+	let ed = get_native_ed();
+	assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 100000 + ed));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 10000));
+
+	assert_ok!(AssetConversion::add_liquidity(
+		RuntimeOrigin::signed(user),
+		Box::new(token_1.clone()),
+		Box::new(token_2.clone()),
+		10000,
+		200,
+		1,
+		1,
+		user,
+	));
+
+	(token_1, token_2)
+}
+
+fn spot_price(asset1: NativeOrWithId<u32>, asset2: NativeOrWithId<u32>) -> FixedU128 {
+	let (reserve1, reserve2) = AssetConversion::get_reserves(asset1, asset2).unwrap();
+	FixedU128::saturating_from_rational(reserve2, reserve1)
+}
+
+#[test]
+fn time_weighted_average_price_works() {
+	new_test_ext().execute_with(|| {
+		let (token_1, token_2) = setup_price_oracle_pool();
+		let twap = |asset1: &NativeOrWithId<u32>, asset2: &NativeOrWithId<u32>, window| {
+			AssetConversion::time_weighted_average_price(asset1.clone(), asset2.clone(), window)
+		};
+
+		System::set_block_number(11);
+		assert_eq!(twap(&token_2, &token_1, 10), Some(FixedU128::from_u32(50)));
+		assert_eq!(twap(&token_1, &token_2, 10), Some(FixedU128::from_rational(200, 10000)));
+
+		let price_before = spot_price(token_2.clone(), token_1.clone());
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(1),
+			bvec![token_2.clone(), token_1.clone()],
+			100,
+			1,
+			1,
+			false,
+		));
+		let price_after = spot_price(token_2.clone(), token_1.clone());
+		assert!(price_after < price_before);
+
+		// a swap does not change the average price of the block it was made in.
+		assert_eq!(twap(&token_2, &token_1, 10), Some(FixedU128::from_u32(50)));
+
+		System::set_block_number(21);
+		assert_eq!(twap(&token_2, &token_1, 10), Some(price_after));
+		assert_eq!(
+			twap(&token_2, &token_1, 20),
+			Some(FixedU128::from_inner((price_before.into_inner() + price_after.into_inner()) / 2))
+		);
+	});
+}
+
+#[test]
+fn time_weighted_average_price_requires_history() {
+	new_test_ext().execute_with(|| {
+		let (token_1, token_2) = setup_price_oracle_pool();
+		let token_3 = NativeOrWithId::WithId(3);
+		let twap = |window| {
+			AssetConversion::time_weighted_average_price(token_2.clone(), token_1.clone(), window)
+		};
+
+		// no pool.
+		System::set_block_number(11);
+		assert_eq!(
+			AssetConversion::time_weighted_average_price(token_1.clone(), token_3, 10),
+			None
+		);
+
+		// the window reaches back before the pool was created.
+		System::set_block_number(5);
+		assert_eq!(twap(10), None);
+
+		// only `MaxPriceObservations` observations are kept, at most one per
+		// `PriceObservationPeriod`.
+		for block in [6, 8, 11, 16] {
+			System::set_block_number(block);
+			assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+				RuntimeOrigin::signed(1),
+				bvec![token_2.clone(), token_1.clone()],
+				10,
+				1,
+				1,
+				false,
+			));
+		}
+		let observed_blocks = |pool_id| {
+			PriceObservations::<Test>::get(pool_id)
+				.into_iter()
+				.map(|o| o.block)
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(observed_blocks((token_1.clone(), token_2.clone())), vec![6, 11, 16]);
+		assert_eq!(
+			PriceCumulatives::<Test>::get((token_1.clone(), token_2.clone())).unwrap().block,
+			16
+		);
+
+		System::set_block_number(20);
+		assert_eq!(twap(19), None);
+		assert!(twap(14).is_some());
+	});
+}
+
+fn setup_pools_of_kinds(
+	liquidity: u128,
+) -> (NativeOrWithId<u32>, NativeOrWithId<u32>, NativeOrWithId<u32>) {
+	let user = 1;
+	let token_1 = NativeOrWithId::Native;
+	let token_2 = NativeOrWithId::WithId(2);
+	let token_3 = NativeOrWithId::WithId(3);
+
+	create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+	assert_ok!(AssetConversion::create_pool(
+		RuntimeOrigin::signed(user),
+		Box::new(token_1.clone()),
+		Box::new(token_2.clone())
+	));
+	assert_ok!(AssetConversion::create_pool_with_kind(
+		RuntimeOrigin::signed(user),
+		Box::new(token_1.clone()),
+		Box::new(token_3.clone()),
+		PoolKind::StableSwap { amplification: 100 },
+	));
+
+	let ed = get_native_ed();
+	assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 4 * liquidity + ed));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 2 * liquidity));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, 2 * liquidity));
+
+	for token in [&token_2, &token_3] {
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token.clone()),
+			liquidity,
+			liquidity,
+			1,
+			1,
+			user,
+		));
+	}
+
+	(token_1, token_2, token_3)
+}
+
+#[test]
+fn create_pool_with_kind_works() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let pool_id = (token_1.clone(), token_2.clone());
+		create_tokens(user, vec![token_2.clone()]);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 1000));
+
+		for amplification in [0, MAX_AMPLIFICATION + 1] {
+			assert_noop!(
+				AssetConversion::create_pool_with_kind(
+					RuntimeOrigin::signed(user),
+					Box::new(token_1.clone()),
+					Box::new(token_2.clone()),
+					PoolKind::StableSwap { amplification },
+				),
+				Error::<Test>::InvalidPoolKind
+			);
+		}
+
+		let kind = PoolKind::StableSwap { amplification: 100 };
+		assert_ok!(AssetConversion::create_pool_with_kind(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_1.clone()),
+			kind,
+		));
+		assert_eq!(pools(), vec![pool_id.clone()]);
+		assert_eq!(PoolKinds::<Test>::get(&pool_id), kind);
+		assert_eq!(AssetConversion::pool_kind(&token_1, &token_2), kind);
+		assert_eq!(
+			AssetConversion::pool_kind(&token_1, &NativeOrWithId::WithId(3)),
+			PoolKind::ConstantProduct
+		);
+
+		assert_noop!(
+			AssetConversion::create_pool(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone())
+			),
+			Error::<Test>::PoolExists
+		);
+	});
+}
+
+#[test]
+fn stable_swap_pool_gives_better_rates_near_parity() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let liquidity = 100_000;
+		let (token_1, token_2, token_3) = setup_pools_of_kinds(liquidity);
+		let amount_in = 10_000;
+
+		let constant_product_out = AssetConversion::quote_price_exact_tokens_for_tokens(
+			token_1.clone(),
+			token_2.clone(),
+			amount_in,
+			true,
+		)
+		.unwrap();
+		let stable_swap_out = AssetConversion::quote_price_exact_tokens_for_tokens(
+			token_1.clone(),
+			token_3.clone(),
+			amount_in,
+			true,
+		)
+		.unwrap();
+		assert_eq!(
+			constant_product_out,
+			AssetConversion::get_amount_out(&amount_in, &liquidity, &liquidity).unwrap()
+		);
+		assert!(constant_product_out < 9_100);
+		// the fee is 0.3%, so at most 9970 can be received.
+		assert!(stable_swap_out > 9_900 && stable_swap_out < 9_970);
+
+		let before = balance(user, token_3.clone());
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_3.clone()],
+			amount_in,
+			stable_swap_out,
+			user,
+			false,
+		));
+		assert_eq!(balance(user, token_3.clone()), before + stable_swap_out);
+
+		// buying back the exact amount costs at least what was received for it.
+		let amount_in = AssetConversion::quote_price_tokens_for_exact_tokens(
+			token_3.clone(),
+			token_1.clone(),
+			amount_in,
+			true,
+		)
+		.unwrap();
+		assert!(amount_in > stable_swap_out);
+		let before = balance(user, token_1.clone());
+		assert_ok!(AssetConversion::swap_tokens_for_exact_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_3.clone(), token_1.clone()],
+			10_000,
+			amount_in,
+			user,
+			false,
+		));
+		assert_eq!(balance(user, token_1.clone()), before + 10_000);
+	});
+}
+
+#[test]
+fn swaps_route_through_pools_of_different_kinds() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let liquidity = 100_000;
+		let (token_1, token_2, token_3) = setup_pools_of_kinds(liquidity);
+		let amount_in = 1_000;
+
+		let via_native = AssetConversion::get_amount_out_for_kind(
+			&PoolKind::StableSwap { amplification: 100 },
+			&amount_in,
+			&liquidity,
+			&liquidity,
+		)
+		.unwrap();
+		let expected_out =
+			AssetConversion::get_amount_out(&via_native, &liquidity, &liquidity).unwrap();
+
+		let before = balance(user, token_2.clone());
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_3.clone(), token_1.clone(), token_2.clone()],
+			amount_in,
+			1,
+			user,
+			false,
+		));
+		assert_eq!(balance(user, token_2.clone()), before + expected_out);
+		assert_eq!(
+			events().last(),
+			Some(&Event::<Test>::SwapExecuted {
+				who: user,
+				send_to: user,
+				amount_in,
+				amount_out: expected_out,
+				path: vec![(token_3, amount_in), (token_1, via_native), (token_2, expected_out)],
+			})
+		);
+	});
+}
+
+#[test]
+fn time_weighted_average_price_follows_stable_swap_curve() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let (token_1, _, token_3) = setup_pools_of_kinds(100_000);
+		let twap = |asset1: &NativeOrWithId<u32>, asset2: &NativeOrWithId<u32>, window| {
+			AssetConversion::time_weighted_average_price(asset1.clone(), asset2.clone(), window)
+		};
+
+		System::set_block_number(6);
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_3.clone()],
+			10_000,
+			1,
+			user,
+			false,
+		));
+		let (reserve1, reserve3) =
+			AssetConversion::get_reserves(token_1.clone(), token_3.clone()).unwrap();
+		assert!(FixedU128::saturating_from_rational(reserve1, reserve3) > FixedU128::from_u32(1));
+
+		System::set_block_number(16);
+		let (numerator, denominator) =
+			AssetConversion::stable_swap_marginal_price(&reserve3, &reserve1, 100).unwrap();
+		let price = twap(&token_3, &token_1, 10).unwrap();
+		assert_eq!(price, FixedU128::saturating_from_rational(numerator, denominator));
+		// the pool still prices close to parity, unlike the ratio of its reserves.
+		assert!(price > FixedU128::from_u32(1));
+		assert!(price < FixedU128::from_rational(101, 100));
+		assert!(price < FixedU128::saturating_from_rational(reserve1, reserve3));
+		assert!(twap(&token_1, &token_3, 10).unwrap() < FixedU128::from_u32(1));
+	});
+}
@@ -16,10 +16,14 @@
 // limitations under the License.
 
 use super::*;
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use core::marker::PhantomData;
 use scale_info::TypeInfo;
-use sp_runtime::traits::TryConvert;
+use sp_arithmetic::FixedPointNumber;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, TryConvert, UniqueSaturatedInto},
+	RuntimeDebug,
+};
 
 /// Represents a swap path with associated asset amounts indicating how much of the asset needs to
 /// be deposited to get the following asset's amount withdrawn (this is inclusive of fees).
@@ -41,6 +45,88 @@ pub struct PoolInfo<PoolAssetId> {
 	pub lp_token: PoolAssetId,
 }
 
+/// The maximum amplification coefficient of a [`PoolKind::StableSwap`] pool.
+pub const MAX_AMPLIFICATION: u32 = 1_000_000;
+
+/// The maximum number of iterations of Newton's method when computing stable-swap amounts.
+pub(crate) const STABLE_SWAP_MAX_ITERATIONS: u32 = 255;
+
+/// The curve along which a pool prices its assets.
+#[derive(
+	Decode,
+	DecodeWithMemTracking,
+	Encode,
+	Clone,
+	Copy,
+	Default,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub enum PoolKind {
+	/// Constant-product pool following `x * y = k`, as in Uniswap V2.
+	#[default]
+	ConstantProduct,
+	/// Stable-swap pool following the StableSwap invariant of Curve, which offers much deeper
+	/// liquidity than a constant-product pool for assets of (nearly) the same value.
+	StableSwap {
+		/// The amplification coefficient `A`. The higher it is, the closer the pool prices
+		/// around a 1:1 ratio of its reserves. Must be within `1..=MAX_AMPLIFICATION`.
+		amplification: u32,
+	},
+}
+
+impl PoolKind {
+	/// Whether the parameters of the pool kind are within bounds.
+	pub fn is_valid(&self) -> bool {
+		match self {
+			PoolKind::ConstantProduct => true,
+			PoolKind::StableSwap { amplification } =>
+				(1..=MAX_AMPLIFICATION).contains(amplification),
+		}
+	}
+}
+
+/// Cumulative prices of a pool, from which its time-weighted average prices are derived.
+///
+/// The assets of a pool are ordered by their SCALE encoding. `price1_cumulative` accumulates the
+/// price of the first asset denominated in the second one, `price2_cumulative` the inverse.
+///
+/// Each accumulator is the sum over all blocks of the raw [`FixedU128`] price which held at the
+/// beginning of the block. Accumulators wrap around on overflow, since only the difference
+/// between two of them is meaningful.
+#[derive(
+	Decode, Encode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub struct PriceCumulative<BlockNumber> {
+	/// The accumulated price of the first asset of the pool.
+	pub price1_cumulative: U256,
+	/// The accumulated price of the second asset of the pool.
+	pub price2_cumulative: U256,
+	/// The block up to which the prices have been accumulated.
+	pub block: BlockNumber,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> PriceCumulative<BlockNumber> {
+	/// Returns the accumulators after `prices` held from [`Self::block`] until `now`.
+	pub fn accrued(&self, prices: (FixedU128, FixedU128), now: BlockNumber) -> Self {
+		let elapsed: u128 = now.saturating_sub(self.block).unique_saturated_into();
+		let elapsed = U256::from(elapsed);
+		let accrue = |cumulative: U256, price: FixedU128| {
+			cumulative
+				.overflowing_add(U256::from(price.into_inner()).overflowing_mul(elapsed).0)
+				.0
+		};
+		Self {
+			price1_cumulative: accrue(self.price1_cumulative, prices.0),
+			price2_cumulative: accrue(self.price2_cumulative, prices.1),
+			block: now.max(self.block),
+		}
+	}
+}
+
 /// Provides means to resolve the `PoolId` and `AccountId` from a pair of assets.
 ///
 /// Resulting `PoolId` remains consistent whether the asset pair is presented as (asset1, asset2)
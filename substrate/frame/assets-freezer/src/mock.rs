@@ -103,6 +103,7 @@ impl pallet_assets::Config for Test {
 	type Extra = ();
 	type RemoveItemsLimit = ConstU32<10>;
 	type CallbackHandle = ();
+	type TransferRestrictions = ();
 	type Currency = Balances;
 	type Holder = ();
 	type Freezer = AssetsFreezer;
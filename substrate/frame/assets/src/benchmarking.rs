@@ -131,6 +131,22 @@ fn add_approvals<T: Config<I>, I: 'static>(minter: T::AccountId, n: u32) {
 	}
 }
 
+// Restrict the default asset to `allowed`, so that transfers have to check its allowlist.
+fn restrict_asset<T: Config<I>, I: 'static>(owner: T::AccountId, allowed: &[T::AccountId]) {
+	let asset_id = default_asset_id::<T, I>();
+	let origin = SystemOrigin::Signed(owner);
+	assert!(Assets::<T, I>::set_restricted(origin.clone().into(), asset_id.clone(), true).is_ok());
+	for who in allowed {
+		let who_lookup = T::Lookup::unlookup(who.clone());
+		assert!(Assets::<T, I>::add_to_allowlist(
+			origin.clone().into(),
+			asset_id.clone(),
+			who_lookup
+		)
+		.is_ok());
+	}
+}
+
 fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
@@ -225,6 +241,7 @@ benchmarks_instance_pallet! {
 
 	mint {
 		let (asset_id, caller, caller_lookup) = create_default_asset::<T, I>(true);
+		restrict_asset::<T, I>(caller.clone(), &[caller.clone()]);
 		let amount = T::Balance::from(100u32);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), caller_lookup, amount)
 	verify {
@@ -244,6 +261,7 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		restrict_asset::<T, I>(caller.clone(), &[caller.clone(), target.clone()]);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), target_lookup, amount)
 	verify {
 		assert_last_event::<T, I>(Event::Transferred { asset_id: asset_id.into(), from: caller, to: target, amount }.into());
@@ -255,6 +273,7 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, mint_amount);
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		restrict_asset::<T, I>(caller.clone(), &[caller.clone(), target.clone()]);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), target_lookup, amount)
 	verify {
 		assert!(frame_system::Pallet::<T>::account_exists(&caller));
@@ -266,6 +285,7 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		restrict_asset::<T, I>(caller.clone(), &[target.clone()]);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), caller_lookup, target_lookup, amount)
 	verify {
 		assert_last_event::<T, I>(
@@ -444,6 +464,7 @@ benchmarks_instance_pallet! {
 
 		let dest: T::AccountId = account("dest", 0, SEED);
 		let dest_lookup = T::Lookup::unlookup(dest.clone());
+		restrict_asset::<T, I>(owner.clone(), &[owner.clone(), dest.clone()]);
 	}: _(SystemOrigin::Signed(delegate.clone()), asset_id.clone(), owner_lookup, dest_lookup, amount)
 	verify {
 		assert!(T::Currency::reserved_balance(&owner).is_zero());
@@ -559,10 +580,44 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		restrict_asset::<T, I>(caller.clone(), &[caller.clone(), target.clone()]);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), target_lookup, false)
 	verify {
 		assert_last_event::<T, I>(Event::Transferred { asset_id: asset_id.into(), from: caller, to: target, amount }.into());
 	}
 
+	set_restricted {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(false);
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), true)
+	verify {
+		assert!(RestrictedAssets::<T, I>::contains_key(asset_id.clone().into()));
+		assert_last_event::<T, I>(Event::RestrictionSet { asset_id: asset_id.into(), restricted: true }.into());
+	}
+
+	add_to_allowlist {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(false);
+		let who: T::AccountId = account("who", 0, SEED);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), who_lookup)
+	verify {
+		assert!(Allowlist::<T, I>::contains_key(asset_id.clone().into(), &who));
+		assert_last_event::<T, I>(Event::AddedToAllowlist { asset_id: asset_id.into(), who }.into());
+	}
+
+	remove_from_allowlist {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(false);
+		let who: T::AccountId = account("who", 0, SEED);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+		Assets::<T, I>::add_to_allowlist(
+			SystemOrigin::Signed(caller.clone()).into(),
+			asset_id.clone(),
+			who_lookup.clone(),
+		)?;
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), who_lookup)
+	verify {
+		assert!(!Allowlist::<T, I>::contains_key(asset_id.clone().into(), &who));
+		assert_last_event::<T, I>(Event::RemovedFromAllowlist { asset_id: asset_id.into(), who }.into());
+	}
+
 	impl_benchmark_test_suite!(Assets, crate::mock::new_test_ext(), crate::mock::Test)
 }
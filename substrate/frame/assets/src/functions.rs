@@ -149,6 +149,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		if increase_supply && details.supply.checked_add(&amount).is_none() {
 			return DepositConsequence::Overflow
 		}
+		if !Self::is_allowed(&id, who) ||
+			!T::TransferRestrictions::can_receive(id.clone(), who, amount)
+		{
+			return DepositConsequence::Blocked
+		}
 		if let Some(account) = Account::<T, I>::get(id, who) {
 			if account.status.is_blocked() {
 				return DepositConsequence::Blocked
@@ -171,12 +176,21 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		DepositConsequence::Success
 	}
 
+	/// Returns `true` if `who` may hold and move asset `id`, that is if the asset is not restricted
+	/// or `who` is in its allowlist.
+	pub fn is_allowed(id: &T::AssetId, who: &T::AccountId) -> bool {
+		!RestrictedAssets::<T, I>::contains_key(id) || Allowlist::<T, I>::contains_key(id, who)
+	}
+
 	/// Return the consequence of a withdraw.
+	///
+	/// The allowlist of a restricted asset is only checked if `respect_allowlist` is `true`.
 	pub(super) fn can_decrease(
 		id: T::AssetId,
 		who: &T::AccountId,
 		amount: T::Balance,
 		keep_alive: bool,
+		respect_allowlist: bool,
 	) -> WithdrawConsequence<T::Balance> {
 		use WithdrawConsequence::*;
 		let details = match Asset::<T, I>::get(&id) {
@@ -195,6 +209,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		if amount.is_zero() {
 			return Success
 		}
+		if (respect_allowlist && !Self::is_allowed(&id, who)) ||
+			!T::TransferRestrictions::can_send(id.clone(), who, amount)
+		{
+			return Frozen
+		}
 		let account = match Account::<T, I>::get(&id, who) {
 			Some(a) => a,
 			None => return BalanceLow,
@@ -241,17 +260,20 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	}
 
 	// Maximum `amount` that can be passed into `can_withdraw` to result in a `WithdrawConsequence`
-	// of `Success`.
+	// of `Success`. The allowlist of a restricted asset is only checked if `respect_allowlist` is
+	// `true`.
 	pub(super) fn reducible_balance(
 		id: T::AssetId,
 		who: &T::AccountId,
 		keep_alive: bool,
+		respect_allowlist: bool,
 	) -> Result<T::Balance, DispatchError> {
 		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 
 		let account = Account::<T, I>::get(&id, who).ok_or(Error::<T, I>::NoAccount)?;
 		ensure!(!account.status.is_frozen(), Error::<T, I>::Frozen);
+		ensure!(!respect_allowlist || Self::is_allowed(&id, who), Error::<T, I>::Restricted);
 
 		let untouchable = match (
 			T::Holder::balance_on_hold(id.clone(), who),
@@ -280,6 +302,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// - `keep_alive`: Require that `target` must stay alive.
 	/// - `respect_freezer`: Respect any freezes on the account or token (or not).
 	/// - `best_effort`: The debit amount may be less than `amount`.
+	/// - `respect_allowlist`: Require that `target` is in the allowlist of a restricted asset (or
+	///   not, for the admin to recover funds).
 	///
 	/// On success, the amount which should be debited (this will always be at least `amount` unless
 	/// `best_effort` is `true`) together with an optional value indicating the argument which must
@@ -291,11 +315,18 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		target: &T::AccountId,
 		amount: T::Balance,
 		f: DebitFlags,
+		respect_allowlist: bool,
 	) -> Result<T::Balance, DispatchError> {
-		let actual = Self::reducible_balance(id.clone(), target, f.keep_alive)?.min(amount);
+		let actual = Self::reducible_balance(id.clone(), target, f.keep_alive, respect_allowlist)?
+			.min(amount);
 		ensure!(f.best_effort || actual >= amount, Error::<T, I>::BalanceLow);
 
-		let conseq = Self::can_decrease(id, target, actual, f.keep_alive);
+		ensure!(
+			T::TransferRestrictions::can_send(id.clone(), target, actual),
+			Error::<T, I>::Restricted
+		);
+
+		let conseq = Self::can_decrease(id, target, actual, f.keep_alive, respect_allowlist);
 		let actual = match conseq.into_result(f.keep_alive) {
 			Ok(dust) => actual.saturating_add(dust), //< guaranteed by reducible_balance
 			Err(e) => {
@@ -521,7 +552,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Reduces asset `id` balance of `target` by `amount`. Flags `f` can be given to alter whether
 	/// it attempts a `best_effort` or makes sure to `keep_alive` the account.
 	///
-	/// This alters the registered supply of the asset and emits an event.
+	/// This alters the registered supply of the asset and emits an event. If the burn is made by
+	/// the admin, `target` does not need to be in the allowlist of a restricted asset.
 	///
 	/// Will return an error and do nothing or will decrease the amount and return the amount
 	/// reduced by.
@@ -538,17 +570,25 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Error::<T, I>::IncorrectStatus
 		);
 
-		let actual = Self::decrease_balance(id.clone(), target, amount, f, |actual, details| {
-			// Check admin rights.
-			if let Some(check_admin) = maybe_check_admin {
-				ensure!(check_admin == details.admin, Error::<T, I>::NoPermission);
-			}
+		let respect_allowlist = maybe_check_admin.is_none();
+		let actual = Self::decrease_balance(
+			id.clone(),
+			target,
+			amount,
+			f,
+			respect_allowlist,
+			|actual, details| {
+				// Check admin rights.
+				if let Some(check_admin) = maybe_check_admin {
+					ensure!(check_admin == details.admin, Error::<T, I>::NoPermission);
+				}
 
-			debug_assert!(details.supply >= actual, "checked in prep; qed");
-			details.supply = details.supply.saturating_sub(actual);
+				debug_assert!(details.supply >= actual, "checked in prep; qed");
+				details.supply = details.supply.saturating_sub(actual);
 
-			Ok(())
-		})?;
+				Ok(())
+			},
+		)?;
 		Self::deposit_event(Event::Burned { asset_id: id, owner: target.clone(), balance: actual });
 		Ok(actual)
 	}
@@ -566,6 +606,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		target: &T::AccountId,
 		amount: T::Balance,
 		f: DebitFlags,
+		respect_allowlist: bool,
 		check: impl FnOnce(
 			T::Balance,
 			&mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
@@ -578,7 +619,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 
-		let actual = Self::prep_debit(id.clone(), target, amount, f)?;
+		let actual = Self::prep_debit(id.clone(), target, amount, f, respect_allowlist)?;
 		let mut target_died: Option<DeadConsequence> = None;
 
 		Asset::<T, I>::try_mutate(&id, |maybe_details| -> DispatchResult {
@@ -621,7 +662,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// `f`.
 	///
 	/// Will fail if the amount transferred is so small that it cannot create the destination due
-	/// to minimum balance requirements.
+	/// to minimum balance requirements. If the transfer is forced by the admin, `source` does not
+	/// need to be in the allowlist of a restricted asset.
 	pub(super) fn do_transfer(
 		id: T::AssetId,
 		source: &T::AccountId,
@@ -657,7 +699,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 
 		// Figure out the debit and credit, together with side-effects.
-		let debit = Self::prep_debit(id.clone(), source, amount, f.into())?;
+		let respect_allowlist = maybe_need_admin.is_none();
+		let debit = Self::prep_debit(id.clone(), source, amount, f.into(), respect_allowlist)?;
 		let (credit, maybe_burn) = Self::prep_credit(id.clone(), dest, amount, debit, f.burn_dust)?;
 
 		let mut source_account =
@@ -807,8 +850,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 	/// Destroy accounts associated with a given asset up to the max (T::RemoveItemsLimit).
 	///
+	/// Any budget left is spent on clearing the [`Allowlist`] of the asset.
+	///
 	/// Each call emits the `Event::DestroyedAccounts` event.
-	/// Returns the number of destroyed accounts.
+	/// Returns the number of destroyed accounts and cleared allowlist entries.
 	pub(super) fn do_destroy_accounts(
 		id: T::AssetId,
 		max_items: u32,
@@ -851,12 +896,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			T::Holder::died(id.clone(), &who);
 		}
 
+		// Spend whatever is left of the budget on clearing the allowlist of the asset.
+		let budget = max_items.saturating_sub(dead_accounts.len() as u32);
+		let cleared = Allowlist::<T, I>::drain_prefix(&id).take(budget as usize).count() as u32;
+
 		Self::deposit_event(Event::AccountsDestroyed {
 			asset_id: id,
 			accounts_destroyed: dead_accounts.len() as u32,
 			accounts_remaining: remaining_accounts as u32,
 		});
-		Ok(dead_accounts.len() as u32)
+		Ok((dead_accounts.len() as u32).saturating_add(cleared))
 	}
 
 	/// Destroy approvals associated with a given asset up to the max (T::RemoveItemsLimit).
@@ -904,8 +953,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
 			ensure!(details.accounts == 0, Error::<T, I>::InUse);
 			ensure!(details.approvals == 0, Error::<T, I>::InUse);
+			ensure!(!Allowlist::<T, I>::contains_prefix(&id), Error::<T, I>::InUse);
 			ensure!(T::CallbackHandle::destroyed(&id).is_ok(), Error::<T, I>::CallbackFailed);
 
+			RestrictedAssets::<T, I>::remove(&id);
+
 			let metadata = Metadata::<T, I>::take(&id);
 			T::Currency::unreserve(
 				&details.owner,
@@ -57,7 +57,7 @@ impl<T: Config<I>, I: 'static> fungibles::Inspect<<T as SystemConfig>::AccountId
 		preservation: Preservation,
 		_: Fortitude,
 	) -> Self::Balance {
-		Pallet::<T, I>::reducible_balance(asset, who, !matches!(preservation, Expendable))
+		Pallet::<T, I>::reducible_balance(asset, who, !matches!(preservation, Expendable), true)
 			.unwrap_or(Zero::zero())
 	}
 
@@ -75,7 +75,7 @@ impl<T: Config<I>, I: 'static> fungibles::Inspect<<T as SystemConfig>::AccountId
 		who: &<T as SystemConfig>::AccountId,
 		amount: Self::Balance,
 	) -> WithdrawConsequence<Self::Balance> {
-		Pallet::<T, I>::can_decrease(asset, who, amount, false)
+		Pallet::<T, I>::can_decrease(asset, who, amount, false, true)
 	}
 
 	fn asset_exists(asset: Self::AssetId) -> bool {
@@ -170,7 +170,7 @@ impl<T: Config<I>, I: 'static> fungibles::Unbalanced<T::AccountId> for Pallet<T,
 			keep_alive: preservation != Expendable,
 			best_effort: precision == BestEffort,
 		};
-		Self::decrease_balance(asset, who, amount, f, |_, _| Ok(()))
+		Self::decrease_balance(asset, who, amount, f, true, |_, _| Ok(()))
 	}
 	fn increase_balance(
 		asset: T::AssetId,
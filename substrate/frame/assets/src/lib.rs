@@ -300,6 +300,7 @@ pub mod pallet {
 			type Holder = ();
 			type Extra = ();
 			type CallbackHandle = ();
+			type TransferRestrictions = ();
 			type WeightInfo = ();
 			#[cfg(feature = "runtime-benchmarks")]
 			type BenchmarkHelper = ();
@@ -412,6 +413,14 @@ pub mod pallet {
 		/// used to set up auto-incrementing asset IDs for this collection.
 		type CallbackHandle: AssetsCallback<Self::AssetId, Self::AccountId>;
 
+		/// A hook to enforce a custom policy on every mint, burn and transfer of an asset. This is
+		/// applied in addition to the [`Allowlist`] of assets in [`RestrictedAssets`].
+		type TransferRestrictions: TransferRestrictions<
+			Self::AssetId,
+			Self::AccountId,
+			Self::Balance,
+		>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -476,6 +485,23 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NextAssetId<T: Config<I>, I: 'static = ()> = StorageValue<_, T::AssetId, OptionQuery>;
 
+	#[pallet::storage]
+	/// Assets which may only be held and moved by the accounts in their [`Allowlist`].
+	pub type RestrictedAssets<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, (), OptionQuery>;
+
+	#[pallet::storage]
+	/// The accounts allowed to hold and move a restricted asset, managed by its Issuer.
+	pub type Allowlist<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -647,6 +673,12 @@ pub mod pallet {
 		Deposited { asset_id: T::AssetId, who: T::AccountId, amount: T::Balance },
 		/// Some assets were withdrawn from the account (e.g. for transaction fees).
 		Withdrawn { asset_id: T::AssetId, who: T::AccountId, amount: T::Balance },
+		/// Some asset `asset_id` was restricted to, or released from, its allowlist.
+		RestrictionSet { asset_id: T::AssetId, restricted: bool },
+		/// Some account `who` was added to the allowlist of an asset.
+		AddedToAllowlist { asset_id: T::AssetId, who: T::AccountId },
+		/// Some account `who` was removed from the allowlist of an asset.
+		RemovedFromAllowlist { asset_id: T::AssetId, who: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -700,6 +732,8 @@ pub mod pallet {
 		ContainsFreezes,
 		/// The asset cannot be destroyed because some accounts for this asset contain holds.
 		ContainsHolds,
+		/// The account is not allowed to hold or move the restricted asset.
+		Restricted,
 	}
 
 	#[pallet::call(weight(<T as Config<I>>::WeightInfo))]
@@ -832,7 +866,8 @@ pub mod pallet {
 		/// asset is in a `Destroying` state.
 		///
 		/// Due to weight restrictions, this function may need to be called multiple times to fully
-		/// destroy all accounts. It will destroy `RemoveItemsLimit` accounts at a time.
+		/// destroy all accounts. It will destroy `RemoveItemsLimit` accounts at a time. Any items
+		/// left over are spent on clearing the [`Allowlist`] of the asset.
 		///
 		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
 		///   asset.
@@ -1819,6 +1854,128 @@ pub mod pallet {
 			)?;
 			Ok(())
 		}
+
+		/// Restrict an asset to the accounts in its allowlist, or lift such a restriction.
+		///
+		/// While an asset is restricted, only accounts in its [`Allowlist`] may receive it (by
+		/// minting or transfer) or give it up (by burning or transfer).
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `restricted`: Whether the asset should be restricted to its allowlist.
+		///
+		/// Emits `RestrictionSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::set_restricted())]
+		pub fn set_restricted(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			restricted: bool,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+
+			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+			ensure!(origin == d.owner, Error::<T, I>::NoPermission);
+
+			if restricted {
+				RestrictedAssets::<T, I>::insert(&id, ());
+			} else {
+				RestrictedAssets::<T, I>::remove(&id);
+			}
+
+			Self::deposit_event(Event::<T, I>::RestrictionSet { asset_id: id, restricted });
+			Ok(())
+		}
+
+		/// Allow an account `who` to hold and move a restricted asset `id`.
+		///
+		/// Origin must be Signed and the sender should be the Issuer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account to be added to the allowlist.
+		///
+		/// Emits `AddedToAllowlist`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::add_to_allowlist())]
+		pub fn add_to_allowlist(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+
+			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(
+				d.status == AssetStatus::Live || d.status == AssetStatus::Frozen,
+				Error::<T, I>::IncorrectStatus
+			);
+			ensure!(origin == d.issuer, Error::<T, I>::NoPermission);
+			let who = T::Lookup::lookup(who)?;
+
+			Allowlist::<T, I>::insert(&id, &who, ());
+
+			Self::deposit_event(Event::<T, I>::AddedToAllowlist { asset_id: id, who });
+			Ok(())
+		}
+
+		/// Remove an account `who` from the allowlist of asset `id`.
+		///
+		/// If the asset is restricted, the account will no longer be able to receive or give up
+		/// any of it, though its existing balance is left untouched.
+		///
+		/// Origin must be Signed and the sender should be the Issuer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account to be removed from the allowlist.
+		///
+		/// Emits `RemovedFromAllowlist`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::remove_from_allowlist())]
+		pub fn remove_from_allowlist(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+
+			let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(
+				d.status == AssetStatus::Live || d.status == AssetStatus::Frozen,
+				Error::<T, I>::IncorrectStatus
+			);
+			ensure!(origin == d.issuer, Error::<T, I>::NoPermission);
+			let who = T::Lookup::lookup(who)?;
+
+			Allowlist::<T, I>::remove(&id, &who);
+
+			Self::deposit_event(Event::<T, I>::RemovedFromAllowlist { asset_id: id, who });
+			Ok(())
+		}
+	}
+
+	#[pallet::view_functions]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// The asset `id` balance of `who`, if the asset-account exists.
+		pub fn account_balance(id: T::AssetId, who: T::AccountId) -> Option<T::Balance> {
+			Self::maybe_balance(id, who)
+		}
+
+		/// The asset `id` balance `who` could transfer away while keeping the account alive, or
+		/// zero if the asset is not live or `who` may not send it.
+		pub fn spendable_balance(id: T::AssetId, who: T::AccountId) -> T::Balance {
+			Self::reducible_balance(id, &who, true, true).unwrap_or_default()
+		}
 	}
 
 	/// Implements [`AccountTouch`] trait.
@@ -105,6 +105,7 @@ impl Config for Test {
 	type Freezer = TestFreezer;
 	type Holder = TestHolder;
 	type CallbackHandle = (AssetsCallbackHandle, AutoIncAssetId<Test>);
+	type TransferRestrictions = TestRestrictions;
 }
 
 use std::collections::HashMap;
@@ -117,6 +118,7 @@ parameter_types! {
 	static Frozen: HashMap<(u32, u64), u64> = Default::default();
 	static OnHold: HashMap<(u32, u64), u64> = Default::default();
 	static Hooks: Vec<Hook> = Default::default();
+	static Sanctioned: Vec<(u32, u64)> = Default::default();
 }
 
 pub struct TestHolder;
@@ -142,7 +144,7 @@ pub(crate) fn set_balance_on_hold(asset: u32, who: u64, amount: u64) {
 			// Hold more funds
 			let amount = amount - amount_on_hold;
 			let f = DebitFlags { keep_alive: true, best_effort: false };
-			assert_ok!(Assets::decrease_balance(asset, &who, amount, f, |_, _| Ok(())));
+			assert_ok!(Assets::decrease_balance(asset, &who, amount, f, true, |_, _| Ok(())));
 		} else {
 			// Release funds on hold
 			let amount = amount_on_hold - amount;
@@ -190,6 +192,21 @@ pub(crate) fn clear_frozen_balance(asset: u32, who: u64) {
 	});
 }
 
+pub struct TestRestrictions;
+impl TransferRestrictions<u32, u64, u64> for TestRestrictions {
+	fn can_receive(asset: u32, who: &u64, _: u64) -> bool {
+		!Sanctioned::get().contains(&(asset, *who))
+	}
+
+	fn can_send(asset: u32, who: &u64, _: u64) -> bool {
+		!Sanctioned::get().contains(&(asset, *who))
+	}
+}
+
+pub(crate) fn set_sanctioned(asset: u32, who: u64) {
+	Sanctioned::mutate(|v| v.push((asset, who)));
+}
+
 pub(crate) fn hooks() -> Vec<Hook> {
 	Hooks::get().clone()
 }
@@ -222,6 +239,7 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 	let mut ext: sp_io::TestExternalities = storage.into();
 	// Clear thread local vars for https://github.com/paritytech/substrate/issues/10479.
 	ext.execute_with(|| take_hooks());
+	ext.execute_with(|| Sanctioned::take());
 	ext.execute_with(|| System::set_block_number(1));
 	ext
 }
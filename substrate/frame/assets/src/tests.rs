@@ -1579,13 +1579,13 @@ fn freezing_and_holds_work() {
 
 		// Can freeze up to held + min_balance without affecting reducible
 		set_frozen_balance(0, 1, 59);
-		assert_eq!(Assets::reducible_balance(0, &1, true), Ok(40));
+		assert_eq!(Assets::reducible_balance(0, &1, true, true), Ok(40));
 		set_frozen_balance(0, 1, 61);
-		assert_eq!(Assets::reducible_balance(0, &1, true), Ok(39));
+		assert_eq!(Assets::reducible_balance(0, &1, true, true), Ok(39));
 
 		// Increasing hold is not necessarily restricted by the frozen balance
 		set_balance_on_hold(0, 1, 62);
-		assert_eq!(Assets::reducible_balance(0, &1, true), Ok(28));
+		assert_eq!(Assets::reducible_balance(0, &1, true, true), Ok(28));
 
 		// Transfers are bound to the spendable amount
 		assert_noop!(
@@ -2099,7 +2099,7 @@ fn increasing_or_decreasing_destroying_asset_should_not_work() {
 		assert_eq!(Assets::can_deposit(0, &1, 10, Provenance::Extant), DepositConsequence::Success);
 		assert_eq!(Assets::can_withdraw(0, &1, 10), WithdrawConsequence::<_>::Success);
 		assert_eq!(Assets::can_increase(0, &1, 10, false), DepositConsequence::Success);
-		assert_eq!(Assets::can_decrease(0, &1, 10, false), WithdrawConsequence::<_>::Success);
+		assert_eq!(Assets::can_decrease(0, &1, 10, false, true), WithdrawConsequence::<_>::Success);
 
 		assert_ok!(Assets::start_destroy(admin_origin, 0));
 
@@ -2109,7 +2109,10 @@ fn increasing_or_decreasing_destroying_asset_should_not_work() {
 		);
 		assert_eq!(Assets::can_withdraw(0, &1, 10), WithdrawConsequence::<_>::UnknownAsset);
 		assert_eq!(Assets::can_increase(0, &1, 10, false), DepositConsequence::UnknownAsset);
-		assert_eq!(Assets::can_decrease(0, &1, 10, false), WithdrawConsequence::<_>::UnknownAsset);
+		assert_eq!(
+			Assets::can_decrease(0, &1, 10, false, true),
+			WithdrawConsequence::<_>::UnknownAsset
+		);
 	});
 }
 
@@ -2180,3 +2183,176 @@ fn asset_id_cannot_be_reused() {
 		assert!(Asset::<Test>::contains_key(7));
 	});
 }
+
+#[test]
+fn restricted_asset_only_moves_between_allowlisted_accounts() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		// Only the owner can restrict the asset, and only the issuer can manage the allowlist.
+		assert_noop!(
+			Assets::set_restricted(RuntimeOrigin::signed(2), 0, true),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Assets::set_restricted(RuntimeOrigin::signed(1), 0, true));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::RestrictionSet {
+			asset_id: 0,
+			restricted: true,
+		}));
+		assert_noop!(
+			Assets::add_to_allowlist(RuntimeOrigin::signed(2), 0, 2),
+			Error::<Test>::NoPermission
+		);
+
+		// Neither the sender nor the receiver is allowlisted.
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50),
+			Error::<Test>::Restricted
+		);
+		assert_ok!(Assets::add_to_allowlist(RuntimeOrigin::signed(1), 0, 1));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AddedToAllowlist {
+			asset_id: 0,
+			who: 1,
+		}));
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50), TokenError::Blocked);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 50), TokenError::Blocked);
+
+		assert_ok!(Assets::add_to_allowlist(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_eq!(Assets::balance(0, 2), 100);
+
+		// A removed account keeps its balance but can no longer move it.
+		assert_ok!(Assets::remove_from_allowlist(RuntimeOrigin::signed(1), 0, 2));
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(2), 0, 1, 10),
+			Error::<Test>::Restricted
+		);
+		assert_eq!(Assets::balance(0, 2), 100);
+
+		// The admin can still burn or claw back its funds, but only to an allowlisted account.
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::force_transfer(RuntimeOrigin::signed(1), 0, 2, 1, 10));
+		assert_noop!(
+			Assets::force_transfer(RuntimeOrigin::signed(1), 0, 2, 3, 10),
+			TokenError::Blocked
+		);
+		assert_eq!(Assets::balance(0, 2), 80);
+		assert_eq!(Assets::balance(0, 1), 60);
+
+		// Lifting the restriction lets anyone move the asset again.
+		assert_ok!(Assets::set_restricted(RuntimeOrigin::signed(1), 0, false));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 10));
+		assert_eq!(Assets::balance(0, 3), 10);
+	});
+}
+
+#[test]
+fn transfer_restrictions_apply_to_fungibles_impls() {
+	use frame_support::traits::fungibles::{Inspect, Mutate};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::set_restricted(RuntimeOrigin::signed(1), 0, true));
+		assert_ok!(Assets::add_to_allowlist(RuntimeOrigin::signed(1), 0, 1));
+
+		assert_eq!(
+			<Assets as Inspect<_>>::can_deposit(0, &2, 10, Provenance::Extant),
+			DepositConsequence::Blocked
+		);
+		assert_noop!(
+			<Assets as Mutate<_>>::transfer(0, &1, &2, 10, Expendable),
+			TokenError::Blocked
+		);
+		assert_noop!(<Assets as Mutate<_>>::mint_into(0, &2, 10), TokenError::Blocked);
+
+		assert_ok!(Assets::add_to_allowlist(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(<Assets as Mutate<_>>::transfer(0, &1, &2, 10, Expendable));
+		assert_ok!(<Assets as Mutate<_>>::mint_into(0, &2, 10));
+		assert_eq!(Assets::balance(0, 2), 20);
+	});
+}
+
+#[test]
+fn transfer_restrictions_hook_is_respected() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+
+		// The hook applies to assets which are not restricted to an allowlist.
+		set_sanctioned(0, 2);
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10), TokenError::Blocked);
+		assert_noop!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 10), TokenError::Blocked);
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(2), 0, 1, 10),
+			Error::<Test>::Restricted
+		);
+		assert_noop!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, 10), Error::<Test>::Restricted);
+		assert_eq!(Assets::can_decrease(0, &2, 10, false, true), WithdrawConsequence::Frozen);
+
+		// Other accounts are unaffected.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 10));
+	});
+}
+
+#[test]
+fn destroying_restricted_asset_clears_allowlist() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::set_restricted(RuntimeOrigin::signed(1), 0, true));
+		for who in 1..=3 {
+			assert_ok!(Assets::add_to_allowlist(RuntimeOrigin::signed(1), 0, who));
+		}
+
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_noop!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0), Error::<Test>::InUse);
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+
+		assert!(!RestrictedAssets::<Test>::contains_key(0));
+		assert!(!Allowlist::<Test>::contains_prefix(0));
+	});
+}
+
+#[test]
+fn destroying_restricted_asset_clears_allowlist_in_batches() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::set_restricted(RuntimeOrigin::signed(1), 0, true));
+		for who in 1..=8 {
+			assert_ok!(Assets::add_to_allowlist(RuntimeOrigin::signed(1), 0, who));
+		}
+
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		// At most `RemoveItemsLimit` entries are cleared at a time.
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Allowlist::<Test>::iter_prefix(0).count(), 3);
+		assert_noop!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0), Error::<Test>::InUse);
+
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
+		assert!(!Allowlist::<Test>::contains_prefix(0));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+	});
+}
+
+#[test]
+fn view_functions_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		assert_eq!(Assets::account_balance(0, 1), Some(100));
+		assert_eq!(Assets::account_balance(0, 2), None);
+		// The minimum balance has to be left behind.
+		assert_eq!(Assets::spendable_balance(0, 1), 90);
+		assert_eq!(Assets::spendable_balance(0, 2), 0);
+
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Assets::spendable_balance(0, 1), 0);
+	});
+}
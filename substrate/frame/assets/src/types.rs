@@ -274,6 +274,28 @@ impl<AssetId, AccountId, Balance> BalanceOnHold<AssetId, AccountId, Balance> for
 	}
 }
 
+/// Trait for enforcing a custom policy (e.g. compliance checks) on the movement of an asset.
+///
+/// This is consulted for every change of an account's balance, whether it comes from a mint, a
+/// burn or a transfer, and whether it goes through a dispatchable or the `fungibles` traits. It
+/// applies on top of the allowlist of restricted assets.
+pub trait TransferRestrictions<AssetId, AccountId, Balance> {
+	/// Return whether `who` may receive `amount` of `asset`, either minted or transferred.
+	fn can_receive(asset: AssetId, who: &AccountId, amount: Balance) -> bool;
+
+	/// Return whether `who` may give up `amount` of `asset`, either burned or transferred.
+	fn can_send(asset: AssetId, who: &AccountId, amount: Balance) -> bool;
+}
+
+impl<AssetId, AccountId, Balance> TransferRestrictions<AssetId, AccountId, Balance> for () {
+	fn can_receive(_: AssetId, _: &AccountId, _: Balance) -> bool {
+		true
+	}
+	fn can_send(_: AssetId, _: &AccountId, _: Balance) -> bool {
+		true
+	}
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub struct TransferFlags {
 	/// The debited account must stay alive at the end of the operation; an error is returned if
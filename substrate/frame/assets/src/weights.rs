@@ -105,6 +105,9 @@ pub trait WeightInfo {
 	fn refund_other() -> Weight;
 	fn block() -> Weight;
 	fn transfer_all() -> Weight;
+	fn set_restricted() -> Weight;
+	fn add_to_allowlist() -> Weight;
+	fn remove_from_allowlist() -> Weight;
 }
 
 /// Weights for `pallet_assets` using the Substrate node and recommended hardware.
@@ -206,13 +209,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:1 w:1)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:1 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `217`
+		//  Measured:  `312`
 		//  Estimated: `3675`
-		// Minimum execution time: 19_364_000 picoseconds.
-		Weight::from_parts(19_954_000, 3675)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		// Minimum execution time: 22_234_000 picoseconds.
+		Weight::from_parts(22_824_000, 3675)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -234,13 +241,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:2 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `330`
+		//  Measured:  `497`
 		//  Estimated: `6208`
-		// Minimum execution time: 40_145_000 picoseconds.
-		Weight::from_parts(40_920_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// Minimum execution time: 44_555_000 picoseconds.
+		Weight::from_parts(45_330_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -249,13 +260,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:2 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer_keep_alive() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `330`
+		//  Measured:  `497`
 		//  Estimated: `6208`
-		// Minimum execution time: 36_083_000 picoseconds.
-		Weight::from_parts(36_804_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// Minimum execution time: 40_473_000 picoseconds.
+		Weight::from_parts(41_194_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -264,13 +279,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:1 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn force_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `330`
+		//  Measured:  `425`
 		//  Estimated: `6208`
-		// Minimum execution time: 39_945_000 picoseconds.
-		Weight::from_parts(40_933_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// Minimum execution time: 42_875_000 picoseconds.
+		Weight::from_parts(43_863_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Assets::Asset` (r:1 w:0)
@@ -441,13 +460,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:2 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer_approved() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `500`
+		//  Measured:  `667`
 		//  Estimated: `6208`
-		// Minimum execution time: 59_493_000 picoseconds.
-		Weight::from_parts(60_467_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		// Minimum execution time: 63_963_000 picoseconds.
+		Weight::from_parts(64_937_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -562,15 +585,58 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:2 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer_all() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `330`
+		//  Measured:  `497`
 		//  Estimated: `6208`
-		// Minimum execution time: 49_370_000 picoseconds.
-		Weight::from_parts(50_734_000, 6208)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// Minimum execution time: 53_830_000 picoseconds.
+		Weight::from_parts(55_194_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:0 w:1)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn set_restricted() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `217`
+		//  Estimated: `3675`
+		// Minimum execution time: 9_657_000 picoseconds.
+		Weight::from_parts(9_994_000, 3675)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:0 w:1)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn add_to_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `217`
+		//  Estimated: `3675`
+		// Minimum execution time: 10_375_000 picoseconds.
+		Weight::from_parts(10_712_000, 3675)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:0 w:1)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn remove_from_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `217`
+		//  Estimated: `3675`
+		// Minimum execution time: 10_810_000 picoseconds.
+		Weight::from_parts(11_147_000, 3675)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -671,13 +737,17 @@ impl WeightInfo for () {
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:1 w:1)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:1 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `217`
+		//  Measured:  `312`
 		//  Estimated: `3675`
-		// Minimum execution time: 19_364_000 picoseconds.
-		Weight::from_parts(19_954_000, 3675)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		// Minimum execution time: 22_234_000 picoseconds.
+		Weight::from_parts(22_824_000, 3675)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -699,13 +769,17 @@ impl WeightInfo for () {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:2 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `330`
+		//  Measured:  `497`
 		//  Estimated: `6208`
-		// Minimum execution time: 40_145_000 picoseconds.
-		Weight::from_parts(40_920_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// Minimum execution time: 44_555_000 picoseconds.
+		Weight::from_parts(45_330_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -714,13 +788,17 @@ impl WeightInfo for () {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:2 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer_keep_alive() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `330`
+		//  Measured:  `497`
 		//  Estimated: `6208`
-		// Minimum execution time: 36_083_000 picoseconds.
-		Weight::from_parts(36_804_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// Minimum execution time: 40_473_000 picoseconds.
+		Weight::from_parts(41_194_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -729,13 +807,17 @@ impl WeightInfo for () {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:1 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn force_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `330`
+		//  Measured:  `425`
 		//  Estimated: `6208`
-		// Minimum execution time: 39_945_000 picoseconds.
-		Weight::from_parts(40_933_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// Minimum execution time: 42_875_000 picoseconds.
+		Weight::from_parts(43_863_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Assets::Asset` (r:1 w:0)
@@ -906,13 +988,17 @@ impl WeightInfo for () {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:2 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer_approved() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `500`
+		//  Measured:  `667`
 		//  Estimated: `6208`
-		// Minimum execution time: 59_493_000 picoseconds.
-		Weight::from_parts(60_467_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		// Minimum execution time: 63_963_000 picoseconds.
+		Weight::from_parts(64_937_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Assets::Asset` (r:1 w:1)
@@ -1027,13 +1113,56 @@ impl WeightInfo for () {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:1 w:0)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:2 w:0)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	fn transfer_all() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `330`
+		//  Measured:  `497`
 		//  Estimated: `6208`
-		// Minimum execution time: 49_370_000 picoseconds.
-		Weight::from_parts(50_734_000, 6208)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// Minimum execution time: 53_830_000 picoseconds.
+		Weight::from_parts(55_194_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::RestrictedAssets` (r:0 w:1)
+	/// Proof: `Assets::RestrictedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn set_restricted() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `217`
+		//  Estimated: `3675`
+		// Minimum execution time: 9_657_000 picoseconds.
+		Weight::from_parts(9_994_000, 3675)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:0 w:1)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn add_to_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `217`
+		//  Estimated: `3675`
+		// Minimum execution time: 10_375_000 picoseconds.
+		Weight::from_parts(10_712_000, 3675)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Allowlist` (r:0 w:1)
+	/// Proof: `Assets::Allowlist` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn remove_from_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `217`
+		//  Estimated: `3675`
+		// Minimum execution time: 10_810_000 picoseconds.
+		Weight::from_parts(11_147_000, 3675)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
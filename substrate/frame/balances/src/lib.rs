@@ -854,6 +854,20 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::view_functions]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// The balance `who` could transfer away, including any amount which would see the
+		/// account reaped.
+		pub fn spendable_balance(who: T::AccountId) -> T::Balance {
+			<Self as fungible::Inspect<_>>::reducible_balance(&who, Expendable, Polite)
+		}
+
+		/// The free, reserved and frozen balances of `who`.
+		pub fn account_data(who: T::AccountId) -> AccountData<T::Balance> {
+			Self::account(&who)
+		}
+	}
+
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Public function to get the total issuance.
 		pub fn total_issuance() -> T::Balance {
@@ -25,7 +25,7 @@ use crate::{
 use frame_support::{
 	assert_noop, assert_ok, hypothetically,
 	traits::{
-		fungible::{Mutate, MutateHold},
+		fungible::{Mutate, MutateFreeze, MutateHold},
 		tokens::Precision,
 	},
 };
@@ -110,6 +110,18 @@ fn regression_historic_acc_does_not_evaporate_reserve() {
 	});
 }
 
+#[test]
+fn view_functions_work() {
+	ExtBuilder::default().existential_deposit(1).build_and_execute_with(|| {
+		Balances::set_balance(&1, 100);
+		assert_ok!(Balances::set_freeze(&TestId::Foo, &1, 30));
+
+		assert_eq!(Balances::spendable_balance(1), 70);
+		let data = Balances::account_data(1);
+		assert_eq!((data.free, data.reserved, data.frozen), (100, 0, 30));
+	});
+}
+
 #[cfg(feature = "try-runtime")]
 #[test]
 fn try_state_works() {
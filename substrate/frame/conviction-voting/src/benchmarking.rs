@@ -65,6 +65,39 @@ fn account_vote<T: Config<I>, I: 'static>(b: BalanceOf<T, I>) -> AccountVote<Bal
 	AccountVote::Standard { vote: v, balance: b }
 }
 
+/// Create a chain of `depth` accounts delegating to one another in `class`, the last of which votes
+/// on all `polls`. Return the first account of the chain.
+fn delegation_chain<T: Config<I>, I: 'static>(
+	name: &'static str,
+	class: ClassOf<T, I>,
+	depth: u32,
+	polls: &[IndexOf<T, I>],
+) -> Result<T::AccountId, DispatchError> {
+	let delegated_balance: BalanceOf<T, I> = 1000u32.into();
+	let voter = funded_account::<T, I>(name, depth.saturating_sub(1));
+	for i in polls.iter() {
+		ConvictionVoting::<T, I>::vote(
+			RawOrigin::Signed(voter.clone()).into(),
+			*i,
+			account_vote::<T, I>(delegated_balance),
+		)?;
+	}
+	// Delegate from the end of the chain, so that each delegation extends an existing chain.
+	let mut target = voter;
+	for index in (0..depth.saturating_sub(1)).rev() {
+		let delegator = funded_account::<T, I>(name, index);
+		ConvictionVoting::<T, I>::delegate(
+			RawOrigin::Signed(delegator.clone()).into(),
+			class.clone(),
+			T::Lookup::unlookup(target),
+			Conviction::Locked1x,
+			delegated_balance,
+		)?;
+		target = delegator;
+	}
+	Ok(target)
+}
+
 benchmarks_instance_pallet! {
 	where_clause {  where T::MaxVotes: core::fmt::Debug }
 
@@ -287,6 +320,134 @@ benchmarks_instance_pallet! {
 		assert_eq!(orig_usable, <T::Currency as fungible::Inspect<T::AccountId>>::reducible_balance(&caller, Expendable, Polite));
 	}
 
+	override_vote {
+		let d in 1 .. T::MaxDelegationDepth::get().max(1);
+
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+		let account_vote = account_vote::<T, I>(100u32.into());
+
+		T::VotingHooks::on_vote_worst_case(&caller);
+
+		let (class, all_polls) = fill_voting::<T, I>();
+		let polls = &all_polls[&class];
+		let r = polls.len();
+		// The voting power of the caller flows through `d` accounts.
+		let target = delegation_chain::<T, I>("delegatee", class.clone(), d, polls)?;
+		ConvictionVoting::<T, I>::delegate(
+			RawOrigin::Signed(caller.clone()).into(),
+			class.clone(),
+			T::Lookup::unlookup(target),
+			Conviction::Locked1x,
+			1000u32.into(),
+		)?;
+		// We need to create existing overrides
+		for i in polls.iter().skip(1) {
+			ConvictionVoting::<T, I>::override_vote(RawOrigin::Signed(caller.clone()).into(), *i, account_vote)?;
+		}
+		assert_eq!(OverridesFor::<T, I>::get(&caller, &class).len(), r - 1, "Overrides were not recorded.");
+
+		let index = polls[0];
+	}: _(RawOrigin::Signed(caller.clone()), index, account_vote)
+	verify {
+		assert_eq!(OverridesFor::<T, I>::get(&caller, &class).len(), r);
+	}
+
+	remove_override {
+		let d in 1 .. T::MaxDelegationDepth::get().max(1);
+
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+		let account_vote = account_vote::<T, I>(100u32.into());
+
+		T::VotingHooks::on_vote_worst_case(&caller);
+
+		let (class, all_polls) = fill_voting::<T, I>();
+		let polls = &all_polls[&class];
+		let r = polls.len();
+		// The voting power of the caller flows through `d` accounts.
+		let target = delegation_chain::<T, I>("delegatee", class.clone(), d, polls)?;
+		ConvictionVoting::<T, I>::delegate(
+			RawOrigin::Signed(caller.clone()).into(),
+			class.clone(),
+			T::Lookup::unlookup(target),
+			Conviction::Locked1x,
+			1000u32.into(),
+		)?;
+		// We need to create existing overrides
+		for i in polls.iter() {
+			ConvictionVoting::<T, I>::override_vote(RawOrigin::Signed(caller.clone()).into(), *i, account_vote)?;
+		}
+		assert_eq!(OverridesFor::<T, I>::get(&caller, &class).len(), r, "Overrides were not recorded.");
+
+		let index = polls[0];
+	}: _(RawOrigin::Signed(caller.clone()), Some(class.clone()), index)
+	verify {
+		assert_eq!(OverridesFor::<T, I>::get(&caller, &class).len(), r - 1);
+	}
+
+	redelegate {
+		let r in 0 .. T::MaxVotes::get().min(T::Polls::max_ongoing().1);
+		let d in 1 .. T::MaxDelegationDepth::get().max(1);
+
+		let all_polls = fill_voting::<T, I>().1;
+		let class = T::Polls::max_ongoing().0;
+		let polls = &all_polls[&class][..r as usize];
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+
+		let delegated_balance: BalanceOf<T, I> = 1000u32.into();
+
+		// Both the prior and the new delegation chains are `d` accounts long, and end with an
+		// account which voted on `r` polls.
+		let old_target = delegation_chain::<T, I>("old", class.clone(), d, polls)?;
+		let new_target = delegation_chain::<T, I>("new", class.clone(), d, polls)?;
+		let new_target_lookup = T::Lookup::unlookup(new_target.clone());
+		ConvictionVoting::<T, I>::delegate(
+			RawOrigin::Signed(caller.clone()).into(),
+			class.clone(),
+			T::Lookup::unlookup(old_target),
+			Conviction::Locked1x,
+			delegated_balance,
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), class.clone(), new_target_lookup, Conviction::Locked1x, delegated_balance)
+	verify {
+		assert_matches!(
+			VotingFor::<T, I>::get(&caller, &class),
+			Voting::Delegating(Delegating { target, .. }) if target == new_target
+		);
+	}
+
+	clear_overridden_delegations {
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+		let delegator = funded_account::<T, I>("delegator", 0);
+
+		let (class, all_polls) = fill_voting::<T, I>();
+		let polls = &all_polls[&class];
+		let target = delegation_chain::<T, I>("delegatee", class.clone(), 1, polls)?;
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		ConvictionVoting::<T, I>::delegate(
+			RawOrigin::Signed(delegator.clone()).into(),
+			class.clone(),
+			target_lookup.clone(),
+			Conviction::Locked1x,
+			1000u32.into(),
+		)?;
+
+		let index = polls[0];
+		ConvictionVoting::<T, I>::override_vote(
+			RawOrigin::Signed(delegator.clone()).into(),
+			index,
+			account_vote::<T, I>(100u32.into()),
+		)?;
+		assert!(OverriddenDelegations::<T, I>::contains_key((target.clone(), class.clone()), index));
+		assert!(T::Polls::end_ongoing(index, false).is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), target_lookup, class.clone(), index)
+	verify {
+		assert!(!OverriddenDelegations::<T, I>::contains_key((target, class), index));
+	}
+
 	impl_benchmark_test_suite!(
 		ConvictionVoting,
 		crate::tests::new_test_ext(),
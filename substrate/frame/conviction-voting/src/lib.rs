@@ -23,12 +23,28 @@
 //! ## Overview
 //!
 //! Pallet for managing actual voting in polls.
+//!
+//! ### Delegation
+//!
+//! An account may delegate its voting power in a class of polls to another account, which may in
+//! turn delegate it further, up to [`Config::MaxDelegationDepth`] accounts deep. The accumulated
+//! voting power follows the votes of the account at the end of the chain.
+//!
+//! A delegating account may still vote on a particular poll with [`Pallet::override_vote`]. Its
+//! voting power, along with whatever it has been delegated itself, then follows this vote instead
+//! of its delegation, for this poll only. A delegation can be moved to another account with
+//! [`Pallet::redelegate`], without locking the prior conviction as long as the new delegation is at
+//! least as committed as the prior one.
 
 #![recursion_limit = "256"]
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
+use alloc::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	vec::Vec,
+};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
@@ -79,7 +95,6 @@ pub type VotingOf<T, I = ()> = Voting<
 	PollIndexOf<T, I>,
 	<T as Config<I>>::MaxVotes,
 >;
-#[allow(dead_code)]
 type DelegatingOf<T, I = ()> =
 	Delegating<BalanceOf<T, I>, <T as frame_system::Config>::AccountId, BlockNumberFor<T, I>>;
 pub type TallyOf<T, I = ()> = Tally<BalanceOf<T, I>, <T as Config<I>>::MaxTurnout>;
@@ -157,6 +172,15 @@ pub mod pallet {
 		/// of the calling function. This means that if the calling function fails, the hook will
 		/// be rolled back without further notice.
 		type VotingHooks: VotingHooks<Self::AccountId, PollIndexOf<Self, I>, BalanceOf<Self, I>>;
+
+		/// The maximum number of accounts that delegated voting power may flow through, i.e. the
+		/// maximum length of a chain of delegations.
+		///
+		/// A value of `1` only permits delegating to accounts that are not delegating themselves.
+		/// Since delegations to delegating accounts used not to be forwarded, this should only be
+		/// increased once no account is delegating to a delegating account.
+		#[pallet::constant]
+		type MaxDelegationDepth: Get<u32>;
 	}
 
 	/// All voting for a particular voter in a particular voting class. We store the balance for the
@@ -184,6 +208,59 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The votes of delegating accounts which override their delegation on particular polls.
+	#[pallet::storage]
+	pub type OverridesFor<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		ClassOf<T, I>,
+		BoundedVec<(PollIndexOf<T, I>, AccountVote<BalanceOf<T, I>>), T::MaxVotes>,
+		ValueQuery,
+	>;
+
+	/// The part of the delegations received by an account which does not follow it on a particular
+	/// poll, since an account further down the delegation chain has overridden its delegation.
+	#[pallet::storage]
+	pub type OverriddenDelegations<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		(T::AccountId, ClassOf<T, I>),
+		Twox64Concat,
+		PollIndexOf<T, I>,
+		Delegations<BalanceOf<T, I>>,
+		ValueQuery,
+	>;
+
+	/// The delegating accounts which forward the delegations that they receive themselves.
+	///
+	/// Accounts which have delegated before delegation chains were supported do not, until they
+	/// delegate again.
+	#[pallet::storage]
+	pub type Forwarding<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		ClassOf<T, I>,
+		bool,
+		ValueQuery,
+	>;
+
+	/// An upper bound of the length of the longest delegation chain ending at an account. Reset
+	/// once the account is not being delegated to anymore.
+	#[pallet::storage]
+	pub type DelegationDepth<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		ClassOf<T, I>,
+		u32,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -197,6 +274,30 @@ pub mod pallet {
 		VoteRemoved { who: T::AccountId, vote: AccountVote<BalanceOf<T, I>> },
 		/// The lockup period of a conviction vote expired, and the funds have been unlocked.
 		VoteUnlocked { who: T::AccountId, class: ClassOf<T, I> },
+		/// A delegating account has voted on a poll, overriding its delegation for this poll.
+		DelegationOverridden {
+			who: T::AccountId,
+			poll_index: PollIndexOf<T, I>,
+			vote: AccountVote<BalanceOf<T, I>>,
+		},
+		/// A delegating account has removed the vote overriding its delegation on a poll.
+		DelegationOverrideRemoved { who: T::AccountId, poll_index: PollIndexOf<T, I> },
+		/// An account has moved its delegation to another account.
+		Redelegated { who: T::AccountId, from: T::AccountId, to: T::AccountId },
+		/// The voting power delegated to an account, directly or through a chain of delegations,
+		/// has changed.
+		DelegatedVotingPowerChanged {
+			who: T::AccountId,
+			class: ClassOf<T, I>,
+			delegations: Delegations<BalanceOf<T, I>>,
+		},
+		/// The record of the delegations received by an account which have been overridden on a
+		/// poll that is no longer ongoing has been removed.
+		OverriddenDelegationsCleared {
+			who: T::AccountId,
+			class: ClassOf<T, I>,
+			poll_index: PollIndexOf<T, I>,
+		},
 	}
 
 	#[pallet::error]
@@ -226,6 +327,12 @@ pub mod pallet {
 		ClassNeeded,
 		/// The class ID supplied is invalid.
 		BadClass,
+		/// The delegation would create a cycle.
+		DelegationCycle,
+		/// The delegation chain would become longer than `MaxDelegationDepth`.
+		DelegationTooDeep,
+		/// None of the delegations received by the account have been overridden on the poll.
+		NoOverriddenDelegations,
 	}
 
 	#[pallet::call]
@@ -261,7 +368,9 @@ pub mod pallet {
 		///   - have no voting activity (if there is, then it will need to be removed through
 		///     `remove_vote`).
 		///
-		/// - `to`: The account whose voting the `target` account's voting power will follow.
+		/// - `to`: The account whose voting the `target` account's voting power will follow. If
+		///   `to` is delegating itself, the voting power follows its delegation in turn, up to
+		///   `MaxDelegationDepth` accounts deep.
 		/// - `class`: The class of polls to delegate. To delegate multiple classes, multiple calls
 		///   to this function are required.
 		/// - `conviction`: The conviction that will be attached to the delegated votes. When the
@@ -271,12 +380,12 @@ pub mod pallet {
 		///
 		/// Emits `Delegated`.
 		///
-		/// Weight: `O(R)` where R is the number of polls the voter delegating to has
+		/// Weight: `O(R)` where R is the number of polls the accounts of the delegation chain have
 		///   voted on. Weight is initially charged as if maximum votes, but is refunded later.
 		// NOTE: weight must cover an incorrect voting of origin with max votes, this is ensure
 		// because a valid delegation cover decoding a direct voting with max votes.
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::delegate(T::MaxVotes::get()))]
+		#[pallet::weight(T::WeightInfo::delegate(Pallet::<T, I>::max_chain_votes()))]
 		pub fn delegate(
 			origin: OriginFor<T>,
 			class: ClassOf<T, I>,
@@ -301,14 +410,16 @@ pub mod pallet {
 		///
 		/// - `class`: The class of polls to remove the delegation from.
 		///
+		/// Any votes overriding the delegation become regular votes of the account.
+		///
 		/// Emits `Undelegated`.
 		///
-		/// Weight: `O(R)` where R is the number of polls the voter delegating to has
+		/// Weight: `O(R)` where R is the number of polls the accounts of the delegation chain have
 		///   voted on. Weight is initially charged as if maximum votes, but is refunded later.
 		// NOTE: weight must cover an incorrect voting of origin with max votes, this is ensure
 		// because a valid delegation cover decoding a direct voting with max votes.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::undelegate(T::MaxVotes::get().into()))]
+		#[pallet::weight(T::WeightInfo::undelegate(Pallet::<T, I>::max_chain_votes()))]
 		pub fn undelegate(
 			origin: OriginFor<T>,
 			class: ClassOf<T, I>,
@@ -411,6 +522,184 @@ pub mod pallet {
 			Self::try_remove_vote(&target, index, Some(class), scope)?;
 			Ok(())
 		}
+
+		/// Vote in a poll while delegating, overriding the delegation of the sending account for
+		/// this poll only.
+		///
+		/// The voting power of the sending account, along with the voting power delegated to it,
+		/// follows `vote` rather than the delegation. The delegation remains in place for all other
+		/// polls.
+		///
+		/// The dispatch origin of this call must be _Signed_, and the signing account must be
+		/// delegating in the class of the poll.
+		///
+		/// - `poll_index`: The index of the poll to vote for.
+		/// - `vote`: The vote configuration.
+		///
+		/// Emits `DelegationOverridden`.
+		///
+		/// Weight: `O(R + D)` where R is the number of polls the voter has overridden its
+		///   delegation on and D the length of its delegation chain. Weight is initially charged
+		///   as if the chain was `MaxDelegationDepth` long, but is refunded later.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::override_vote(T::MaxDelegationDepth::get()))]
+		pub fn override_vote(
+			origin: OriginFor<T>,
+			#[pallet::compact] poll_index: PollIndexOf<T, I>,
+			vote: AccountVote<BalanceOf<T, I>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let depth = Self::try_override_vote(&who, poll_index, vote)?;
+			Ok(Some(T::WeightInfo::override_vote(depth)).into())
+		}
+
+		/// Remove the vote overriding the delegation of the sending account on a poll, such that
+		/// its voting power follows the delegation again.
+		///
+		/// The vote is removed under the same conditions as with `remove_vote`.
+		///
+		/// The dispatch origin of this call must be _Signed_, and the signer must have overridden
+		/// its delegation on poll `index`.
+		///
+		/// - `index`: The index of poll of the vote to be removed.
+		/// - `class`: Optional parameter, if given it indicates the class of the poll. For polls
+		///   which have finished or are cancelled, this must be `Some`.
+		///
+		/// Emits `DelegationOverrideRemoved`.
+		///
+		/// Weight: `O(R + log R + D)` where R is the number of polls the voter has overridden its
+		///   delegation on and D the length of its delegation chain. Weight is initially charged
+		///   as if the chain was `MaxDelegationDepth` long, but is refunded later.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::remove_override(T::MaxDelegationDepth::get()))]
+		pub fn remove_override(
+			origin: OriginFor<T>,
+			class: Option<ClassOf<T, I>>,
+			index: PollIndexOf<T, I>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let depth = Self::try_remove_override(&who, index, class)?;
+			Ok(Some(T::WeightInfo::remove_override(depth)).into())
+		}
+
+		/// Move the delegation of the sending account for a particular class of polls to another
+		/// account.
+		///
+		/// Contrary to `undelegate` followed by `delegate`, the prior conviction is not locked as
+		/// long as the new delegation has at least the same `balance` and lock period, since the
+		/// funds remain locked by the new delegation. Votes overriding the delegation remain in
+		/// place.
+		///
+		/// The dispatch origin of this call must be _Signed_ and the signing account must be
+		/// currently delegating.
+		///
+		/// - `class`: The class of polls of the delegation to move.
+		/// - `to`: The account whose voting the account's voting power will follow from now on.
+		/// - `conviction`: The conviction that will be attached to the delegated votes.
+		/// - `balance`: The amount of the account's balance to be used in delegating.
+		///
+		/// Emits `Redelegated`.
+		///
+		/// Weight: `O(R + D)` where R is the number of polls the accounts of the busiest of the two
+		///   delegation chains have voted on and D the length of the longest of them. Weight is
+		///   initially charged as if maximum votes on chains `MaxDelegationDepth` long, but is
+		///   refunded later.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::redelegate(
+			Pallet::<T, I>::max_chain_votes(),
+			T::MaxDelegationDepth::get(),
+		))]
+		pub fn redelegate(
+			origin: OriginFor<T>,
+			class: ClassOf<T, I>,
+			to: AccountIdLookupOf<T>,
+			conviction: Conviction,
+			balance: BalanceOf<T, I>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+			let (votes, depth) = Self::try_redelegate(who, class, to, conviction, balance)?;
+
+			Ok(Some(T::WeightInfo::redelegate(votes, depth)).into())
+		}
+
+		/// Remove the record of the delegations received by an account which have been overridden
+		/// on a poll that is no longer ongoing.
+		///
+		/// These records are only needed to tally ongoing polls, but are otherwise kept until the
+		/// accounts which have overridden their delegation remove their vote.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `target`: The account which received the overridden delegations.
+		/// - `class`: The class of the poll.
+		/// - `index`: The index of the poll, which must have finished or been cancelled.
+		///
+		/// Emits `OverriddenDelegationsCleared`.
+		///
+		/// Weight: `O(1)`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::clear_overridden_delegations())]
+		pub fn clear_overridden_delegations(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			class: ClassOf<T, I>,
+			index: PollIndexOf<T, I>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			ensure!(T::Polls::as_ongoing(index).is_none(), Error::<T, I>::NoPermissionYet);
+			ensure!(
+				OverriddenDelegations::<T, I>::contains_key((target.clone(), class.clone()), index),
+				Error::<T, I>::NoOverriddenDelegations
+			);
+			OverriddenDelegations::<T, I>::remove((target.clone(), class.clone()), index);
+			Self::deposit_event(Event::<T, I>::OverriddenDelegationsCleared {
+				who: target,
+				class,
+				poll_index: index,
+			});
+			Ok(())
+		}
+	}
+
+	#[pallet::view_functions]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// The accounts that the voting power of `who` flows through in `class`, ending with the
+		/// account whose votes it follows. Empty if `who` is not delegating.
+		pub fn delegation_chain(who: T::AccountId, class: ClassOf<T, I>) -> Vec<T::AccountId> {
+			let mut chain = Vec::new();
+			let mut next = who;
+			while let Voting::Delegating(Delegating { target, .. }) =
+				VotingFor::<T, I>::get(&next, &class)
+			{
+				let forwarding = Forwarding::<T, I>::get(&next, &class) || chain.is_empty();
+				if !forwarding || chain.len() as u32 > T::MaxDelegationDepth::get() {
+					break
+				}
+				chain.push(target.clone());
+				next = target;
+			}
+			chain
+		}
+
+		/// The voting power delegated to `who` in `class`, directly or through a chain of
+		/// delegations. If `poll_index` is given, the delegations which have been overridden on
+		/// this poll are excluded.
+		pub fn delegated_voting_power(
+			who: T::AccountId,
+			class: ClassOf<T, I>,
+			poll_index: Option<PollIndexOf<T, I>>,
+		) -> Delegations<BalanceOf<T, I>> {
+			let delegations = match VotingFor::<T, I>::get(&who, &class) {
+				Voting::Casting(Casting { delegations, .. }) => delegations,
+				Voting::Delegating(Delegating { delegations, .. }) => delegations,
+			};
+			match poll_index {
+				Some(poll_index) => Self::delegations_on(&who, &class, poll_index, delegations),
+				None => delegations,
+			}
+		}
 	}
 }
 
@@ -432,12 +721,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			let (tally, class) = poll_status.ensure_ongoing().ok_or(Error::<T, I>::NotOngoing)?;
 			VotingFor::<T, I>::try_mutate(who, &class, |voting| {
 				if let Voting::Casting(Casting { ref mut votes, delegations, .. }) = voting {
+					let delegations = Self::delegations_on(who, &class, poll_index, *delegations);
 					match votes.binary_search_by_key(&poll_index, |i| i.0) {
 						Ok(i) => {
 							// Shouldn't be possible to fail, but we handle it gracefully.
 							tally.remove(votes[i].1).ok_or(ArithmeticError::Underflow)?;
 							if let Some(approve) = votes[i].1.as_standard() {
-								tally.reduce(approve, *delegations);
+								tally.reduce(approve, delegations);
 							}
 							votes[i].1 = vote;
 						},
@@ -450,7 +740,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					// Shouldn't be possible to fail, but we handle it gracefully.
 					tally.add(vote).ok_or(ArithmeticError::Overflow)?;
 					if let Some(approve) = vote.as_standard() {
-						tally.increase(approve, *delegations);
+						tally.increase(approve, delegations);
 					}
 				} else {
 					return Err(Error::<T, I>::AlreadyDelegating.into());
@@ -479,7 +769,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let class = class_hint
 			.or_else(|| Some(T::Polls::as_ongoing(poll_index)?.1))
 			.ok_or(Error::<T, I>::ClassNeeded)?;
-		VotingFor::<T, I>::try_mutate(who, class, |voting| {
+		VotingFor::<T, I>::try_mutate(who, &class, |voting| {
 			if let Voting::Casting(Casting { ref mut votes, delegations, ref mut prior }) = voting {
 				let i = votes
 					.binary_search_by_key(&poll_index, |i| i.0)
@@ -492,49 +782,19 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						// Shouldn't be possible to fail, but we handle it gracefully.
 						tally.remove(v.1).ok_or(ArithmeticError::Underflow)?;
 						if let Some(approve) = v.1.as_standard() {
-							tally.reduce(approve, *delegations);
+							tally.reduce(
+								approve,
+								Self::delegations_on(who, &class, poll_index, *delegations),
+							);
 						}
 						Self::deposit_event(Event::VoteRemoved { who: who.clone(), vote: v.1 });
 						T::VotingHooks::on_remove_vote(who, poll_index, Status::Ongoing);
 						Ok(())
 					},
 					PollStatus::Completed(end, approved) => {
-						if let Some((lock_periods, balance)) =
-							v.1.locked_if(vote::LockedIf::Status(approved))
-						{
-							let unlock_at = end.saturating_add(
-								T::VoteLockingPeriod::get().saturating_mul(lock_periods.into()),
-							);
-							let now = T::BlockNumberProvider::current_block_number();
-							if now < unlock_at {
-								ensure!(
-									matches!(scope, UnvoteScope::Any),
-									Error::<T, I>::NoPermissionYet
-								);
-								prior.accumulate(unlock_at, balance)
-							}
-						} else if v.1.as_standard().is_some_and(|vote| vote != approved) {
-							// Unsuccessful vote, use special hook to lock the funds too in case of
-							// conviction.
-							if let Some(to_lock) =
-								T::VotingHooks::lock_balance_on_unsuccessful_vote(who, poll_index)
-							{
-								if let AccountVote::Standard { vote, .. } = v.1 {
-									let unlock_at = end.saturating_add(
-										T::VoteLockingPeriod::get()
-											.saturating_mul(vote.conviction.lock_periods().into()),
-									);
-									let now = T::BlockNumberProvider::current_block_number();
-									if now < unlock_at {
-										ensure!(
-											matches!(scope, UnvoteScope::Any),
-											Error::<T, I>::NoPermissionYet
-										);
-										prior.accumulate(unlock_at, to_lock)
-									}
-								}
-							}
-						}
+						Self::lock_completed_vote(
+							who, poll_index, v.1, end, approved, &scope, prior,
+						)?;
 						// Call on_remove_vote hook
 						T::VotingHooks::on_remove_vote(who, poll_index, Status::Completed);
 						Ok(())
@@ -551,60 +811,297 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
-	/// Return the number of votes for `who`.
-	fn increase_upstream_delegation(
+	/// Accumulate the lock required by `vote`, cast on the poll `poll_index` which has completed at
+	/// `end`, into `prior`.
+	fn lock_completed_vote(
 		who: &T::AccountId,
-		class: &ClassOf<T, I>,
-		amount: Delegations<BalanceOf<T, I>>,
-	) -> u32 {
-		VotingFor::<T, I>::mutate(who, class, |voting| match voting {
-			Voting::Delegating(Delegating { delegations, .. }) => {
-				// We don't support second level delegating, so we don't need to do anything more.
-				*delegations = delegations.saturating_add(amount);
-				1
-			},
-			Voting::Casting(Casting { votes, delegations, .. }) => {
-				*delegations = delegations.saturating_add(amount);
-				for &(poll_index, account_vote) in votes.iter() {
-					if let AccountVote::Standard { vote, .. } = account_vote {
-						T::Polls::access_poll(poll_index, |poll_status| {
-							if let PollStatus::Ongoing(tally, _) = poll_status {
-								tally.increase(vote.aye, amount);
-							}
-						});
+		poll_index: PollIndexOf<T, I>,
+		vote: AccountVote<BalanceOf<T, I>>,
+		end: BlockNumberFor<T, I>,
+		approved: bool,
+		scope: &UnvoteScope,
+		prior: &mut vote::PriorLock<BlockNumberFor<T, I>, BalanceOf<T, I>>,
+	) -> DispatchResult {
+		if let Some((lock_periods, balance)) = vote.locked_if(vote::LockedIf::Status(approved)) {
+			let unlock_at =
+				end.saturating_add(T::VoteLockingPeriod::get().saturating_mul(lock_periods.into()));
+			let now = T::BlockNumberProvider::current_block_number();
+			if now < unlock_at {
+				ensure!(matches!(scope, UnvoteScope::Any), Error::<T, I>::NoPermissionYet);
+				prior.accumulate(unlock_at, balance)
+			}
+		} else if vote.as_standard().is_some_and(|vote| vote != approved) {
+			// Unsuccessful vote, use special hook to lock the funds too in case of conviction.
+			if let Some(to_lock) =
+				T::VotingHooks::lock_balance_on_unsuccessful_vote(who, poll_index)
+			{
+				if let AccountVote::Standard { vote, .. } = vote {
+					let unlock_at = end.saturating_add(
+						T::VoteLockingPeriod::get()
+							.saturating_mul(vote.conviction.lock_periods().into()),
+					);
+					let now = T::BlockNumberProvider::current_block_number();
+					if now < unlock_at {
+						ensure!(matches!(scope, UnvoteScope::Any), Error::<T, I>::NoPermissionYet);
+						prior.accumulate(unlock_at, to_lock)
 					}
 				}
-				votes.len() as u32
+			}
+		}
+		Ok(())
+	}
+
+	/// The number of votes that a change of delegation may touch, at most.
+	fn max_chain_votes() -> u32 {
+		T::MaxVotes::get().saturating_mul(T::MaxDelegationDepth::get().max(1))
+	}
+
+	/// The part of `delegations`, received by `who`, which follows its vote on `poll_index`.
+	fn delegations_on(
+		who: &T::AccountId,
+		class: &ClassOf<T, I>,
+		poll_index: PollIndexOf<T, I>,
+		delegations: Delegations<BalanceOf<T, I>>,
+	) -> Delegations<BalanceOf<T, I>> {
+		delegations.saturating_sub(OverriddenDelegations::<T, I>::get(
+			(who.clone(), class.clone()),
+			poll_index,
+		))
+	}
+
+	/// The part of the delegations received by the delegating account `who` which it forwards on
+	/// `poll_index`.
+	fn forwarded_on(
+		who: &T::AccountId,
+		class: &ClassOf<T, I>,
+		poll_index: PollIndexOf<T, I>,
+		delegations: Delegations<BalanceOf<T, I>>,
+	) -> Delegations<BalanceOf<T, I>> {
+		if Forwarding::<T, I>::get(who, class) {
+			Self::delegations_on(who, class, poll_index, delegations)
+		} else {
+			Default::default()
+		}
+	}
+
+	fn mutate_overridden(
+		who: &T::AccountId,
+		class: &ClassOf<T, I>,
+		poll_index: PollIndexOf<T, I>,
+		f: impl FnOnce(Delegations<BalanceOf<T, I>>) -> Delegations<BalanceOf<T, I>>,
+	) {
+		OverriddenDelegations::<T, I>::mutate_exists(
+			(who.clone(), class.clone()),
+			poll_index,
+			|o| {
+				let new = f(o.unwrap_or_default());
+				*o = if new == Default::default() { None } else { Some(new) };
 			},
-		})
+		);
 	}
 
-	/// Return the number of votes for `who`.
-	fn reduce_upstream_delegation(
+	/// The vote of `who` on `poll_index`, either cast directly or overriding its delegation, along
+	/// with the account it delegates to, if it forwards the delegations it receives.
+	fn vote_and_next(
 		who: &T::AccountId,
 		class: &ClassOf<T, I>,
+		poll_index: PollIndexOf<T, I>,
+	) -> (Option<AccountVote<BalanceOf<T, I>>>, Option<T::AccountId>) {
+		let find = |votes: &[(PollIndexOf<T, I>, AccountVote<BalanceOf<T, I>>)]| {
+			votes.binary_search_by_key(&poll_index, |i| i.0).ok().map(|i| votes[i].1)
+		};
+		match VotingFor::<T, I>::get(who, class) {
+			Voting::Casting(Casting { votes, .. }) => (find(votes.as_slice()), None),
+			Voting::Delegating(Delegating { target, .. }) => (
+				find(OverridesFor::<T, I>::get(who, class).as_slice()),
+				Forwarding::<T, I>::get(who, class).then_some(target),
+			),
+		}
+	}
+
+	/// Propagate a change of `amount` of voting power delegated to `target` up the delegation
+	/// chain starting at `target`.
+	///
+	/// `overridden` are the parts of `amount` which do not follow the delegation on particular
+	/// polls, and `resolved` the polls on which `amount` does not follow the delegation at all.
+	///
+	/// Return the number of votes touched.
+	fn propagate_delegation(
+		target: &T::AccountId,
+		class: &ClassOf<T, I>,
 		amount: Delegations<BalanceOf<T, I>>,
+		overridden: &BTreeMap<PollIndexOf<T, I>, Delegations<BalanceOf<T, I>>>,
+		mut resolved: BTreeSet<PollIndexOf<T, I>>,
+		increase: bool,
 	) -> u32 {
-		VotingFor::<T, I>::mutate(who, class, |voting| match voting {
-			Voting::Delegating(Delegating { delegations, .. }) => {
-				// We don't support second level delegating, so we don't need to do anything more.
-				*delegations = delegations.saturating_sub(amount);
-				1
-			},
-			Voting::Casting(Casting { votes, delegations, .. }) => {
-				*delegations = delegations.saturating_sub(amount);
-				for &(poll_index, account_vote) in votes.iter() {
-					if let AccountVote::Standard { vote, .. } = account_vote {
-						T::Polls::access_poll(poll_index, |poll_status| {
-							if let PollStatus::Ongoing(tally, _) = poll_status {
-								tally.reduce(vote.aye, amount);
+		let apply = |d: Delegations<BalanceOf<T, I>>, x: Delegations<BalanceOf<T, I>>| {
+			if increase {
+				d.saturating_add(x)
+			} else {
+				d.saturating_sub(x)
+			}
+		};
+		let mut who = target.clone();
+		let mut touched = 0u32;
+		// The chain is at most `MaxDelegationDepth` long, plus one for delegations made before
+		// chains were supported.
+		for _ in 0..=T::MaxDelegationDepth::get() {
+			for poll_index in resolved.iter() {
+				Self::mutate_overridden(&who, class, *poll_index, |o| apply(o, amount));
+			}
+			for (poll_index, part) in overridden.iter().filter(|(p, _)| !resolved.contains(*p)) {
+				Self::mutate_overridden(&who, class, *poll_index, |o| apply(o, *part));
+			}
+
+			let forwarding = Forwarding::<T, I>::get(&who, class);
+			let (delegations, votes, next) =
+				VotingFor::<T, I>::mutate(&who, class, |voting| match voting {
+					Voting::Delegating(Delegating { delegations, target, .. }) => {
+						*delegations = apply(*delegations, amount);
+						// The votes of a delegating account only follow its delegations if it
+						// forwards them.
+						let votes = if forwarding {
+							OverridesFor::<T, I>::get(&who, class).into_inner()
+						} else {
+							Vec::new()
+						};
+						(*delegations, votes, forwarding.then(|| target.clone()))
+					},
+					Voting::Casting(Casting { votes, delegations, .. }) => {
+						*delegations = apply(*delegations, amount);
+						(*delegations, votes.to_vec(), None)
+					},
+				});
+			if delegations == Default::default() {
+				DelegationDepth::<T, I>::remove(&who, class);
+			}
+			Self::deposit_event(Event::<T, I>::DelegatedVotingPowerChanged {
+				who: who.clone(),
+				class: class.clone(),
+				delegations,
+			});
+
+			for (poll_index, account_vote) in votes {
+				touched.saturating_inc();
+				if !resolved.insert(poll_index) {
+					// `amount` is already following a vote further down the chain.
+					continue
+				}
+				if let AccountVote::Standard { vote, .. } = account_vote {
+					let part = amount
+						.saturating_sub(overridden.get(&poll_index).copied().unwrap_or_default());
+					T::Polls::access_poll(poll_index, |poll_status| {
+						if let PollStatus::Ongoing(tally, _) = poll_status {
+							if increase {
+								tally.increase(vote.aye, part);
+							} else {
+								tally.reduce(vote.aye, part);
 							}
-						});
+						}
+					});
+				}
+			}
+
+			match next {
+				Some(next) => who = next,
+				None => break,
+			}
+		}
+		touched
+	}
+
+	/// Detach `amount` of voting power delegated to `target` from its delegation chain on
+	/// `poll_index`, or re-attach it if `attach`.
+	///
+	/// The tally of the poll is adjusted if given.
+	///
+	/// Return the number of accounts of the chain that `amount` was routed through.
+	fn route_override(
+		target: &T::AccountId,
+		class: &ClassOf<T, I>,
+		poll_index: PollIndexOf<T, I>,
+		amount: Delegations<BalanceOf<T, I>>,
+		attach: bool,
+		tally: Option<&mut TallyOf<T, I>>,
+	) -> u32 {
+		let mut who = target.clone();
+		let mut depth = 0u32;
+		for _ in 0..=T::MaxDelegationDepth::get() {
+			depth.saturating_inc();
+			Self::mutate_overridden(&who, class, poll_index, |o| {
+				if attach {
+					o.saturating_sub(amount)
+				} else {
+					o.saturating_add(amount)
+				}
+			});
+			let (vote, next) = Self::vote_and_next(&who, class, poll_index);
+			if let Some(account_vote) = vote {
+				if let (AccountVote::Standard { vote, .. }, Some(tally)) = (account_vote, tally) {
+					if attach {
+						tally.increase(vote.aye, amount);
+					} else {
+						tally.reduce(vote.aye, amount);
 					}
 				}
-				votes.len() as u32
-			},
-		})
+				break
+			}
+			match next {
+				Some(next) => who = next,
+				None => break,
+			}
+		}
+		depth
+	}
+
+	/// The ongoing polls on which delegations received by `who` have been overridden, and by how
+	/// much.
+	///
+	/// The records of polls which are no longer ongoing are not carried along delegation chains,
+	/// since they do not affect any tally anymore. See [`Pallet::clear_overridden_delegations`].
+	fn overridden_of(
+		who: &T::AccountId,
+		class: &ClassOf<T, I>,
+	) -> BTreeMap<PollIndexOf<T, I>, Delegations<BalanceOf<T, I>>> {
+		OverriddenDelegations::<T, I>::iter_prefix((who.clone(), class.clone()))
+			.filter(|(poll_index, _)| T::Polls::as_ongoing(*poll_index).is_some())
+			.collect()
+	}
+
+	/// The polls on which `who` has overridden its delegation.
+	fn overrides_of(who: &T::AccountId, class: &ClassOf<T, I>) -> BTreeSet<PollIndexOf<T, I>> {
+		OverridesFor::<T, I>::get(who, class).into_iter().map(|(p, _)| p).collect()
+	}
+
+	/// Ensure that `who` may delegate to `target`, i.e. that it creates neither a cycle nor a chain
+	/// longer than `MaxDelegationDepth`. Return the accounts that the voting power would flow
+	/// through.
+	fn ensure_can_delegate(
+		who: &T::AccountId,
+		class: &ClassOf<T, I>,
+		target: &T::AccountId,
+	) -> Result<Vec<T::AccountId>, DispatchError> {
+		let depth = DelegationDepth::<T, I>::get(who, class);
+		let mut chain = Vec::new();
+		let mut next = Some(target.clone());
+		while let Some(account) = next {
+			ensure!(&account != who, Error::<T, I>::DelegationCycle);
+			next = match VotingFor::<T, I>::get(&account, class) {
+				Voting::Delegating(Delegating { target, .. })
+					if Forwarding::<T, I>::get(&account, class) =>
+					Some(target),
+				_ => None,
+			};
+			chain.push(account);
+			if chain.len() as u32 > T::MaxDelegationDepth::get() {
+				break
+			}
+		}
+		ensure!(
+			depth.saturating_add(chain.len() as u32) <= T::MaxDelegationDepth::get(),
+			Error::<T, I>::DelegationTooDeep
+		);
+		Ok(chain)
 	}
 
 	/// Attempt to delegate `balance` times `conviction` of voting power from `who` to `target`.
@@ -632,18 +1129,28 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						prior: Default::default(),
 					}),
 				);
-				match old {
+				let delegations = match old {
 					Voting::Delegating(Delegating { .. }) =>
 						return Err(Error::<T, I>::AlreadyDelegating.into()),
 					Voting::Casting(Casting { votes, delegations, prior }) => {
 						// here we just ensure that we're currently idling with no votes recorded.
 						ensure!(votes.is_empty(), Error::<T, I>::AlreadyVoting);
 						voting.set_common(delegations, prior);
+						delegations
 					},
-				}
+				};
 
-				let votes =
-					Self::increase_upstream_delegation(&target, &class, conviction.votes(balance));
+				let chain = Self::ensure_can_delegate(&who, &class, &target)?;
+				Forwarding::<T, I>::insert(&who, &class, true);
+				Self::note_delegation_depth(&who, &class, &chain);
+				let votes = Self::propagate_delegation(
+					&target,
+					&class,
+					conviction.votes(balance).saturating_add(delegations),
+					&Self::overridden_of(&who, &class),
+					Default::default(),
+					true,
+				);
 				// Extend the lock to `balance` (rather than setting it) since we don't know what
 				// other votes are in place.
 				Self::extend_lock(&who, &class, balance);
@@ -653,6 +1160,63 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(votes)
 	}
 
+	/// Record that the chain of delegations ending at `who` now extends through `chain`.
+	fn note_delegation_depth(who: &T::AccountId, class: &ClassOf<T, I>, chain: &[T::AccountId]) {
+		let depth = DelegationDepth::<T, I>::get(who, class);
+		for (hops, account) in chain.iter().enumerate() {
+			DelegationDepth::<T, I>::mutate(account, class, |d| {
+				*d = (*d).max(depth.saturating_add(hops as u32).saturating_add(1))
+			});
+		}
+	}
+
+	/// Remove the voting power of the delegating account `who` from the chain of its delegation.
+	///
+	/// Return the number of votes touched.
+	fn remove_delegation(
+		who: &T::AccountId,
+		class: &ClassOf<T, I>,
+		delegating: &DelegatingOf<T, I>,
+	) -> u32 {
+		let forwarded = if Forwarding::<T, I>::take(who, class) {
+			delegating.delegations
+		} else {
+			Default::default()
+		};
+		let overridden = if forwarded == Default::default() {
+			Default::default()
+		} else {
+			Self::overridden_of(who, class)
+		};
+		Self::propagate_delegation(
+			&delegating.target,
+			class,
+			delegating.conviction.votes(delegating.balance).saturating_add(forwarded),
+			&overridden,
+			Self::overrides_of(who, class),
+			false,
+		)
+	}
+
+	/// Count the delegations received by the delegating account `who` towards the votes overriding
+	/// its delegation, which are not counted unless it forwards them.
+	fn count_received_on_overrides(
+		who: &T::AccountId,
+		class: &ClassOf<T, I>,
+		delegations: Delegations<BalanceOf<T, I>>,
+	) {
+		for (poll_index, account_vote) in OverridesFor::<T, I>::get(who, class) {
+			if let AccountVote::Standard { vote, .. } = account_vote {
+				let part = Self::delegations_on(who, class, poll_index, delegations);
+				T::Polls::access_poll(poll_index, |poll_status| {
+					if let PollStatus::Ongoing(tally, _) = poll_status {
+						tally.increase(vote.aye, part);
+					}
+				});
+			}
+		}
+	}
+
 	/// Attempt to end the current delegation.
 	///
 	/// Return the number of votes of upstream.
@@ -660,19 +1224,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let votes =
 			VotingFor::<T, I>::try_mutate(&who, &class, |voting| -> Result<u32, DispatchError> {
 				match core::mem::replace(voting, Voting::default()) {
-					Voting::Delegating(Delegating {
-						balance,
-						target,
-						conviction,
-						delegations,
-						mut prior,
-					}) => {
+					Voting::Delegating(delegating) => {
+						let forwarding = Forwarding::<T, I>::get(&who, &class);
 						// remove any delegation votes to our current target.
-						let votes = Self::reduce_upstream_delegation(
-							&target,
-							&class,
-							conviction.votes(balance),
-						);
+						let votes = Self::remove_delegation(&who, &class, &delegating);
+						// The votes overriding the delegation become regular votes, which are
+						// followed by all the delegations received.
+						if !forwarding {
+							Self::count_received_on_overrides(&who, &class, delegating.delegations);
+						}
+						let Delegating { balance, conviction, delegations, mut prior, .. } =
+							delegating;
 						let now = T::BlockNumberProvider::current_block_number();
 						let lock_periods = conviction.lock_periods().into();
 						prior.accumulate(
@@ -681,7 +1243,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 							),
 							balance,
 						);
-						voting.set_common(delegations, prior);
+						*voting = Voting::Casting(Casting {
+							votes: OverridesFor::<T, I>::take(&who, &class),
+							delegations,
+							prior,
+						});
 
 						Ok(votes)
 					},
@@ -692,6 +1258,216 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(votes)
 	}
 
+	/// Attempt to move the current delegation to `target`.
+	///
+	/// Return the number of votes touched on the busiest of the prior and the new delegation
+	/// chains, along with the length of the longest of the two.
+	fn try_redelegate(
+		who: T::AccountId,
+		class: ClassOf<T, I>,
+		target: T::AccountId,
+		conviction: Conviction,
+		balance: BalanceOf<T, I>,
+	) -> Result<(u32, u32), DispatchError> {
+		ensure!(who != target, Error::<T, I>::Nonsense);
+		ensure!(balance <= T::Currency::total_balance(&who), Error::<T, I>::InsufficientFunds);
+		let from =
+			VotingFor::<T, I>::try_mutate(&who, &class, |voting| -> Result<_, DispatchError> {
+				let Voting::Delegating(delegating) = voting else {
+					return Err(Error::<T, I>::NotDelegating.into())
+				};
+				let chain = Self::ensure_can_delegate(&who, &class, &target)?;
+				let depth = (Self::delegation_chain(who.clone(), class.clone()).len() as u32)
+					.max(chain.len() as u32);
+				let forwarding = Forwarding::<T, I>::get(&who, &class);
+				let removed = Self::remove_delegation(&who, &class, delegating);
+
+				// The funds remain locked by the new delegation, unless it is less committed.
+				if balance < delegating.balance ||
+					conviction.lock_periods() < delegating.conviction.lock_periods()
+				{
+					let now = T::BlockNumberProvider::current_block_number();
+					let lock_periods = delegating.conviction.lock_periods().into();
+					delegating.prior.accumulate(
+						now.saturating_add(
+							T::VoteLockingPeriod::get().saturating_mul(lock_periods),
+						),
+						delegating.balance,
+					);
+				}
+				let from = core::mem::replace(&mut delegating.target, target.clone());
+				delegating.balance = balance;
+				delegating.conviction = conviction;
+
+				if !forwarding {
+					Self::count_received_on_overrides(&who, &class, delegating.delegations);
+				}
+				Forwarding::<T, I>::insert(&who, &class, true);
+				Self::note_delegation_depth(&who, &class, &chain);
+				let added = Self::propagate_delegation(
+					&target,
+					&class,
+					conviction.votes(balance).saturating_add(delegating.delegations),
+					&Self::overridden_of(&who, &class),
+					Self::overrides_of(&who, &class),
+					true,
+				);
+				Self::extend_lock(&who, &class, balance);
+				Ok((from, removed.max(added), depth))
+			});
+		let (from, votes, depth) = from?;
+		Self::deposit_event(Event::<T, I>::Redelegated { who, from, to: target });
+		Ok((votes, depth))
+	}
+
+	/// Actually enact a vote overriding the delegation of `who`, if legit.
+	///
+	/// Return the number of accounts of the delegation chain that the voting power of `who` was
+	/// detached from.
+	fn try_override_vote(
+		who: &T::AccountId,
+		poll_index: PollIndexOf<T, I>,
+		vote: AccountVote<BalanceOf<T, I>>,
+	) -> Result<u32, DispatchError> {
+		ensure!(
+			vote.balance() <= T::Currency::total_balance(who),
+			Error::<T, I>::InsufficientFunds
+		);
+		T::VotingHooks::on_before_vote(who, poll_index, vote)?;
+
+		T::Polls::try_access_poll(poll_index, |poll_status| {
+			let (tally, class) = poll_status.ensure_ongoing().ok_or(Error::<T, I>::NotOngoing)?;
+			let Voting::Delegating(delegating) = VotingFor::<T, I>::get(who, &class) else {
+				return Err(Error::<T, I>::NotDelegating.into())
+			};
+			let forwarded = Self::forwarded_on(who, &class, poll_index, delegating.delegations);
+			let depth = OverridesFor::<T, I>::try_mutate(who, &class, |overrides| {
+				let depth = match overrides.binary_search_by_key(&poll_index, |i| i.0) {
+					Ok(i) => {
+						// Shouldn't be possible to fail, but we handle it gracefully.
+						tally.remove(overrides[i].1).ok_or(ArithmeticError::Underflow)?;
+						if let Some(approve) = overrides[i].1.as_standard() {
+							tally.reduce(approve, forwarded);
+						}
+						overrides[i].1 = vote;
+						0
+					},
+					Err(i) => {
+						overrides
+							.try_insert(i, (poll_index, vote))
+							.map_err(|_| Error::<T, I>::MaxVotesReached)?;
+						// The voting power does not follow the delegation on this poll anymore.
+						Self::route_override(
+							&delegating.target,
+							&class,
+							poll_index,
+							delegating
+								.conviction
+								.votes(delegating.balance)
+								.saturating_add(forwarded),
+							false,
+							Some(&mut *tally),
+						)
+					},
+				};
+				// Shouldn't be possible to fail, but we handle it gracefully.
+				tally.add(vote).ok_or(ArithmeticError::Overflow)?;
+				if let Some(approve) = vote.as_standard() {
+					tally.increase(approve, forwarded);
+				}
+				Ok::<_, DispatchError>(depth)
+			})?;
+			// Extend the lock to `balance` (rather than setting it) since we don't know what
+			// other votes are in place.
+			Self::extend_lock(who, &class, vote.balance());
+			Self::deposit_event(Event::DelegationOverridden { who: who.clone(), poll_index, vote });
+			Ok(depth)
+		})
+	}
+
+	/// Remove the vote of `who` overriding its delegation on the given poll, if possible.
+	///
+	/// Return the number of accounts of the delegation chain that the voting power of `who` was
+	/// re-attached to.
+	fn try_remove_override(
+		who: &T::AccountId,
+		poll_index: PollIndexOf<T, I>,
+		class_hint: Option<ClassOf<T, I>>,
+	) -> Result<u32, DispatchError> {
+		let class = class_hint
+			.or_else(|| Some(T::Polls::as_ongoing(poll_index)?.1))
+			.ok_or(Error::<T, I>::ClassNeeded)?;
+		let depth = VotingFor::<T, I>::try_mutate(who, &class, |voting| {
+			let Voting::Delegating(delegating) = voting else {
+				return Err(Error::<T, I>::NotDelegating.into())
+			};
+			let v = OverridesFor::<T, I>::try_mutate(who, &class, |overrides| {
+				let i = overrides
+					.binary_search_by_key(&poll_index, |i| i.0)
+					.map_err(|_| Error::<T, I>::NotVoter)?;
+				Ok::<_, DispatchError>(overrides.remove(i).1)
+			})?;
+			let forwarded = Self::forwarded_on(who, &class, poll_index, delegating.delegations);
+			let amount = delegating.conviction.votes(delegating.balance).saturating_add(forwarded);
+
+			T::Polls::try_access_poll(poll_index, |poll_status| match poll_status {
+				PollStatus::Ongoing(tally, _) => {
+					// Shouldn't be possible to fail, but we handle it gracefully.
+					tally.remove(v).ok_or(ArithmeticError::Underflow)?;
+					if let Some(approve) = v.as_standard() {
+						tally.reduce(approve, forwarded);
+					}
+					let depth = Self::route_override(
+						&delegating.target,
+						&class,
+						poll_index,
+						amount,
+						true,
+						Some(tally),
+					);
+					T::VotingHooks::on_remove_vote(who, poll_index, Status::Ongoing);
+					Ok(depth)
+				},
+				PollStatus::Completed(end, approved) => {
+					Self::lock_completed_vote(
+						who,
+						poll_index,
+						v,
+						end,
+						approved,
+						&UnvoteScope::Any,
+						&mut delegating.prior,
+					)?;
+					let depth = Self::route_override(
+						&delegating.target,
+						&class,
+						poll_index,
+						amount,
+						true,
+						None,
+					);
+					T::VotingHooks::on_remove_vote(who, poll_index, Status::Completed);
+					Ok(depth)
+				},
+				PollStatus::None => {
+					// Poll was cancelled.
+					let depth = Self::route_override(
+						&delegating.target,
+						&class,
+						poll_index,
+						amount,
+						true,
+						None,
+					);
+					T::VotingHooks::on_remove_vote(who, poll_index, Status::None);
+					Ok(depth)
+				},
+			})
+		})?;
+		Self::deposit_event(Event::DelegationOverrideRemoved { who: who.clone(), poll_index });
+		Ok(depth)
+	}
+
 	fn extend_lock(who: &T::AccountId, class: &ClassOf<T, I>, amount: BalanceOf<T, I>) {
 		ClassLocksFor::<T, I>::mutate(who, |locks| {
 			match locks.iter().position(|x| &x.0 == class) {
@@ -722,6 +1498,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			voting.rejig(T::BlockNumberProvider::current_block_number());
 			voting.locked_balance()
 		});
+		let class_lock_needed = OverridesFor::<T, I>::get(who, class)
+			.iter()
+			.map(|i| i.1.balance())
+			.fold(class_lock_needed, |a, i| a.max(i));
 		let lock_needed = ClassLocksFor::<T, I>::mutate(who, |locks| {
 			locks.retain(|x| &x.0 != class);
 			if !class_lock_needed.is_zero() {
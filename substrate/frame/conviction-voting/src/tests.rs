@@ -151,6 +151,7 @@ impl Config for Test {
 	type Currency = pallet_balances::Pallet<Self>;
 	type VoteLockingPeriod = ConstU64<3>;
 	type MaxVotes = ConstU32<3>;
+	type MaxDelegationDepth = ConstU32<3>;
 	type WeightInfo = ();
 	type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, Self::AccountId>;
 	type Polls = TestPolls;
@@ -590,6 +591,202 @@ fn redelegation_after_vote_ending_should_keep_lock() {
 	});
 }
 
+#[test]
+fn chained_delegation_works() {
+	new_test_ext().execute_with(|| {
+		Polls::set(vec![(0, Ongoing(Tally::new(0), 0))].into_iter().collect());
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 0, aye(10, 0)));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 5));
+		assert_eq!(tally(0), Tally::from_parts(1, 0, 10));
+
+		// 2 forwards the voting power delegated by 1 along with its own.
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(2), 0, 3, Conviction::Locked1x, 10));
+		assert_eq!(tally(0), Tally::from_parts(16, 0, 25));
+		assert_eq!(Voting::delegation_chain(1, 0), vec![2, 3]);
+		assert_eq!(
+			Voting::delegated_voting_power(3, 0, None),
+			Delegations { votes: 15, capital: 15 }
+		);
+		System::assert_last_event(tests::RuntimeEvent::Voting(Event::Delegated(2, 3)));
+
+		// 3 changes its vote, along with the whole chain.
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 0, nay(10, 0)));
+		assert_eq!(tally(0), Tally::from_parts(0, 16, 0));
+
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(1), 0));
+		assert_eq!(tally(0), Tally::from_parts(0, 11, 0));
+		assert_eq!(
+			Voting::delegated_voting_power(3, 0, None),
+			Delegations { votes: 10, capital: 10 }
+		);
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(2), 0));
+		assert_eq!(tally(0), Tally::from_parts(0, 1, 0));
+	});
+}
+
+#[test]
+fn delegation_cycles_and_depth_are_prevented() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::None, 5));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(2), 0, 3, Conviction::None, 5));
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(3), 0, 1, Conviction::None, 5),
+			Error::<Test>::DelegationCycle
+		);
+
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(3), 0, 4, Conviction::None, 5));
+		assert_eq!(Voting::delegation_chain(1, 0), vec![2, 3, 4]);
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(4), 0, 5, Conviction::None, 5),
+			Error::<Test>::DelegationTooDeep
+		);
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(5), 0, 1, Conviction::None, 5),
+			Error::<Test>::DelegationTooDeep
+		);
+
+		// Other classes are not affected.
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(4), 1, 5, Conviction::None, 5));
+	});
+}
+
+#[test]
+fn override_vote_works() {
+	new_test_ext().execute_with(|| {
+		Polls::set(vec![(0, Ongoing(Tally::new(0), 0))].into_iter().collect());
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 0, aye(10, 0)));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 5));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(2), 0, 3, Conviction::Locked1x, 10));
+		assert_eq!(tally(0), Tally::from_parts(16, 0, 25));
+
+		assert_noop!(
+			Voting::override_vote(RuntimeOrigin::signed(3), 0, nay(10, 0)),
+			Error::<Test>::NotDelegating
+		);
+
+		// 2 votes along with the voting power delegated by 1.
+		assert_ok!(Voting::override_vote(RuntimeOrigin::signed(2), 0, nay(20, 0)));
+		assert_eq!(tally(0), Tally::from_parts(1, 7, 10));
+		assert_eq!(Voting::delegated_voting_power(3, 0, Some(0)), Default::default());
+		System::assert_last_event(tests::RuntimeEvent::Voting(Event::DelegationOverridden {
+			who: 2,
+			poll_index: 0,
+			vote: nay(20, 0),
+		}));
+
+		// 1 in turn votes on its own.
+		assert_ok!(Voting::override_vote(RuntimeOrigin::signed(1), 0, aye(10, 0)));
+		assert_eq!(tally(0), Tally::from_parts(2, 2, 20));
+
+		// 2 follows its delegation again, 1 does not.
+		assert_ok!(Voting::remove_override(RuntimeOrigin::signed(2), Some(0), 0));
+		assert_eq!(tally(0), Tally::from_parts(12, 0, 30));
+		System::assert_last_event(tests::RuntimeEvent::Voting(Event::DelegationOverrideRemoved {
+			who: 2,
+			poll_index: 0,
+		}));
+		assert_noop!(
+			Voting::remove_override(RuntimeOrigin::signed(2), Some(0), 0),
+			Error::<Test>::NotVoter
+		);
+
+		// Once undelegated, the override of 1 is a regular vote.
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(1), 0));
+		assert_eq!(tally(0), Tally::from_parts(12, 0, 30));
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(1), Some(0), 0));
+		assert_eq!(tally(0), Tally::from_parts(11, 0, 20));
+	});
+}
+
+#[test]
+fn override_vote_is_locked() {
+	new_test_ext().execute_with(|| {
+		Polls::set(vec![(0, Ongoing(Tally::new(0), 0))].into_iter().collect());
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 5));
+		assert_ok!(Voting::override_vote(RuntimeOrigin::signed(1), 0, aye(8, 1)));
+		assert_eq!(Balances::usable_balance(1), 2);
+
+		Polls::set(vec![(0, Completed(1, true))].into_iter().collect());
+		assert_ok!(Voting::remove_override(RuntimeOrigin::signed(1), Some(0), 0));
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 2);
+
+		run_to(7);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 10);
+	});
+}
+
+#[test]
+fn overridden_delegations_of_finished_polls_can_be_cleared() {
+	new_test_ext().execute_with(|| {
+		Polls::set(vec![(0, Ongoing(Tally::new(0), 0))].into_iter().collect());
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 0, aye(10, 0)));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 5));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(2), 0, 3, Conviction::Locked1x, 10));
+		assert_ok!(Voting::override_vote(RuntimeOrigin::signed(1), 0, aye(10, 0)));
+		assert!(OverriddenDelegations::<Test>::contains_key((2, 0), 0));
+		assert!(OverriddenDelegations::<Test>::contains_key((3, 0), 0));
+
+		// the records are needed as long as the poll is ongoing.
+		assert_noop!(
+			Voting::clear_overridden_delegations(RuntimeOrigin::signed(4), 2, 0, 0),
+			Error::<Test>::NoPermissionYet
+		);
+
+		Polls::set(vec![(0, Completed(1, true))].into_iter().collect());
+		assert_ok!(Voting::clear_overridden_delegations(RuntimeOrigin::signed(4), 2, 0, 0));
+		assert!(!OverriddenDelegations::<Test>::contains_key((2, 0), 0));
+		System::assert_last_event(tests::RuntimeEvent::Voting(
+			Event::OverriddenDelegationsCleared { who: 2, class: 0, poll_index: 0 },
+		));
+		assert_noop!(
+			Voting::clear_overridden_delegations(RuntimeOrigin::signed(4), 2, 0, 0),
+			Error::<Test>::NoOverriddenDelegations
+		);
+
+		// the records of finished polls are not carried along a new delegation chain.
+		assert_ok!(Voting::redelegate(RuntimeOrigin::signed(2), 0, 4, Conviction::Locked1x, 10));
+		assert!(!OverriddenDelegations::<Test>::contains_key((4, 0), 0));
+		assert_ok!(Voting::clear_overridden_delegations(RuntimeOrigin::signed(4), 3, 0, 0));
+		assert!(!OverriddenDelegations::<Test>::contains_key((3, 0), 0));
+
+		// the override of 1 can still be removed.
+		assert_ok!(Voting::remove_override(RuntimeOrigin::signed(1), Some(0), 0));
+		assert_eq!(OverriddenDelegations::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn redelegate_works() {
+	new_test_ext().execute_with(|| {
+		Polls::set(vec![(0, Ongoing(Tally::new(0), 0))].into_iter().collect());
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 0, aye(10, 0)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 0, nay(10, 0)));
+		assert_noop!(
+			Voting::redelegate(RuntimeOrigin::signed(1), 0, 3, Conviction::Locked1x, 5),
+			Error::<Test>::NotDelegating
+		);
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 5));
+		assert_eq!(tally(0), Tally::from_parts(6, 1, 15));
+
+		assert_ok!(Voting::redelegate(RuntimeOrigin::signed(1), 0, 3, Conviction::Locked1x, 5));
+		assert_eq!(tally(0), Tally::from_parts(1, 6, 10));
+		System::assert_last_event(tests::RuntimeEvent::Voting(Event::Redelegated {
+			who: 1,
+			from: 2,
+			to: 3,
+		}));
+
+		// A less committed delegation keeps the prior lock.
+		assert_ok!(Voting::redelegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 3));
+		assert_eq!(tally(0), Tally::from_parts(4, 1, 13));
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 5);
+	});
+}
+
 #[test]
 fn lock_amalgamation_valid_with_multiple_removed_votes() {
 	new_test_ext().execute_with(|| {
@@ -79,6 +79,10 @@ pub trait WeightInfo {
 	fn delegate(r: u32, ) -> Weight;
 	fn undelegate(r: u32, ) -> Weight;
 	fn unlock() -> Weight;
+	fn override_vote(d: u32, ) -> Weight;
+	fn remove_override(d: u32, ) -> Weight;
+	fn redelegate(r: u32, d: u32, ) -> Weight;
+	fn clear_overridden_delegations() -> Weight;
 }
 
 /// Weights for `pallet_conviction_voting` using the Substrate node and recommended hardware.
@@ -100,13 +104,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:1 w:0)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
 	fn vote_new() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `12820`
 		//  Estimated: `219984`
-		// Minimum execution time: 122_673_000 picoseconds.
-		Weight::from_parts(132_468_000, 219984)
-			.saturating_add(T::DbWeight::get().reads(8_u64))
+		// Minimum execution time: 125_387_000 picoseconds.
+		Weight::from_parts(135_182_000, 219984)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
 			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -125,13 +131,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:1 w:0)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
 	fn vote_existing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `19983`
 		//  Estimated: `219984`
-		// Minimum execution time: 318_133_000 picoseconds.
-		Weight::from_parts(333_487_000, 219984)
-			.saturating_add(T::DbWeight::get().reads(8_u64))
+		// Minimum execution time: 321_024_000 picoseconds.
+		Weight::from_parts(336_378_000, 219984)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
 			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
@@ -142,13 +150,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:1 w:0)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
 	fn remove_vote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `19820`
 		//  Estimated: `219984`
-		// Minimum execution time: 288_951_000 picoseconds.
-		Weight::from_parts(305_052_000, 219984)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// Minimum execution time: 291_604_000 picoseconds.
+		Weight::from_parts(307_705_000, 219984)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
@@ -237,6 +247,130 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotingFor` (r:5 w:0)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::Forwarding` (r:5 w:0)
+	/// Proof: `ConvictionVoting::Forwarding` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverridesFor` (r:5 w:1)
+	/// Proof: `ConvictionVoting::OverridesFor` (`max_values`: None, `max_size`: Some(27190), added: 29665, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:5 w:4)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
+	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Parameters::Parameters` (r:1 w:0)
+	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:2 w:2)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:0 w:1)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 4]`.
+	fn override_vote(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `20127 + d * (187 ±0)`
+		//  Estimated: `219984 + d * (29716 ±0)`
+		// Minimum execution time: 338_032_000 picoseconds.
+		Weight::from_parts(318_904_117, 219984)
+			// Standard Error: 211_473
+			.saturating_add(Weight::from_parts(13_862_406, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 29716).saturating_mul(d.into()))
+	}
+	/// Storage: `ConvictionVoting::VotingFor` (r:5 w:1)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverridesFor` (r:5 w:1)
+	/// Proof: `ConvictionVoting::OverridesFor` (`max_values`: None, `max_size`: Some(27190), added: 29665, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::Forwarding` (r:5 w:0)
+	/// Proof: `ConvictionVoting::Forwarding` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:5 w:4)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:2 w:2)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:0 w:1)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 4]`.
+	fn remove_override(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `19964 + d * (187 ±0)`
+		//  Estimated: `219984 + d * (29716 ±0)`
+		// Minimum execution time: 310_450_000 picoseconds.
+		Weight::from_parts(291_375_842, 219984)
+			// Standard Error: 243_106
+			.saturating_add(Weight::from_parts(14_518_093, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 29716).saturating_mul(d.into()))
+	}
+	/// Storage: `ConvictionVoting::VotingFor` (r:10 w:10)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::Forwarding` (r:5 w:1)
+	/// Proof: `ConvictionVoting::Forwarding` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::DelegationDepth` (r:5 w:5)
+	/// Proof: `ConvictionVoting::DelegationDepth` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverridesFor` (r:1 w:0)
+	/// Proof: `ConvictionVoting::OverridesFor` (`max_values`: None, `max_size`: Some(27190), added: 29665, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:1 w:0)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::ReferendumInfoFor` (r:2 w:2)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
+	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Parameters::Parameters` (r:1 w:0)
+	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:4 w:4)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:0 w:2)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 1]`.
+	/// The range of component `d` is `[1, 4]`.
+	fn redelegate(r: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1046 + r * (3032 ±0) + d * (374 ±0)`
+		//  Estimated: `219984 + r * (219984 ±0) + d * (29716 ±0)`
+		// Minimum execution time: 86_417_000 picoseconds.
+		Weight::from_parts(64_218_540, 219984)
+			// Standard Error: 402_315
+			.saturating_add(Weight::from_parts(83_614_271, 0).saturating_mul(r.into()))
+			// Standard Error: 520_788
+			.saturating_add(Weight::from_parts(27_384_519, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 219984).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 29716).saturating_mul(d.into()))
+	}
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:0)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:1 w:1)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	fn clear_overridden_delegations() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `449`
+		//  Estimated: `3831`
+		// Minimum execution time: 28_645_000 picoseconds.
+		Weight::from_parts(29_531_000, 3831)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -257,13 +391,15 @@ impl WeightInfo for () {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:1 w:0)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
 	fn vote_new() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `12820`
 		//  Estimated: `219984`
-		// Minimum execution time: 122_673_000 picoseconds.
-		Weight::from_parts(132_468_000, 219984)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
+		// Minimum execution time: 125_387_000 picoseconds.
+		Weight::from_parts(135_182_000, 219984)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -282,13 +418,15 @@ impl WeightInfo for () {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:1 w:0)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
 	fn vote_existing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `19983`
 		//  Estimated: `219984`
-		// Minimum execution time: 318_133_000 picoseconds.
-		Weight::from_parts(333_487_000, 219984)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
+		// Minimum execution time: 321_024_000 picoseconds.
+		Weight::from_parts(336_378_000, 219984)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
@@ -299,13 +437,15 @@ impl WeightInfo for () {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:1 w:0)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
 	fn remove_vote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `19820`
 		//  Estimated: `219984`
-		// Minimum execution time: 288_951_000 picoseconds.
-		Weight::from_parts(305_052_000, 219984)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// Minimum execution time: 291_604_000 picoseconds.
+		Weight::from_parts(307_705_000, 219984)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
@@ -394,4 +534,128 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotingFor` (r:5 w:0)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::Forwarding` (r:5 w:0)
+	/// Proof: `ConvictionVoting::Forwarding` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverridesFor` (r:5 w:1)
+	/// Proof: `ConvictionVoting::OverridesFor` (`max_values`: None, `max_size`: Some(27190), added: 29665, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:5 w:4)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
+	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Parameters::Parameters` (r:1 w:0)
+	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:2 w:2)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:0 w:1)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 4]`.
+	fn override_vote(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `20127 + d * (187 ±0)`
+		//  Estimated: `219984 + d * (29716 ±0)`
+		// Minimum execution time: 338_032_000 picoseconds.
+		Weight::from_parts(318_904_117, 219984)
+			// Standard Error: 211_473
+			.saturating_add(Weight::from_parts(13_862_406, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 29716).saturating_mul(d.into()))
+	}
+	/// Storage: `ConvictionVoting::VotingFor` (r:5 w:1)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverridesFor` (r:5 w:1)
+	/// Proof: `ConvictionVoting::OverridesFor` (`max_values`: None, `max_size`: Some(27190), added: 29665, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::Forwarding` (r:5 w:0)
+	/// Proof: `ConvictionVoting::Forwarding` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:5 w:4)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:2 w:2)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:0 w:1)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 4]`.
+	fn remove_override(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `19964 + d * (187 ±0)`
+		//  Estimated: `219984 + d * (29716 ±0)`
+		// Minimum execution time: 310_450_000 picoseconds.
+		Weight::from_parts(291_375_842, 219984)
+			// Standard Error: 243_106
+			.saturating_add(Weight::from_parts(14_518_093, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 29716).saturating_mul(d.into()))
+	}
+	/// Storage: `ConvictionVoting::VotingFor` (r:10 w:10)
+	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::Forwarding` (r:5 w:1)
+	/// Proof: `ConvictionVoting::Forwarding` (`max_values`: None, `max_size`: Some(51), added: 2526, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::DelegationDepth` (r:5 w:5)
+	/// Proof: `ConvictionVoting::DelegationDepth` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverridesFor` (r:1 w:0)
+	/// Proof: `ConvictionVoting::OverridesFor` (`max_values`: None, `max_size`: Some(27190), added: 29665, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:1 w:0)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::ReferendumInfoFor` (r:2 w:2)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
+	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Parameters::Parameters` (r:1 w:0)
+	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:4 w:4)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Retries` (r:0 w:2)
+	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 1]`.
+	/// The range of component `d` is `[1, 4]`.
+	fn redelegate(r: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1046 + r * (3032 ±0) + d * (374 ±0)`
+		//  Estimated: `219984 + r * (219984 ±0) + d * (29716 ±0)`
+		// Minimum execution time: 86_417_000 picoseconds.
+		Weight::from_parts(64_218_540, 219984)
+			// Standard Error: 402_315
+			.saturating_add(Weight::from_parts(83_614_271, 0).saturating_mul(r.into()))
+			// Standard Error: 520_788
+			.saturating_add(Weight::from_parts(27_384_519, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((8_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 219984).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 29716).saturating_mul(d.into()))
+	}
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:0)
+	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::OverriddenDelegations` (r:1 w:1)
+	/// Proof: `ConvictionVoting::OverriddenDelegations` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	fn clear_overridden_delegations() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `449`
+		//  Estimated: `3831`
+		// Minimum execution time: 28_645_000 picoseconds.
+		Weight::from_parts(29_531_000, 3831)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
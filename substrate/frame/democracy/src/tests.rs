@@ -95,6 +95,15 @@ impl pallet_preimage::Config for Test {
 	type Consideration = ();
 }
 
+/// Timed scheduling is not used in these tests.
+pub struct NoTime;
+impl frame_support::traits::Time for NoTime {
+	type Moment = u64;
+	fn now() -> u64 {
+		0
+	}
+}
+
 impl pallet_scheduler::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeOrigin = RuntimeOrigin;
@@ -107,6 +116,8 @@ impl pallet_scheduler::Config for Test {
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = ();
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type TimeProvider = NoTime;
+	type MaxTimedAgendas = ConstU32<0>;
 }
 
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
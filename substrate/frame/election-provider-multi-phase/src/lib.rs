@@ -104,6 +104,12 @@
 //! Validators will only submit solutions if the one that they have computed is strictly better than
 //! the best queued one and will limit the weight of the solution to [`MinerConfig::MaxWeight`].
 //!
+//! Validators can also delegate mining to an external solver by storing its URL in the persistent
+//! offchain storage of their node, under [`unsigned::OFFCHAIN_EXTERNAL_SOLVER`]. The solution it
+//! returns goes through the same checks as a locally mined one before being submitted, and the
+//! node falls back to [`Config::Solver`] if it is unavailable or its solution is rejected. See
+//! [`Pallet::mine_external_solution`] for the format of the exchange.
+//!
 //! The unsigned phase can be made passive depending on how the previous signed phase went, by
 //! setting the first inner value of [`Phase`] to `false`. For now, the signed phase is always
 //! active.
@@ -32,7 +32,7 @@ use multi_phase::unsigned::{IndexAssignmentOf, VoterOf};
 use parking_lot::RwLock;
 use sp_core::{
 	offchain::{
-		testing::{PoolState, TestOffchainExt, TestTransactionPoolExt},
+		testing::{OffchainState, PoolState, TestOffchainExt, TestTransactionPoolExt},
 		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
 	},
 	H256,
@@ -611,6 +611,14 @@ impl ExtBuilder {
 		self,
 		iters: u32,
 	) -> (sp_io::TestExternalities, Arc<RwLock<PoolState>>) {
+		let (ext, pool_state, _) = self.build_offchainify_with_state(iters);
+		(ext, pool_state)
+	}
+
+	pub fn build_offchainify_with_state(
+		self,
+		iters: u32,
+	) -> (sp_io::TestExternalities, Arc<RwLock<PoolState>>, Arc<RwLock<OffchainState>>) {
 		let mut ext = self.build();
 		let (offchain, offchain_state) = TestOffchainExt::new();
 		let (pool, pool_state) = TestTransactionPoolExt::new();
@@ -623,7 +631,7 @@ impl ExtBuilder {
 		ext.register_extension(OffchainWorkerExt::new(offchain));
 		ext.register_extension(TransactionPoolExt::new(pool));
 
-		(ext, pool_state)
+		(ext, pool_state, offchain_state)
 	}
 
 	pub fn build_and_execute(self, test: impl FnOnce() -> ()) {
@@ -20,10 +20,11 @@
 use crate::{
 	helpers, Call, Config, CurrentPhase, DesiredTargets, ElectionCompute, Error, FeasibilityError,
 	Pallet, QueuedSolution, RawSolution, ReadySolution, Round, RoundSnapshot, Snapshot,
-	SolutionAccuracyOf, SolutionOf, SolutionOrSnapshotSize, Weight,
+	SnapshotMetadata, SolutionAccuracyOf, SolutionOf, SolutionOrSnapshotSize, Weight,
 };
 use alloc::{boxed::Box, vec::Vec};
-use codec::Encode;
+use codec::{Decode, Encode};
+use core::time::Duration;
 use frame_election_provider_support::{NposSolution, NposSolver, PerThing128, VoteWeight};
 use frame_support::{
 	dispatch::DispatchResult,
@@ -41,7 +42,11 @@ use sp_npos_elections::{
 	ElectionScore, EvaluateSupport,
 };
 use sp_runtime::{
-	offchain::storage::{MutateStorageError, StorageValueRef},
+	offchain::{
+		http,
+		storage::{MutateStorageError, StorageValueRef},
+		StorageKind,
+	},
 	DispatchError, SaturatedConversion,
 };
 
@@ -53,6 +58,15 @@ pub(crate) const OFFCHAIN_LOCK: &[u8] = b"parity/multi-phase-unsigned-election/l
 /// Storage key used to cache the solution `call`.
 pub(crate) const OFFCHAIN_CACHED_CALL: &[u8] = b"parity/multi-phase-unsigned-election/call";
 
+/// Persistent offchain storage key of the URL of an external solver, if any.
+///
+/// The value is the raw UTF-8 encoded URL, e.g. as set by the node operator with the
+/// `offchain_localStorageSet` RPC. See [`Pallet::mine_external_solution`].
+pub const OFFCHAIN_EXTERNAL_SOLVER: &[u8] = b"parity/multi-phase-unsigned-election/solver";
+
+/// Time given to the external solver to reply with a solution, in milliseconds.
+pub(crate) const EXTERNAL_SOLVER_DEADLINE: u64 = 30_000;
+
 /// A voter's fundamental data: their ID, their stake, and the list of candidates for whom they
 /// voted.
 pub type VoterOf<T> = frame_election_provider_support::VoterOf<<T as Config>::DataProvider>;
@@ -98,6 +112,10 @@ pub enum MinerError {
 	NoMoreVoters,
 	/// An error from the solver.
 	Solver,
+	/// The external solver failed to provide a solution.
+	ExternalSolver(&'static str),
+	/// The solution of the external solver does not fit the length or weight limits.
+	ExternalSolutionTooBig,
 }
 
 impl From<sp_npos_elections::Error> for MinerError {
@@ -176,6 +194,12 @@ fn clear_offchain_repeat_frequency() {
 	last_block.clear();
 }
 
+/// The URL of the external solver configured in the offchain storage of this node, if any.
+fn external_solver_url() -> Option<Vec<u8>> {
+	sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, OFFCHAIN_EXTERNAL_SOLVER)
+		.filter(|url| !url.is_empty())
+}
+
 /// `true` when OCW storage contains a solution
 #[cfg(test)]
 fn ocw_solution_exists<T: Config>() -> bool {
@@ -313,15 +337,84 @@ impl<T: Config + CreateInherent<Call<T>>> Pallet<T> {
 	/// If you want an unchecked solution, use [`Pallet::mine_solution`].
 	/// If you want a checked solution and submit it at the same time, use
 	/// [`Pallet::mine_check_save_submit`].
+	///
+	/// If an external solver is configured, see [`OFFCHAIN_EXTERNAL_SOLVER`], its solution is
+	/// used instead, as long as it passes the same checks. Otherwise, this falls back to mining
+	/// with [`crate::Config::Solver`].
 	pub fn mine_and_check() -> Result<
 		(RawSolution<SolutionOf<T::MinerConfig>>, SolutionOrSnapshotSize, TrimmingStatus),
 		MinerError,
 	> {
+		if let Some(url) = external_solver_url() {
+			match Self::mine_external_solution(&url).and_then(|(raw_solution, witness)| {
+				Self::basic_checks(&raw_solution, "external")?;
+				Ok((raw_solution, witness))
+			}) {
+				Ok((raw_solution, witness)) =>
+					return Ok((raw_solution, witness, TrimmingStatus { weight: 0, length: 0 })),
+				Err(why) => log!(warn, "external solver failed due to {:?}, mining locally.", why),
+			}
+		}
+
 		let (raw_solution, witness, is_trimmed) = Self::mine_solution()?;
 		Self::basic_checks(&raw_solution, "mined")?;
 		Ok((raw_solution, witness, is_trimmed))
 	}
 
+	/// Request a solution from the external solver at `url`.
+	///
+	/// The solver receives an HTTP `POST` request whose body is the SCALE encoded tuple
+	/// `(round, desired_targets, snapshot)`, where `snapshot` is the [`RoundSnapshot`] of the
+	/// current round. It must reply within [`EXTERNAL_SOLVER_DEADLINE`] milliseconds with a `200`
+	/// response whose body is the SCALE encoded `RawSolution` for this round.
+	///
+	/// The returned solution is only checked against the length and weight limits of
+	/// [`MinerConfig`], it is up to the caller to check its validity and score.
+	pub fn mine_external_solution(
+		url: &[u8],
+	) -> Result<(RawSolution<SolutionOf<T::MinerConfig>>, SolutionOrSnapshotSize), MinerError> {
+		let url = core::str::from_utf8(url)
+			.map_err(|_| MinerError::ExternalSolver("solver url is not valid utf8."))?;
+		let snapshot = Snapshot::<T>::get().ok_or(MinerError::SnapshotUnAvailable)?;
+		let witness = SnapshotMetadata::<T>::get().ok_or(MinerError::SnapshotUnAvailable)?;
+		let desired_targets = DesiredTargets::<T>::get().ok_or(MinerError::SnapshotUnAvailable)?;
+		let round = Round::<T>::get();
+
+		let body = (round, desired_targets, snapshot).encode();
+		let deadline =
+			sp_io::offchain::timestamp().add(Duration::from_millis(EXTERNAL_SOLVER_DEADLINE));
+		let response = http::Request::post(url, alloc::vec![body])
+			.add_header("Content-Type", "application/octet-stream")
+			.deadline(deadline)
+			.send()
+			.map_err(|_| MinerError::ExternalSolver("failed to send request."))?
+			.try_wait(deadline)
+			.map_err(|_| MinerError::ExternalSolver("deadline reached."))?
+			.map_err(|_| MinerError::ExternalSolver("request failed."))?;
+		if response.code != 200 {
+			return Err(MinerError::ExternalSolver("unexpected status code."))
+		}
+
+		let raw_solution = RawSolution::<SolutionOf<T::MinerConfig>>::decode(
+			&mut &response.body().collect::<Vec<u8>>()[..],
+		)
+		.map_err(|_| MinerError::ExternalSolver("failed to decode solution."))?;
+
+		// unlike the local miner, the solution cannot be trimmed here. It must fit as-is.
+		ensure!(
+			raw_solution.solution.encoded_size() <=
+				<T::MinerConfig as MinerConfig>::MaxLength::get().saturated_into::<usize>(),
+			MinerError::ExternalSolutionTooBig
+		);
+		ensure!(
+			Self::solution_weight_of(&raw_solution, witness)
+				.all_lte(<T::MinerConfig as MinerConfig>::MaxWeight::get()),
+			MinerError::ExternalSolutionTooBig
+		);
+
+		Ok((raw_solution, witness))
+	}
+
 	/// Checks if an execution of the offchain worker is permitted at the given block number, or
 	/// not.
 	///
@@ -1028,6 +1121,7 @@ mod tests {
 	use codec::Decode;
 	use frame_election_provider_support::IndexAssignment;
 	use frame_support::{assert_noop, assert_ok, traits::OffchainWorker};
+	use sp_core::offchain::testing::PendingRequest;
 	use sp_npos_elections::ElectionScore;
 	use sp_runtime::{
 		bounded_vec,
@@ -1862,6 +1956,56 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn miner_uses_external_solver_if_configured() {
+		let (mut ext, _, offchain) = ExtBuilder::default().build_offchainify_with_state(0);
+		ext.execute_with(|| {
+			roll_to_unsigned();
+			assert!(CurrentPhase::<Runtime>::get().is_unsigned());
+
+			let url = "http://localhost:9999/solve";
+			sp_io::offchain::local_storage_set(
+				StorageKind::PERSISTENT,
+				OFFCHAIN_EXTERNAL_SOLVER,
+				url.as_bytes(),
+			);
+			let expect_solver_request = |response: Vec<u8>| {
+				offchain.write().expect_request(PendingRequest {
+					method: "POST".into(),
+					uri: url.into(),
+					headers: vec![("Content-Type".into(), "application/octet-stream".into())],
+					body: (
+						Round::<Runtime>::get(),
+						DesiredTargets::<Runtime>::get().unwrap(),
+						Snapshot::<Runtime>::get().unwrap(),
+					)
+						.encode(),
+					response: Some(response),
+					sent: true,
+					..Default::default()
+				});
+			};
+
+			// the solver replies with a valid solution, which is used as-is.
+			let (solution, witness, _) = MultiPhase::mine_solution().unwrap();
+			expect_solver_request(solution.encode());
+			let (mined, mined_witness, trimming) = MultiPhase::mine_and_check().unwrap();
+			assert_eq!((mined, mined_witness), (solution.clone(), witness));
+			assert!(!trimming.is_trimmed());
+
+			// a solution with a wrong score is rejected, and the miner falls back to mining
+			// locally.
+			let mut wrong_score = solution.clone();
+			wrong_score.score.minimal_stake += 1;
+			expect_solver_request(wrong_score.encode());
+			assert_eq!(MultiPhase::mine_and_check().unwrap().0, solution);
+
+			// same for a reply that cannot be decoded.
+			expect_solver_request(vec![1, 2, 3]);
+			assert_eq!(MultiPhase::mine_and_check().unwrap().0, solution);
+		})
+	}
+
 	#[test]
 	fn trim_assignments_length_does_not_modify_when_short_enough() {
 		ExtBuilder::default().build_and_execute(|| {
@@ -21,7 +21,9 @@
 use alloc::vec::Vec;
 use codec::Decode;
 use frame_benchmarking::v2::*;
-use frame_election_provider_support::{NposSolver, PhragMMS, SequentialPhragmen};
+use frame_election_provider_support::{
+	NposSolver, PhragMMS, SequentialPhragmen, SingleTransferableVote,
+};
 use sp_runtime::Perbill;
 
 const VOTERS: [u32; 2] = [1_000, 2_000];
@@ -56,6 +58,31 @@ fn set_up_voters_targets<AccountId: Decode + Clone>(
 	(voters, targets)
 }
 
+// Each voter ranks `degree` of the targets, starting from a different one, so that the counts are
+// spread and almost every target has to be eliminated in a round of its own.
+fn set_up_ranked_voters_targets<AccountId: Decode + Clone>(
+	voters_len: u32,
+	targets_len: u32,
+	degree: usize,
+) -> (Vec<(AccountId, u64, Vec<AccountId>)>, Vec<AccountId>) {
+	let targets = (0..targets_len)
+		.map(|i| frame_benchmarking::account::<AccountId>("Target", i, SEED))
+		.collect::<Vec<_>>();
+	assert!(targets.len() > degree, "we should always have enough targets to rank");
+
+	let voters = (0..voters_len)
+		.map(|i| {
+			let voter = frame_benchmarking::account::<AccountId>("Voter", i, SEED);
+			let ranked = (0..degree)
+				.map(|k| targets[(i as usize + k) % targets.len()].clone())
+				.collect::<Vec<_>>();
+			(voter, 1_000, ranked)
+		})
+		.collect::<Vec<_>>();
+
+	(voters, targets)
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -99,4 +126,25 @@ mod benchmarks {
 
 		assert!(result.is_ok());
 	}
+
+	#[benchmark]
+	fn stv(
+		// Number of votes in snapshot.
+		v: Linear<{ VOTERS[0] }, { VOTERS[1] }>,
+		// Number of targets in snapshot.
+		t: Linear<{ TARGETS[0] }, { TARGETS[1] }>,
+		// Number of votes per voter (ie the degree).
+		d: Linear<{ VOTES_PER_VOTER[0] }, { VOTES_PER_VOTER[1] }>,
+	) {
+		let (voters, targets) = set_up_ranked_voters_targets::<T::AccountId>(v, t, d as _);
+		let result;
+
+		#[block]
+		{
+			result =
+				SingleTransferableVote::<T::AccountId, Perbill>::solve(d as _, targets, voters);
+		}
+
+		assert!(result.is_ok());
+	}
 }
@@ -74,6 +74,7 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn phragmen(v: u32, t: u32, d: u32, ) -> Weight;
 	fn phragmms(v: u32, t: u32, d: u32, ) -> Weight;
+	fn stv(v: u32, t: u32, d: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_election_provider_support_benchmarking` using the Substrate node and recommended hardware.
@@ -107,6 +108,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 15_344_440
 			.saturating_add(Weight::from_parts(1_672_952_586, 0).saturating_mul(d.into()))
 	}
+	/// The range of component `v` is `[1000, 2000]`.
+	/// The range of component `t` is `[500, 1000]`.
+	/// The range of component `d` is `[5, 16]`.
+	fn stv(v: u32, t: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_286_114_000 picoseconds.
+		Weight::from_parts(12_417_389_000, 0)
+			// Standard Error: 9_417_528
+			.saturating_add(Weight::from_parts(231_618_402, 0).saturating_mul(v.into()))
+			// Standard Error: 18_835_057
+			.saturating_add(Weight::from_parts(486_027_331, 0).saturating_mul(t.into()))
+			// Standard Error: 856_149_310
+			.saturating_add(Weight::from_parts(33_108_624_117, 0).saturating_mul(d.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -139,4 +156,20 @@ impl WeightInfo for () {
 			// Standard Error: 15_344_440
 			.saturating_add(Weight::from_parts(1_672_952_586, 0).saturating_mul(d.into()))
 	}
+	/// The range of component `v` is `[1000, 2000]`.
+	/// The range of component `t` is `[500, 1000]`.
+	/// The range of component `d` is `[5, 16]`.
+	fn stv(v: u32, t: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_286_114_000 picoseconds.
+		Weight::from_parts(12_417_389_000, 0)
+			// Standard Error: 9_417_528
+			.saturating_add(Weight::from_parts(231_618_402, 0).saturating_mul(v.into()))
+			// Standard Error: 18_835_057
+			.saturating_add(Weight::from_parts(486_027_331, 0).saturating_mul(t.into()))
+			// Standard Error: 856_149_310
+			.saturating_add(Weight::from_parts(33_108_624_117, 0).saturating_mul(d.into()))
+	}
 }
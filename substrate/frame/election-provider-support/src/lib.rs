@@ -667,6 +667,89 @@ impl<AccountId: IdentifierT, Accuracy: PerThing128, Balancing: Get<Option<Balanc
 	}
 }
 
+/// A wrapper for [`sp_npos_elections::approval_voting()`] that implements [`NposSolver`]. See the
+/// documentation of [`sp_npos_elections::approval_voting()`] for more info.
+///
+/// The weight is approximated with that of [`SequentialPhragmen`].
+pub struct ApprovalVoting<AccountId, Accuracy>(core::marker::PhantomData<(AccountId, Accuracy)>);
+
+impl<AccountId: IdentifierT, Accuracy: PerThing128> NposSolver
+	for ApprovalVoting<AccountId, Accuracy>
+{
+	type AccountId = AccountId;
+	type Accuracy = Accuracy;
+	type Error = sp_npos_elections::Error;
+	fn solve(
+		winners: usize,
+		targets: Vec<Self::AccountId>,
+		voters: Vec<(Self::AccountId, VoteWeight, impl IntoIterator<Item = Self::AccountId>)>,
+	) -> Result<ElectionResult<Self::AccountId, Self::Accuracy>, Self::Error> {
+		sp_npos_elections::approval_voting(winners, targets, voters)
+	}
+
+	fn weight<T: WeightInfo>(voters: u32, targets: u32, vote_degree: u32) -> Weight {
+		T::phragmen(voters, targets, vote_degree)
+	}
+}
+
+/// A wrapper for [`sp_npos_elections::stv()`] that implements [`NposSolver`]. See the
+/// documentation of [`sp_npos_elections::stv()`] for more info.
+///
+/// Note that the votes of each voter are interpreted as a ranked list of preferences, thus the
+/// data provider must preserve their order.
+///
+/// Each round elects or eliminates at least one target and recounts all the votes, so its weight
+/// is measured separately, with a round for almost every target.
+pub struct SingleTransferableVote<AccountId, Accuracy>(
+	core::marker::PhantomData<(AccountId, Accuracy)>,
+);
+
+impl<AccountId: IdentifierT, Accuracy: PerThing128> NposSolver
+	for SingleTransferableVote<AccountId, Accuracy>
+{
+	type AccountId = AccountId;
+	type Accuracy = Accuracy;
+	type Error = sp_npos_elections::Error;
+	fn solve(
+		winners: usize,
+		targets: Vec<Self::AccountId>,
+		voters: Vec<(Self::AccountId, VoteWeight, impl IntoIterator<Item = Self::AccountId>)>,
+	) -> Result<ElectionResult<Self::AccountId, Self::Accuracy>, Self::Error> {
+		sp_npos_elections::stv(winners, targets, voters)
+	}
+
+	fn weight<T: WeightInfo>(voters: u32, targets: u32, vote_degree: u32) -> Weight {
+		T::stv(voters, targets, vote_degree)
+	}
+}
+
+/// A wrapper for [`sp_npos_elections::fast_greedy`] that implements [`NposSolver`]. See the
+/// documentation of [`sp_npos_elections::fast_greedy`] for more info.
+///
+/// The weight is measured with that of [`SequentialPhragmen`], which is an upper bound for it.
+pub struct FastGreedy<AccountId, Accuracy, Balancing = ()>(
+	core::marker::PhantomData<(AccountId, Accuracy, Balancing)>,
+);
+
+impl<AccountId: IdentifierT, Accuracy: PerThing128, Balancing: Get<Option<BalancingConfig>>>
+	NposSolver for FastGreedy<AccountId, Accuracy, Balancing>
+{
+	type AccountId = AccountId;
+	type Accuracy = Accuracy;
+	type Error = sp_npos_elections::Error;
+	fn solve(
+		winners: usize,
+		targets: Vec<Self::AccountId>,
+		voters: Vec<(Self::AccountId, VoteWeight, impl IntoIterator<Item = Self::AccountId>)>,
+	) -> Result<ElectionResult<Self::AccountId, Self::Accuracy>, Self::Error> {
+		sp_npos_elections::fast_greedy(winners, targets, voters, Balancing::get())
+	}
+
+	fn weight<T: WeightInfo>(voters: u32, targets: u32, vote_degree: u32) -> Weight {
+		T::phragmen(voters, targets, vote_degree)
+	}
+}
+
 /// A voter, at the level of abstraction of this crate.
 pub type Voter<AccountId, Bound> = (AccountId, VoteWeight, BoundedVec<AccountId, Bound>);
 
@@ -47,6 +47,7 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn phragmen(v: u32, t: u32, d: u32, ) -> Weight;
 	fn phragmms(v: u32, t: u32, d: u32, ) -> Weight;
+	fn stv(v: u32, t: u32, d: u32, ) -> Weight;
 }
 
 /// Weights for pallet_election_provider_support_benchmarking using the Substrate node and recommended hardware.
@@ -70,6 +71,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 6_649_000
 			.saturating_add(Weight::from_parts(1_711_424_000 as u64, 0).saturating_mul(d as u64))
 	}
+	fn stv(v: u32, t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0 as u64, 0)
+			// Standard Error: 9_417_000
+			.saturating_add(Weight::from_parts(231_618_000 as u64, 0).saturating_mul(v as u64))
+			// Standard Error: 18_835_000
+			.saturating_add(Weight::from_parts(486_027_000 as u64, 0).saturating_mul(t as u64))
+			// Standard Error: 856_149_000
+			.saturating_add(Weight::from_parts(33_108_624_000 as u64, 0).saturating_mul(d as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -92,4 +102,13 @@ impl WeightInfo for () {
 			// Standard Error: 6_649_000
 			.saturating_add(Weight::from_parts(1_711_424_000 as u64, 0).saturating_mul(d as u64))
 	}
+	fn stv(v: u32, t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0 as u64, 0)
+			// Standard Error: 9_417_000
+			.saturating_add(Weight::from_parts(231_618_000 as u64, 0).saturating_mul(v as u64))
+			// Standard Error: 18_835_000
+			.saturating_add(Weight::from_parts(486_027_000 as u64, 0).saturating_mul(t as u64))
+			// Standard Error: 856_149_000
+			.saturating_add(Weight::from_parts(33_108_624_000 as u64, 0).saturating_mul(d as u64))
+	}
 }
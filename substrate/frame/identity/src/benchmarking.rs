@@ -114,6 +114,24 @@ fn add_sub_accounts<T: Config>(
 	Ok(subs)
 }
 
+// The registrar `registrar` at index `reg_index` attests to all fields of the identity of `who`.
+fn add_attestation<T: Config>(
+	registrar: &T::AccountId,
+	reg_index: RegistrarIndex,
+	who: &T::AccountId,
+) -> Result<(), DispatchError> {
+	let identity = T::Hashing::hash_of(&IdentityOf::<T>::get(who).ok_or("No identity")?.info);
+	Identity::<T>::provide_attestation(
+		RawOrigin::Signed(registrar.clone()).into(),
+		reg_index,
+		T::Lookup::unlookup(who.clone()),
+		T::IdentityInformation::all_fields(),
+		identity,
+		BlockNumberFor::<T>::max_value(),
+		None,
+	)
+}
+
 fn bench_suffix() -> Vec<u8> {
 	b"bench".to_vec()
 }
@@ -252,7 +270,7 @@ mod benchmarks {
 		let info = T::IdentityInformation::create_identity_info();
 		Identity::<T>::set_identity(caller_origin.clone(), Box::new(info.clone()))?;
 
-		// User requests judgement from all the registrars, and they approve
+		// User requests judgement from all the registrars, and they approve and attest
 		for i in 0..r {
 			let registrar: T::AccountId = account("registrar", i, SEED);
 			let balance_to_use = T::Currency::minimum_balance() * 10u32.into();
@@ -260,12 +278,13 @@ mod benchmarks {
 
 			Identity::<T>::request_judgement(caller_origin.clone(), i, 10u32.into())?;
 			Identity::<T>::provide_judgement(
-				RawOrigin::Signed(registrar).into(),
+				RawOrigin::Signed(registrar.clone()).into(),
 				i,
 				caller_lookup.clone(),
 				Judgement::Reasonable,
 				T::Hashing::hash_of(&info),
 			)?;
+			add_attestation::<T>(&registrar, i, &caller)?;
 		}
 
 		ensure!(IdentityOf::<T>::contains_key(&caller), "Identity does not exist.");
@@ -274,6 +293,10 @@ mod benchmarks {
 		_(RawOrigin::Signed(caller.clone()));
 
 		ensure!(!IdentityOf::<T>::contains_key(&caller), "Identity not cleared.");
+		ensure!(
+			AttestationsOf::<T>::iter_prefix(&caller).next().is_none(),
+			"Attestations not cleared."
+		);
 		Ok(())
 	}
 
@@ -466,7 +489,7 @@ mod benchmarks {
 		Identity::<T>::set_identity(target_origin.clone(), Box::new(info.clone()))?;
 		add_sub_accounts::<T>(&target, s)?;
 
-		// User requests judgement from all the registrars, and they approve
+		// User requests judgement from all the registrars, and they approve and attest
 		for i in 0..r {
 			let registrar: T::AccountId = account("registrar", i, SEED);
 			let balance_to_use = T::Currency::minimum_balance() * 10u32.into();
@@ -474,12 +497,13 @@ mod benchmarks {
 
 			Identity::<T>::request_judgement(target_origin.clone(), i, 10u32.into())?;
 			Identity::<T>::provide_judgement(
-				RawOrigin::Signed(registrar).into(),
+				RawOrigin::Signed(registrar.clone()).into(),
 				i,
 				target_lookup.clone(),
 				Judgement::Reasonable,
 				T::Hashing::hash_of(&info),
 			)?;
+			add_attestation::<T>(&registrar, i, &target)?;
 		}
 
 		ensure!(IdentityOf::<T>::contains_key(&target), "Identity not set");
@@ -491,6 +515,10 @@ mod benchmarks {
 		_(origin as T::RuntimeOrigin, target_lookup);
 
 		ensure!(!IdentityOf::<T>::contains_key(&target), "Identity not removed");
+		ensure!(
+			AttestationsOf::<T>::iter_prefix(&target).next().is_none(),
+			"Attestations not removed"
+		);
 
 		Ok(())
 	}
@@ -873,6 +901,118 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn provide_attestation(
+		r: Linear<1, { T::MaxRegistrars::get() }>,
+	) -> Result<(), BenchmarkError> {
+		add_registrars::<T>(r - 1)?;
+
+		// The attesting registrar signs off-chain.
+		let public = sr25519_generate(0.into(), None);
+		let registrar: T::AccountId = MultiSigner::Sr25519(public).into_account().into();
+		let registrar_origin = T::RegistrarOrigin::try_successful_origin()
+			.expect("RegistrarOrigin has no successful origin required for the benchmark");
+		Identity::<T>::add_registrar(registrar_origin, T::Lookup::unlookup(registrar))?;
+		let reg_index = r - 1;
+
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let _ = T::Currency::make_free_balance_be(&target, BalanceOf::<T>::max_value());
+		let info = T::IdentityInformation::create_identity_info();
+		Identity::<T>::set_identity(RawOrigin::Signed(target.clone()).into(), Box::new(info))?;
+		let identity = T::Hashing::hash_of(&IdentityOf::<T>::get(&target).unwrap().info);
+
+		let attestation = Attestation {
+			fields: T::IdentityInformation::all_fields(),
+			identity,
+			expiry: BlockNumberFor::<T>::max_value(),
+		};
+		// Sign the wrapped payload, such that both signature checks are performed.
+		let payload = Identity::<T>::attestation_payload(&target, reg_index, &attestation);
+		let wrapped = [&b"<Bytes>"[..], &payload[..], &b"</Bytes>"[..]].concat();
+		let signature =
+			MultiSignature::Sr25519(sr25519_sign(0.into(), &public, &wrapped[..]).unwrap());
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			reg_index,
+			target_lookup,
+			attestation.fields.clone(),
+			identity,
+			attestation.expiry,
+			Some(signature.into()),
+		);
+
+		assert_last_event::<T>(
+			Event::<T>::AttestationProvided {
+				target: target.clone(),
+				registrar_index: reg_index,
+				expiry: attestation.expiry,
+			}
+			.into(),
+		);
+		assert_eq!(AttestationsOf::<T>::get(&target, reg_index), Some(attestation));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_attestation(r: Linear<1, { T::MaxRegistrars::get() }>) -> Result<(), BenchmarkError> {
+		add_registrars::<T>(r)?;
+		let reg_index = r - 1;
+		let registrar: T::AccountId = account("registrar", reg_index, SEED);
+
+		let target: T::AccountId = account("target", 0, SEED);
+		let _ = T::Currency::make_free_balance_be(&target, BalanceOf::<T>::max_value());
+		let info = T::IdentityInformation::create_identity_info();
+		Identity::<T>::set_identity(RawOrigin::Signed(target.clone()).into(), Box::new(info))?;
+		add_attestation::<T>(&registrar, reg_index, &target)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(registrar), reg_index, T::Lookup::unlookup(target.clone()));
+
+		assert_last_event::<T>(
+			Event::<T>::AttestationRevoked { target: target.clone(), registrar_index: reg_index }
+				.into(),
+		);
+		assert!(!AttestationsOf::<T>::contains_key(&target, reg_index));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_stale_attestation() -> Result<(), BenchmarkError> {
+		let target: T::AccountId = account("target", 0, SEED);
+		let _ = T::Currency::make_free_balance_be(&target, BalanceOf::<T>::max_value());
+		let info = T::IdentityInformation::create_identity_info();
+		Identity::<T>::set_identity(RawOrigin::Signed(target.clone()).into(), Box::new(info))?;
+
+		// The attestation has not expired but is about other identity information, such that the
+		// identity has to be read and hashed.
+		AttestationsOf::<T>::insert(
+			&target,
+			0,
+			Attestation {
+				fields: T::IdentityInformation::all_fields(),
+				identity: Default::default(),
+				expiry: BlockNumberFor::<T>::max_value(),
+			},
+		);
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), T::Lookup::unlookup(target.clone()), 0);
+
+		assert_last_event::<T>(
+			Event::<T>::StaleAttestationRemoved { target: target.clone(), registrar_index: 0 }
+				.into(),
+		);
+		assert!(!AttestationsOf::<T>::contains_key(&target, 0));
+		Ok(())
+	}
+
 	#[benchmark]
 	fn migration_v2_authority_step() -> Result<(), BenchmarkError> {
 		let setup = LazyMigrationV1ToV2::<T>::setup_benchmark_env_for_migration();
@@ -40,6 +40,15 @@
 //! The number of registrars should be limited, and the deposit made sufficiently large, to ensure
 //! no state-bloat attack is viable.
 //!
+//! ### Attestations
+//!
+//! Beyond judgements, which are about an identity as a whole, registrars can attest to particular
+//! fields of an identity until some expiry. Attestations are signed by the registrar off-chain,
+//! over the payload given by [`Pallet::attestation_payload`], such that anyone can submit them. An
+//! attestation is only valid for the identity information that it has been made for, and can be
+//! revoked by the registrar at any time. The fields currently attested for an account can be
+//! queried with [`Pallet::attested_fields`].
+//!
 //! ### Usernames
 //!
 //! The pallet provides functionality for username authorities to issue usernames, which are
@@ -77,6 +86,8 @@
 //! * `remove_expired_approval` - Remove a username that was issued but never accepted.
 //! * `set_primary_username` - Set a given username as an account's primary.
 //! * `remove_username` - Remove a username after its grace period has ended.
+//! * `remove_stale_attestation` - Remove an attestation which has expired or is about outdated
+//!   identity information.
 //!
 //! #### For General Users with Sub-Identities
 //! * `set_subs` - Set the sub-accounts of an identity.
@@ -89,6 +100,8 @@
 //! * `set_fee` - Set the fee required to be paid for a judgement to be given by the registrar.
 //! * `set_fields` - Set the fields that a registrar cares about in their judgements.
 //! * `provide_judgement` - Provide a judgement to an identity.
+//! * `provide_attestation` - Attest to some fields of an identity, possibly through a signature.
+//! * `revoke_attestation` - Revoke an attestation, including those signed but not yet provided.
 //!
 //! #### For Username Authorities
 //! * `set_username_for` - Set a username for a given account. The account must approve it.
@@ -133,7 +146,8 @@ use sp_runtime::traits::{
 	AppendZerosInput, Hash, IdentifyAccount, Saturating, StaticLookup, Verify, Zero,
 };
 pub use types::{
-	Data, IdentityInformationProvider, Judgement, RegistrarIndex, RegistrarInfo, Registration,
+	Attestation, Data, IdentityInformationProvider, Judgement, RegistrarIndex, RegistrarInfo,
+	Registration,
 };
 pub use weights::WeightInfo;
 
@@ -144,6 +158,13 @@ type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
 >>::NegativeImbalance;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 type ProviderOf<T> = Provider<BalanceOf<T>>;
+type FieldsIdentifierOf<T> =
+	<<T as Config>::IdentityInformation as IdentityInformationProvider>::FieldsIdentifier;
+pub type AttestationOf<T> =
+	Attestation<FieldsIdentifierOf<T>, <T as frame_system::Config>::Hash, BlockNumberFor<T>>;
+
+/// The context of the payloads signed by registrars for their attestations.
+pub const ATTESTATION_CONTEXT: &[u8] = b"identity:attestation";
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -338,6 +359,39 @@ pub mod pallet {
 	pub type UnbindingUsernames<T: Config> =
 		StorageMap<_, Blake2_128Concat, Username<T>, BlockNumberFor<T>, OptionQuery>;
 
+	/// The attestations of registrars over particular fields of the identity of an account.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type AttestationsOf<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		RegistrarIndex,
+		AttestationOf<T>,
+		OptionQuery,
+	>;
+
+	/// The number of attestations that a registrar has provided or revoked for an account.
+	/// Attestations are signed over the current nonce, such that a superseded or revoked
+	/// attestation cannot be provided again.
+	///
+	/// The nonces of an account are kept when its identity is removed, such that an attestation
+	/// revoked before then cannot be provided again should the same identity information be set.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type AttestationNonce<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		RegistrarIndex,
+		u32,
+		ValueQuery,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Too many subs-accounts.
@@ -401,6 +455,14 @@ pub mod pallet {
 		/// The action cannot be performed because of insufficient privileges (e.g. authority
 		/// trying to unbind a username provided by the system).
 		InsufficientPrivileges,
+		/// The attestation has already expired.
+		AttestationExpired,
+		/// Some of the attested fields are not set in the identity.
+		AttestedFieldsNotSet,
+		/// No attestation was found.
+		NoAttestation,
+		/// The attestation cannot be removed because it is still valid.
+		AttestationStillValid,
 	}
 
 	#[pallet::event]
@@ -452,6 +514,17 @@ pub mod pallet {
 		UsernameRemoved { username: Username<T> },
 		/// A username has been killed.
 		UsernameKilled { username: Username<T> },
+		/// A registrar has attested to some fields of the identity of `target`.
+		AttestationProvided {
+			target: T::AccountId,
+			registrar_index: RegistrarIndex,
+			expiry: BlockNumberFor<T>,
+		},
+		/// A registrar has revoked its attestation of the identity of `target`.
+		AttestationRevoked { target: T::AccountId, registrar_index: RegistrarIndex },
+		/// An attestation which had expired or was about outdated identity information was
+		/// removed.
+		StaleAttestationRemoved { target: T::AccountId, registrar_index: RegistrarIndex },
 	}
 
 	#[pallet::call]
@@ -632,6 +705,7 @@ pub mod pallet {
 			for sub in sub_ids.iter() {
 				SuperOf::<T>::remove(sub);
 			}
+			let attesters = Self::clear_attestations(&sender);
 
 			let err_amount = T::Currency::unreserve(&sender, deposit);
 			debug_assert!(err_amount.is_zero());
@@ -640,7 +714,7 @@ pub mod pallet {
 
 			#[allow(deprecated)]
 			Ok(Some(T::WeightInfo::clear_identity(
-				id.judgements.len() as u32,
+				(id.judgements.len() as u32).max(attesters),
 				sub_ids.len() as u32,
 			))
 			.into())
@@ -939,14 +1013,18 @@ pub mod pallet {
 			for sub in sub_ids.iter() {
 				SuperOf::<T>::remove(sub);
 			}
+			let attesters = Self::clear_attestations(&target);
 			// Slash their deposit from them.
 			T::Slashed::on_unbalanced(T::Currency::slash_reserved(&target, deposit).0);
 
 			Self::deposit_event(Event::IdentityKilled { who: target, deposit });
 
 			#[allow(deprecated)]
-			Ok(Some(T::WeightInfo::kill_identity(id.judgements.len() as u32, sub_ids.len() as u32))
-				.into())
+			Ok(Some(T::WeightInfo::kill_identity(
+				(id.judgements.len() as u32).max(attesters),
+				sub_ids.len() as u32,
+			))
+			.into())
 		}
 
 		/// Add the given account to the sender's subs.
@@ -1376,6 +1454,167 @@ pub mod pallet {
 			Self::deposit_event(Event::UsernameKilled { username });
 			Ok((Some(actual_weight), Pays::No).into())
 		}
+
+		/// Attest to some fields of the identity of an account, until some expiry.
+		///
+		/// The dispatch origin for this call must be _Signed_. Unless the sender is the account of
+		/// the registrar whose index is `reg_index`, a `signature` of the registrar over the
+		/// [attestation payload](Pallet::attestation_payload) must be provided.
+		///
+		/// - `reg_index`: the index of the registrar whose attestation is being made.
+		/// - `target`: the account whose identity the attestation is upon. This must be an account
+		///   with a registered identity, with all the `fields` set.
+		/// - `fields`: the attested fields of the identity.
+		/// - `identity`: The hash of the [`IdentityInformationProvider`] for that the attestation
+		///   is provided.
+		/// - `expiry`: the first block in which the attestation is no longer valid.
+		/// - `signature`: the signature of the registrar over the attestation payload.
+		///
+		/// Any prior attestation of the registrar for `target` is replaced.
+		///
+		/// Emits `AttestationProvided` if successful.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::provide_attestation(T::MaxRegistrars::get()))]
+		pub fn provide_attestation(
+			origin: OriginFor<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+			target: AccountIdLookupOf<T>,
+			fields: FieldsIdentifierOf<T>,
+			identity: T::Hash,
+			expiry: BlockNumberFor<T>,
+			signature: Option<T::OffchainSignature>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let registrar = Registrars::<T>::get()
+				.get(reg_index as usize)
+				.and_then(Option::as_ref)
+				.map(|r| r.account.clone())
+				.ok_or(Error::<T>::InvalidIndex)?;
+
+			let attestation = Attestation { fields, identity, expiry };
+			match signature {
+				Some(s) => Self::validate_signature(
+					&Self::attestation_payload(&target, reg_index, &attestation)[..],
+					&s,
+					&registrar,
+				)?,
+				None => ensure!(sender == registrar, Error::<T>::RequiresSignature),
+			}
+
+			ensure!(
+				attestation.expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::AttestationExpired
+			);
+			let id = IdentityOf::<T>::get(&target).ok_or(Error::<T>::InvalidTarget)?;
+			ensure!(
+				T::Hashing::hash_of(&id.info) == attestation.identity,
+				Error::<T>::JudgementForDifferentIdentity
+			);
+			ensure!(
+				id.info.has_identity(attestation.fields.clone()),
+				Error::<T>::AttestedFieldsNotSet
+			);
+
+			AttestationNonce::<T>::mutate(&target, reg_index, |n| n.saturating_inc());
+			AttestationsOf::<T>::insert(&target, reg_index, attestation);
+			Self::deposit_event(Event::AttestationProvided {
+				target,
+				registrar_index: reg_index,
+				expiry,
+			});
+			Ok(())
+		}
+
+		/// Revoke the attestation of a registrar for an account.
+		///
+		/// Attestations which have been signed for `target` but not provided yet are void as well.
+		/// There must be a provided attestation to revoke; signed attestations are otherwise only
+		/// bounded by their expiry.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the account
+		/// of the registrar whose index is `reg_index`.
+		///
+		/// - `reg_index`: the index of the registrar whose attestation is being revoked.
+		/// - `target`: the account whose identity the attestation is upon.
+		///
+		/// Emits `AttestationRevoked` if successful.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::revoke_attestation(T::MaxRegistrars::get()))]
+		pub fn revoke_attestation(
+			origin: OriginFor<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+			target: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			Registrars::<T>::get()
+				.get(reg_index as usize)
+				.and_then(Option::as_ref)
+				.filter(|r| r.account == sender)
+				.ok_or(Error::<T>::InvalidIndex)?;
+			ensure!(
+				AttestationsOf::<T>::contains_key(&target, reg_index),
+				Error::<T>::NoAttestation
+			);
+
+			AttestationNonce::<T>::mutate(&target, reg_index, |n| n.saturating_inc());
+			AttestationsOf::<T>::remove(&target, reg_index);
+			Self::deposit_event(Event::AttestationRevoked { target, registrar_index: reg_index });
+			Ok(())
+		}
+
+		/// Remove an attestation which has expired, or which is about identity information that
+		/// has since been changed or cleared.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: the account whose identity the attestation is upon.
+		/// - `reg_index`: the index of the registrar who made the attestation.
+		///
+		/// Emits `StaleAttestationRemoved` if successful.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::remove_stale_attestation())]
+		pub fn remove_stale_attestation(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let attestation =
+				AttestationsOf::<T>::get(&target, reg_index).ok_or(Error::<T>::NoAttestation)?;
+			ensure!(
+				!Self::is_attestation_valid(&target, &attestation),
+				Error::<T>::AttestationStillValid
+			);
+
+			AttestationsOf::<T>::remove(&target, reg_index);
+			Self::deposit_event(Event::StaleAttestationRemoved {
+				target,
+				registrar_index: reg_index,
+			});
+			Ok(Pays::No.into())
+		}
+	}
+
+	#[pallet::view_functions]
+	impl<T: Config> Pallet<T> {
+		/// The fields of the identity of `who` which are currently attested, along with the index
+		/// of the attesting registrar and the expiry of its attestation.
+		pub fn attested_fields(
+			who: T::AccountId,
+		) -> Vec<(RegistrarIndex, FieldsIdentifierOf<T>, BlockNumberFor<T>)> {
+			let now = frame_system::Pallet::<T>::block_number();
+			let Some(identity) = IdentityOf::<T>::get(&who).map(|id| T::Hashing::hash_of(&id.info))
+			else {
+				return Vec::new()
+			};
+			AttestationsOf::<T>::iter_prefix(&who)
+				.filter(|(_, a)| a.expiry > now && a.identity == identity)
+				.map(|(index, a)| (index, a.fields, a.expiry))
+				.collect()
+		}
 	}
 }
 
@@ -1389,6 +1628,38 @@ impl<T: Config> Pallet<T> {
 			.collect()
 	}
 
+	/// The payload that the registrar of index `reg_index` signs to attest to the identity of
+	/// `target`.
+	///
+	/// This includes the current attestation nonce of the registrar for `target`, thus it changes
+	/// whenever an attestation is provided or revoked.
+	pub fn attestation_payload(
+		target: &T::AccountId,
+		reg_index: RegistrarIndex,
+		attestation: &AttestationOf<T>,
+	) -> Vec<u8> {
+		let nonce = AttestationNonce::<T>::get(target, reg_index);
+		(ATTESTATION_CONTEXT, target, reg_index, attestation, nonce).encode()
+	}
+
+	/// Remove all attestations upon the identity of `who`.
+	///
+	/// The attestation nonces are kept, so that signatures over a prior nonce remain void.
+	///
+	/// Returns the number of registrars with an attestation for `who`.
+	fn clear_attestations(who: &T::AccountId) -> u32 {
+		// There is at most one attestation per registrar.
+		AttestationsOf::<T>::clear_prefix(who, T::MaxRegistrars::get(), None).unique
+	}
+
+	/// Whether `attestation` is still valid for the identity of `who`, i.e. it has not expired and
+	/// the identity information has not changed.
+	fn is_attestation_valid(who: &T::AccountId, attestation: &AttestationOf<T>) -> bool {
+		attestation.expiry > frame_system::Pallet::<T>::block_number() &&
+			IdentityOf::<T>::get(who)
+				.is_some_and(|id| T::Hashing::hash_of(&id.info) == attestation.identity)
+	}
+
 	/// Calculate the deposit required for a number of `sub` accounts.
 	fn subs_deposit(subs: u32) -> BalanceOf<T> {
 		T::SubAccountDeposit::get().saturating_mul(BalanceOf::<T>::from(subs))
@@ -1562,6 +1833,9 @@ impl<T: Config> Pallet<T> {
 			SuperOf::<T>::remove(sub);
 		}
 
+		// attestations
+		let _ = Self::clear_attestations(who);
+
 		// unreserve any deposits
 		let deposit = id.total_deposit().saturating_add(subs_deposit);
 		let err_amount = T::Currency::unreserve(&who, deposit);
@@ -840,6 +840,363 @@ fn test_has_identity() {
 	});
 }
 
+#[test]
+fn signed_attestation_should_work() {
+	new_test_ext().execute_with(|| {
+		let [one, _, _, _, ten, _, _, _] = accounts();
+		let public = sr25519_generate(0.into(), None);
+		let registrar: AccountIdOf<Test> = MultiSigner::Sr25519(public).into_account().into();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), registrar.clone()));
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+
+		let fields = IdentityField::Display as u64 | IdentityField::Legal as u64;
+		let attestation =
+			Attestation { fields, identity: BlakeTwo256::hash_of(&infoof_ten()), expiry: 10 };
+		let payload = Identity::attestation_payload(&ten, 0, &attestation);
+		let signature = MultiSignature::Sr25519(sr25519_sign(0.into(), &public, &payload).unwrap());
+
+		// Anyone may provide the attestation signed by the registrar, but not without signature.
+		assert_noop!(
+			Identity::provide_attestation(
+				RuntimeOrigin::signed(one.clone()),
+				0,
+				ten.clone(),
+				fields,
+				attestation.identity,
+				10,
+				None,
+			),
+			Error::<Test>::RequiresSignature
+		);
+		assert_noop!(
+			Identity::provide_attestation(
+				RuntimeOrigin::signed(one.clone()),
+				0,
+				ten.clone(),
+				fields,
+				attestation.identity,
+				11,
+				Some(signature.clone()),
+			),
+			Error::<Test>::InvalidSignature
+		);
+		assert_ok!(Identity::provide_attestation(
+			RuntimeOrigin::signed(one.clone()),
+			0,
+			ten.clone(),
+			fields,
+			attestation.identity,
+			10,
+			Some(signature.clone()),
+		));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::AttestationProvided {
+			target: ten.clone(),
+			registrar_index: 0,
+			expiry: 10,
+		}));
+		assert_eq!(AttestationsOf::<Test>::get(&ten, 0), Some(attestation.clone()));
+		assert_eq!(Identity::attested_fields(ten.clone()), vec![(0, fields, 10)]);
+
+		// The attestation cannot be provided again.
+		assert_noop!(
+			Identity::provide_attestation(
+				RuntimeOrigin::signed(one.clone()),
+				0,
+				ten.clone(),
+				fields,
+				attestation.identity,
+				10,
+				Some(signature),
+			),
+			Error::<Test>::InvalidSignature
+		);
+
+		// The attestation expires.
+		assert_noop!(
+			Identity::remove_stale_attestation(RuntimeOrigin::signed(one.clone()), ten.clone(), 0),
+			Error::<Test>::AttestationStillValid
+		);
+		System::set_block_number(10);
+		assert_eq!(Identity::attested_fields(ten.clone()), vec![]);
+		assert_ok!(Identity::remove_stale_attestation(
+			RuntimeOrigin::signed(one.clone()),
+			ten.clone(),
+			0
+		));
+		assert_eq!(AttestationsOf::<Test>::get(&ten, 0), None);
+		assert_noop!(
+			Identity::remove_stale_attestation(RuntimeOrigin::signed(one), ten, 0),
+			Error::<Test>::NoAttestation
+		);
+	});
+}
+
+#[test]
+fn attestation_validity_should_work() {
+	new_test_ext().execute_with(|| {
+		let [one, _, three, _, ten, _, _, _] = accounts();
+		let identity = BlakeTwo256::hash_of(&infoof_ten());
+		let display = IdentityField::Display as u64;
+		assert_noop!(
+			Identity::provide_attestation(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				display,
+				identity,
+				10,
+				None,
+			),
+			Error::<Test>::InvalidIndex
+		);
+
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_noop!(
+			Identity::provide_attestation(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				display,
+				identity,
+				10,
+				None,
+			),
+			Error::<Test>::InvalidTarget
+		);
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_noop!(
+			Identity::provide_attestation(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				display,
+				H256::random(),
+				10,
+				None,
+			),
+			Error::<Test>::JudgementForDifferentIdentity
+		);
+		assert_noop!(
+			Identity::provide_attestation(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				IdentityField::Email as u64,
+				identity,
+				10,
+				None,
+			),
+			Error::<Test>::AttestedFieldsNotSet
+		);
+		assert_noop!(
+			Identity::provide_attestation(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				display,
+				identity,
+				1,
+				None,
+			),
+			Error::<Test>::AttestationExpired
+		);
+
+		// The registrar may provide its attestation directly.
+		assert_ok!(Identity::provide_attestation(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			display,
+			identity,
+			10,
+			None,
+		));
+		assert_eq!(Identity::attested_fields(ten.clone()), vec![(0, display, 10)]);
+
+		// Changing the identity voids the attestation.
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_twenty())
+		));
+		assert_eq!(Identity::attested_fields(ten.clone()), vec![]);
+		assert_ok!(Identity::remove_stale_attestation(RuntimeOrigin::signed(one), ten.clone(), 0));
+
+		// Only the registrar may revoke its attestation.
+		let identity = BlakeTwo256::hash_of(&infoof_twenty());
+		assert_ok!(Identity::provide_attestation(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			display,
+			identity,
+			10,
+			None,
+		));
+		assert_noop!(
+			Identity::revoke_attestation(RuntimeOrigin::signed(ten.clone()), 0, ten.clone()),
+			Error::<Test>::InvalidIndex
+		);
+		assert_ok!(Identity::revoke_attestation(RuntimeOrigin::signed(three), 0, ten.clone()));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::AttestationRevoked {
+			target: ten.clone(),
+			registrar_index: 0,
+		}));
+		assert_eq!(Identity::attested_fields(ten), vec![]);
+	});
+}
+
+#[test]
+fn revoked_attestation_cannot_be_provided() {
+	new_test_ext().execute_with(|| {
+		let [one, _, _, _, ten, _, _, _] = accounts();
+		let public = sr25519_generate(0.into(), None);
+		let registrar: AccountIdOf<Test> = MultiSigner::Sr25519(public).into_account().into();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), registrar.clone()));
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+
+		let attestation = Attestation {
+			fields: IdentityField::Display as u64,
+			identity: BlakeTwo256::hash_of(&infoof_ten()),
+			expiry: 10,
+		};
+		let payload = Identity::attestation_payload(&ten, 0, &attestation);
+		let signature = MultiSignature::Sr25519(sr25519_sign(0.into(), &public, &payload).unwrap());
+
+		// Nothing to revoke before the attestation is provided, and no nonce is written.
+		assert_noop!(
+			Identity::revoke_attestation(RuntimeOrigin::signed(registrar.clone()), 0, ten.clone()),
+			Error::<Test>::NoAttestation
+		);
+		assert!(!AttestationNonce::<Test>::contains_key(&ten, 0));
+
+		assert_ok!(Identity::provide_attestation(
+			RuntimeOrigin::signed(one.clone()),
+			0,
+			ten.clone(),
+			attestation.fields,
+			attestation.identity,
+			attestation.expiry,
+			Some(signature),
+		));
+
+		// The registrar signs a renewal, then revokes before the renewal is provided.
+		let renewal = Attestation { expiry: 20, ..attestation };
+		let payload = Identity::attestation_payload(&ten, 0, &renewal);
+		let signature = MultiSignature::Sr25519(sr25519_sign(0.into(), &public, &payload).unwrap());
+		assert_ok!(Identity::revoke_attestation(RuntimeOrigin::signed(registrar), 0, ten.clone()));
+		assert_noop!(
+			Identity::provide_attestation(
+				RuntimeOrigin::signed(one),
+				0,
+				ten.clone(),
+				renewal.fields,
+				renewal.identity,
+				renewal.expiry,
+				Some(signature),
+			),
+			Error::<Test>::InvalidSignature
+		);
+		assert_eq!(Identity::attested_fields(ten), vec![]);
+	});
+}
+
+#[test]
+fn clearing_identity_removes_attestations() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, twenty, _, _] = accounts();
+		let display = IdentityField::Display as u64;
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		for (who, info) in [(ten.clone(), infoof_ten()), (twenty.clone(), infoof_twenty())] {
+			assert_ok!(Identity::set_identity(
+				RuntimeOrigin::signed(who.clone()),
+				Box::new(info.clone())
+			));
+			assert_ok!(Identity::provide_attestation(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				who.clone(),
+				display,
+				BlakeTwo256::hash_of(&info),
+				10,
+				None,
+			));
+			assert!(AttestationsOf::<Test>::contains_key(&who, 0));
+			assert_eq!(AttestationNonce::<Test>::get(&who, 0), 1);
+		}
+
+		// The nonces are kept, such that prior signatures remain void.
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten.clone())));
+		assert!(!AttestationsOf::<Test>::contains_key(&ten, 0));
+		assert_eq!(AttestationNonce::<Test>::get(&ten, 0), 1);
+
+		assert_ok!(Identity::kill_identity(RuntimeOrigin::root(), twenty.clone()));
+		assert!(!AttestationsOf::<Test>::contains_key(&twenty, 0));
+		assert_eq!(AttestationNonce::<Test>::get(&twenty, 0), 1);
+	});
+}
+
+#[test]
+fn revoked_attestation_cannot_be_provided_after_identity_is_set_again() {
+	new_test_ext().execute_with(|| {
+		let [one, _, _, _, ten, _, _, _] = accounts();
+		let public = sr25519_generate(0.into(), None);
+		let registrar: AccountIdOf<Test> = MultiSigner::Sr25519(public).into_account().into();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), registrar.clone()));
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+
+		let attestation = Attestation {
+			fields: IdentityField::Display as u64,
+			identity: BlakeTwo256::hash_of(&infoof_ten()),
+			expiry: 10,
+		};
+		let payload = Identity::attestation_payload(&ten, 0, &attestation);
+		let signature = MultiSignature::Sr25519(sr25519_sign(0.into(), &public, &payload).unwrap());
+		assert_ok!(Identity::provide_attestation(
+			RuntimeOrigin::signed(one.clone()),
+			0,
+			ten.clone(),
+			attestation.fields,
+			attestation.identity,
+			attestation.expiry,
+			Some(signature.clone()),
+		));
+		assert_ok!(Identity::revoke_attestation(RuntimeOrigin::signed(registrar), 0, ten.clone()));
+
+		// Clearing and setting the same identity information again does not revive the revoked
+		// attestation.
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten.clone())));
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_noop!(
+			Identity::provide_attestation(
+				RuntimeOrigin::signed(one),
+				0,
+				ten.clone(),
+				attestation.fields,
+				attestation.identity,
+				attestation.expiry,
+				Some(signature),
+			),
+			Error::<Test>::InvalidSignature
+		);
+		assert_eq!(Identity::attested_fields(ten), vec![]);
+	});
+}
+
 #[test]
 fn reap_identity_works() {
 	new_test_ext().execute_with(|| {
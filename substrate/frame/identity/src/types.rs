@@ -242,6 +242,24 @@ impl<Balance: Encode + Decode + MaxEncodedLen + Copy + Clone + Debug + Eq + Part
 	}
 }
 
+/// An attestation of a registrar that some fields of an identity have been verified, valid until
+/// it expires.
+///
+/// Contrary to a [`Judgement`], an attestation is specific to the attested fields. It is signed by
+/// the registrar off-chain and may be submitted by anyone.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Attestation<FieldsIdentifier, Hash, BlockNumber> {
+	/// The attested fields of the identity.
+	pub fields: FieldsIdentifier,
+
+	/// The hash of the identity information that the attestation is about. The attestation is void
+	/// once the identity information changes.
+	pub identity: Hash,
+
+	/// The first block in which the attestation is no longer valid.
+	pub expiry: BlockNumber,
+}
+
 /// Information concerning the identity of the controller of an account.
 pub trait IdentityInformationProvider:
 	Encode + Decode + MaxEncodedLen + Clone + Debug + Eq + PartialEq + TypeInfo + Default
@@ -103,6 +103,9 @@ pub trait WeightInfo {
 	fn migration_v2_pending_username_step() -> Weight;
 	fn migration_v2_cleanup_authority_step() -> Weight;
 	fn migration_v2_cleanup_username_step() -> Weight;
+	fn provide_attestation(r: u32, ) -> Weight;
+	fn revoke_attestation(r: u32, ) -> Weight;
+	fn remove_stale_attestation() -> Weight;
 }
 
 /// Weights for `pallet_identity` using the Substrate node and recommended hardware.
@@ -182,6 +185,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::SuperOf` (r:0 w:100)
 	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:0 w:20)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	fn clear_identity(r: u32, s: u32, ) -> Weight {
@@ -191,11 +196,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 54_514_000 picoseconds.
 		Weight::from_parts(53_735_553, 11003)
 			// Standard Error: 16_389
-			.saturating_add(Weight::from_parts(299_626, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(184_882, 0).saturating_mul(r.into()))
 			// Standard Error: 3_198
 			.saturating_add(Weight::from_parts(1_439_399, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
 	}
 	/// Storage: `Identity::Registrars` (r:1 w:0)
@@ -294,6 +300,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::SuperOf` (r:0 w:100)
 	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:0 w:20)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	fn kill_identity(r: u32, s: u32, ) -> Weight {
@@ -303,11 +311,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 74_949_000 picoseconds.
 		Weight::from_parts(72_306_168, 11003)
 			// Standard Error: 19_097
-			.saturating_add(Weight::from_parts(462_202, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(285_191, 0).saturating_mul(r.into()))
 			// Standard Error: 3_726
 			.saturating_add(Weight::from_parts(1_487_085, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
 	}
 	/// Storage: `Identity::IdentityOf` (r:1 w:0)
@@ -593,6 +602,57 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationNonce` (r:1 w:1)
+	/// Proof: `Identity::AttestationNonce` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:0 w:1)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 20]`.
+	fn provide_attestation(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7080 + r * (57 ±0)`
+		//  Estimated: `11003`
+		// Minimum execution time: 190_042_000 picoseconds.
+		Weight::from_parts(195_920_071, 11003)
+			// Standard Error: 4_757
+			.saturating_add(Weight::from_parts(99_527, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:1 w:1)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationNonce` (r:1 w:1)
+	/// Proof: `Identity::AttestationNonce` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 20]`.
+	fn revoke_attestation(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `259 + r * (57 ±0)`
+		//  Estimated: `3561`
+		// Minimum execution time: 14_902_000 picoseconds.
+		Weight::from_parts(15_363_556, 3561)
+			// Standard Error: 1_986
+			.saturating_add(Weight::from_parts(98_575, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Identity::AttestationsOf` (r:1 w:1)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	fn remove_stale_attestation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7173`
+		//  Estimated: `11003`
+		// Minimum execution time: 62_602_000 picoseconds.
+		Weight::from_parts(64_539_000, 11003)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -671,6 +731,8 @@ impl WeightInfo for () {
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::SuperOf` (r:0 w:100)
 	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:0 w:20)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	fn clear_identity(r: u32, s: u32, ) -> Weight {
@@ -680,11 +742,12 @@ impl WeightInfo for () {
 		// Minimum execution time: 54_514_000 picoseconds.
 		Weight::from_parts(53_735_553, 11003)
 			// Standard Error: 16_389
-			.saturating_add(Weight::from_parts(299_626, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(184_882, 0).saturating_mul(r.into()))
 			// Standard Error: 3_198
 			.saturating_add(Weight::from_parts(1_439_399, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
 	}
 	/// Storage: `Identity::Registrars` (r:1 w:0)
@@ -783,6 +846,8 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::SuperOf` (r:0 w:100)
 	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:0 w:20)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	fn kill_identity(r: u32, s: u32, ) -> Weight {
@@ -792,11 +857,12 @@ impl WeightInfo for () {
 		// Minimum execution time: 74_949_000 picoseconds.
 		Weight::from_parts(72_306_168, 11003)
 			// Standard Error: 19_097
-			.saturating_add(Weight::from_parts(462_202, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(285_191, 0).saturating_mul(r.into()))
 			// Standard Error: 3_726
 			.saturating_add(Weight::from_parts(1_487_085, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
 	}
 	/// Storage: `Identity::IdentityOf` (r:1 w:0)
@@ -1082,4 +1148,55 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationNonce` (r:1 w:1)
+	/// Proof: `Identity::AttestationNonce` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:0 w:1)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 20]`.
+	fn provide_attestation(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7080 + r * (57 ±0)`
+		//  Estimated: `11003`
+		// Minimum execution time: 190_042_000 picoseconds.
+		Weight::from_parts(195_920_071, 11003)
+			// Standard Error: 4_757
+			.saturating_add(Weight::from_parts(99_527, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationsOf` (r:1 w:1)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttestationNonce` (r:1 w:1)
+	/// Proof: `Identity::AttestationNonce` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 20]`.
+	fn revoke_attestation(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `259 + r * (57 ±0)`
+		//  Estimated: `3561`
+		// Minimum execution time: 14_902_000 picoseconds.
+		Weight::from_parts(15_363_556, 3561)
+			// Standard Error: 1_986
+			.saturating_add(Weight::from_parts(98_575, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Identity::AttestationsOf` (r:1 w:1)
+	/// Proof: `Identity::AttestationsOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	fn remove_stale_attestation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7173`
+		//  Estimated: `11003`
+		// Minimum execution time: 62_602_000 picoseconds.
+		Weight::from_parts(64_539_000, 11003)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
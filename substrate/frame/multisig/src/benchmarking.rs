@@ -45,6 +45,25 @@ fn setup_multi<T: Config>(
 	Ok((signatories, Box::new(call)))
 }
 
+fn setup_persistent<T: Config>(
+	s: u32,
+) -> Result<(T::AccountId, Vec<(T::AccountId, u32)>), &'static str> {
+	let (signatories, _) = setup_multi::<T>(s, 0)?;
+	let creator = signatories.first().cloned().ok_or("signatories should have len 1 or more")?;
+	let signatories: Vec<_> = signatories.into_iter().map(|who| (who, 1)).collect();
+	let multisig = Multisig::<T>::persistent_account_id(&creator, 0, None);
+	Multisig::<T>::create_persistent_multisig(
+		RawOrigin::Signed(creator).into(),
+		signatories.clone(),
+		s,
+		0,
+	)
+	.map_err(|_| "persistent multisig could not be created")?;
+	// Give the multisig some balance for taking over the deposit.
+	T::Currency::make_free_balance_be(&multisig, BalanceOf::<T>::max_value());
+	Ok((multisig, signatories))
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -359,5 +378,52 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// `s`: Signatories of the persistent multisig
+	#[benchmark]
+	fn create_persistent_multisig(
+		s: Linear<1, { T::MaxSignatories::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let (signatories, _) = setup_multi::<T>(s, 0)?;
+		let caller = signatories.first().cloned().ok_or("signatories should have len 1 or more")?;
+		let signatories: Vec<_> = signatories.into_iter().map(|who| (who, 1)).collect();
+		let multisig = Multisig::<T>::persistent_account_id(&caller, 0, None);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), signatories, s, 0);
+
+		assert!(PersistentMultisigs::<T>::contains_key(multisig));
+		Ok(())
+	}
+
+	/// `s`: New signatories of the persistent multisig
+	#[benchmark]
+	fn set_signatories(s: Linear<1, { T::MaxSignatories::get() }>) -> Result<(), BenchmarkError> {
+		let (multisig, _) = setup_persistent::<T>(T::MaxSignatories::get())?;
+		let mut new_signatories: Vec<(T::AccountId, u32)> =
+			(0..s).map(|i| (account("new_signatory", i, SEED), 1)).collect();
+		new_signatories.sort();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(multisig.clone()), new_signatories, s);
+
+		let config = PersistentMultisigs::<T>::get(&multisig).ok_or("multisig not found")?;
+		assert_eq!(config.depositor, multisig);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn destroy_persistent_multisig() -> Result<(), BenchmarkError> {
+		let (multisig, _) = setup_persistent::<T>(T::MaxSignatories::get())?;
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(multisig.clone()), beneficiary.clone());
+
+		assert!(!PersistentMultisigs::<T>::contains_key(&multisig));
+		assert!(T::Currency::total_balance(&multisig).is_zero());
+		assert!(!T::Currency::free_balance(&beneficiary).is_zero());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -39,6 +39,24 @@
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `create_persistent_multisig` - Create a persistent multisig account whose signatories and
+//!   threshold can later be changed without changing the account id.
+//! * `as_persistent_multi` - Approve and if possible dispatch a call from a persistent multisig.
+//! * `approve_as_persistent_multi` - Approve a call from a persistent multisig.
+//! * `cancel_as_persistent_multi` - Cancel a call from a persistent multisig.
+//! * `set_signatories` - Change the signatories and threshold of a persistent multisig. Must be
+//!   dispatched by the persistent multisig itself.
+//! * `destroy_persistent_multisig` - Remove a persistent multisig. Must be dispatched by the
+//!   persistent multisig itself.
+//!
+//! ### Persistent multisigs
+//!
+//! A regular multisig account is derived from its signatories and threshold, so changing either
+//! results in a different account. A persistent multisig instead has an account id that is fixed
+//! at creation and a configuration stored on-chain. Every signatory carries an approval weight and
+//! a call is dispatched once the summed weight of the approving signatories reaches the
+//! threshold. Approvals are always weighed against the current configuration, so approvals given
+//! by accounts which are no longer signatories do not count.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -52,7 +70,7 @@ extern crate alloc;
 use alloc::{boxed::Box, vec, vec::Vec};
 use frame::{
 	prelude::*,
-	traits::{Currency, ReservableCurrency},
+	traits::{Currency, ExistenceRequirement, ReservableCurrency},
 };
 use frame_system::RawOrigin;
 pub use weights::WeightInfo;
@@ -120,6 +138,46 @@ where
 	pub approvals: BoundedVec<AccountId, MaxApprovals>,
 }
 
+/// The configuration of a persistent multisig account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxSignatories))]
+pub struct PersistentMultisig<Balance, AccountId, MaxSignatories>
+where
+	MaxSignatories: Get<u32>,
+{
+	/// The signatories together with their approval weight. Always sorted by account.
+	pub signatories: BoundedVec<(AccountId, u32), MaxSignatories>,
+	/// The total approval weight required for a call to be dispatched.
+	pub threshold: u32,
+	/// The account holding the deposit for this configuration.
+	pub depositor: AccountId,
+	/// The amount held in reserve of the `depositor`.
+	pub deposit: Balance,
+}
+
+impl<Balance, AccountId: Ord, MaxSignatories: Get<u32>>
+	PersistentMultisig<Balance, AccountId, MaxSignatories>
+{
+	/// The approval weight of `who`, if it is a signatory.
+	pub fn weight_of(&self, who: &AccountId) -> Option<u32> {
+		self.signatories
+			.binary_search_by(|(signatory, _)| signatory.cmp(who))
+			.ok()
+			.map(|index| self.signatories[index].1)
+	}
+
+	/// The summed weight of all `approvals` which are current signatories.
+	///
+	/// `approvals` must be sorted.
+	pub fn approval_weight(&self, approvals: &[AccountId]) -> u64 {
+		self.signatories
+			.iter()
+			.filter(|(signatory, _)| approvals.binary_search(signatory).is_ok())
+			.map(|(_, weight)| *weight as u64)
+			.sum()
+	}
+}
+
 type CallHash = [u8; 32];
 
 enum CallOrHash<T: Config> {
@@ -211,6 +269,18 @@ pub mod pallet {
 		Multisig<BlockNumberFor<T>, BalanceOf<T>, T::AccountId, T::MaxSignatories>,
 	>;
 
+	/// The configuration of persistent multisig accounts.
+	///
+	/// Open operations of a persistent multisig are tracked in [`Multisigs`] like those of any
+	/// other multisig account.
+	#[pallet::storage]
+	pub type PersistentMultisigs<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		PersistentMultisig<BalanceOf<T>, T::AccountId, T::MaxSignatories>,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -242,6 +312,16 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// The persistent multisig account already exists.
+		AlreadyExists,
+		/// The sender is not a signatory of the persistent multisig.
+		NotSignatory,
+		/// A signatory was given an approval weight of zero.
+		ZeroWeight,
+		/// The threshold is zero or greater than the total weight of the signatories.
+		InvalidThreshold,
+		/// The persistent multisig account still holds funds which cannot be moved.
+		StillHoldsFunds,
 	}
 
 	#[pallet::event]
@@ -278,6 +358,12 @@ pub mod pallet {
 			old_deposit: BalanceOf<T>,
 			new_deposit: BalanceOf<T>,
 		},
+		/// A persistent multisig account has been created.
+		PersistentMultisigCreated { creator: T::AccountId, multisig: T::AccountId, threshold: u32 },
+		/// The signatories or threshold of a persistent multisig have been changed.
+		SignatoriesChanged { multisig: T::AccountId, threshold: u32 },
+		/// A persistent multisig account has been destroyed.
+		PersistentMultisigDestroyed { multisig: T::AccountId },
 	}
 
 	#[pallet::hooks]
@@ -604,6 +690,281 @@ pub mod pallet {
 				},
 			)
 		}
+
+		/// Create a persistent multisig account.
+		///
+		/// Unlike the account of a regular multisig, the account id of a persistent multisig does
+		/// not depend on its signatories or threshold, which can later be changed through
+		/// [`Pallet::set_signatories`] without moving any funds.
+		///
+		/// Payment: `DepositBase` plus `DepositFactor` times the number of signatories will be
+		/// reserved for as long as the persistent multisig exists.
+		///
+		/// The dispatch origin for this call must be _Signed_. The sender does not need to be one
+		/// of the signatories.
+		///
+		/// - `signatories`: The accounts who can approve dispatches, each with its approval
+		/// weight. Must be sorted by account and may not contain a weight of zero.
+		/// - `threshold`: The total approval weight needed for a dispatch to be executed. Must not
+		/// be zero or exceed the summed weight of all `signatories`.
+		/// - `index`: A disambiguation index, in case this is called multiple times in the same
+		/// transaction. Use `0` if unsure.
+		///
+		/// Emits `PersistentMultisigCreated`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::create_persistent_multisig(signatories.len() as u32))]
+		pub fn create_persistent_multisig(
+			origin: OriginFor<T>,
+			signatories: Vec<(T::AccountId, u32)>,
+			threshold: u32,
+			index: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let signatories = Self::ensure_valid_signatories(signatories, threshold)?;
+
+			let multisig = Self::persistent_account_id(&who, index, None);
+			ensure!(!PersistentMultisigs::<T>::contains_key(&multisig), Error::<T>::AlreadyExists);
+
+			let deposit = Self::persistent_deposit(signatories.len());
+			T::Currency::reserve(&who, deposit)?;
+
+			PersistentMultisigs::<T>::insert(
+				&multisig,
+				PersistentMultisig { signatories, threshold, depositor: who.clone(), deposit },
+			);
+			Self::deposit_event(Event::PersistentMultisigCreated {
+				creator: who,
+				multisig,
+				threshold,
+			});
+			Ok(())
+		}
+
+		/// Register approval for a dispatch to be made from a persistent multisig account.
+		///
+		/// If the summed weight of the approving signatories reaches the threshold, then dispatch
+		/// the call.
+		///
+		/// Payment: `DepositBase` will be reserved if this is the first approval, plus the number
+		/// of signatories times `DepositFactor`. It is returned once this dispatch happens or is
+		/// cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a signatory of
+		/// `multisig`.
+		///
+		/// - `multisig`: The persistent multisig account to dispatch from.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call`: The call to be executed.
+		///
+		/// NOTE: Unless this is the final approval, you will generally want to use
+		/// `approve_as_persistent_multi` instead, since it only requires a hash of the call.
+		#[pallet::call_index(6)]
+		#[pallet::weight({
+			let s = T::MaxSignatories::get();
+			let z = call.using_encoded(|d| d.len()) as u32;
+
+			T::WeightInfo::as_multi_create(s, z)
+				.max(T::WeightInfo::as_multi_approve(s, z))
+				.max(T::WeightInfo::as_multi_complete(s, z))
+				.saturating_add(T::DbWeight::get().reads(1))
+				.saturating_add(*max_weight)
+		})]
+		pub fn as_persistent_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call: Box<<T as Config>::RuntimeCall>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::operate_persistent(
+				who,
+				multisig,
+				maybe_timepoint,
+				CallOrHash::Call(*call),
+				max_weight,
+			)
+		}
+
+		/// Register approval for a dispatch to be made from a persistent multisig account.
+		///
+		/// Payment: `DepositBase` will be reserved if this is the first approval, plus the number
+		/// of signatories times `DepositFactor`. It is returned once this dispatch happens or is
+		/// cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a signatory of
+		/// `multisig`.
+		///
+		/// - `multisig`: The persistent multisig account to dispatch from.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// NOTE: If this is the final approval, you will want to use `as_persistent_multi`
+		/// instead.
+		#[pallet::call_index(7)]
+		#[pallet::weight({
+			let s = T::MaxSignatories::get();
+
+			T::WeightInfo::approve_as_multi_create(s)
+				.max(T::WeightInfo::approve_as_multi_approve(s))
+				.saturating_add(T::DbWeight::get().reads(1))
+		})]
+		pub fn approve_as_persistent_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call_hash: [u8; 32],
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			// Only the call hash is known, so the call is never dispatched on this path.
+			Self::operate_persistent(
+				who,
+				multisig,
+				maybe_timepoint,
+				CallOrHash::Hash(call_hash),
+				Weight::zero(),
+			)
+		}
+
+		/// Cancel a pre-existing, on-going operation of a persistent multisig. Any deposit
+		/// reserved previously for this operation will be unreserved on success.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be the account that opened
+		/// the operation. It does not need to still be a signatory of `multisig`.
+		///
+		/// - `multisig`: The persistent multisig account the operation belongs to.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `call_hash`: The hash of the call to be executed.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::cancel_as_multi(T::MaxSignatories::get()))]
+		pub fn cancel_as_persistent_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let m = <Multisigs<T>>::get(&multisig, call_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+			ensure!(m.depositor == who, Error::<T>::NotOwner);
+
+			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+			debug_assert!(err_amount.is_zero());
+			<Multisigs<T>>::remove(&multisig, &call_hash);
+
+			Self::deposit_event(Event::MultisigCancelled {
+				cancelling: who,
+				timepoint,
+				multisig,
+				call_hash,
+			});
+			Ok(())
+		}
+
+		/// Change the signatories and threshold of a persistent multisig account.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the persistent multisig itself,
+		/// i.e. it is expected to be dispatched through `as_persistent_multi`.
+		///
+		/// Operations which are already open are kept and weighed against the new configuration
+		/// when further approvals arrive. Approvals of removed signatories are dropped from an
+		/// operation the next time it is approved.
+		///
+		/// The deposit for the configuration is taken over by the persistent multisig account and
+		/// any deposit held by a different account is returned to it.
+		///
+		/// - `signatories`: The new signatories, each with its approval weight. Must be sorted by
+		/// account and may not contain a weight of zero.
+		/// - `threshold`: The new total approval weight needed for a dispatch to be executed.
+		///
+		/// Emits `SignatoriesChanged`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_signatories(signatories.len() as u32))]
+		pub fn set_signatories(
+			origin: OriginFor<T>,
+			signatories: Vec<(T::AccountId, u32)>,
+			threshold: u32,
+		) -> DispatchResult {
+			let multisig = ensure_signed(origin)?;
+			let signatories = Self::ensure_valid_signatories(signatories, threshold)?;
+
+			PersistentMultisigs::<T>::try_mutate(&multisig, |maybe_config| -> DispatchResult {
+				let config = maybe_config.as_mut().ok_or(Error::<T>::NotFound)?;
+				let new_deposit = Self::persistent_deposit(signatories.len());
+
+				if config.depositor == multisig {
+					if new_deposit > config.deposit {
+						T::Currency::reserve(
+							&multisig,
+							new_deposit.saturating_sub(config.deposit),
+						)?;
+					} else {
+						let excess = config.deposit.saturating_sub(new_deposit);
+						let err_amount = T::Currency::unreserve(&multisig, excess);
+						debug_assert!(err_amount.is_zero());
+					}
+				} else {
+					T::Currency::reserve(&multisig, new_deposit)?;
+					let err_amount = T::Currency::unreserve(&config.depositor, config.deposit);
+					debug_assert!(err_amount.is_zero());
+				}
+
+				config.signatories = signatories;
+				config.threshold = threshold;
+				config.depositor = multisig.clone();
+				config.deposit = new_deposit;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::SignatoriesChanged { multisig, threshold });
+			Ok(())
+		}
+
+		/// Destroy a persistent multisig account and return its deposit.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the persistent multisig itself,
+		/// i.e. it is expected to be dispatched through `as_persistent_multi`.
+		///
+		/// All funds of the account, including a deposit held by the account itself, are
+		/// transferred to `beneficiary`. Fails if the account holds funds which cannot be moved,
+		/// e.g. because they are locked or reserved for something else. Open operations can no
+		/// longer be approved, but can still be cancelled by their depositors.
+		///
+		/// - `beneficiary`: The account receiving the remaining funds of the multisig.
+		///
+		/// Emits `PersistentMultisigDestroyed`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::destroy_persistent_multisig())]
+		pub fn destroy_persistent_multisig(
+			origin: OriginFor<T>,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			let multisig = ensure_signed(origin)?;
+			let config = PersistentMultisigs::<T>::take(&multisig).ok_or(Error::<T>::NotFound)?;
+
+			let err_amount = T::Currency::unreserve(&config.depositor, config.deposit);
+			debug_assert!(err_amount.is_zero());
+
+			let remaining = T::Currency::free_balance(&multisig);
+			if !remaining.is_zero() && beneficiary != multisig {
+				T::Currency::transfer(
+					&multisig,
+					&beneficiary,
+					remaining,
+					ExistenceRequirement::AllowDeath,
+				)?;
+			}
+			ensure!(T::Currency::total_balance(&multisig).is_zero(), Error::<T>::StillHoldsFunds);
+
+			Self::deposit_event(Event::PersistentMultisigDestroyed { multisig });
+			Ok(())
+		}
 	}
 }
 
@@ -747,6 +1108,184 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Derive the account ID of a persistent multisig created by `who`.
+	///
+	/// - `index`: The disambiguation index given at creation.
+	/// - `maybe_when`: The timepoint at which the persistent multisig was created. Use `None` to
+	///   derive the account of a persistent multisig created in the current extrinsic.
+	pub fn persistent_account_id(
+		who: &T::AccountId,
+		index: u16,
+		maybe_when: Option<Timepoint<BlockNumberFor<T>>>,
+	) -> T::AccountId {
+		let when = maybe_when.unwrap_or_else(Self::timepoint);
+		let entropy =
+			(b"modlpy/persmsig_", who, when.height, when.index, index).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
+	fn operate_persistent(
+		who: T::AccountId,
+		multisig: T::AccountId,
+		maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		let config = PersistentMultisigs::<T>::get(&multisig).ok_or(Error::<T>::NotFound)?;
+		let who_weight = config.weight_of(&who).ok_or(Error::<T>::NotSignatory)?;
+		let threshold = config.threshold as u64;
+		let signatories_len = config.signatories.len() as u32;
+
+		let (call_hash, call_len, maybe_call) = match call_or_hash {
+			CallOrHash::Call(call) => {
+				let (call_hash, call_len) = call.using_encoded(|d| (blake2_256(d), d.len()));
+				(call_hash, call_len, Some(call))
+			},
+			CallOrHash::Hash(h) => (h, 0, None),
+		};
+		// Account for reading the persistent multisig configuration.
+		let config_weight = T::DbWeight::get().reads(1);
+
+		// Branch on whether the operation has already started or not.
+		if let Some(mut m) = <Multisigs<T>>::get(&multisig, call_hash) {
+			// Yes; ensure that the timepoint exists and agrees.
+			let timepoint = maybe_timepoint.ok_or(Error::<T>::NoTimepoint)?;
+			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+
+			// Approvals of accounts which are no longer signatories do not count, so drop them
+			// before they fill up the approvals.
+			m.approvals.retain(|approving| config.weight_of(approving).is_some());
+			let mut approved = config.approval_weight(&m.approvals);
+			// We only bother with the approval if we're below threshold.
+			let maybe_pos = m.approvals.binary_search(&who).err().filter(|_| approved < threshold);
+			// Bump approvals if not yet voted and the vote is needed.
+			if maybe_pos.is_some() {
+				approved = approved.saturating_add(who_weight as u64);
+			}
+
+			// We only bother fetching/decoding call if we know that we're ready to execute.
+			if let Some(call) = maybe_call.filter(|_| approved >= threshold) {
+				ensure!(
+					call.get_dispatch_info().call_weight.all_lte(max_weight),
+					Error::<T>::MaxWeightTooLow
+				);
+
+				// Clean up storage before executing call to avoid an possibility of reentrancy
+				// attack.
+				<Multisigs<T>>::remove(&multisig, call_hash);
+				T::Currency::unreserve(&m.depositor, m.deposit);
+
+				Self::dispatch_persistent(
+					who,
+					multisig,
+					timepoint,
+					call_hash,
+					call,
+					T::WeightInfo::as_multi_complete(signatories_len, call_len as u32)
+						.saturating_add(config_weight),
+				)
+			} else {
+				// We cannot dispatch the call now; either it isn't available, or it is, but we
+				// don't have threshold approvals even with our signature.
+
+				if let Some(pos) = maybe_pos {
+					// Record approval.
+					m.approvals
+						.try_insert(pos, who.clone())
+						.map_err(|_| Error::<T>::TooManySignatories)?;
+					<Multisigs<T>>::insert(&multisig, call_hash, m);
+					Self::deposit_event(Event::MultisigApproval {
+						approving: who,
+						timepoint,
+						multisig,
+						call_hash,
+					});
+				} else {
+					// If we already approved and didn't store the Call, then this was useless and
+					// we report an error.
+					Err(Error::<T>::AlreadyApproved)?
+				}
+
+				let final_weight =
+					T::WeightInfo::as_multi_approve(signatories_len, call_len as u32)
+						.saturating_add(config_weight);
+				// Call is not made, so the actual weight does not include call
+				Ok(Some(final_weight).into())
+			}
+		} else {
+			// Not yet started; there should be no timepoint given.
+			ensure!(maybe_timepoint.is_none(), Error::<T>::UnexpectedTimepoint);
+
+			// A single signatory may carry enough weight to dispatch right away.
+			if let Some(call) = maybe_call.filter(|_| who_weight as u64 >= threshold) {
+				ensure!(
+					call.get_dispatch_info().call_weight.all_lte(max_weight),
+					Error::<T>::MaxWeightTooLow
+				);
+
+				return Self::dispatch_persistent(
+					who,
+					multisig,
+					Self::timepoint(),
+					call_hash,
+					call,
+					T::WeightInfo::as_multi_complete(signatories_len, call_len as u32)
+						.saturating_add(config_weight),
+				)
+			}
+
+			// Just start the operation by recording it in storage.
+			let deposit = Self::persistent_deposit(config.signatories.len());
+
+			T::Currency::reserve(&who, deposit)?;
+
+			let initial_approvals =
+				vec![who.clone()].try_into().map_err(|_| Error::<T>::TooManySignatories)?;
+
+			<Multisigs<T>>::insert(
+				&multisig,
+				call_hash,
+				Multisig {
+					when: Self::timepoint(),
+					deposit,
+					depositor: who.clone(),
+					approvals: initial_approvals,
+				},
+			);
+			Self::deposit_event(Event::NewMultisig { approving: who, multisig, call_hash });
+
+			let final_weight = T::WeightInfo::as_multi_create(signatories_len, call_len as u32)
+				.saturating_add(config_weight);
+			// Call is not made, so the actual weight does not include call
+			Ok(Some(final_weight).into())
+		}
+	}
+
+	/// Dispatch `call` from the persistent `multisig` and report the outcome.
+	///
+	/// `base_weight` is the weight of the operation excluding the call itself.
+	fn dispatch_persistent(
+		who: T::AccountId,
+		multisig: T::AccountId,
+		timepoint: Timepoint<BlockNumberFor<T>>,
+		call_hash: CallHash,
+		call: <T as Config>::RuntimeCall,
+		base_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		let result = call.dispatch(RawOrigin::Signed(multisig.clone()).into());
+		Self::deposit_event(Event::MultisigExecuted {
+			approving: who,
+			timepoint,
+			multisig,
+			call_hash,
+			result: result.map(|_| ()).map_err(|e| e.error),
+		});
+		Ok(get_result_weight(result)
+			.map(|actual_weight| base_weight.saturating_add(actual_weight))
+			.into())
+	}
+
 	/// The current `Timepoint`.
 	pub fn timepoint() -> Timepoint<BlockNumberFor<T>> {
 		Timepoint {
@@ -777,12 +1316,39 @@ impl<T: Config> Pallet<T> {
 		Ok(signatories)
 	}
 
+	/// Check that the persistent multisig configuration is valid and bound the signatories.
+	fn ensure_valid_signatories(
+		signatories: Vec<(T::AccountId, u32)>,
+		threshold: u32,
+	) -> Result<BoundedVec<(T::AccountId, u32), T::MaxSignatories>, DispatchError> {
+		ensure!(!signatories.is_empty(), Error::<T>::TooFewSignatories);
+		let mut total_weight = 0u64;
+		let mut maybe_last = None;
+		for (signatory, weight) in signatories.iter() {
+			if let Some(last) = maybe_last {
+				ensure!(last < signatory, Error::<T>::SignatoriesOutOfOrder);
+			}
+			ensure!(*weight > 0, Error::<T>::ZeroWeight);
+			total_weight = total_weight.saturating_add(*weight as u64);
+			maybe_last = Some(signatory);
+		}
+		ensure!(threshold > 0 && threshold as u64 <= total_weight, Error::<T>::InvalidThreshold);
+		signatories.try_into().map_err(|_| Error::<T>::TooManySignatories.into())
+	}
+
 	/// Calculate the deposit for a multisig operation.
 	///
 	/// The deposit is calculated as `DepositBase + DepositFactor * threshold`.
 	pub fn deposit(threshold: u16) -> BalanceOf<T> {
 		T::DepositBase::get() + T::DepositFactor::get() * threshold.into()
 	}
+
+	/// Calculate the deposit for a persistent multisig configuration or one of its operations.
+	///
+	/// The deposit is calculated as `DepositBase + DepositFactor * signatories`.
+	pub fn persistent_deposit(signatories: usize) -> BalanceOf<T> {
+		T::DepositBase::get() + T::DepositFactor::get() * (signatories as u32).into()
+	}
 }
 
 /// Return the weight of a dispatch call result as an `Option`.
@@ -54,6 +54,8 @@ impl Contains<RuntimeCall> for TestBaseCallFilter {
 			RuntimeCall::Balances(_) => true,
 			// Needed for benchmarking
 			RuntimeCall::System(frame_system::Call::remark { .. }) => true,
+			// Needed for persistent multisigs to manage themselves.
+			RuntimeCall::Multisig(pallet_multisig::Call::set_signatories { .. }) => true,
 			_ => false,
 		}
 	}
@@ -1005,3 +1007,425 @@ fn poke_deposit_handles_insufficient_balance() {
 		);
 	});
 }
+
+fn call_set_signatories(signatories: Vec<(u64, u32)>, threshold: u32) -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::Multisig(pallet_multisig::Call::set_signatories {
+		signatories,
+		threshold,
+	}))
+}
+
+#[test]
+fn create_persistent_multisig_works() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::persistent_account_id(&1, 0, None);
+		assert_ok!(Multisig::create_persistent_multisig(
+			RuntimeOrigin::signed(1),
+			vec![(1, 2), (2, 1), (3, 1)],
+			3,
+			0
+		));
+		System::assert_last_event(
+			pallet_multisig::Event::PersistentMultisigCreated {
+				creator: 1,
+				multisig: multi,
+				threshold: 3,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(1), 6);
+		assert_eq!(Balances::reserved_balance(1), 4);
+
+		let config = PersistentMultisigs::<Test>::get(multi).unwrap();
+		assert_eq!(config.signatories.into_inner(), vec![(1, 2), (2, 1), (3, 1)]);
+		assert_eq!(config.threshold, 3);
+		assert_eq!(config.depositor, 1);
+
+		assert_noop!(
+			Multisig::create_persistent_multisig(RuntimeOrigin::signed(1), vec![(2, 1)], 1, 0),
+			Error::<Test>::AlreadyExists,
+		);
+		assert_ok!(Multisig::create_persistent_multisig(
+			RuntimeOrigin::signed(1),
+			vec![(2, 1)],
+			1,
+			1
+		));
+	});
+}
+
+#[test]
+fn create_persistent_multisig_checks_configuration() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Multisig::create_persistent_multisig(RuntimeOrigin::signed(1), vec![], 1, 0),
+			Error::<Test>::TooFewSignatories,
+		);
+		assert_noop!(
+			Multisig::create_persistent_multisig(
+				RuntimeOrigin::signed(1),
+				vec![(1, 1), (2, 1), (3, 1), (4, 1)],
+				2,
+				0
+			),
+			Error::<Test>::TooManySignatories,
+		);
+		assert_noop!(
+			Multisig::create_persistent_multisig(
+				RuntimeOrigin::signed(1),
+				vec![(2, 1), (1, 1)],
+				2,
+				0
+			),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_noop!(
+			Multisig::create_persistent_multisig(
+				RuntimeOrigin::signed(1),
+				vec![(1, 1), (1, 1)],
+				2,
+				0
+			),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_noop!(
+			Multisig::create_persistent_multisig(
+				RuntimeOrigin::signed(1),
+				vec![(1, 1), (2, 0)],
+				1,
+				0
+			),
+			Error::<Test>::ZeroWeight,
+		);
+		assert_noop!(
+			Multisig::create_persistent_multisig(
+				RuntimeOrigin::signed(1),
+				vec![(1, 1), (2, 1)],
+				0,
+				0
+			),
+			Error::<Test>::InvalidThreshold,
+		);
+		assert_noop!(
+			Multisig::create_persistent_multisig(
+				RuntimeOrigin::signed(1),
+				vec![(1, 1), (2, 1)],
+				3,
+				0
+			),
+			Error::<Test>::InvalidThreshold,
+		);
+	});
+}
+
+#[test]
+fn persistent_multisig_weighted_threshold_works() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::persistent_account_id(&1, 0, None);
+		assert_ok!(Multisig::create_persistent_multisig(
+			RuntimeOrigin::signed(1),
+			vec![(1, 2), (2, 1), (3, 1)],
+			3,
+			0
+		));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().call_weight;
+		let hash = blake2_256(&call.encode());
+
+		assert_noop!(
+			Multisig::approve_as_persistent_multi(RuntimeOrigin::signed(4), multi, None, hash),
+			Error::<Test>::NotSignatory,
+		);
+
+		assert_ok!(Multisig::approve_as_persistent_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			None,
+			hash
+		));
+		assert_eq!(Balances::reserved_balance(2), 4);
+
+		// Signatory 3 brings the approval weight to 2, which is still below the threshold.
+		assert_ok!(Multisig::as_persistent_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			Some(now()),
+			call.clone(),
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+
+		assert_ok!(Multisig::as_persistent_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn persistent_multisig_heavy_signatory_dispatches_immediately() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::persistent_account_id(&1, 0, None);
+		assert_ok!(Multisig::create_persistent_multisig(
+			RuntimeOrigin::signed(1),
+			vec![(1, 2), (2, 1), (3, 1)],
+			2,
+			0
+		));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().call_weight;
+		let hash = blake2_256(&call.encode());
+
+		assert_noop!(
+			Multisig::as_persistent_multi(
+				RuntimeOrigin::signed(1),
+				multi,
+				None,
+				call.clone(),
+				Weight::zero()
+			),
+			Error::<Test>::MaxWeightTooLow,
+		);
+		assert_ok!(Multisig::as_persistent_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			call,
+			call_weight
+		));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigExecuted {
+				approving: 1,
+				timepoint: now(),
+				multisig: multi,
+				call_hash: hash,
+				result: Ok(()),
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(6), 5);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn persistent_multisig_can_change_its_signatories() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::persistent_account_id(&1, 0, None);
+		assert_ok!(Multisig::create_persistent_multisig(
+			RuntimeOrigin::signed(1),
+			vec![(1, 1), (2, 1)],
+			1,
+			0
+		));
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		assert_noop!(
+			Multisig::set_signatories(RuntimeOrigin::signed(1), vec![(3, 1)], 1),
+			Error::<Test>::NotFound,
+		);
+
+		let call = call_set_signatories(vec![(3, 1)], 1);
+		let call_weight = call.get_dispatch_info().call_weight;
+		assert_ok!(Multisig::as_persistent_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			call,
+			call_weight
+		));
+		assert!(System::events().iter().any(|record| record.event ==
+			pallet_multisig::Event::SignatoriesChanged { multisig: multi, threshold: 1 }
+				.into()));
+
+		// The deposit moved from the creator to the multisig account itself.
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(multi), 2);
+
+		// The account stays the same, but only the new signatory can operate it.
+		let call = call_transfer(6, 2);
+		let call_weight = call.get_dispatch_info().call_weight;
+		assert_noop!(
+			Multisig::as_persistent_multi(
+				RuntimeOrigin::signed(1),
+				multi,
+				None,
+				call.clone(),
+				call_weight
+			),
+			Error::<Test>::NotSignatory,
+		);
+		assert_ok!(Multisig::as_persistent_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			None,
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 2);
+	});
+}
+
+#[test]
+fn approvals_of_removed_signatories_do_not_count() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::persistent_account_id(&1, 0, None);
+		assert_ok!(Multisig::create_persistent_multisig(
+			RuntimeOrigin::signed(1),
+			vec![(1, 1), (2, 1), (3, 1)],
+			2,
+			0
+		));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 1);
+		let call_weight = call.get_dispatch_info().call_weight;
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_persistent_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			hash
+		));
+
+		assert_ok!(Multisig::set_signatories(
+			RuntimeOrigin::signed(multi),
+			vec![(2, 1), (3, 1)],
+			2
+		));
+
+		// The approval of 1 is ignored, so 2 alone does not reach the threshold.
+		assert_ok!(Multisig::as_persistent_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			Some(now()),
+			call.clone(),
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+		// The stale approval of 1 was dropped with the new approval.
+		assert_eq!(Multisigs::<Test>::get(multi, hash).unwrap().approvals.into_inner(), vec![2]);
+
+		assert_ok!(Multisig::as_persistent_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 1);
+		// The removed signatory got its deposit back with the execution.
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn cancel_persistent_multisig_operation_works() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::persistent_account_id(&1, 0, None);
+		assert_ok!(Multisig::create_persistent_multisig(
+			RuntimeOrigin::signed(1),
+			vec![(1, 1), (2, 1), (3, 1)],
+			3,
+			0
+		));
+
+		let hash = blake2_256(&call_transfer(6, 2).encode());
+		assert_ok!(Multisig::approve_as_persistent_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			None,
+			hash
+		));
+		assert_eq!(Balances::reserved_balance(2), 4);
+
+		assert_noop!(
+			Multisig::cancel_as_persistent_multi(RuntimeOrigin::signed(1), multi, now(), hash),
+			Error::<Test>::NotOwner,
+		);
+		assert_ok!(Multisig::cancel_as_persistent_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			now(),
+			hash
+		));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn destroy_persistent_multisig_works() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::persistent_account_id(&1, 0, None);
+		assert_ok!(Multisig::create_persistent_multisig(
+			RuntimeOrigin::signed(1),
+			vec![(1, 1), (2, 1)],
+			2,
+			0
+		));
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		assert_noop!(
+			Multisig::destroy_persistent_multisig(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::NotFound,
+		);
+		assert_ok!(Multisig::destroy_persistent_multisig(RuntimeOrigin::signed(multi), 1));
+		System::assert_last_event(
+			pallet_multisig::Event::PersistentMultisigDestroyed { multisig: multi }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!PersistentMultisigs::<Test>::contains_key(multi));
+
+		let hash = blake2_256(&call_transfer(6, 2).encode());
+		assert_noop!(
+			Multisig::approve_as_persistent_multi(RuntimeOrigin::signed(1), multi, None, hash),
+			Error::<Test>::NotFound,
+		);
+	});
+}
+
+#[test]
+fn destroy_persistent_multisig_sweeps_funds() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::persistent_account_id(&1, 0, None);
+		assert_ok!(Multisig::create_persistent_multisig(
+			RuntimeOrigin::signed(1),
+			vec![(1, 1), (2, 1)],
+			1,
+			0
+		));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+		// Let the multisig take over its own deposit.
+		assert_ok!(Multisig::set_signatories(
+			RuntimeOrigin::signed(multi),
+			vec![(1, 1), (2, 1)],
+			1
+		));
+		assert_eq!(Balances::reserved_balance(multi), 3);
+		assert_eq!(Balances::free_balance(multi), 2);
+
+		// The funds can not stay with the destroyed multisig.
+		assert_noop!(
+			Multisig::destroy_persistent_multisig(RuntimeOrigin::signed(multi), multi),
+			Error::<Test>::StillHoldsFunds,
+		);
+
+		let free_before = Balances::free_balance(6);
+		assert_ok!(Multisig::destroy_persistent_multisig(RuntimeOrigin::signed(multi), 6));
+		assert_eq!(Balances::free_balance(6), free_before + 5);
+		assert_eq!(Balances::total_balance(&multi), 0);
+		assert!(!PersistentMultisigs::<Test>::contains_key(multi));
+	});
+}
@@ -72,6 +72,7 @@ impl pallet_assets::Config for Test {
 	type Freezer = ();
 	type Extra = ();
 	type CallbackHandle = ();
+	type TransferRestrictions = ();
 	type WeightInfo = ();
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
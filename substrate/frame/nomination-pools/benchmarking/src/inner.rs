@@ -31,10 +31,11 @@ use frame_support::{
 use frame_system::RawOrigin as RuntimeOrigin;
 use pallet_nomination_pools::{
 	adapter::{Member, Pool, StakeStrategy, StakeStrategyType},
-	BalanceOf, BondExtra, BondedPoolInner, BondedPools, ClaimPermission, ClaimPermissions,
-	Commission, CommissionChangeRate, CommissionClaimPermission, ConfigOp, GlobalMaxCommission,
-	MaxPoolMembers, MaxPoolMembersPerPool, MaxPools, Metadata, MinCreateBond, MinJoinBond,
-	Pallet as Pools, PoolId, PoolMembers, PoolRoles, PoolState, RewardPools, SubPoolsStorage,
+	AutoCompound, BalanceOf, BondExtra, BondedPoolInner, BondedPools, ClaimPermission,
+	ClaimPermissions, Commission, CommissionChangeRate, CommissionClaimPermission, ConfigOp,
+	GlobalMaxCommission, MaxPoolMembers, MaxPoolMembersPerPool, MaxPools, Metadata, MinCreateBond,
+	MinJoinBond, Pallet as Pools, PoolId, PoolMembers, PoolRoles, PoolState, RewardPools,
+	SubPoolsStorage,
 };
 use pallet_staking::MaxNominationsOf;
 use sp_runtime::{
@@ -1172,5 +1173,43 @@ mod benchmarks {
 		assert_eq!(PoolMembers::<T>::get(&depositor).unwrap().total_balance(), deposit_amount);
 	}
 
+	#[benchmark]
+	fn set_auto_compound() {
+		// Create a pool
+		let (depositor, _pool_account) =
+			create_pool_account::<T>(0, Pools::<T>::depositor_min_bond() * 2u32.into(), None);
+		whitelist_account!(depositor);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(depositor), 1, true);
+
+		assert!(AutoCompound::<T>::get(1).is_some());
+	}
+
+	#[benchmark]
+	fn compound() {
+		let caller: T::AccountId = account("caller", USER_SEED + 4, 0);
+		let origin_weight = Pools::<T>::depositor_min_bond() * 2u32.into();
+		let (depositor, pool_account) =
+			create_pool_account::<T>(0, origin_weight, Some(Perbill::from_percent(50)));
+		assert_ok!(Pools::<T>::set_auto_compound(RuntimeOrigin::Signed(depositor).into(), 1, true));
+
+		// the pool received as much rewards as it has stake.
+		let reward_account = Pools::<T>::generate_reward_account(1);
+		let _ = CurrencyOf::<T>::mint_into(&reward_account, origin_weight);
+
+		whitelist_account!(caller);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(caller), 1);
+
+		// commission of 50% deducted here.
+		assert_eq!(
+			T::StakeAdapter::active_stake(Pool::from(pool_account)),
+			origin_weight + origin_weight / 2u32.into()
+		);
+		assert!(AutoCompound::<T>::get(1).is_some());
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
@@ -187,6 +187,19 @@ pub trait StakeStrategy {
 	/// Dissolve the pool account.
 	fn dissolve(pool_account: Pool<Self::AccountId>) -> DispatchResult;
 
+	/// Release any rewards compounded by the pool that are still staked on behalf of
+	/// `reward_account` back to it, once all members have left the pool.
+	///
+	/// Compounded rewards are bonded with [`Self::pledge_bond`] from the reward account of the
+	/// pool. See [`Call::compound`].
+	fn release_compounded(
+		_pool_account: Pool<Self::AccountId>,
+		_reward_account: &Self::AccountId,
+	) -> DispatchResult {
+		// noop by default: compounded rewards are withdrawn along with the members' stake.
+		Ok(())
+	}
+
 	/// Check if there is any pending slash for the pool.
 	fn pending_slash(pool_account: Pool<Self::AccountId>) -> Self::Balance;
 
@@ -237,6 +250,11 @@ pub trait StakeStrategy {
 	}
 }
 
+/// The account that delegates the rewards compounded by the pool of `pool_account`.
+fn compounding_delegator<T: Config>(pool_account: &Pool<T::AccountId>) -> Option<T::AccountId> {
+	ReversePoolIdLookup::<T>::get(&pool_account.0).map(Pallet::<T>::generate_reward_account)
+}
+
 /// A staking strategy implementation that supports transfer based staking.
 ///
 /// In order to stake, this adapter transfers the funds from the member/delegator account to the
@@ -387,10 +405,22 @@ impl<
 		pool_account: Pool<Self::AccountId>,
 		member_account: Member<Self::AccountId>,
 	) -> BalanceOf<T> {
+		// the share of the member in the rewards compounded by the pool is delegated by the
+		// reward account of the pool.
+		let compounded = compounding_delegator::<T>(&pool_account)
+			.and_then(|reward_account| Delegation::delegator_balance(reward_account.into()))
+			.unwrap_or_default();
+		let compounded_share =
+			Pallet::<T>::member_compounded_share(member_account.clone(), compounded);
+
 		Delegation::agent_transferable_balance(pool_account.clone().into())
 			// pool should always be an agent.
 			.defensive_unwrap_or_default()
-			.min(Delegation::delegator_balance(member_account.into()).unwrap_or_default())
+			.min(
+				Delegation::delegator_balance(member_account.into())
+					.unwrap_or_default()
+					.saturating_add(compounded_share),
+			)
 	}
 
 	fn total_balance(pool_account: Pool<Self::AccountId>) -> Option<BalanceOf<T>> {
@@ -427,13 +457,91 @@ impl<
 		amount: BalanceOf<T>,
 		num_slashing_spans: u32,
 	) -> DispatchResult {
-		Delegation::withdraw_delegation(who.into(), pool_account.into(), amount, num_slashing_spans)
+		let delegated = Delegation::delegator_balance(who.clone().into())
+			.unwrap_or_default()
+			.min(amount);
+
+		// anything above the member's own delegation is their share of the rewards compounded by
+		// the pool, which are released from the reward account of the pool.
+		let compounded = amount.saturating_sub(delegated);
+		if compounded.is_zero() {
+			return Delegation::withdraw_delegation(
+				who.into(),
+				pool_account.into(),
+				delegated,
+				num_slashing_spans,
+			)
+		}
+
+		let reward_account =
+			compounding_delegator::<T>(&pool_account).ok_or(Error::<T>::PoolNotFound)?;
+
+		// the share is backed by the points of the member, so it must be computed before its own
+		// delegation is withdrawn, and only once its pending slash is applied.
+		ensure!(
+			Pallet::<T>::api_member_pending_slash(who.0.clone()).is_zero(),
+			Error::<T>::Defensive(DefensiveError::SlashNotApplied)
+		);
+		let pot = Delegation::delegator_balance(reward_account.clone().into()).unwrap_or_default();
+		ensure!(
+			compounded <= Pallet::<T>::member_compounded_share(who.clone(), pot),
+			Error::<T>::Defensive(DefensiveError::CompoundedShareExceeded)
+		);
+
+		Delegation::withdraw_delegation(
+			who.clone().into(),
+			pool_account.clone().into(),
+			delegated,
+			num_slashing_spans,
+		)?;
+		Delegation::withdraw_delegation(
+			reward_account.clone().into(),
+			pool_account.into(),
+			compounded,
+			num_slashing_spans,
+		)?;
+		T::Currency::transfer(&reward_account, &who.0, compounded, Preservation::Preserve)?;
+
+		Ok(())
 	}
 
 	fn dissolve(pool_account: Pool<Self::AccountId>) -> DispatchResult {
 		Delegation::remove_agent(pool_account.into())
 	}
 
+	fn release_compounded(
+		pool_account: Pool<Self::AccountId>,
+		reward_account: &Self::AccountId,
+	) -> DispatchResult {
+		let Some(compounded) = Delegation::delegator_balance(reward_account.clone().into()) else {
+			return Ok(())
+		};
+
+		// whatever is left of the pending slash of the pool is due to the compounded rewards.
+		let pending_slash = Delegation::pending_slash(pool_account.clone().into())
+			.unwrap_or_default()
+			.min(compounded);
+		if !pending_slash.is_zero() {
+			Delegation::delegator_slash(
+				pool_account.clone().into(),
+				reward_account.clone().into(),
+				pending_slash,
+				None,
+			)?;
+		}
+
+		let remaining = compounded.saturating_sub(pending_slash);
+		if remaining.is_zero() {
+			return Ok(())
+		}
+		Delegation::withdraw_delegation(
+			reward_account.clone().into(),
+			pool_account.into(),
+			remaining,
+			0,
+		)
+	}
+
 	fn pending_slash(pool_account: Pool<Self::AccountId>) -> Self::Balance {
 		Delegation::pending_slash(pool_account.into()).defensive_unwrap_or_default()
 	}
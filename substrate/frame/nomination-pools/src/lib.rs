@@ -72,6 +72,18 @@
 //!
 //! For design docs see the [reward pool](#reward-pool) section.
 //!
+//! ### Auto-compounding
+//!
+//! The `root` role of a pool can enable auto-compounding with [`Call::set_auto_compound`]. Once
+//! enabled, anyone can call [`Call::compound`] once per era to bond the rewards the pool received
+//! since the last time they were recorded into the pool's stake. No points are issued for them, so
+//! the balance of each point grows and all members compound their share at once, without having to
+//! bond their rewards individually. Commission is still taken from these rewards as usual.
+//!
+//! Compounded rewards are bonded from the reward account of the pool. With
+//! [`adapter::DelegateStake`], they remain delegated by the reward account and are released to
+//! members on top of their own delegation when they withdraw.
+//!
 //! ### Leave
 //!
 //! In order to leave, a member must take two steps.
@@ -1383,6 +1395,41 @@ impl<T: Config> RewardPool<T> {
 		self.total_rewards_claimed = self.total_rewards_claimed.saturating_add(reward);
 	}
 
+	/// Take the rewards received since the last update out of the reward pool, to be compounded
+	/// into the stake of the pool.
+	///
+	/// Commission is applied to the new rewards as in [`Self::update_records`], but the remaining
+	/// rewards are not added to the reward counter. Instead, they are returned and MUST leave the
+	/// reward account right after, as they are recorded as such.
+	fn take_compoundable_rewards(
+		&mut self,
+		id: PoolId,
+		commission: Perbill,
+	) -> Result<BalanceOf<T>, Error<T>> {
+		let balance = Self::current_balance(id);
+
+		// See `current_reward_counter` for how the payouts since the last update are computed.
+		let current_payout_balance = balance
+			.saturating_add(self.total_rewards_claimed)
+			.saturating_add(self.total_commission_claimed)
+			.saturating_sub(self.last_recorded_total_payouts);
+
+		let new_pending_commission = commission * current_payout_balance;
+		let compoundable = current_payout_balance.saturating_sub(new_pending_commission);
+
+		self.total_commission_pending =
+			self.total_commission_pending.saturating_add(new_pending_commission);
+
+		// Only the commission stays in the reward account: once `compoundable` has left, the
+		// payouts since the last update are fully accounted for.
+		self.last_recorded_total_payouts = self
+			.last_recorded_total_payouts
+			.checked_add(&new_pending_commission)
+			.ok_or(Error::<T>::OverflowRisk)?;
+
+		Ok(compoundable)
+	}
+
 	/// Update the recorded values of the reward pool.
 	///
 	/// This function MUST be called whenever the points in the bonded pool change, AND whenever the
@@ -1823,6 +1870,11 @@ pub mod pallet {
 	pub type ClaimPermissions<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, ClaimPermission, ValueQuery>;
 
+	/// Pools that have auto-compounding enabled, mapped to the earliest era in which their rewards
+	/// can be compounded next.
+	#[pallet::storage]
+	pub type AutoCompound<T: Config> = StorageMap<_, Twox64Concat, PoolId, EraIndex, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub min_join_bond: BalanceOf<T>,
@@ -1966,6 +2018,10 @@ pub mod pallet {
 			max_members_per_pool: Option<u32>,
 			global_max_commission: Option<Perbill>,
 		},
+		/// Auto-compounding of a pool's rewards has been enabled or disabled.
+		AutoCompoundUpdated { pool_id: PoolId, enabled: bool },
+		/// The rewards of a pool have been compounded into its stake.
+		Compounded { pool_id: PoolId, amount: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -2056,6 +2112,12 @@ pub mod pallet {
 		/// Account is restricted from participation in pools. This may happen if the account is
 		/// staking in another way already.
 		Restricted,
+		/// The pool does not have auto-compounding enabled.
+		AutoCompoundDisabled,
+		/// The rewards of the pool have already been compounded in this era.
+		AlreadyCompounded,
+		/// There are no rewards to compound.
+		NothingToCompound,
 	}
 
 	#[derive(
@@ -2077,6 +2139,9 @@ pub mod pallet {
 		DelegationUnsupported,
 		/// Unable to slash to the member of the pool.
 		SlashNotApplied,
+		/// The amount withdrawn by a member exceeds its share of the rewards compounded by the
+		/// pool.
+		CompoundedShareExceeded,
 	}
 
 	impl<T> From<DefensiveError> for Error<T> {
@@ -3236,6 +3301,74 @@ pub mod pallet {
 			Self::migrate_to_delegate_stake(pool_id)?;
 			Ok(Pays::No.into())
 		}
+
+		/// Enable or disable auto-compounding of the rewards of a pool.
+		///
+		/// The dispatch origin of this call must be signed by the `root` role of the pool. Once
+		/// enabled, the rewards of the pool can be compounded with [`Call::compound`], starting
+		/// from the current era.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_auto_compound())]
+		pub fn set_auto_compound(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			enabled: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(bonded_pool.is_root(&who), Error::<T>::DoesNotHavePermission);
+
+			if enabled {
+				// keep the era of the next compound if already enabled.
+				AutoCompound::<T>::mutate(pool_id, |next| {
+					next.get_or_insert_with(T::StakeAdapter::current_era);
+				});
+			} else {
+				AutoCompound::<T>::remove(pool_id);
+			}
+
+			Self::deposit_event(Event::<T>::AutoCompoundUpdated { pool_id, enabled });
+			Ok(())
+		}
+
+		/// Bond the rewards a pool received since they were last recorded into its stake,
+		/// without issuing any points.
+		///
+		/// This call can be dispatched permissionlessly, at most once per era for each pool that
+		/// has auto-compounding enabled with [`Call::set_auto_compound`]. The commission of the
+		/// pool is taken from the rewards before they are compounded.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::compound())]
+		pub fn compound(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			let _caller = ensure_signed(origin)?;
+			// ensure pool is not in an un-migrated state.
+			ensure!(!Self::api_pool_needs_delegate_migration(pool_id), Error::<T>::NotMigrated);
+
+			Self::do_compound(pool_id)
+		}
+	}
+
+	#[pallet::view_functions]
+	impl<T: Config> Pallet<T> {
+		/// The rewards `who` could claim from their pool, if they are a member of one.
+		pub fn pending_rewards(who: T::AccountId) -> Option<BalanceOf<T>> {
+			Self::api_pending_rewards(who)
+		}
+
+		/// The balance `points` of pool `pool_id` are worth.
+		pub fn points_to_balance(pool_id: PoolId, points: BalanceOf<T>) -> BalanceOf<T> {
+			Self::api_points_to_balance(pool_id, points)
+		}
+
+		/// The points `new_funds` would buy in pool `pool_id`.
+		pub fn balance_to_points(pool_id: PoolId, new_funds: BalanceOf<T>) -> BalanceOf<T> {
+			Self::api_balance_to_points(pool_id, new_funds)
+		}
+
+		/// The total balance of `who` in their pool, both active and unbonding.
+		pub fn member_total_balance(who: T::AccountId) -> BalanceOf<T> {
+			Self::api_member_total_balance(who)
+		}
 	}
 
 	#[pallet::hooks]
@@ -3282,9 +3415,17 @@ impl<T: Config> Pallet<T> {
 		let reward_account = bonded_pool.reward_account();
 		let bonded_account = bonded_pool.bonded_account();
 
+		// release what is left of the compounded rewards, if any, so that it can be drained below.
+		let _ = T::StakeAdapter::release_compounded(
+			Pool::from(bonded_account.clone()),
+			&reward_account,
+		)
+		.defensive();
+
 		ReversePoolIdLookup::<T>::remove(&bonded_account);
 		RewardPools::<T>::remove(bonded_pool.id);
 		SubPoolsStorage::<T>::remove(bonded_pool.id);
+		AutoCompound::<T>::remove(bonded_pool.id);
 
 		// remove the ED restriction from the pool reward account.
 		let _ = Self::unfreeze_pool_deposit(&bonded_pool.reward_account()).defensive();
@@ -3623,6 +3764,41 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn do_compound(pool_id: PoolId) -> DispatchResult {
+		let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		ensure!(!bonded_pool.is_destroying(), Error::<T>::CanNotChangeState);
+
+		let next_era = AutoCompound::<T>::get(pool_id).ok_or(Error::<T>::AutoCompoundDisabled)?;
+		let current_era = T::StakeAdapter::current_era();
+		ensure!(current_era >= next_era, Error::<T>::AlreadyCompounded);
+
+		let mut reward_pool = RewardPools::<T>::get(pool_id)
+			.defensive_ok_or::<Error<T>>(DefensiveError::RewardPoolNotFound.into())?;
+		let amount =
+			reward_pool.take_compoundable_rewards(pool_id, bonded_pool.commission.current())?;
+		ensure!(!amount.is_zero(), Error::<T>::NothingToCompound);
+
+		// The rewards are bonded on behalf of the reward account, and since no points are issued
+		// for them, they are shared among all members in proportion to their points.
+		let reward_account = bonded_pool.reward_account();
+		T::StakeAdapter::pledge_bond(
+			Member::from(reward_account.clone()),
+			Pool::from(bonded_pool.bonded_account()),
+			&reward_account,
+			amount,
+			BondType::Extra,
+		)?;
+		TotalValueLocked::<T>::mutate(|tvl| {
+			tvl.saturating_accrue(amount);
+		});
+
+		RewardPools::<T>::insert(pool_id, reward_pool);
+		AutoCompound::<T>::insert(pool_id, current_era.saturating_add(1));
+
+		Self::deposit_event(Event::<T>::Compounded { pool_id, amount });
+		Ok(())
+	}
+
 	fn do_claim_commission(who: T::AccountId, pool_id: PoolId) -> DispatchResult {
 		let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 		ensure!(bonded_pool.can_claim_commission(&who), Error::<T>::DoesNotHavePermission);
@@ -3781,6 +3957,24 @@ impl<T: Config> Pallet<T> {
 		Ok(actual_balance.saturating_sub(expected_balance))
 	}
 
+	/// The share of a member in the rewards compounded by its pool, out of `compounded`.
+	///
+	/// This is the part of the balance of the member, according to its points, that is not backed
+	/// by its own delegation. Any pending slash of the member must be applied beforehand, see
+	/// [`Self::member_pending_slash`].
+	pub(crate) fn member_compounded_share(
+		member_account: Member<T::AccountId>,
+		compounded: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		let Some(pool_member) = PoolMembers::<T>::get(member_account.clone().get()) else {
+			return Zero::zero()
+		};
+
+		let delegated =
+			T::StakeAdapter::member_delegation_balance(member_account).unwrap_or_default();
+		pool_member.total_balance().saturating_sub(delegated).min(compounded)
+	}
+
 	/// Apply freeze on reward account to restrict it from going below ED.
 	pub(crate) fn freeze_pool_deposit(reward_acc: &T::AccountId) -> DispatchResult {
 		T::Currency::set_freeze(
@@ -7638,3 +7638,129 @@ mod filter {
 		});
 	}
 }
+
+mod auto_compound {
+	use super::*;
+
+	#[test]
+	fn compound_works() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			let _ = pool_events_since_last_call();
+			assert_eq!(BondedPools::<Runtime>::get(1).unwrap().points, 30);
+			deposit_rewards(30);
+
+			// only the root of the pool can enable auto-compounding.
+			assert_noop!(
+				Pools::compound(RuntimeOrigin::signed(99), 1),
+				Error::<Runtime>::AutoCompoundDisabled
+			);
+			assert_noop!(
+				Pools::set_auto_compound(RuntimeOrigin::signed(10), 1, true),
+				Error::<Runtime>::DoesNotHavePermission
+			);
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(900), 1, true));
+
+			// when
+			assert_ok!(Pools::compound(RuntimeOrigin::signed(99), 1));
+			// the mock does not hold the delegated rewards, so emulate it.
+			remove_rewards(30);
+
+			// then the rewards are staked without issuing any points.
+			assert_eq!(member_delegation(default_reward_account()), 30);
+			assert_eq!(pool_balance(1), 60);
+			assert_eq!(TotalValueLocked::<Runtime>::get(), 60);
+			assert_eq!(BondedPools::<Runtime>::get(1).unwrap().points, 30);
+			assert_eq!(PoolMembers::<Runtime>::get(20).unwrap().total_balance(), 40);
+			assert_eq!(PoolMembers::<Runtime>::get(10).unwrap().total_balance(), 20);
+			// and they are no longer claimable.
+			assert_eq!(Pools::api_pending_rewards(20), Some(0));
+			assert_eq!(Pools::api_pending_rewards(10), Some(0));
+
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::AutoCompoundUpdated { pool_id: 1, enabled: true },
+					Event::Compounded { pool_id: 1, amount: 30 },
+				]
+			);
+
+			// commission is still taken from compounded rewards.
+			assert_ok!(Pools::set_commission(
+				RuntimeOrigin::signed(900),
+				1,
+				Some((Perbill::from_percent(50), 900))
+			));
+			deposit_rewards(20);
+
+			// rewards can only be compounded once per era.
+			assert_noop!(
+				Pools::compound(RuntimeOrigin::signed(99), 1),
+				Error::<Runtime>::AlreadyCompounded
+			);
+
+			CurrentEra::set(1);
+			assert_ok!(Pools::compound(RuntimeOrigin::signed(99), 1));
+			remove_rewards(10);
+
+			assert_eq!(RewardPools::<Runtime>::get(1).unwrap().total_commission_pending, 10);
+			assert_eq!(pool_balance(1), 70);
+			assert_eq!(Pools::api_pending_rewards(20), Some(0));
+
+			// nothing left to compound.
+			CurrentEra::set(2);
+			assert_noop!(
+				Pools::compound(RuntimeOrigin::signed(99), 1),
+				Error::<Runtime>::NothingToCompound
+			);
+
+			// once disabled, rewards accrue to members as usual.
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(900), 1, false));
+			assert_noop!(
+				Pools::compound(RuntimeOrigin::signed(99), 1),
+				Error::<Runtime>::AutoCompoundDisabled
+			);
+			assert!(AutoCompound::<Runtime>::get(1).is_none());
+		});
+	}
+
+	#[test]
+	fn withdraw_is_capped_at_compounded_share() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(900), 1, true));
+			deposit_rewards(30);
+			assert_ok!(Pools::compound(RuntimeOrigin::signed(99), 1));
+			// the mock does not hold the delegated rewards, so emulate it.
+			remove_rewards(30);
+			assert_eq!(member_delegation(default_reward_account()), 30);
+
+			// each member is entitled to the compounded rewards in proportion to its points.
+			assert_eq!(Pools::member_compounded_share(Member::from(20), 30), 20);
+			assert_eq!(Pools::member_compounded_share(Member::from(10), 30), 10);
+			// and never to more than what is left of them.
+			assert_eq!(Pools::member_compounded_share(Member::from(20), 15), 15);
+			assert_eq!(Pools::member_compounded_share(Member::from(99), 30), 0);
+
+			// when
+			assert_ok!(Pools::unbond(RuntimeOrigin::signed(20), 20, 20));
+			CurrentEra::set(3);
+			let _ = pool_events_since_last_call();
+			let free_before = Currency::free_balance(&20);
+			// the mock does not release the delegated rewards, so emulate it.
+			deposit_rewards(20);
+			assert_ok!(Pools::withdraw_unbonded(RuntimeOrigin::signed(20), 20, 0));
+
+			// then the member gets its own delegation back along with its share of the compounded
+			// rewards, while the share of the depositor stays delegated.
+			assert_eq!(Currency::free_balance(&20), free_before + 40);
+			assert_eq!(member_delegation(default_reward_account()), 10);
+			assert_eq!(Pools::member_compounded_share(Member::from(10), 10), 10);
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::Withdrawn { member: 20, pool_id: 1, points: 40, balance: 40 },
+					Event::MemberRemoved { pool_id: 1, member: 20, released_balance: 0 },
+				]
+			);
+		});
+	}
+}
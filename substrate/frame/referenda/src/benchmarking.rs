@@ -21,12 +21,13 @@ use super::*;
 use crate::Pallet as Referenda;
 use alloc::{borrow::Cow, vec, vec::Vec};
 use assert_matches::assert_matches;
+use codec::Decode;
 use frame_benchmarking::v1::{
 	account, benchmarks_instance_pallet, whitelist_account, BenchmarkError,
 };
 use frame_support::{
 	assert_ok,
-	traits::{Currency, EnsureOrigin, EnsureOriginWithArg, UnfilteredDispatchable},
+	traits::{Currency, EnsureOrigin, EnsureOriginWithArg, OriginTrait, UnfilteredDispatchable},
 };
 use frame_system::RawOrigin;
 use sp_runtime::traits::Bounded as ArithBounded;
@@ -54,6 +55,8 @@ fn dummy_call<T: Config<I>, I: 'static>() -> BoundedCallOf<T, I> {
 }
 
 fn create_referendum<T: Config<I>, I: 'static>(origin: T::RuntimeOrigin) -> ReferendumIndex {
+	// Reading the tracks from storage is the worst case.
+	Referenda::<T, I>::do_store_tracks();
 	if let Ok(caller) = frame_system::ensure_signed(origin.clone()) {
 		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value());
 		whitelist_account!(caller);
@@ -112,7 +115,7 @@ fn fill_queue<T: Config<I>, I: 'static>(
 
 fn info<T: Config<I>, I: 'static>(index: ReferendumIndex) -> Cow<'static, TrackInfoOf<T, I>> {
 	let status = Referenda::<T, I>::ensure_ongoing(index).unwrap();
-	T::Tracks::info(status.track).expect("Id value returned from T::Tracks")
+	DynamicTracks::<T, I>::info(status.track).expect("Id value returned from DynamicTracks")
 }
 
 fn make_passing_after<T: Config<I>, I: 'static>(index: ReferendumIndex, period_portion: Perbill) {
@@ -197,6 +200,14 @@ fn is_not_confirming<T: Config<I>, I: 'static>(index: ReferendumIndex) -> bool {
 	)
 }
 
+fn existing_track<T: Config<I>, I: 'static>() -> (TrackIdOf<T, I>, TrackInfoOf<T, I>) {
+	let Track { id, info } = T::Tracks::tracks()
+		.next()
+		.expect("At least one track is configured")
+		.into_owned();
+	(id, info)
+}
+
 benchmarks_instance_pallet! {
 	submit {
 		let origin =
@@ -205,6 +216,7 @@ benchmarks_instance_pallet! {
 			T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value());
 			whitelist_account!(caller);
 		}
+		Referenda::<T, I>::do_store_tracks();
 	}: _<T::RuntimeOrigin>(
 		origin,
 		Box::new(RawOrigin::Root.into()),
@@ -659,6 +671,40 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::MetadataCleared { index, hash }.into());
 	}
 
+	insert_track {
+		let origin = T::UpdateTracksOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		Referenda::<T, I>::do_store_tracks();
+		let (_, info) = existing_track::<T, I>();
+		let id = TrackIdOf::<T, I>::decode(&mut &[u8::MAX; 32][..])
+			.expect("track identifiers are decodable from enough bytes");
+		let proposer: T::AccountId = account("proposer", 0, SEED);
+		let proposal_origin = T::RuntimeOrigin::from(RawOrigin::Signed(proposer)).caller().clone();
+	}: _<T::RuntimeOrigin>(origin, id, info, Box::new(proposal_origin))
+	verify {
+		assert_last_event::<T, I>(Event::TrackInserted { track: id }.into());
+	}
+
+	update_track {
+		let origin = T::UpdateTracksOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		Referenda::<T, I>::do_store_tracks();
+		let (id, info) = existing_track::<T, I>();
+	}: _<T::RuntimeOrigin>(origin, id, info)
+	verify {
+		assert_last_event::<T, I>(Event::TrackUpdated { track: id }.into());
+	}
+
+	retire_track {
+		let origin = T::UpdateTracksOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		Referenda::<T, I>::do_store_tracks();
+		let (id, _) = existing_track::<T, I>();
+	}: _<T::RuntimeOrigin>(origin, id)
+	verify {
+		assert_last_event::<T, I>(Event::TrackRetired { track: id }.into());
+	}
+
 	impl_benchmark_test_suite!(
 		Referenda,
 		crate::mock::ExtBuilder::default().build(),
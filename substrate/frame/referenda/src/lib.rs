@@ -90,18 +90,21 @@ use sp_runtime::{
 
 mod branch;
 pub mod migration;
+pub mod runtime_api;
+mod tracks;
 mod types;
 pub mod weights;
 
 use self::branch::{BeginDecidingBranch, OneFewerDecidingBranch, ServiceBranch};
 pub use self::{
 	pallet::*,
+	tracks::DynamicTracks,
 	types::{
 		BalanceOf, BlockNumberFor, BoundedCallOf, CallOf, ConstTrackInfo, Curve, DecidingStatus,
 		DecidingStatusOf, Deposit, InsertSorted, NegativeImbalanceOf, PalletsOriginOf,
-		ReferendumIndex, ReferendumInfo, ReferendumInfoOf, ReferendumStatus, ReferendumStatusOf,
-		ScheduleAddressOf, StringLike, TallyOf, Track, TrackIdOf, TrackInfo, TrackInfoOf,
-		TracksInfo, VotesOf,
+		ReferendumIndex, ReferendumInfo, ReferendumInfoOf, ReferendumProjection, ReferendumStatus,
+		ReferendumStatusOf, ScheduleAddressOf, StringLike, TallyOf, Track, TrackIdOf, TrackInfo,
+		TrackInfoOf, TracksInfo, VotesOf,
 	},
 	weights::WeightInfo,
 };
@@ -174,6 +177,8 @@ pub mod pallet {
 		type CancelOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// Origin from which any vote may be killed.
 		type KillOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin from which tracks may be added, modified or retired.
+		type UpdateTracksOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// Handler for the unbalanced reduction when slashing a preimage deposit.
 		type Slash: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
 		/// The counting type for votes. Usually just balance.
@@ -196,6 +201,12 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxQueued: Get<u32>;
 
+		/// Maximum number of tracks which may be stored on-chain, including retired ones.
+		///
+		/// Must not be lower than the number of tracks of `Config::Tracks`.
+		#[pallet::constant]
+		type MaxTracks: Get<u32>;
+
 		/// The number of blocks after submission that a referendum must begin being decided by.
 		/// Once this passes, then anyone may cancel the referendum.
 		#[pallet::constant]
@@ -228,11 +239,12 @@ pub mod pallet {
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// A list of tracks.
 		///
-		/// Note: if the tracks are dynamic, the value in the static metadata might be inaccurate.
+		/// Once the tracks are modified on-chain, these are the tracks in [`StoredTracks`] as of
+		/// the time the metadata was generated.
 		#[pallet::constant_name(Tracks)]
 		fn tracks() -> Vec<(TrackIdOf<T, I>, ConstTrackInfo<BalanceOf<T, I>, BlockNumberFor<T, I>>)>
 		{
-			T::Tracks::tracks()
+			DynamicTracks::<T, I>::tracks()
 				.map(|t| t.into_owned())
 				.map(|Track { id, info }| {
 					(
@@ -291,6 +303,30 @@ pub mod pallet {
 	pub type MetadataOf<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, ReferendumIndex, T::Hash>;
 
+	/// Whether the tracks are read from [`StoredTracks`] rather than from `Config::Tracks`.
+	///
+	/// This is set once the tracks are first modified on-chain.
+	#[pallet::storage]
+	pub type UseStoredTracks<T: Config<I>, I: 'static = ()> = StorageValue<_, bool, ValueQuery>;
+
+	/// The on-chain configuration of each track, in use once [`UseStoredTracks`] is set.
+	///
+	/// Holds at most `Config::MaxTracks` tracks.
+	#[pallet::storage]
+	pub type StoredTracks<T: Config<I>, I: 'static = ()> =
+		CountedStorageMap<_, Twox64Concat, TrackIdOf<T, I>, TrackInfoOf<T, I>>;
+
+	/// The tracks which no longer accept new referenda.
+	#[pallet::storage]
+	pub type RetiredTracks<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, TrackIdOf<T, I>, ()>;
+
+	/// The track of each proposal origin of the tracks added on-chain. Other origins are mapped to
+	/// their track by `Config::Tracks`.
+	#[pallet::storage]
+	pub type TrackOrigins<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, PalletsOriginOf<T>, TrackIdOf<T, I>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -410,6 +446,21 @@ pub mod pallet {
 			/// Preimage hash.
 			hash: T::Hash,
 		},
+		/// A track has been added.
+		TrackInserted {
+			/// The identifier of the track.
+			track: TrackIdOf<T, I>,
+		},
+		/// The configuration of a track has been changed.
+		TrackUpdated {
+			/// The identifier of the track.
+			track: TrackIdOf<T, I>,
+		},
+		/// A track has been retired and no longer accepts new referenda.
+		TrackRetired {
+			/// The identifier of the track.
+			track: TrackIdOf<T, I>,
+		},
 	}
 
 	#[pallet::error]
@@ -442,6 +493,16 @@ pub mod pallet {
 		PreimageNotExist,
 		/// The preimage is stored with a different length than the one provided.
 		PreimageStoredWithDifferentLength,
+		/// A track with the given identifier already exists.
+		DuplicateTrack,
+		/// The proposal origin is already mapped to a track which is not retired.
+		OriginInUse,
+		/// The track has been retired.
+		RetiredTrack,
+		/// The track configuration is invalid.
+		InvalidTrack,
+		/// There are already `MaxTracks` tracks, including retired ones.
+		TooManyTracks,
 	}
 
 	#[pallet::hooks]
@@ -455,6 +516,10 @@ pub mod pallet {
 		#[cfg(any(feature = "std", test))]
 		fn integrity_test() {
 			T::Tracks::check_integrity().expect("Static tracks configuration is valid.");
+			assert!(
+				T::Tracks::tracks().count() <= T::MaxTracks::get() as usize,
+				"MaxTracks must not be lower than the number of static tracks."
+			);
 		}
 	}
 
@@ -490,8 +555,8 @@ pub mod pallet {
 				}
 			}
 
-			let track =
-				T::Tracks::track_for(&proposal_origin).map_err(|_| Error::<T, I>::NoTrack)?;
+			let track = DynamicTracks::<T, I>::track_for(&proposal_origin)
+				.map_err(|_| Error::<T, I>::NoTrack)?;
 			let submission_deposit = Self::take_deposit(who, T::SubmissionDeposit::get())?;
 			let index = ReferendumCount::<T, I>::mutate(|x| {
 				let r = *x;
@@ -537,7 +602,7 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			let mut status = Self::ensure_ongoing(index)?;
 			ensure!(status.decision_deposit.is_none(), Error::<T, I>::HasDeposit);
-			let track = T::Tracks::info(status.track).ok_or(Error::<T, I>::NoTrack)?;
+			let track = DynamicTracks::<T, I>::info(status.track).ok_or(Error::<T, I>::NoTrack)?;
 			status.decision_deposit =
 				Some(Self::take_deposit(who.clone(), track.decision_deposit)?);
 			let now = T::BlockNumberProvider::current_block_number();
@@ -667,7 +732,7 @@ pub mod pallet {
 			track: TrackIdOf<T, I>,
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
-			let track_info = T::Tracks::info(track).ok_or(Error::<T, I>::BadTrack)?;
+			let track_info = DynamicTracks::<T, I>::info(track).ok_or(Error::<T, I>::BadTrack)?;
 			let mut track_queue = TrackQueue::<T, I>::get(track);
 			let branch =
 				if let Some((index, mut status)) = Self::next_for_deciding(&mut track_queue) {
@@ -751,6 +816,86 @@ pub mod pallet {
 				Ok(())
 			}
 		}
+
+		/// Add a new track.
+		///
+		/// - `origin`: must be `UpdateTracksOrigin`.
+		/// - `id`: The identifier of the new track. It must not be in use by any other track,
+		///   retired or not.
+		/// - `info`: The configuration of the new track.
+		/// - `proposal_origin`: The origin from which the proposals of the new track are to be
+		///   executed. It must not already be mapped to a track, unless that track is retired.
+		///
+		/// Emits `TrackInserted`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(
+			T::WeightInfo::insert_track().saturating_add(Pallet::<T, I>::store_tracks_weight())
+		)]
+		pub fn insert_track(
+			origin: OriginFor<T>,
+			id: TrackIdOf<T, I>,
+			info: TrackInfoOf<T, I>,
+			proposal_origin: Box<PalletsOriginOf<T>>,
+		) -> DispatchResult {
+			T::UpdateTracksOrigin::ensure_origin(origin)?;
+			Self::do_insert_track(id, info, *proposal_origin)
+		}
+
+		/// Change the configuration of a track.
+		///
+		/// Referenda already in flight on the track are serviced according to the new
+		/// configuration from then on.
+		///
+		/// - `origin`: must be `UpdateTracksOrigin`.
+		/// - `id`: The identifier of an existing track which is not retired.
+		/// - `info`: The new configuration of the track.
+		///
+		/// Emits `TrackUpdated`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(
+			T::WeightInfo::update_track().saturating_add(Pallet::<T, I>::store_tracks_weight())
+		)]
+		pub fn update_track(
+			origin: OriginFor<T>,
+			id: TrackIdOf<T, I>,
+			info: TrackInfoOf<T, I>,
+		) -> DispatchResult {
+			T::UpdateTracksOrigin::ensure_origin(origin)?;
+			Self::do_update_track(id, info)
+		}
+
+		/// Retire a track so that no new referenda may be submitted on it.
+		///
+		/// Referenda already in flight on the track are concluded according to its last
+		/// configuration.
+		///
+		/// - `origin`: must be `UpdateTracksOrigin`.
+		/// - `id`: The identifier of an existing track which is not retired.
+		///
+		/// Emits `TrackRetired`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(
+			T::WeightInfo::retire_track().saturating_add(Pallet::<T, I>::store_tracks_weight())
+		)]
+		pub fn retire_track(origin: OriginFor<T>, id: TrackIdOf<T, I>) -> DispatchResult {
+			T::UpdateTracksOrigin::ensure_origin(origin)?;
+			Self::do_retire_track(id)
+		}
+	}
+
+	#[pallet::view_functions]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Whether the ongoing referendum `index` is passing, or `None` if it is not ongoing.
+		pub fn referendum_passing(index: ReferendumIndex) -> Option<bool> {
+			Self::is_referendum_passing(index).ok()
+		}
+
+		/// How the ongoing referendum `index` will proceed should its tally remain unchanged.
+		pub fn tally_projection(
+			index: ReferendumIndex,
+		) -> Option<ReferendumProjection<TrackIdOf<T, I>, BlockNumberFor<T, I>>> {
+			Self::referendum_projection(index)
+		}
 	}
 }
 
@@ -761,7 +906,7 @@ impl<T: Config<I>, I: 'static> Polling<T::Tally> for Pallet<T, I> {
 	type Class = TrackIdOf<T, I>;
 
 	fn classes() -> Vec<Self::Class> {
-		T::Tracks::track_ids().collect()
+		DynamicTracks::<T, I>::track_ids().collect()
 	}
 
 	fn access_poll<R>(
@@ -853,7 +998,7 @@ impl<T: Config<I>, I: 'static> Polling<T::Tally> for Pallet<T, I> {
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn max_ongoing() -> (Self::Class, u32) {
-		let r = T::Tracks::tracks()
+		let r = DynamicTracks::<T, I>::tracks()
 			.max_by_key(|t| t.info.max_deciding)
 			.expect("Always one class");
 		(r.id, r.info.max_deciding)
@@ -872,13 +1017,94 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
+	/// Project how the ongoing referendum `index` will proceed should its tally remain unchanged.
+	///
+	/// Returns `None` if the referendum is not ongoing or its track does not exist.
+	pub fn referendum_projection(
+		index: ReferendumIndex,
+	) -> Option<ReferendumProjection<TrackIdOf<T, I>, BlockNumberFor<T, I>>> {
+		let status = Self::ensure_ongoing(index).ok()?;
+		let track = DynamicTracks::<T, I>::info(status.track)?;
+		let now = T::BlockNumberProvider::current_block_number();
+		let period = track.decision_period;
+
+		let elapsed = status
+			.deciding
+			.as_ref()
+			.map_or(Zero::zero(), |deciding| now.saturating_sub(deciding.since));
+		let x = Perbill::from_rational(elapsed.min(period), period);
+		let passing = Self::is_passing(
+			&status.tally,
+			elapsed,
+			period,
+			&track.min_support,
+			&track.min_approval,
+			status.track,
+		);
+
+		let queue_position = if status.in_queue {
+			let queue = TrackQueue::<T, I>::get(status.track);
+			// The queue is drained from the back.
+			queue.iter().rev().position(|(i, _)| *i == index).map(|p| p as u32)
+		} else {
+			None
+		};
+
+		// The deciding status the referendum has or, if it has yet to be decided, would have were
+		// it to begin being decided as soon as possible.
+		let deciding = match status.deciding.clone() {
+			Some(deciding) => Some(deciding),
+			None if status.decision_deposit.is_some() && !status.in_queue => {
+				let prepare_end = status.submitted.saturating_add(track.prepare_period);
+				Some(DecidingStatus { since: prepare_end.max(now), confirming: None })
+			},
+			None => None,
+		};
+		let (confirm_start, confirm_end) = match deciding {
+			Some(DecidingStatus { confirming: Some(end), .. }) =>
+				(Some(end.saturating_sub(track.confirm_period)), Some(end)),
+			Some(deciding) => {
+				let at =
+					Self::decision_time(&deciding, &status.tally, status.track, &track).max(now);
+				let will_pass = Self::is_passing(
+					&status.tally,
+					at.saturating_sub(deciding.since),
+					period,
+					&track.min_support,
+					&track.min_approval,
+					status.track,
+				);
+				if will_pass {
+					(Some(at), Some(at.saturating_add(track.confirm_period)))
+				} else {
+					(None, None)
+				}
+			},
+			None => (None, None),
+		};
+
+		Some(ReferendumProjection {
+			track: status.track,
+			approval: status.tally.approval(status.track),
+			approval_threshold: track.min_approval.threshold(x),
+			support: status.tally.support(status.track),
+			support_threshold: track.min_support.threshold(x),
+			passing,
+			deciding_since: status.deciding.map(|d| d.since),
+			confirm_start,
+			confirm_end,
+			queue_position,
+		})
+	}
+
 	/// Returns whether the referendum is passing.
 	/// Referendum must be ongoing and its track must exist.
 	pub fn is_referendum_passing(ref_index: ReferendumIndex) -> Result<bool, DispatchError> {
 		let info = ReferendumInfoFor::<T, I>::get(ref_index).ok_or(Error::<T, I>::BadReferendum)?;
 		match info {
 			ReferendumInfo::Ongoing(status) => {
-				let track = T::Tracks::info(status.track).ok_or(Error::<T, I>::NoTrack)?;
+				let track =
+					DynamicTracks::<T, I>::info(status.track).ok_or(Error::<T, I>::NoTrack)?;
 				let elapsed = if let Some(deciding) = status.deciding {
 					T::BlockNumberProvider::current_block_number().saturating_sub(deciding.since)
 				} else {
@@ -1108,7 +1334,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	) -> (ReferendumInfoOf<T, I>, bool, ServiceBranch) {
 		let mut dirty = false;
 		// Should it begin being decided?
-		let track = match T::Tracks::info(status.track) {
+		let track = match DynamicTracks::<T, I>::info(status.track) {
 			Some(x) => x,
 			None => return (ReferendumInfo::Ongoing(status), false, ServiceBranch::Fail),
 		};
@@ -1375,7 +1601,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			match referendum {
 				ReferendumInfo::Ongoing(status) => {
 					ensure!(
-						T::Tracks::info(status.track).is_some(),
+						DynamicTracks::<T, I>::info(status.track).is_some(),
 						"No track info for the track of the referendum."
 					);
 
@@ -1399,9 +1625,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	///
 	/// * The referendum indices stored in [`TrackQueue`] must exist as keys in the
 	///  [`ReferendumInfoFor`] storage map.
+	/// * There must be at most `MaxTracks` tracks in [`StoredTracks`].
 	#[cfg(any(feature = "try-runtime", test))]
 	fn try_state_tracks() -> Result<(), sp_runtime::TryRuntimeError> {
-		T::Tracks::tracks().try_for_each(|track| {
+		ensure!(
+			StoredTracks::<T, I>::count() <= T::MaxTracks::get(),
+			"`StoredTracks` should hold at most `MaxTracks` tracks"
+		);
+		DynamicTracks::<T, I>::tracks().try_for_each(|track| {
 			TrackQueue::<T, I>::get(track.id).iter().try_for_each(
 				|(referendum_index, _)| -> Result<(), sp_runtime::TryRuntimeError> {
 					ensure!(
@@ -288,6 +288,40 @@ pub mod switch_block_number_provider {
 	}
 }
 
+/// Migration for moving the tracks of `Config::Tracks` into storage, from where they can be
+/// modified on-chain.
+///
+/// This migration is not guarded by the storage version, but does nothing if the tracks are
+/// already in storage.
+pub mod stored_tracks {
+	use super::*;
+
+	/// The log target.
+	const TARGET: &'static str = "runtime::referenda::migration::stored_tracks";
+
+	/// Copies the tracks of `Config::Tracks` into [`StoredTracks`].
+	pub struct MigrateToStoredTracks<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToStoredTracks<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let copied = Pallet::<T, I>::do_store_tracks();
+			log::info!(target: TARGET, "copied {} tracks into storage.", copied);
+			T::DbWeight::get().reads_writes(1, (copied as u64).saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(UseStoredTracks::<T, I>::get(), "tracks must be read from storage.");
+			for track in T::Tracks::tracks() {
+				ensure!(
+					StoredTracks::<T, I>::contains_key(track.id),
+					"all static tracks must be in storage."
+				);
+			}
+			Ok(())
+		}
+	}
+}
+
 #[cfg(test)]
 pub mod test {
 	use super::*;
@@ -404,4 +438,22 @@ pub mod test {
 			);
 		});
 	}
+
+	#[test]
+	fn migration_to_stored_tracks_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert!(!UseStoredTracks::<T, ()>::get());
+			stored_tracks::MigrateToStoredTracks::<T, ()>::on_runtime_upgrade();
+			assert!(UseStoredTracks::<T, ()>::get());
+			for track in <T as Config<()>>::Tracks::tracks() {
+				assert_eq!(StoredTracks::<T, ()>::get(track.id).as_ref(), Some(&track.info));
+			}
+			assert_eq!(StoredTracks::<T, ()>::iter().count(), 3);
+
+			// Running it again does not overwrite tracks modified on-chain.
+			StoredTracks::<T, ()>::mutate(0, |info| info.as_mut().unwrap().max_deciding = 5);
+			stored_tracks::MigrateToStoredTracks::<T, ()>::on_runtime_upgrade();
+			assert_eq!(StoredTracks::<T, ()>::get(0).unwrap().max_deciding, 5);
+		});
+	}
 }
@@ -72,6 +72,15 @@ impl pallet_preimage::Config for Test {
 	type ManagerOrigin = EnsureRoot<u64>;
 	type Consideration = ();
 }
+/// Timed scheduling is not used in these tests.
+pub struct NoTime;
+impl frame_support::traits::Time for NoTime {
+	type Moment = u64;
+	fn now() -> u64 {
+		0
+	}
+}
+
 impl pallet_scheduler::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeOrigin = RuntimeOrigin;
@@ -84,6 +93,8 @@ impl pallet_scheduler::Config for Test {
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = Preimage;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type TimeProvider = NoTime;
+	type MaxTimedAgendas = ConstU32<0>;
 }
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
 impl pallet_balances::Config for Test {
@@ -200,11 +211,13 @@ impl Config for Test {
 	type SubmitOrigin = frame_system::EnsureSigned<u64>;
 	type CancelOrigin = EnsureSignedBy<Four, u64>;
 	type KillOrigin = EnsureRoot<u64>;
+	type UpdateTracksOrigin = EnsureRoot<u64>;
 	type Slash = ();
 	type Votes = u32;
 	type Tally = Tally;
 	type SubmissionDeposit = ConstU64<2>;
 	type MaxQueued = ConstU32<3>;
+	type MaxTracks = ConstU32<5>;
 	type UndecidingTimeout = ConstU64<20>;
 	type AlarmInterval = AlarmInterval;
 	type Tracks = TestTracksInfo;
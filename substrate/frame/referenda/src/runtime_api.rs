@@ -0,0 +1,36 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the FRAME Referenda pallet.
+
+use crate::{ReferendumIndex, ReferendumProjection};
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	pub trait ReferendaApi<TrackId, BlockNumber>
+	where
+		TrackId: Codec,
+		BlockNumber: Codec,
+	{
+		/// Returns the current approval and support of the ongoing referendum `index` against its
+		/// track's curves, together with when it would start and finish confirming and its
+		/// position in the track's deciding queue, assuming the tally stays as it is.
+		fn referendum_projection(
+			index: ReferendumIndex,
+		) -> Option<ReferendumProjection<TrackId, BlockNumber>>;
+	}
+}
@@ -23,7 +23,7 @@ use assert_matches::assert_matches;
 use codec::Decode;
 use frame_support::{assert_noop, assert_ok, dispatch::RawOrigin, traits::Contains};
 use pallet_balances::Error as BalancesError;
-use sp_runtime::DispatchError::BadOrigin;
+use sp_runtime::{DispatchError::BadOrigin, FixedI64};
 
 #[test]
 fn params_should_work() {
@@ -707,3 +707,250 @@ fn zero_enactment_delay_executes_proposal_at_next_block() {
 		assert_eq!(Balances::free_balance(42), 20);
 	});
 }
+
+#[test]
+fn tracks_can_be_inserted_and_retired() {
+	ExtBuilder::default().build_and_execute(|| {
+		let mut info = TestTracksInfo::info(1).unwrap().into_owned();
+		info.decision_deposit = 5;
+		let signed_two: OriginCaller = RawOrigin::Signed(2).into();
+
+		assert_noop!(
+			Referenda::insert_track(
+				RuntimeOrigin::signed(1),
+				3,
+				info.clone(),
+				Box::new(signed_two.clone())
+			),
+			BadOrigin
+		);
+		assert_ok!(Referenda::insert_track(
+			RuntimeOrigin::root(),
+			3,
+			info.clone(),
+			Box::new(signed_two.clone())
+		));
+		System::assert_last_event(RuntimeEvent::Referenda(crate::Event::TrackInserted {
+			track: 3,
+		}));
+		assert!(UseStoredTracks::<Test>::get());
+		assert_eq!(DynamicTracks::<Test>::track_ids().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+
+		// Neither the identifier nor the proposal origin may be reused.
+		assert_noop!(
+			Referenda::insert_track(
+				RuntimeOrigin::root(),
+				3,
+				info.clone(),
+				Box::new(RawOrigin::Signed(3).into())
+			),
+			Error::<Test>::DuplicateTrack
+		);
+		assert_noop!(
+			Referenda::insert_track(
+				RuntimeOrigin::root(),
+				4,
+				info.clone(),
+				Box::new(RawOrigin::Root.into())
+			),
+			Error::<Test>::OriginInUse
+		);
+
+		// Referenda are submitted on the new track with its configuration.
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(signed_two.clone()),
+			set_balance_proposal_bounded(1),
+			DispatchTime::At(10),
+		));
+		assert_eq!(Referenda::ensure_ongoing(0).unwrap().track, 3);
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&2), 5);
+
+		// A retired track accepts no new referenda, but the ones in flight are concluded.
+		assert_ok!(Referenda::retire_track(RuntimeOrigin::root(), 3));
+		System::assert_last_event(RuntimeEvent::Referenda(crate::Event::TrackRetired { track: 3 }));
+		assert_noop!(
+			Referenda::submit(
+				RuntimeOrigin::signed(1),
+				Box::new(signed_two.clone()),
+				set_balance_proposal_bounded(1),
+				DispatchTime::At(10),
+			),
+			Error::<Test>::NoTrack
+		);
+		assert_noop!(
+			Referenda::retire_track(RuntimeOrigin::root(), 3),
+			Error::<Test>::RetiredTrack
+		);
+		assert_noop!(
+			Referenda::update_track(RuntimeOrigin::root(), 3, info.clone()),
+			Error::<Test>::RetiredTrack
+		);
+		set_tally(0, 100, 0);
+		run_to(10);
+		assert_matches!(ReferendumInfoFor::<Test>::get(0), Some(ReferendumInfo::Approved(..)));
+
+		// The proposal origin of a retired track may be given to a new one.
+		assert_ok!(Referenda::insert_track(
+			RuntimeOrigin::root(),
+			4,
+			info.clone(),
+			Box::new(signed_two)
+		));
+		assert_eq!(DynamicTracks::<Test>::track_ids().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+
+		// Retired tracks still count towards `MaxTracks`.
+		assert_noop!(
+			Referenda::insert_track(
+				RuntimeOrigin::root(),
+				5,
+				info,
+				Box::new(RawOrigin::Signed(3).into())
+			),
+			Error::<Test>::TooManyTracks
+		);
+	});
+}
+
+#[test]
+fn updated_track_applies_to_referenda_in_flight() {
+	ExtBuilder::default().build_and_execute(|| {
+		let mut info = TestTracksInfo::info(0).unwrap().into_owned();
+		info.decision_deposit = 20;
+
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			set_balance_proposal_bounded(1),
+			DispatchTime::At(10),
+		));
+		assert_noop!(Referenda::update_track(RuntimeOrigin::signed(1), 0, info.clone()), BadOrigin);
+		assert_noop!(
+			Referenda::update_track(RuntimeOrigin::root(), 9, info.clone()),
+			Error::<Test>::BadTrack
+		);
+		assert_ok!(Referenda::update_track(RuntimeOrigin::root(), 0, info.clone()));
+		System::assert_last_event(RuntimeEvent::Referenda(crate::Event::TrackUpdated { track: 0 }));
+		assert_eq!(DynamicTracks::<Test>::info(0).unwrap().into_owned(), info);
+		// Other tracks are left as they were.
+		assert_eq!(DynamicTracks::<Test>::info(1), TestTracksInfo::info(1));
+
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&2), 20);
+	});
+}
+
+#[test]
+fn referendum_projection_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(propose_set_balance(1, 1, 0));
+		assert_ok!(propose_set_balance(2, 2, 0));
+		assert_eq!(Referenda::referendum_projection(2), None);
+
+		// Without a decision deposit there is nothing to project.
+		set_tally(0, 70, 30);
+		let p = Referenda::referendum_projection(0).unwrap();
+		assert_eq!(p.track, 0);
+		assert_eq!(p.approval, Perbill::from_percent(70));
+		assert_eq!(p.approval_threshold, Perbill::from_percent(100));
+		assert_eq!(p.support, Perbill::from_percent(70));
+		assert_eq!(p.support_threshold, Perbill::from_percent(100));
+		assert!(!p.passing);
+		assert_eq!((p.deciding_since, p.confirm_start, p.queue_position), (None, None, None));
+
+		// Deciding would begin at the end of the prepare period (#5) and the approval curve
+		// would meet the tally 60% of the way through the decision period.
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(3), 0));
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(3), 1));
+		let p = Referenda::referendum_projection(0).unwrap();
+		assert_eq!((p.confirm_start, p.confirm_end), (Some(8), Some(10)));
+
+		run_to(5);
+		assert_eq!(deciding_and_failing_since(0), 5);
+		let p = Referenda::referendum_projection(0).unwrap();
+		assert_eq!(p.deciding_since, Some(5));
+		assert_eq!((p.confirm_start, p.confirm_end), (Some(8), Some(10)));
+		// The track only decides one at a time, so the other is next in the queue.
+		assert_eq!(Referenda::referendum_projection(1).unwrap().queue_position, Some(0));
+
+		// A tally which never meets the approval curve will not confirm.
+		set_tally(0, 40, 60);
+		let p = Referenda::referendum_projection(0).unwrap();
+		assert_eq!((p.confirm_start, p.confirm_end), (None, None));
+
+		// Once confirming, the actual confirmation period is reported.
+		set_tally(0, 100, 0);
+		run_to(6);
+		assert_eq!(confirming_until(0), 8);
+		let p = Referenda::referendum_projection(0).unwrap();
+		assert!(p.passing);
+		assert_eq!((p.confirm_start, p.confirm_end), (Some(6), Some(8)));
+	});
+}
+
+#[test]
+fn invalid_tracks_are_rejected() {
+	ExtBuilder::default().build_and_execute(|| {
+		let info = TestTracksInfo::info(0).unwrap().into_owned();
+		let invalid = |f: fn(&mut TrackInfoOf<Test>)| {
+			let mut info = info.clone();
+			f(&mut info);
+			info
+		};
+		let invalid_infos = [
+			invalid(|i| i.name = [0; 25]),
+			invalid(|i| i.name[0] = 0xff),
+			invalid(|i| i.name[20] = b'x'),
+			invalid(|i| i.max_deciding = 0),
+			invalid(|i| i.confirm_period = 0),
+			invalid(|i| i.confirm_period = i.decision_period + 1),
+			invalid(|i| {
+				i.min_approval = Curve::LinearDecreasing {
+					length: Perbill::from_percent(0),
+					floor: Perbill::from_percent(50),
+					ceil: Perbill::from_percent(100),
+				}
+			}),
+			invalid(|i| {
+				i.min_support = Curve::LinearDecreasing {
+					length: Perbill::from_percent(100),
+					floor: Perbill::from_percent(60),
+					ceil: Perbill::from_percent(50),
+				}
+			}),
+			invalid(|i| {
+				i.min_support = Curve::SteppedDecreasing {
+					begin: Perbill::from_percent(80),
+					end: Perbill::from_percent(10),
+					step: Perbill::from_percent(10),
+					period: Perbill::from_percent(0),
+				}
+			}),
+			invalid(|i| {
+				i.min_support = Curve::Reciprocal {
+					factor: FixedI64::from_inner(100_000_000),
+					x_offset: FixedI64::from_inner(100_000_000),
+					y_offset: FixedI64::from_inner(-500_000_000),
+				}
+			}),
+		];
+
+		for info in invalid_infos {
+			assert_noop!(
+				Referenda::insert_track(
+					RuntimeOrigin::root(),
+					3,
+					info.clone(),
+					Box::new(RawOrigin::Signed(2).into())
+				),
+				Error::<Test>::InvalidTrack
+			);
+			assert_noop!(
+				Referenda::update_track(RuntimeOrigin::root(), 0, info),
+				Error::<Test>::InvalidTrack
+			);
+		}
+		assert!(!UseStoredTracks::<Test>::get());
+	});
+}
@@ -0,0 +1,179 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! On-chain, governance-modifiable track configuration.
+//!
+//! The tracks of `Config::Tracks` remain in use until they are first modified on-chain (or
+//! [`crate::migration::stored_tracks::MigrateToStoredTracks`] is run), at which point they are
+//! copied into [`StoredTracks`] and read from there onwards.
+//!
+//! Changes to a track apply to referenda already in flight on it as of their next servicing:
+//! deposits which were already placed are refunded at their original amount, and lowering
+//! `max_deciding` does not evict referenda already being decided. A retired track accepts no new
+//! referenda, but keeps its configuration so that the ones in flight can be concluded.
+
+use super::*;
+use alloc::borrow::Cow;
+use core::marker::PhantomData;
+use frame_support::weights::Weight;
+
+/// A [`TracksInfo`] implementation backed by the storage of the referenda pallet.
+///
+/// Until [`UseStoredTracks`] is set, this simply defers to `Config::Tracks`. Afterwards, the
+/// tracks are read from [`StoredTracks`] and proposal origins are mapped through
+/// [`TrackOrigins`] before falling back to `Config::Tracks`.
+pub struct DynamicTracks<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> TracksInfo<BalanceOf<T, I>, BlockNumberFor<T, I>>
+	for DynamicTracks<T, I>
+{
+	type Id = TrackIdOf<T, I>;
+	type RuntimeOrigin = PalletsOriginOf<T>;
+
+	fn tracks(
+	) -> impl Iterator<Item = Cow<'static, Track<Self::Id, BalanceOf<T, I>, BlockNumberFor<T, I>>>>
+	{
+		let tracks: Vec<_> = if UseStoredTracks::<T, I>::get() {
+			let mut tracks: Vec<_> = StoredTracks::<T, I>::iter()
+				.map(|(id, info)| Cow::Owned(Track { id, info }))
+				.collect();
+			tracks.sort_by_key(|t| t.id);
+			tracks
+		} else {
+			T::Tracks::tracks().collect()
+		};
+		tracks.into_iter()
+	}
+
+	fn track_for(origin: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
+		if !UseStoredTracks::<T, I>::get() {
+			return T::Tracks::track_for(origin)
+		}
+		let id = match TrackOrigins::<T, I>::get(origin) {
+			Some(id) => id,
+			None => T::Tracks::track_for(origin)?,
+		};
+		if RetiredTracks::<T, I>::contains_key(id) || !StoredTracks::<T, I>::contains_key(id) {
+			return Err(())
+		}
+		Ok(id)
+	}
+
+	fn info(id: Self::Id) -> Option<Cow<'static, TrackInfoOf<T, I>>> {
+		if UseStoredTracks::<T, I>::get() {
+			StoredTracks::<T, I>::get(id).map(Cow::Owned)
+		} else {
+			T::Tracks::info(id)
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The weight of copying `Config::Tracks` into storage, which happens when the tracks are
+	/// first modified on-chain.
+	pub(crate) fn store_tracks_weight() -> Weight {
+		let tracks = T::Tracks::tracks().count() as u64;
+		// One write per track, plus `UseStoredTracks` and the counter of `StoredTracks`.
+		T::DbWeight::get().writes(tracks.saturating_add(2))
+	}
+
+	/// Ensure that `info` describes a usable track.
+	///
+	/// Its name must be non-empty UTF-8 padded with zeroes, it must allow at least one referendum
+	/// to be decided at a time, its decision and confirmation periods must be non-zero with the
+	/// latter fitting into the former, and both of its curves must be valid.
+	fn ensure_valid_track(info: &TrackInfoOf<T, I>) -> DispatchResult {
+		let name_len = info.name.iter().position(|b| *b == 0).unwrap_or(info.name.len());
+		let (name, padding) = info.name.split_at(name_len);
+		ensure!(
+			!name.is_empty() &&
+				core::str::from_utf8(name).is_ok() &&
+				padding.iter().all(|b| *b == 0),
+			Error::<T, I>::InvalidTrack
+		);
+		ensure!(info.max_deciding > 0, Error::<T, I>::InvalidTrack);
+		ensure!(
+			!info.confirm_period.is_zero() && info.confirm_period <= info.decision_period,
+			Error::<T, I>::InvalidTrack
+		);
+		ensure!(
+			info.min_approval.is_valid() && info.min_support.is_valid(),
+			Error::<T, I>::InvalidTrack
+		);
+		Ok(())
+	}
+
+	/// Copy the tracks of `Config::Tracks` into [`StoredTracks`] so that they can be modified
+	/// on-chain. Does nothing if this was already done.
+	///
+	/// Returns the number of tracks copied.
+	pub fn do_store_tracks() -> u32 {
+		if UseStoredTracks::<T, I>::get() {
+			return 0
+		}
+		let mut count = 0;
+		for track in T::Tracks::tracks() {
+			let Track { id, info } = track.into_owned();
+			StoredTracks::<T, I>::insert(id, info);
+			count += 1;
+		}
+		UseStoredTracks::<T, I>::put(true);
+		count
+	}
+
+	/// Add a new track `id`, on which referenda are submitted from `proposal_origin`.
+	pub(crate) fn do_insert_track(
+		id: TrackIdOf<T, I>,
+		info: TrackInfoOf<T, I>,
+		proposal_origin: PalletsOriginOf<T>,
+	) -> DispatchResult {
+		Self::ensure_valid_track(&info)?;
+		Self::do_store_tracks();
+		ensure!(!StoredTracks::<T, I>::contains_key(id), Error::<T, I>::DuplicateTrack);
+		ensure!(StoredTracks::<T, I>::count() < T::MaxTracks::get(), Error::<T, I>::TooManyTracks);
+		ensure!(
+			DynamicTracks::<T, I>::track_for(&proposal_origin).is_err(),
+			Error::<T, I>::OriginInUse
+		);
+		StoredTracks::<T, I>::insert(id, info);
+		TrackOrigins::<T, I>::insert(proposal_origin, id);
+		Self::deposit_event(Event::<T, I>::TrackInserted { track: id });
+		Ok(())
+	}
+
+	/// Replace the configuration of the existing, non-retired track `id`.
+	pub(crate) fn do_update_track(id: TrackIdOf<T, I>, info: TrackInfoOf<T, I>) -> DispatchResult {
+		Self::ensure_valid_track(&info)?;
+		Self::do_store_tracks();
+		ensure!(!RetiredTracks::<T, I>::contains_key(id), Error::<T, I>::RetiredTrack);
+		StoredTracks::<T, I>::try_mutate(id, |maybe_info| -> DispatchResult {
+			*maybe_info.as_mut().ok_or(Error::<T, I>::BadTrack)? = info;
+			Ok(())
+		})?;
+		Self::deposit_event(Event::<T, I>::TrackUpdated { track: id });
+		Ok(())
+	}
+
+	/// Stop the existing track `id` from accepting new referenda.
+	pub(crate) fn do_retire_track(id: TrackIdOf<T, I>) -> DispatchResult {
+		Self::do_store_tracks();
+		ensure!(StoredTracks::<T, I>::contains_key(id), Error::<T, I>::BadTrack);
+		ensure!(!RetiredTracks::<T, I>::contains_key(id), Error::<T, I>::RetiredTrack);
+		RetiredTracks::<T, I>::insert(id, ());
+		Self::deposit_event(Event::<T, I>::TrackRetired { track: id });
+		Ok(())
+	}
+}
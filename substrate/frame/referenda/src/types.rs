@@ -110,6 +110,35 @@ pub struct DecidingStatus<BlockNumber> {
 	pub confirming: Option<BlockNumber>,
 }
 
+/// A projection of how an ongoing referendum will proceed should its tally remain unchanged.
+///
+/// Returned by [`crate::runtime_api::ReferendaApi::referendum_projection`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ReferendumProjection<TrackId, Moment> {
+	/// The track on which the referendum is being decided.
+	pub track: TrackId,
+	/// The current approval of the referendum.
+	pub approval: Perbill,
+	/// The approval currently required by the track's `min_approval` curve.
+	pub approval_threshold: Perbill,
+	/// The current support of the referendum.
+	pub support: Perbill,
+	/// The support currently required by the track's `min_support` curve.
+	pub support_threshold: Perbill,
+	/// Whether the referendum is passing right now.
+	pub passing: bool,
+	/// When the referendum began being decided, if it has.
+	pub deciding_since: Option<Moment>,
+	/// The block at which confirmation began or is projected to begin. `None` if the current
+	/// tally would not pass within the decision period, or the decision period cannot yet be
+	/// projected.
+	pub confirm_start: Option<Moment>,
+	/// The block at which confirmation ends or is projected to end, approving the referendum.
+	pub confirm_end: Option<Moment>,
+	/// The position in the track's deciding queue, where zero is the next to be decided.
+	pub queue_position: Option<u32>,
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct Deposit<AccountId, Balance> {
 	pub who: AccountId,
@@ -524,6 +553,24 @@ impl Curve {
 		}
 	}
 
+	/// Whether the curve is well-formed, i.e. it never increases, stays within `[0, 1]` and none
+	/// of its parameters leads to a division by zero.
+	pub fn is_valid(&self) -> bool {
+		match self {
+			Self::LinearDecreasing { length, floor, ceil } => !length.is_zero() && floor <= ceil,
+			Self::SteppedDecreasing { begin, end, step, period } =>
+				end <= begin && !step.is_zero() && !period.is_zero(),
+			Self::Reciprocal { factor, x_offset, y_offset } => {
+				let zero = FixedI64::from_inner(0);
+				*factor > zero &&
+					*x_offset > zero &&
+					factor
+						.checked_rounding_div(FixedI64::from_u32(1) + *x_offset, Low)
+						.is_some_and(|y| y + *y_offset >= zero)
+			},
+		}
+	}
+
 	/// Determine the `y` value for the given `x` value.
 	pub fn threshold(&self, x: Perbill) -> Perbill {
 		match self {
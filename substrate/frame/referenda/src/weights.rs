@@ -102,6 +102,9 @@ pub trait WeightInfo {
 	fn nudge_referendum_rejected() -> Weight;
 	fn set_some_metadata() -> Weight;
 	fn clear_metadata() -> Weight;
+	fn insert_track() -> Weight;
+	fn update_track() -> Weight;
+	fn retire_track() -> Weight;
 }
 
 /// Weights for `pallet_referenda` using the Substrate node and recommended hardware.
@@ -115,13 +118,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:0 w:1)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::TrackOrigins` (r:1 w:0)
+	/// Proof: `Referenda::TrackOrigins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `Referenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
 	fn submit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `52`
+		//  Measured:  `314`
 		//  Estimated: `110487`
-		// Minimum execution time: 29_951_000 picoseconds.
-		Weight::from_parts(30_644_000, 110487)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Minimum execution time: 34_081_000 picoseconds.
+		Weight::from_parts(34_774_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -132,13 +143,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_preparing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `238`
+		//  Measured:  `411`
 		//  Estimated: `219984`
-		// Minimum execution time: 44_797_000 picoseconds.
-		Weight::from_parts(45_583_000, 219984)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// Minimum execution time: 47_067_000 picoseconds.
+		Weight::from_parts(47_853_000, 219984)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -153,13 +168,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2975`
+		//  Measured:  `3148`
 		//  Estimated: `110487`
-		// Minimum execution time: 63_841_000 picoseconds.
-		Weight::from_parts(65_276_000, 110487)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		// Minimum execution time: 66_111_000 picoseconds.
+		Weight::from_parts(67_546_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -174,13 +193,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_not_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2995`
+		//  Measured:  `3168`
 		//  Estimated: `110487`
-		// Minimum execution time: 62_426_000 picoseconds.
-		Weight::from_parts(66_039_000, 110487)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		// Minimum execution time: 64_696_000 picoseconds.
+		Weight::from_parts(68_309_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -193,13 +216,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `238`
+		//  Measured:  `411`
 		//  Estimated: `219984`
-		// Minimum execution time: 52_748_000 picoseconds.
-		Weight::from_parts(53_966_000, 219984)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		// Minimum execution time: 55_018_000 picoseconds.
+		Weight::from_parts(56_236_000, 219984)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -212,13 +239,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `238`
+		//  Measured:  `411`
 		//  Estimated: `219984`
-		// Minimum execution time: 50_585_000 picoseconds.
-		Weight::from_parts(51_774_000, 219984)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		// Minimum execution time: 52_855_000 picoseconds.
+		Weight::from_parts(54_044_000, 219984)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -281,13 +312,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Referenda::TrackQueue` (`max_values`: None, `max_size`: Some(2012), added: 4487, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::DecidingCount` (r:1 w:1)
 	/// Proof: `Referenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_queue_empty() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `79`
+		//  Measured:  `252`
 		//  Estimated: `14787`
-		// Minimum execution time: 10_985_000 picoseconds.
-		Weight::from_parts(11_371_000, 14787)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Minimum execution time: 13_255_000 picoseconds.
+		Weight::from_parts(13_641_000, 14787)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Parameters::Parameters` (r:1 w:0)
@@ -298,13 +333,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2928`
+		//  Measured:  `3101`
 		//  Estimated: `110487`
-		// Minimum execution time: 43_023_000 picoseconds.
-		Weight::from_parts(44_282_000, 110487)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// Minimum execution time: 45_293_000 picoseconds.
+		Weight::from_parts(46_552_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Parameters::Parameters` (r:1 w:0)
@@ -315,13 +354,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2928`
+		//  Measured:  `3101`
 		//  Estimated: `110487`
-		// Minimum execution time: 43_919_000 picoseconds.
-		Weight::from_parts(45_147_000, 110487)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// Minimum execution time: 46_189_000 picoseconds.
+		Weight::from_parts(47_417_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:0)
@@ -330,13 +373,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::TrackQueue` (r:1 w:1)
 	/// Proof: `Referenda::TrackQueue` (`max_values`: None, `max_size`: Some(2012), added: 4487, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_insertion() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2940`
+		//  Measured:  `3113`
 		//  Estimated: `14787`
-		// Minimum execution time: 26_209_000 picoseconds.
-		Weight::from_parts(27_379_000, 14787)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Minimum execution time: 28_479_000 picoseconds.
+		Weight::from_parts(29_649_000, 14787)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:0)
@@ -345,13 +392,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::TrackQueue` (r:1 w:1)
 	/// Proof: `Referenda::TrackQueue` (`max_values`: None, `max_size`: Some(2012), added: 4487, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_slide() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2940`
+		//  Measured:  `3113`
 		//  Estimated: `14787`
-		// Minimum execution time: 26_523_000 picoseconds.
-		Weight::from_parts(27_759_000, 14787)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Minimum execution time: 28_793_000 picoseconds.
+		Weight::from_parts(30_029_000, 14787)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -362,13 +413,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Referenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::TrackQueue` (r:1 w:1)
 	/// Proof: `Referenda::TrackQueue` (`max_values`: None, `max_size`: Some(2012), added: 4487, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2920`
+		//  Measured:  `3093`
 		//  Estimated: `14787`
-		// Minimum execution time: 31_048_000 picoseconds.
-		Weight::from_parts(32_316_000, 14787)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// Minimum execution time: 33_318_000 picoseconds.
+		Weight::from_parts(34_586_000, 14787)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -379,13 +434,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Referenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::TrackQueue` (r:1 w:1)
 	/// Proof: `Referenda::TrackQueue` (`max_values`: None, `max_size`: Some(2012), added: 4487, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_not_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2940`
+		//  Measured:  `3113`
 		//  Estimated: `14787`
-		// Minimum execution time: 29_936_000 picoseconds.
-		Weight::from_parts(31_863_000, 14787)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// Minimum execution time: 32_206_000 picoseconds.
+		Weight::from_parts(34_133_000, 14787)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -394,13 +453,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_no_deposit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `186`
+		//  Measured:  `359`
 		//  Estimated: `110487`
-		// Minimum execution time: 19_946_000 picoseconds.
-		Weight::from_parts(20_469_000, 110487)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Minimum execution time: 22_216_000 picoseconds.
+		Weight::from_parts(22_739_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -409,26 +472,34 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_preparing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `234`
+		//  Measured:  `407`
 		//  Estimated: `110487`
-		// Minimum execution time: 20_342_000 picoseconds.
-		Weight::from_parts(20_939_000, 110487)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Minimum execution time: 22_612_000 picoseconds.
+		Weight::from_parts(23_209_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `Parameters::Parameters` (r:1 w:0)
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_timed_out() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `186`
+		//  Measured:  `359`
 		//  Estimated: `14787`
-		// Minimum execution time: 15_161_000 picoseconds.
-		Weight::from_parts(15_555_000, 14787)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		// Minimum execution time: 17_431_000 picoseconds.
+		Weight::from_parts(17_825_000, 14787)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -439,13 +510,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Referenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `234`
+		//  Measured:  `407`
 		//  Estimated: `110487`
-		// Minimum execution time: 26_157_000 picoseconds.
-		Weight::from_parts(26_634_000, 110487)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// Minimum execution time: 28_427_000 picoseconds.
+		Weight::from_parts(28_904_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -456,13 +531,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Referenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `234`
+		//  Measured:  `407`
 		//  Estimated: `110487`
-		// Minimum execution time: 28_009_000 picoseconds.
-		Weight::from_parts(28_800_000, 110487)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// Minimum execution time: 30_279_000 picoseconds.
+		Weight::from_parts(31_070_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -471,13 +550,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `273`
+		//  Measured:  `446`
 		//  Estimated: `110487`
-		// Minimum execution time: 22_683_000 picoseconds.
-		Weight::from_parts(23_374_000, 110487)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Minimum execution time: 24_953_000 picoseconds.
+		Weight::from_parts(25_644_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -486,13 +569,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_end_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `277`
+		//  Measured:  `450`
 		//  Estimated: `110487`
-		// Minimum execution time: 23_079_000 picoseconds.
-		Weight::from_parts(23_913_000, 110487)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Minimum execution time: 25_349_000 picoseconds.
+		Weight::from_parts(26_183_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -501,13 +588,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_not_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `273`
+		//  Measured:  `446`
 		//  Estimated: `110487`
-		// Minimum execution time: 22_568_000 picoseconds.
-		Weight::from_parts(23_248_000, 110487)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Minimum execution time: 24_838_000 picoseconds.
+		Weight::from_parts(25_518_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -516,13 +607,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `277`
+		//  Measured:  `450`
 		//  Estimated: `110487`
-		// Minimum execution time: 20_949_000 picoseconds.
-		Weight::from_parts(21_663_000, 110487)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Minimum execution time: 23_219_000 picoseconds.
+		Weight::from_parts(23_933_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -533,13 +628,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Lookup` (r:1 w:1)
 	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_approved() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `277`
+		//  Measured:  `450`
 		//  Estimated: `219984`
-		// Minimum execution time: 32_425_000 picoseconds.
-		Weight::from_parts(33_048_000, 219984)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		// Minimum execution time: 34_695_000 picoseconds.
+		Weight::from_parts(35_318_000, 219984)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -548,13 +647,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_rejected() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `273`
+		//  Measured:  `446`
 		//  Estimated: `110487`
-		// Minimum execution time: 22_900_000 picoseconds.
-		Weight::from_parts(23_450_000, 110487)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Minimum execution time: 25_170_000 picoseconds.
+		Weight::from_parts(25_720_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:0)
@@ -587,6 +690,55 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:1)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::TrackOrigins` (r:1 w:1)
+	/// Proof: `Referenda::TrackOrigins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Referenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `Referenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::CounterForStoredTracks` (r:1 w:1)
+	/// Proof: `Referenda::CounterForStoredTracks` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn insert_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `216`
+		//  Estimated: `3831`
+		// Minimum execution time: 18_937_000 picoseconds.
+		Weight::from_parts(19_290_000, 3831)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `Referenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:1)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn update_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `216`
+		//  Estimated: `3831`
+		// Minimum execution time: 16_521_000 picoseconds.
+		Weight::from_parts(16_874_000, 3831)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::RetiredTracks` (r:1 w:1)
+	/// Proof: `Referenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	fn retire_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `216`
+		//  Estimated: `3831`
+		// Minimum execution time: 15_579_000 picoseconds.
+		Weight::from_parts(15_932_000, 3831)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -599,13 +751,21 @@ impl WeightInfo for () {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:0 w:1)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::TrackOrigins` (r:1 w:0)
+	/// Proof: `Referenda::TrackOrigins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `Referenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
 	fn submit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `52`
+		//  Measured:  `314`
 		//  Estimated: `110487`
-		// Minimum execution time: 29_951_000 picoseconds.
-		Weight::from_parts(30_644_000, 110487)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Minimum execution time: 34_081_000 picoseconds.
+		Weight::from_parts(34_774_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -616,13 +776,17 @@ impl WeightInfo for () {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_preparing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `238`
+		//  Measured:  `411`
 		//  Estimated: `219984`
-		// Minimum execution time: 44_797_000 picoseconds.
-		Weight::from_parts(45_583_000, 219984)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// Minimum execution time: 47_067_000 picoseconds.
+		Weight::from_parts(47_853_000, 219984)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -637,13 +801,17 @@ impl WeightInfo for () {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2975`
+		//  Measured:  `3148`
 		//  Estimated: `110487`
-		// Minimum execution time: 63_841_000 picoseconds.
-		Weight::from_parts(65_276_000, 110487)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		// Minimum execution time: 66_111_000 picoseconds.
+		Weight::from_parts(67_546_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -658,13 +826,17 @@ impl WeightInfo for () {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_not_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2995`
+		//  Measured:  `3168`
 		//  Estimated: `110487`
-		// Minimum execution time: 62_426_000 picoseconds.
-		Weight::from_parts(66_039_000, 110487)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		// Minimum execution time: 64_696_000 picoseconds.
+		Weight::from_parts(68_309_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -677,13 +849,17 @@ impl WeightInfo for () {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `238`
+		//  Measured:  `411`
 		//  Estimated: `219984`
-		// Minimum execution time: 52_748_000 picoseconds.
-		Weight::from_parts(53_966_000, 219984)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		// Minimum execution time: 55_018_000 picoseconds.
+		Weight::from_parts(56_236_000, 219984)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -696,13 +872,17 @@ impl WeightInfo for () {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
 	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `238`
+		//  Measured:  `411`
 		//  Estimated: `219984`
-		// Minimum execution time: 50_585_000 picoseconds.
-		Weight::from_parts(51_774_000, 219984)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		// Minimum execution time: 52_855_000 picoseconds.
+		Weight::from_parts(54_044_000, 219984)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -765,13 +945,17 @@ impl WeightInfo for () {
 	/// Proof: `Referenda::TrackQueue` (`max_values`: None, `max_size`: Some(2012), added: 4487, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::DecidingCount` (r:1 w:1)
 	/// Proof: `Referenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_queue_empty() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `79`
+		//  Measured:  `252`
 		//  Estimated: `14787`
-		// Minimum execution time: 10_985_000 picoseconds.
-		Weight::from_parts(11_371_000, 14787)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Minimum execution time: 13_255_000 picoseconds.
+		Weight::from_parts(13_641_000, 14787)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Parameters::Parameters` (r:1 w:0)
@@ -782,13 +966,17 @@ impl WeightInfo for () {
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2928`
+		//  Measured:  `3101`
 		//  Estimated: `110487`
-		// Minimum execution time: 43_023_000 picoseconds.
-		Weight::from_parts(44_282_000, 110487)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// Minimum execution time: 45_293_000 picoseconds.
+		Weight::from_parts(46_552_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Parameters::Parameters` (r:1 w:0)
@@ -799,13 +987,17 @@ impl WeightInfo for () {
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2928`
+		//  Measured:  `3101`
 		//  Estimated: `110487`
-		// Minimum execution time: 43_919_000 picoseconds.
-		Weight::from_parts(45_147_000, 110487)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// Minimum execution time: 46_189_000 picoseconds.
+		Weight::from_parts(47_417_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:0)
@@ -814,13 +1006,17 @@ impl WeightInfo for () {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::TrackQueue` (r:1 w:1)
 	/// Proof: `Referenda::TrackQueue` (`max_values`: None, `max_size`: Some(2012), added: 4487, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_insertion() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2940`
+		//  Measured:  `3113`
 		//  Estimated: `14787`
-		// Minimum execution time: 26_209_000 picoseconds.
-		Weight::from_parts(27_379_000, 14787)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Minimum execution time: 28_479_000 picoseconds.
+		Weight::from_parts(29_649_000, 14787)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:0)
@@ -829,13 +1025,17 @@ impl WeightInfo for () {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::TrackQueue` (r:1 w:1)
 	/// Proof: `Referenda::TrackQueue` (`max_values`: None, `max_size`: Some(2012), added: 4487, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_slide() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2940`
+		//  Measured:  `3113`
 		//  Estimated: `14787`
-		// Minimum execution time: 26_523_000 picoseconds.
-		Weight::from_parts(27_759_000, 14787)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Minimum execution time: 28_793_000 picoseconds.
+		Weight::from_parts(30_029_000, 14787)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -846,13 +1046,17 @@ impl WeightInfo for () {
 	/// Proof: `Referenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::TrackQueue` (r:1 w:1)
 	/// Proof: `Referenda::TrackQueue` (`max_values`: None, `max_size`: Some(2012), added: 4487, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2920`
+		//  Measured:  `3093`
 		//  Estimated: `14787`
-		// Minimum execution time: 31_048_000 picoseconds.
-		Weight::from_parts(32_316_000, 14787)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// Minimum execution time: 33_318_000 picoseconds.
+		Weight::from_parts(34_586_000, 14787)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -863,13 +1067,17 @@ impl WeightInfo for () {
 	/// Proof: `Referenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::TrackQueue` (r:1 w:1)
 	/// Proof: `Referenda::TrackQueue` (`max_values`: None, `max_size`: Some(2012), added: 4487, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_not_queued() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2940`
+		//  Measured:  `3113`
 		//  Estimated: `14787`
-		// Minimum execution time: 29_936_000 picoseconds.
-		Weight::from_parts(31_863_000, 14787)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// Minimum execution time: 32_206_000 picoseconds.
+		Weight::from_parts(34_133_000, 14787)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -878,13 +1086,17 @@ impl WeightInfo for () {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_no_deposit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `186`
+		//  Measured:  `359`
 		//  Estimated: `110487`
-		// Minimum execution time: 19_946_000 picoseconds.
-		Weight::from_parts(20_469_000, 110487)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Minimum execution time: 22_216_000 picoseconds.
+		Weight::from_parts(22_739_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -893,26 +1105,34 @@ impl WeightInfo for () {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_preparing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `234`
+		//  Measured:  `407`
 		//  Estimated: `110487`
-		// Minimum execution time: 20_342_000 picoseconds.
-		Weight::from_parts(20_939_000, 110487)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Minimum execution time: 22_612_000 picoseconds.
+		Weight::from_parts(23_209_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `Parameters::Parameters` (r:1 w:0)
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_timed_out() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `186`
+		//  Measured:  `359`
 		//  Estimated: `14787`
-		// Minimum execution time: 15_161_000 picoseconds.
-		Weight::from_parts(15_555_000, 14787)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		// Minimum execution time: 17_431_000 picoseconds.
+		Weight::from_parts(17_825_000, 14787)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -923,13 +1143,17 @@ impl WeightInfo for () {
 	/// Proof: `Referenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `234`
+		//  Measured:  `407`
 		//  Estimated: `110487`
-		// Minimum execution time: 26_157_000 picoseconds.
-		Weight::from_parts(26_634_000, 110487)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// Minimum execution time: 28_427_000 picoseconds.
+		Weight::from_parts(28_904_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -940,13 +1164,17 @@ impl WeightInfo for () {
 	/// Proof: `Referenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `234`
+		//  Measured:  `407`
 		//  Estimated: `110487`
-		// Minimum execution time: 28_009_000 picoseconds.
-		Weight::from_parts(28_800_000, 110487)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// Minimum execution time: 30_279_000 picoseconds.
+		Weight::from_parts(31_070_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -955,13 +1183,17 @@ impl WeightInfo for () {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `273`
+		//  Measured:  `446`
 		//  Estimated: `110487`
-		// Minimum execution time: 22_683_000 picoseconds.
-		Weight::from_parts(23_374_000, 110487)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Minimum execution time: 24_953_000 picoseconds.
+		Weight::from_parts(25_644_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -970,13 +1202,17 @@ impl WeightInfo for () {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_end_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `277`
+		//  Measured:  `450`
 		//  Estimated: `110487`
-		// Minimum execution time: 23_079_000 picoseconds.
-		Weight::from_parts(23_913_000, 110487)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Minimum execution time: 25_349_000 picoseconds.
+		Weight::from_parts(26_183_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -985,13 +1221,17 @@ impl WeightInfo for () {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_not_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `273`
+		//  Measured:  `446`
 		//  Estimated: `110487`
-		// Minimum execution time: 22_568_000 picoseconds.
-		Weight::from_parts(23_248_000, 110487)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Minimum execution time: 24_838_000 picoseconds.
+		Weight::from_parts(25_518_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -1000,13 +1240,17 @@ impl WeightInfo for () {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_confirming() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `277`
+		//  Measured:  `450`
 		//  Estimated: `110487`
-		// Minimum execution time: 20_949_000 picoseconds.
-		Weight::from_parts(21_663_000, 110487)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Minimum execution time: 23_219_000 picoseconds.
+		Weight::from_parts(23_933_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -1017,13 +1261,17 @@ impl WeightInfo for () {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Lookup` (r:1 w:1)
 	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_approved() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `277`
+		//  Measured:  `450`
 		//  Estimated: `219984`
-		// Minimum execution time: 32_425_000 picoseconds.
-		Weight::from_parts(33_048_000, 219984)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		// Minimum execution time: 34_695_000 picoseconds.
+		Weight::from_parts(35_318_000, 219984)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
@@ -1032,13 +1280,17 @@ impl WeightInfo for () {
 	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(11322), added: 13797, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn nudge_referendum_rejected() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `273`
+		//  Measured:  `446`
 		//  Estimated: `110487`
-		// Minimum execution time: 22_900_000 picoseconds.
-		Weight::from_parts(23_450_000, 110487)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Minimum execution time: 25_170_000 picoseconds.
+		Weight::from_parts(25_720_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:0)
@@ -1071,4 +1323,53 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:1)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::TrackOrigins` (r:1 w:1)
+	/// Proof: `Referenda::TrackOrigins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Referenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `Referenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::CounterForStoredTracks` (r:1 w:1)
+	/// Proof: `Referenda::CounterForStoredTracks` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn insert_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `216`
+		//  Estimated: `3831`
+		// Minimum execution time: 18_937_000 picoseconds.
+		Weight::from_parts(19_290_000, 3831)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::RetiredTracks` (r:1 w:0)
+	/// Proof: `Referenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:1)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	fn update_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `216`
+		//  Estimated: `3831`
+		// Minimum execution time: 16_521_000 picoseconds.
+		Weight::from_parts(16_874_000, 3831)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Referenda::UseStoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::UseStoredTracks` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::StoredTracks` (r:1 w:0)
+	/// Proof: `Referenda::StoredTracks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Referenda::RetiredTracks` (r:1 w:1)
+	/// Proof: `Referenda::RetiredTracks` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	fn retire_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `216`
+		//  Estimated: `3831`
+		// Minimum execution time: 15_579_000 picoseconds.
+		Weight::from_parts(15_932_000, 3831)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
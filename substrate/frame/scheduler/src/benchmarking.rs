@@ -62,6 +62,24 @@ fn fill_schedule<T: Config>(when: BlockNumberFor<T>, n: u32) -> Result<(), &'sta
 	Ok(())
 }
 
+/// The point in time against which timed tasks are scheduled in the benchmarks.
+fn timed_when<T: Config>() -> MomentOf<T> {
+	T::TimeProvider::now().saturating_add(1_000u32.into())
+}
+
+/// Add `n` named, periodic items to the timed schedule at `when`.
+fn fill_timed_schedule<T: Config>(when: MomentOf<T>, n: u32) -> Result<(), &'static str> {
+	let origin: <T as Config>::PalletsOrigin = frame_system::RawOrigin::Root.into();
+	for i in 0..n {
+		let call = make_call::<T>(None);
+		let period = Some(((i + 100).into(), 100));
+		let name = u32_to_name(i);
+		Pallet::<T>::do_schedule_timed(Some(name), when, period, 0, origin.clone(), call)?;
+	}
+	ensure!(TimedAgenda::<T>::get(when).len() == n as usize, "didn't fill timed schedule");
+	Ok(())
+}
+
 fn u32_to_name(i: u32) -> TaskName {
 	i.using_encoded(blake2_256)
 }
@@ -506,6 +524,267 @@ mod benchmarks {
 		Ok(())
 	}
 
+	// `service_timed_agendas` when no work is done.
+	#[benchmark]
+	fn service_timed_agendas_base() -> Result<(), BenchmarkError> {
+		let when = timed_when::<T>();
+		fill_timed_schedule::<T>(when, 1)?;
+
+		#[block]
+		{
+			Pallet::<T>::service_timed_agendas(
+				&mut WeightMeter::new(),
+				when.saturating_sub(One::one()),
+				0,
+			);
+		}
+
+		assert_eq!(TimedAgendaIndex::<T>::get().into_inner(), vec![when]);
+
+		Ok(())
+	}
+
+	// `service_timed_agenda` when no work is done.
+	#[benchmark]
+	fn service_timed_agenda_base(
+		s: Linear<0, { T::MaxScheduledPerBlock::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let when = timed_when::<T>();
+		fill_timed_schedule::<T>(when, s)?;
+		let mut executed = 0;
+
+		#[block]
+		{
+			Pallet::<T>::service_timed_agenda(
+				&mut WeightMeter::new(),
+				&mut executed,
+				when,
+				when,
+				0,
+			);
+		}
+
+		assert_eq!(executed, 0);
+		assert_eq!(TimedAgendaIndex::<T>::get().is_empty(), s == 0);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_at_time(
+		s: Linear<0, { T::MaxScheduledPerBlock::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let when = timed_when::<T>();
+		let periodic = Some((MomentOf::<T>::one(), 100));
+		let priority = 0;
+		// Essentially a no-op call.
+		let call = Box::new(SystemCall::set_storage { items: vec![] }.into());
+
+		fill_timed_schedule::<T>(when, s)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, when, periodic, priority, call);
+
+		ensure!(TimedAgenda::<T>::get(when).len() == s as usize + 1, "didn't add to schedule");
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_timed(
+		s: Linear<1, { T::MaxScheduledPerBlock::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let when = timed_when::<T>();
+
+		fill_timed_schedule::<T>(when, s)?;
+		let schedule_origin =
+			T::ScheduleOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(schedule_origin as SystemOrigin<T>, when, 0);
+
+		ensure!(TimedLookup::<T>::get(u32_to_name(0)).is_none(), "didn't remove from timed lookup");
+		ensure!(
+			s == 1 || TimedAgenda::<T>::get(when)[0].is_none(),
+			"didn't remove from schedule if more than 1 task scheduled for `when`"
+		);
+		ensure!(
+			s > 1 || TimedAgendaIndex::<T>::get().is_empty(),
+			"didn't remove from the index if only 1 task scheduled for `when`"
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_named_at_time(
+		s: Linear<0, { T::MaxScheduledPerBlock::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let id = u32_to_name(s);
+		let when = timed_when::<T>();
+		let periodic = Some((MomentOf::<T>::one(), 100));
+		let priority = 0;
+		// Essentially a no-op call.
+		let call = Box::new(SystemCall::set_storage { items: vec![] }.into());
+
+		fill_timed_schedule::<T>(when, s)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, id, when, periodic, priority, call);
+
+		ensure!(TimedAgenda::<T>::get(when).len() == s as usize + 1, "didn't add to schedule");
+		ensure!(TimedLookup::<T>::contains_key(id), "didn't add to timed lookup");
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_named_timed(
+		s: Linear<1, { T::MaxScheduledPerBlock::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let when = timed_when::<T>();
+
+		fill_timed_schedule::<T>(when, s)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, u32_to_name(0));
+
+		ensure!(TimedLookup::<T>::get(u32_to_name(0)).is_none(), "didn't remove from timed lookup");
+		ensure!(
+			s == 1 || TimedAgenda::<T>::get(when)[0].is_none(),
+			"didn't remove from schedule if more than 1 task scheduled for `when`"
+		);
+		ensure!(
+			s > 1 || TimedAgendaIndex::<T>::get().is_empty(),
+			"didn't remove from the index if only 1 task scheduled for `when`"
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_timed_retry(
+		s: Linear<1, { T::MaxScheduledPerBlock::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let when = timed_when::<T>();
+
+		fill_timed_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let (when, index) = TimedLookup::<T>::get(name).unwrap();
+		let period = MomentOf::<T>::one();
+		let retry_config = RetryConfig { total_retries: 10, remaining: 10, period };
+		TimedRetries::<T>::insert((when, index), retry_config);
+		let task = TimedAgenda::<T>::get(when)[index as usize].clone().unwrap();
+		let mut weight_counter = WeightMeter::with_limit(T::MaximumWeight::get());
+
+		#[block]
+		{
+			Pallet::<T>::schedule_timed_retry(
+				&mut weight_counter,
+				when,
+				when,
+				index,
+				&task,
+				retry_config,
+			);
+		}
+
+		assert_eq!(
+			TimedRetries::<T>::get((when.saturating_add(period), 0)),
+			Some(RetryConfig { total_retries: 10, remaining: 9, period })
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_retry_timed() -> Result<(), BenchmarkError> {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = timed_when::<T>();
+
+		fill_timed_schedule::<T>(when, s)?;
+		let address = TimedLookup::<T>::get(u32_to_name(s - 1)).unwrap();
+		let period = MomentOf::<T>::one();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, address, 10, period);
+
+		assert_eq!(
+			TimedRetries::<T>::get(address),
+			Some(RetryConfig { total_retries: 10, remaining: 10, period })
+		);
+		assert_last_event::<T>(
+			Event::TimedRetrySet { task: address, id: None, period, retries: 10 }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_retry_named_timed() -> Result<(), BenchmarkError> {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = timed_when::<T>();
+
+		fill_timed_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = TimedLookup::<T>::get(name).unwrap();
+		let period = MomentOf::<T>::one();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, name, 10, period);
+
+		assert_eq!(
+			TimedRetries::<T>::get(address),
+			Some(RetryConfig { total_retries: 10, remaining: 10, period })
+		);
+		assert_last_event::<T>(
+			Event::TimedRetrySet { task: address, id: Some(name), period, retries: 10 }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_retry_timed() -> Result<(), BenchmarkError> {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = timed_when::<T>();
+
+		fill_timed_schedule::<T>(when, s)?;
+		let address = TimedLookup::<T>::get(u32_to_name(s - 1)).unwrap();
+		let period = MomentOf::<T>::one();
+		assert!(Pallet::<T>::set_retry_timed(RawOrigin::Root.into(), address, 10, period).is_ok());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, address);
+
+		assert!(!TimedRetries::<T>::contains_key(address));
+		assert_last_event::<T>(Event::TimedRetryCancelled { task: address, id: None }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_retry_named_timed() -> Result<(), BenchmarkError> {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = timed_when::<T>();
+
+		fill_timed_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = TimedLookup::<T>::get(name).unwrap();
+		let period = MomentOf::<T>::one();
+		assert!(
+			Pallet::<T>::set_retry_named_timed(RawOrigin::Root.into(), name, 10, period).is_ok()
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, name);
+
+		assert!(!TimedRetries::<T>::contains_key(address));
+		assert_last_event::<T>(Event::TimedRetryCancelled { task: address, id: Some(name) }.into());
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		Pallet,
 		mock::new_test_ext(),
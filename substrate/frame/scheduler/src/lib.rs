@@ -33,6 +33,11 @@
 //! number or at a specified period. These scheduled runtime calls may be named or anonymous and may
 //! be canceled.
 //!
+//! Runtime calls can also be scheduled against on-chain time, as provided by
+//! [`Config::TimeProvider`], rather than a block number. Such timed tasks support the same naming,
+//! periodicity, retry and weight accounting as block number based tasks and are executed in the
+//! first block in which the on-chain time has reached their target.
+//!
 //! __NOTE:__ Instead of using the filter contained in the origin to call `fn schedule`, scheduled
 //! runtime calls will be dispatched with the default filter for the origin: namely
 //! `frame_system::Config::BaseCallFilter` for all origin types (except root which will get no
@@ -81,8 +86,10 @@ mod benchmarking;
 pub mod migration;
 #[cfg(test)]
 mod mock;
+pub mod runtime_api;
 #[cfg(test)]
 mod tests;
+mod timed;
 pub mod weights;
 
 extern crate alloc;
@@ -96,7 +103,7 @@ use frame_support::{
 	traits::{
 		schedule::{self, DispatchTime, MaybeHashed},
 		Bounded, CallerTrait, EnsureOrigin, Get, IsType, OriginTrait, PalletInfoAccess,
-		PrivilegeCmp, QueryPreimage, StorageVersion, StorePreimage,
+		PrivilegeCmp, QueryPreimage, StorageVersion, StorePreimage, Time,
 	},
 	weights::{Weight, WeightMeter},
 };
@@ -125,6 +132,9 @@ pub type BoundedCallOf<T> =
 pub type BlockNumberFor<T> =
 	<<T as Config>::BlockNumberProvider as BlockNumberProvider>::BlockNumber;
 
+/// The on-chain time against which timed tasks are scheduled.
+pub type MomentOf<T> = <<T as Config>::TimeProvider as Time>::Moment;
+
 /// The configuration of the retry mechanism for a given task along with its current state.
 #[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub struct RetryConfig<Period> {
@@ -208,6 +218,33 @@ pub type ScheduledOf<T> = Scheduled<
 	<T as frame_system::Config>::AccountId,
 >;
 
+/// A task scheduled against on-chain time, with its period given in units of
+/// [`Config::TimeProvider`].
+pub type TimedScheduledOf<T> = Scheduled<
+	TaskName,
+	BoundedCallOf<T>,
+	MomentOf<T>,
+	<T as Config>::PalletsOrigin,
+	<T as frame_system::Config>::AccountId,
+>;
+
+/// Information about a timed task which is yet to be executed.
+#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct TimedTaskInfo<Moment, Hash> {
+	/// The address of the task.
+	pub task: TaskAddress<Moment>,
+	/// The unique identity of the task, if there is one.
+	pub id: Option<TaskName>,
+	/// The priority of the task.
+	pub priority: schedule::Priority,
+	/// The hash of the call to be dispatched.
+	pub call_hash: Hash,
+	/// If the task is periodic, then this points to the information concerning that.
+	pub maybe_periodic: Option<schedule::Period<Moment>>,
+	/// The amount of retries left for the task, if it has a retry configuration.
+	pub remaining_retries: Option<u8>,
+}
+
 pub(crate) trait MarginalWeightInfo: WeightInfo {
 	fn service_task(maybe_lookup_len: Option<usize>, named: bool, periodic: bool) -> Weight {
 		let base = Self::service_task_base();
@@ -322,6 +359,20 @@ pub mod pallet {
 		/// swap block number providers on the fly, then please at least ensure that you do not run
 		/// any pallet migration in the same runtime upgrade.
 		type BlockNumberProvider: BlockNumberProvider;
+
+		/// The source of on-chain time against which timed tasks are scheduled.
+		///
+		/// This is usually `pallet_timestamp::Pallet`. Note that timed tasks are serviced in
+		/// `on_initialize`, before the timestamp inherent of the current block is applied. A
+		/// timed task therefore executes in the first block whose parent has a timestamp at or
+		/// beyond the task's target.
+		type TimeProvider: Time;
+
+		/// The maximum number of distinct points in time for which timed tasks can be scheduled.
+		///
+		/// Setting this to zero effectively disables scheduling by time.
+		#[pallet::constant]
+		type MaxTimedAgendas: Get<u32>;
 	}
 
 	/// Block number at which the agenda began incomplete execution.
@@ -356,6 +407,38 @@ pub mod pallet {
 	pub type Lookup<T: Config> =
 		StorageMap<_, Twox64Concat, TaskName, TaskAddress<BlockNumberFor<T>>>;
 
+	/// Items to be executed, indexed by the on-chain time from which on they should be executed.
+	#[pallet::storage]
+	pub type TimedAgenda<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		MomentOf<T>,
+		BoundedVec<Option<TimedScheduledOf<T>>, T::MaxScheduledPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The points in time for which a [`TimedAgenda`] is pending execution, in ascending order.
+	#[pallet::storage]
+	pub type TimedAgendaIndex<T: Config> =
+		StorageValue<_, BoundedVec<MomentOf<T>, T::MaxTimedAgendas>, ValueQuery>;
+
+	/// Retry configurations for timed items to be executed, indexed by task address.
+	#[pallet::storage]
+	pub type TimedRetries<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		TaskAddress<MomentOf<T>>,
+		RetryConfig<MomentOf<T>>,
+		OptionQuery,
+	>;
+
+	/// Lookup from a name to the point in time and index of a timed task.
+	///
+	/// Names are shared with [`Lookup`], a name can only be used by one task at a time.
+	#[pallet::storage]
+	pub type TimedLookup<T: Config> =
+		StorageMap<_, Twox64Concat, TaskName, TaskAddress<MomentOf<T>>>;
+
 	/// Events type.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -390,6 +473,34 @@ pub mod pallet {
 		PermanentlyOverweight { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
 		/// Agenda is incomplete from `when`.
 		AgendaIncomplete { when: BlockNumberFor<T> },
+		/// Scheduled some task by time.
+		TimedScheduled { when: MomentOf<T>, index: u32 },
+		/// Canceled some task scheduled by time.
+		TimedCanceled { when: MomentOf<T>, index: u32 },
+		/// Dispatched some task scheduled by time.
+		TimedDispatched {
+			task: TaskAddress<MomentOf<T>>,
+			id: Option<TaskName>,
+			result: DispatchResult,
+		},
+		/// Set a retry configuration for some task scheduled by time.
+		TimedRetrySet {
+			task: TaskAddress<MomentOf<T>>,
+			id: Option<TaskName>,
+			period: MomentOf<T>,
+			retries: u8,
+		},
+		/// Cancel a retry configuration for some task scheduled by time.
+		TimedRetryCancelled { task: TaskAddress<MomentOf<T>>, id: Option<TaskName> },
+		/// The call for the provided hash was not found so the timed task has been aborted.
+		TimedCallUnavailable { task: TaskAddress<MomentOf<T>>, id: Option<TaskName> },
+		/// The given timed task was unable to be renewed since the agenda is full at that time.
+		TimedPeriodicFailed { task: TaskAddress<MomentOf<T>>, id: Option<TaskName> },
+		/// The given timed task was unable to be retried since the agenda is full at that time or
+		/// there was not enough weight to reschedule it.
+		TimedRetryFailed { task: TaskAddress<MomentOf<T>>, id: Option<TaskName> },
+		/// The given timed task can never be executed since it is overweight.
+		TimedPermanentlyOverweight { task: TaskAddress<MomentOf<T>>, id: Option<TaskName> },
 	}
 
 	#[pallet::error]
@@ -404,6 +515,8 @@ pub mod pallet {
 		RescheduleNoChange,
 		/// Attempt to use a non-named function on a named task.
 		Named,
+		/// Given target time is not in the future.
+		TargetTimeInPast,
 	}
 
 	#[pallet::hooks]
@@ -412,7 +525,8 @@ pub mod pallet {
 		fn on_initialize(_now: SystemBlockNumberFor<T>) -> Weight {
 			let now = T::BlockNumberProvider::current_block_number();
 			let mut weight_counter = WeightMeter::with_limit(T::MaximumWeight::get());
-			Self::service_agendas(&mut weight_counter, now, u32::MAX);
+			let executed = Self::service_agendas(&mut weight_counter, now, u32::MAX);
+			Self::service_timed_agendas(&mut weight_counter, T::TimeProvider::now(), executed);
 			weight_counter.consumed()
 		}
 	}
@@ -635,6 +749,150 @@ pub mod pallet {
 			Self::deposit_event(Event::RetryCancelled { task, id: Some(id) });
 			Ok(())
 		}
+
+		/// Anonymously schedule a task to be executed once the on-chain time reaches `when`.
+		///
+		/// Any period of `maybe_periodic` is given in units of [`Config::TimeProvider`].
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_at_time(T::MaxScheduledPerBlock::get()))]
+		pub fn schedule_at_time(
+			origin: OriginFor<T>,
+			when: MomentOf<T>,
+			maybe_periodic: Option<schedule::Period<MomentOf<T>>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_schedule_timed(
+				None,
+				when,
+				maybe_periodic,
+				priority,
+				origin.caller().clone(),
+				T::Preimages::bound(*call)?,
+			)?;
+			Ok(())
+		}
+
+		/// Schedule a named task to be executed once the on-chain time reaches `when`.
+		///
+		/// Any period of `maybe_periodic` is given in units of [`Config::TimeProvider`].
+		#[pallet::call_index(11)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::schedule_named_at_time(T::MaxScheduledPerBlock::get())
+		)]
+		pub fn schedule_named_at_time(
+			origin: OriginFor<T>,
+			id: TaskName,
+			when: MomentOf<T>,
+			maybe_periodic: Option<schedule::Period<MomentOf<T>>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_schedule_timed(
+				Some(id),
+				when,
+				maybe_periodic,
+				priority,
+				origin.caller().clone(),
+				T::Preimages::bound(*call)?,
+			)?;
+			Ok(())
+		}
+
+		/// Cancel an anonymously scheduled timed task.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_timed(T::MaxScheduledPerBlock::get()))]
+		pub fn cancel_timed(origin: OriginFor<T>, when: MomentOf<T>, index: u32) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_cancel_timed(Some(origin.caller().clone()), (when, index))?;
+			Ok(())
+		}
+
+		/// Cancel a named timed task.
+		#[pallet::call_index(13)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::cancel_named_timed(T::MaxScheduledPerBlock::get())
+		)]
+		pub fn cancel_named_timed(origin: OriginFor<T>, id: TaskName) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_cancel_named_timed(Some(origin.caller().clone()), id)?;
+			Ok(())
+		}
+
+		/// Set a retry configuration for a timed task so that, in case its scheduled run fails, it
+		/// will be retried after `period` has elapsed, for a total amount of `retries` retries or
+		/// until it succeeds.
+		///
+		/// The same rules as for [`Pallet::set_retry`] apply, with `period` given in units of
+		/// [`Config::TimeProvider`].
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_retry_timed())]
+		pub fn set_retry_timed(
+			origin: OriginFor<T>,
+			task: TaskAddress<MomentOf<T>>,
+			retries: u8,
+			period: MomentOf<T>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_set_retry_timed(origin.caller(), task, retries, period)?;
+			Self::deposit_event(Event::TimedRetrySet { task, id: None, period, retries });
+			Ok(())
+		}
+
+		/// Set a retry configuration for a named timed task so that, in case its scheduled run
+		/// fails, it will be retried after `period` has elapsed, for a total amount of `retries`
+		/// retries or until it succeeds.
+		///
+		/// The same rules as for [`Pallet::set_retry_named`] apply, with `period` given in units
+		/// of [`Config::TimeProvider`].
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_retry_named_timed())]
+		pub fn set_retry_named_timed(
+			origin: OriginFor<T>,
+			id: TaskName,
+			retries: u8,
+			period: MomentOf<T>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let task = TimedLookup::<T>::get(&id).ok_or(Error::<T>::NotFound)?;
+			Self::do_set_retry_timed(origin.caller(), task, retries, period)?;
+			Self::deposit_event(Event::TimedRetrySet { task, id: Some(id), period, retries });
+			Ok(())
+		}
+
+		/// Removes the retry configuration of a timed task.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_retry_timed())]
+		pub fn cancel_retry_timed(
+			origin: OriginFor<T>,
+			task: TaskAddress<MomentOf<T>>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_cancel_retry_timed(origin.caller(), task)?;
+			Self::deposit_event(Event::TimedRetryCancelled { task, id: None });
+			Ok(())
+		}
+
+		/// Cancel the retry configuration of a named timed task.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_retry_named_timed())]
+		pub fn cancel_retry_named_timed(origin: OriginFor<T>, id: TaskName) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let task = TimedLookup::<T>::get(&id).ok_or(Error::<T>::NotFound)?;
+			Self::do_cancel_retry_timed(origin.caller(), task)?;
+			Self::deposit_event(Event::TimedRetryCancelled { task, id: Some(id) });
+			Ok(())
+		}
 	}
 }
 
@@ -1082,7 +1340,7 @@ impl<T: Config> Pallet<T> {
 		call: BoundedCallOf<T>,
 	) -> Result<TaskAddress<BlockNumberFor<T>>, DispatchError> {
 		// ensure id it is unique
-		if Lookup::<T>::contains_key(&id) {
+		if Lookup::<T>::contains_key(&id) || TimedLookup::<T>::contains_key(&id) {
 			return Err(Error::<T>::FailedToSchedule.into())
 		}
 
@@ -1185,9 +1443,11 @@ use ServiceTaskError::*;
 
 impl<T: Config> Pallet<T> {
 	/// Service up to `max` agendas queue starting from earliest incompletely executed agenda.
-	fn service_agendas(weight: &mut WeightMeter, now: BlockNumberFor<T>, max: u32) {
+	///
+	/// Returns the number of executed tasks.
+	fn service_agendas(weight: &mut WeightMeter, now: BlockNumberFor<T>, max: u32) -> u32 {
 		if weight.try_consume(T::WeightInfo::service_agendas_base()).is_err() {
-			return
+			return 0
 		}
 
 		let mut incomplete_since = now + One::one();
@@ -1209,6 +1469,7 @@ impl<T: Config> Pallet<T> {
 			Self::deposit_event(Event::AgendaIncomplete { when: incomplete_since });
 			IncompleteSince::<T>::put(incomplete_since);
 		}
+		executed
 	}
 
 	/// Returns `true` if the agenda was fully completed, `false` if it should be revisited at a
@@ -215,6 +215,16 @@ impl WeightInfo for TestWeightInfo {
 parameter_types! {
 	pub storage MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
 		BlockWeights::get().max_block;
+	pub static MockNow: u64 = 0;
+}
+
+/// A time provider whose current time is set by the tests through [`MockNow`].
+pub struct MockTime;
+impl Time for MockTime {
+	type Moment = u64;
+	fn now() -> u64 {
+		MockNow::get()
+	}
 }
 
 impl Config for Test {
@@ -229,6 +239,8 @@ impl Config for Test {
 	type WeightInfo = TestWeightInfo;
 	type Preimages = Preimage;
 	type BlockNumberProvider = frame_system::Pallet<Self>;
+	type TimeProvider = MockTime;
+	type MaxTimedAgendas = ConstU32<10>;
 }
 
 pub type LoggerCall = logger::Call<Test>;
@@ -3076,3 +3076,310 @@ fn postponed_task_is_still_available() {
 		assert!(Agenda::<Test>::get(4).is_empty());
 	});
 }
+
+#[test]
+fn timed_scheduling_works() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::schedule_at_time(
+			RuntimeOrigin::root(),
+			1000,
+			None,
+			127,
+			Box::new(call)
+		));
+		assert_eq!(TimedAgendaIndex::<Test>::get().into_inner(), vec![1000]);
+
+		System::run_to_block::<AllPalletsWithSystem>(2);
+		MockNow::set(999);
+		System::run_to_block::<AllPalletsWithSystem>(3);
+		assert!(logger::log().is_empty());
+
+		MockNow::set(1000);
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert!(TimedAgenda::<Test>::get(1000).is_empty());
+		assert!(TimedAgendaIndex::<Test>::get().is_empty());
+
+		MockNow::set(5000);
+		System::run_to_block::<AllPalletsWithSystem>(10);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+	});
+}
+
+#[test]
+fn timed_scheduling_in_the_past_fails() {
+	new_test_ext().execute_with(|| {
+		MockNow::set(100);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_noop!(
+			Scheduler::schedule_at_time(RuntimeOrigin::root(), 100, None, 127, Box::new(call)),
+			Error::<Test>::TargetTimeInPast,
+		);
+	});
+}
+
+#[test]
+fn timed_tasks_run_after_block_tasks_in_time_order() {
+	new_test_ext().execute_with(|| {
+		for (i, when) in [(1u32, 3000u64), (2, 1000), (3, 2000)] {
+			let call =
+				RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) });
+			assert_ok!(Scheduler::schedule_at_time(
+				RuntimeOrigin::root(),
+				when,
+				None,
+				127,
+				Box::new(call)
+			));
+		}
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+				i: 4,
+				weight: Weight::from_parts(10, 0)
+			}))
+			.unwrap()
+		));
+		assert_eq!(TimedAgendaIndex::<Test>::get().into_inner(), vec![1000, 2000, 3000]);
+
+		System::run_to_block::<AllPalletsWithSystem>(3);
+		assert!(logger::log().is_empty());
+
+		MockNow::set(2500);
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		assert_eq!(logger::log(), vec![(root(), 4u32), (root(), 2u32), (root(), 3u32)]);
+		assert_eq!(TimedAgendaIndex::<Test>::get().into_inner(), vec![3000]);
+	});
+}
+
+#[test]
+fn named_timed_scheduling_and_cancel_works() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::schedule_named_at_time(
+			RuntimeOrigin::root(),
+			[1u8; 32],
+			1000,
+			None,
+			127,
+			Box::new(call.clone())
+		));
+		assert_eq!(TimedLookup::<Test>::get([1u8; 32]), Some((1000, 0)));
+
+		// Names are shared with tasks scheduled by block number.
+		assert_noop!(
+			Scheduler::schedule_named_at_time(
+				RuntimeOrigin::root(),
+				[1u8; 32],
+				2000,
+				None,
+				127,
+				Box::new(call.clone())
+			),
+			Error::<Test>::FailedToSchedule,
+		);
+		assert_noop!(
+			Scheduler::schedule_named(
+				RuntimeOrigin::root(),
+				[1u8; 32],
+				4,
+				None,
+				127,
+				Box::new(call)
+			),
+			Error::<Test>::FailedToSchedule,
+		);
+
+		assert_ok!(Scheduler::cancel_named_timed(RuntimeOrigin::root(), [1u8; 32]));
+		System::assert_last_event(crate::Event::TimedCanceled { when: 1000, index: 0 }.into());
+		assert!(TimedLookup::<Test>::get([1u8; 32]).is_none());
+		assert!(TimedAgendaIndex::<Test>::get().is_empty());
+
+		MockNow::set(1000);
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		assert!(logger::log().is_empty());
+		assert_noop!(
+			Scheduler::cancel_named_timed(RuntimeOrigin::root(), [1u8; 32]),
+			Error::<Test>::NotFound,
+		);
+	});
+}
+
+#[test]
+fn cancel_timed_works() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::schedule_at_time(
+			RuntimeOrigin::root(),
+			1000,
+			None,
+			127,
+			Box::new(call)
+		));
+		// Only the origin which scheduled the task (or a more privileged one) may cancel it.
+		assert_noop!(Scheduler::cancel_timed(RuntimeOrigin::signed(1), 1000, 0), BadOrigin);
+		assert_ok!(Scheduler::cancel_timed(RuntimeOrigin::root(), 1000, 0));
+		assert!(TimedAgenda::<Test>::get(1000).is_empty());
+		assert!(TimedAgendaIndex::<Test>::get().is_empty());
+		assert_noop!(
+			Scheduler::cancel_timed(RuntimeOrigin::root(), 1000, 0),
+			Error::<Test>::NotFound,
+		);
+	});
+}
+
+#[test]
+fn periodic_timed_scheduling_works() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::schedule_at_time(
+			RuntimeOrigin::root(),
+			1000,
+			Some((500, 3)),
+			127,
+			Box::new(call)
+		));
+
+		MockNow::set(1000);
+		System::run_to_block::<AllPalletsWithSystem>(2);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert_eq!(TimedAgendaIndex::<Test>::get().into_inner(), vec![1500]);
+
+		// The next run is placed relative to the time it actually ran.
+		MockNow::set(1600);
+		System::run_to_block::<AllPalletsWithSystem>(3);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 42u32)]);
+		assert_eq!(TimedAgendaIndex::<Test>::get().into_inner(), vec![2100]);
+
+		MockNow::set(2100);
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 42u32), (root(), 42u32)]);
+		assert!(TimedAgendaIndex::<Test>::get().is_empty());
+
+		MockNow::set(5000);
+		System::run_to_block::<AllPalletsWithSystem>(10);
+		assert_eq!(logger::log().len(), 3);
+	});
+}
+
+#[test]
+fn timed_retry_works() {
+	new_test_ext().execute_with(|| {
+		// task fails until block 4
+		Threshold::<Test>::put((4, 100));
+		let call =
+			RuntimeCall::Logger(LoggerCall::timed_log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::schedule_named_at_time(
+			RuntimeOrigin::root(),
+			[1u8; 32],
+			1000,
+			None,
+			127,
+			Box::new(call)
+		));
+		assert_ok!(Scheduler::set_retry_named_timed(RuntimeOrigin::root(), [1u8; 32], 3, 10));
+		assert_eq!(
+			TimedRetries::<Test>::get((1000, 0)),
+			Some(RetryConfig { total_retries: 3, remaining: 3, period: 10 })
+		);
+
+		// fails at block 2, retried 10 units of time later
+		MockNow::set(1000);
+		System::run_to_block::<AllPalletsWithSystem>(2);
+		assert!(logger::log().is_empty());
+		assert_eq!(
+			TimedRetries::<Test>::get((1010, 0)),
+			Some(RetryConfig { total_retries: 3, remaining: 2, period: 10 })
+		);
+
+		// fails again at block 3
+		MockNow::set(1010);
+		System::run_to_block::<AllPalletsWithSystem>(3);
+		assert!(logger::log().is_empty());
+		assert_eq!(
+			TimedRetries::<Test>::get((1020, 0)),
+			Some(RetryConfig { total_retries: 3, remaining: 1, period: 10 })
+		);
+
+		// succeeds at block 4
+		MockNow::set(1020);
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert_eq!(TimedRetries::<Test>::iter().count(), 0);
+		assert!(TimedAgendaIndex::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn upcoming_timed_tasks_works() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let call_hash = Preimage::bound(call.clone()).unwrap().hash();
+		for when in [3000u64, 1000, 2000] {
+			assert_ok!(Scheduler::schedule_at_time(
+				RuntimeOrigin::root(),
+				when,
+				None,
+				127,
+				Box::new(call.clone())
+			));
+		}
+		assert_ok!(Scheduler::set_retry_timed(RuntimeOrigin::root(), (2000, 0), 5, 10));
+
+		let info = |when, remaining_retries| TimedTaskInfo {
+			task: (when, 0),
+			id: None,
+			priority: 127,
+			call_hash,
+			maybe_periodic: None,
+			remaining_retries,
+		};
+		assert_eq!(
+			Scheduler::upcoming_timed_tasks(10),
+			vec![info(1000, None), info(2000, Some(5)), info(3000, None)]
+		);
+		assert_eq!(Scheduler::upcoming_timed_tasks(1), vec![info(1000, None)]);
+	});
+}
+
+#[test]
+fn unavailable_timed_task_is_dropped() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(1000, 0) });
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+		let len = call.using_encoded(|x| x.len()) as u32;
+		// Important to use here `Bounded::Lookup` to ensure that we request the hash.
+		let hashed = Bounded::Lookup { hash, len };
+		let name: [u8; 32] = hash.as_ref().try_into().unwrap();
+
+		assert_ok!(Scheduler::do_schedule_timed(Some(name), 1000, None, 127, root(), hashed));
+		assert_ok!(Scheduler::set_retry_named_timed(RuntimeOrigin::root(), name, 3, 10));
+		assert_eq!(TimedAgendaIndex::<Test>::get().into_inner(), vec![1000]);
+
+		MockNow::set(1000);
+		System::run_to_block::<AllPalletsWithSystem>(4);
+
+		// It was not executed.
+		assert!(logger::log().is_empty());
+		System::assert_last_event(
+			crate::Event::TimedCallUnavailable { task: (1000, 0), id: Some(name) }.into(),
+		);
+
+		// The task is gone from both the agenda and the index, along with its retry config.
+		assert!(!TimedAgenda::<Test>::contains_key(1000));
+		assert!(TimedAgendaIndex::<Test>::get().is_empty());
+		assert!(!TimedLookup::<Test>::contains_key(name));
+		assert_eq!(TimedRetries::<Test>::iter().count(), 0);
+	});
+}
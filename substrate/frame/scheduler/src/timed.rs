@@ -0,0 +1,511 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scheduling of tasks against on-chain time.
+//!
+//! Timed tasks live in their own [`TimedAgenda`], keyed by the point in time from which on they
+//! should be executed. The keys of all pending agendas are kept in ascending order in
+//! [`TimedAgendaIndex`], so that servicing only needs to look at the front of the index.
+
+use super::*;
+
+impl<T: Config> Pallet<T> {
+	fn resolve_timed(when: MomentOf<T>) -> Result<MomentOf<T>, DispatchError> {
+		if when <= T::TimeProvider::now() {
+			return Err(Error::<T>::TargetTimeInPast.into())
+		}
+
+		Ok(when)
+	}
+
+	fn place_timed_task(
+		when: MomentOf<T>,
+		what: TimedScheduledOf<T>,
+	) -> Result<TaskAddress<MomentOf<T>>, (DispatchError, TimedScheduledOf<T>)> {
+		let maybe_name = what.maybe_id;
+		let index = Self::push_to_timed_agenda(when, what)?;
+		let address = (when, index);
+		if let Some(name) = maybe_name {
+			TimedLookup::<T>::insert(name, address)
+		}
+		Self::deposit_event(Event::TimedScheduled { when, index });
+		Ok(address)
+	}
+
+	fn push_to_timed_agenda(
+		when: MomentOf<T>,
+		what: TimedScheduledOf<T>,
+	) -> Result<u32, (DispatchError, TimedScheduledOf<T>)> {
+		let mut agenda = TimedAgenda::<T>::get(when);
+		if agenda.is_empty() {
+			// A new agenda needs to be registered in the index first.
+			let mut timed_index = TimedAgendaIndex::<T>::get();
+			if let Err(pos) = timed_index.binary_search(&when) {
+				if timed_index.try_insert(pos, when).is_err() {
+					return Err((DispatchError::Exhausted, what))
+				}
+				TimedAgendaIndex::<T>::put(timed_index);
+			}
+		}
+		let index = if (agenda.len() as u32) < T::MaxScheduledPerBlock::get() {
+			// will always succeed due to the above check.
+			let _ = agenda.try_push(Some(what));
+			agenda.len() as u32 - 1
+		} else {
+			if let Some(hole_index) = agenda.iter().position(|i| i.is_none()) {
+				agenda[hole_index] = Some(what);
+				hole_index as u32
+			} else {
+				return Err((DispatchError::Exhausted, what))
+			}
+		};
+		TimedAgenda::<T>::insert(when, agenda);
+		Ok(index)
+	}
+
+	/// Remove `when` from the [`TimedAgendaIndex`], if present.
+	fn remove_from_timed_index(when: MomentOf<T>) {
+		TimedAgendaIndex::<T>::mutate(|timed_index| {
+			if let Ok(pos) = timed_index.binary_search(&when) {
+				timed_index.remove(pos);
+			}
+		});
+	}
+
+	/// Remove trailing `None` items of a timed agenda at `when`. If all items are `None` remove
+	/// the agenda record entirely.
+	fn cleanup_timed_agenda(when: MomentOf<T>) {
+		let mut agenda = TimedAgenda::<T>::get(when);
+		match agenda.iter().rposition(|i| i.is_some()) {
+			Some(i) if agenda.len() > i + 1 => {
+				agenda.truncate(i + 1);
+				TimedAgenda::<T>::insert(when, agenda);
+			},
+			Some(_) => {},
+			None => {
+				TimedAgenda::<T>::remove(when);
+				Self::remove_from_timed_index(when);
+			},
+		}
+	}
+
+	pub(crate) fn do_schedule_timed(
+		maybe_id: Option<TaskName>,
+		when: MomentOf<T>,
+		maybe_periodic: Option<schedule::Period<MomentOf<T>>>,
+		priority: schedule::Priority,
+		origin: T::PalletsOrigin,
+		call: BoundedCallOf<T>,
+	) -> Result<TaskAddress<MomentOf<T>>, DispatchError> {
+		// ensure id it is unique
+		if let Some(ref id) = maybe_id {
+			if Lookup::<T>::contains_key(id) || TimedLookup::<T>::contains_key(id) {
+				return Err(Error::<T>::FailedToSchedule.into())
+			}
+		}
+
+		let when = Self::resolve_timed(when)?;
+
+		let lookup_hash = call.lookup_hash();
+
+		// sanitize maybe_periodic
+		let maybe_periodic = maybe_periodic
+			.filter(|p| p.1 > 1 && !p.0.is_zero())
+			// Remove one from the number of repetitions since we will schedule one now.
+			.map(|(p, c)| (p, c - 1));
+		let task =
+			Scheduled { maybe_id, priority, call, maybe_periodic, origin, _phantom: PhantomData };
+		let res = Self::place_timed_task(when, task).map_err(|x| x.0)?;
+
+		if let Some(hash) = lookup_hash {
+			// Request the call to be made available.
+			T::Preimages::request(&hash);
+		}
+
+		Ok(res)
+	}
+
+	pub(crate) fn do_cancel_timed(
+		origin: Option<T::PalletsOrigin>,
+		(when, index): TaskAddress<MomentOf<T>>,
+	) -> Result<(), DispatchError> {
+		let scheduled = TimedAgenda::<T>::try_mutate(when, |agenda| {
+			agenda.get_mut(index as usize).map_or(
+				Ok(None),
+				|s| -> Result<Option<TimedScheduledOf<T>>, DispatchError> {
+					if let (Some(ref o), Some(ref s)) = (origin, s.borrow()) {
+						Self::ensure_privilege(o, &s.origin)?;
+					};
+					Ok(s.take())
+				},
+			)
+		})?;
+		if let Some(s) = scheduled {
+			T::Preimages::drop(&s.call);
+			if let Some(id) = s.maybe_id {
+				TimedLookup::<T>::remove(id);
+			}
+			TimedRetries::<T>::remove((when, index));
+			Self::cleanup_timed_agenda(when);
+			Self::deposit_event(Event::TimedCanceled { when, index });
+			Ok(())
+		} else {
+			return Err(Error::<T>::NotFound.into())
+		}
+	}
+
+	pub(crate) fn do_cancel_named_timed(
+		origin: Option<T::PalletsOrigin>,
+		id: TaskName,
+	) -> DispatchResult {
+		TimedLookup::<T>::try_mutate_exists(id, |lookup| -> DispatchResult {
+			if let Some((when, index)) = lookup.take() {
+				let i = index as usize;
+				TimedAgenda::<T>::try_mutate(when, |agenda| -> DispatchResult {
+					if let Some(s) = agenda.get_mut(i) {
+						if let (Some(ref o), Some(ref s)) = (origin, s.borrow()) {
+							Self::ensure_privilege(o, &s.origin)?;
+							TimedRetries::<T>::remove((when, index));
+							T::Preimages::drop(&s.call);
+						}
+						*s = None;
+					}
+					Ok(())
+				})?;
+				Self::cleanup_timed_agenda(when);
+				Self::deposit_event(Event::TimedCanceled { when, index });
+				Ok(())
+			} else {
+				return Err(Error::<T>::NotFound.into())
+			}
+		})
+	}
+
+	pub(crate) fn do_set_retry_timed(
+		origin: &T::PalletsOrigin,
+		(when, index): TaskAddress<MomentOf<T>>,
+		retries: u8,
+		period: MomentOf<T>,
+	) -> Result<(), DispatchError> {
+		let agenda = TimedAgenda::<T>::get(when);
+		let scheduled = agenda
+			.get(index as usize)
+			.and_then(Option::as_ref)
+			.ok_or(Error::<T>::NotFound)?;
+		Self::ensure_privilege(origin, &scheduled.origin)?;
+		TimedRetries::<T>::insert(
+			(when, index),
+			RetryConfig { total_retries: retries, remaining: retries, period },
+		);
+		Ok(())
+	}
+
+	pub(crate) fn do_cancel_retry_timed(
+		origin: &T::PalletsOrigin,
+		(when, index): TaskAddress<MomentOf<T>>,
+	) -> Result<(), DispatchError> {
+		let agenda = TimedAgenda::<T>::get(when);
+		let scheduled = agenda
+			.get(index as usize)
+			.and_then(Option::as_ref)
+			.ok_or(Error::<T>::NotFound)?;
+		Self::ensure_privilege(origin, &scheduled.origin)?;
+		TimedRetries::<T>::remove((when, index));
+		Ok(())
+	}
+
+	/// Service the timed agendas which are due at `now`, earliest first, for as long as there is
+	/// weight left.
+	///
+	/// `executed` is the number of tasks which were already executed in this block.
+	pub(crate) fn service_timed_agendas(
+		weight: &mut WeightMeter,
+		now: MomentOf<T>,
+		mut executed: u32,
+	) {
+		let max_items = T::MaxScheduledPerBlock::get();
+		let service_agenda_base_weight = T::WeightInfo::service_timed_agenda_base(max_items);
+		loop {
+			// Looking up the next agenda in the index and removing it once completed.
+			if weight.try_consume(T::WeightInfo::service_timed_agendas_base()).is_err() {
+				return
+			}
+			let Some(when) = TimedAgendaIndex::<T>::get().first().copied() else { return };
+			if when > now || !weight.can_consume(service_agenda_base_weight) {
+				return
+			}
+			if !Self::service_timed_agenda(weight, &mut executed, now, when, u32::MAX) {
+				return
+			}
+		}
+	}
+
+	/// Returns `true` if the timed agenda was fully completed, `false` if it should be revisited
+	/// at a later block.
+	///
+	/// Once completed, the agenda is removed from both the [`TimedAgenda`] and the
+	/// [`TimedAgendaIndex`]. Tasks which cannot ever be executed are dropped along with their
+	/// retry configuration, as nothing would service them anymore.
+	pub(crate) fn service_timed_agenda(
+		weight: &mut WeightMeter,
+		executed: &mut u32,
+		now: MomentOf<T>,
+		when: MomentOf<T>,
+		max: u32,
+	) -> bool {
+		let mut agenda = TimedAgenda::<T>::get(when);
+		let mut ordered = agenda
+			.iter()
+			.enumerate()
+			.filter_map(|(index, maybe_item)| {
+				maybe_item.as_ref().map(|item| (index as u32, item.priority))
+			})
+			.collect::<Vec<_>>();
+		ordered.sort_by_key(|k| k.1);
+		let within_limit = weight
+			.try_consume(T::WeightInfo::service_timed_agenda_base(ordered.len() as u32))
+			.is_ok();
+		debug_assert!(within_limit, "weight limit should have been checked in advance");
+
+		// Items which we know can be executed and have postponed for execution in a later block.
+		let mut postponed = (ordered.len() as u32).saturating_sub(max);
+
+		for (agenda_index, _) in ordered.into_iter().take(max as usize) {
+			let Some(task) = agenda[agenda_index as usize].take() else { continue };
+			let base_weight = T::WeightInfo::service_task(
+				task.call.lookup_len().map(|x| x as usize),
+				task.maybe_id.is_some(),
+				task.maybe_periodic.is_some(),
+			);
+			if !weight.can_consume(base_weight) {
+				postponed += 1;
+				agenda[agenda_index as usize] = Some(task);
+				break
+			}
+			let result =
+				Self::service_timed_task(weight, now, when, agenda_index, *executed == 0, task);
+			agenda[agenda_index as usize] = match result {
+				Err((ServiceTaskError::Unavailable, _)) => {
+					TimedRetries::<T>::remove((when, agenda_index));
+					None
+				},
+				Err((ServiceTaskError::Overweight, slot)) => {
+					postponed += 1;
+					slot
+				},
+				Ok(()) => {
+					*executed += 1;
+					None
+				},
+			};
+		}
+		if postponed > 0 {
+			TimedAgenda::<T>::insert(when, agenda);
+		} else {
+			TimedAgenda::<T>::remove(when);
+			Self::remove_from_timed_index(when);
+		}
+
+		postponed == 0
+	}
+
+	/// Service (i.e. execute) the given timed task, being careful not to overflow the `weight`
+	/// counter.
+	///
+	/// This mirrors [`Pallet::service_task`] for tasks scheduled against on-chain time.
+	fn service_timed_task(
+		weight: &mut WeightMeter,
+		now: MomentOf<T>,
+		when: MomentOf<T>,
+		agenda_index: u32,
+		is_first: bool,
+		mut task: TimedScheduledOf<T>,
+	) -> Result<(), (ServiceTaskError, Option<TimedScheduledOf<T>>)> {
+		if let Some(ref id) = task.maybe_id {
+			TimedLookup::<T>::remove(id);
+		}
+
+		let (call, lookup_len) = match T::Preimages::peek(&task.call) {
+			Ok(c) => c,
+			Err(_) => {
+				Self::deposit_event(Event::TimedCallUnavailable {
+					task: (when, agenda_index),
+					id: task.maybe_id,
+				});
+
+				// It was not available when we needed it, so we don't need to have requested it
+				// anymore.
+				T::Preimages::drop(&task.call);
+
+				// We don't know why `peek` failed, thus we most account here for the "full weight".
+				let _ = weight.try_consume(T::WeightInfo::service_task(
+					task.call.lookup_len().map(|x| x as usize),
+					task.maybe_id.is_some(),
+					task.maybe_periodic.is_some(),
+				));
+
+				return Err((ServiceTaskError::Unavailable, Some(task)))
+			},
+		};
+
+		let _ = weight.try_consume(T::WeightInfo::service_task(
+			lookup_len.map(|x| x as usize),
+			task.maybe_id.is_some(),
+			task.maybe_periodic.is_some(),
+		));
+
+		match Self::execute_dispatch(weight, task.origin.clone(), call) {
+			Err(()) if is_first => {
+				T::Preimages::drop(&task.call);
+				Self::deposit_event(Event::TimedPermanentlyOverweight {
+					task: (when, agenda_index),
+					id: task.maybe_id,
+				});
+				Err((ServiceTaskError::Unavailable, Some(task)))
+			},
+			Err(()) => {
+				// Keep the name reserved while the task is postponed.
+				if let Some(id) = task.maybe_id {
+					TimedLookup::<T>::insert(id, (when, agenda_index));
+				}
+				Err((ServiceTaskError::Overweight, Some(task)))
+			},
+			Ok(result) => {
+				let failed = result.is_err();
+				let maybe_retry_config = TimedRetries::<T>::take((when, agenda_index));
+				Self::deposit_event(Event::TimedDispatched {
+					task: (when, agenda_index),
+					id: task.maybe_id,
+					result,
+				});
+
+				match maybe_retry_config {
+					Some(retry_config) if failed => {
+						Self::schedule_timed_retry(
+							weight,
+							now,
+							when,
+							agenda_index,
+							&task,
+							retry_config,
+						);
+					},
+					_ => {},
+				}
+
+				if let &Some((period, count)) = &task.maybe_periodic {
+					if count > 1 {
+						task.maybe_periodic = Some((period, count - 1));
+					} else {
+						task.maybe_periodic = None;
+					}
+					let wake = now.saturating_add(period);
+					match Self::place_timed_task(wake, task) {
+						Ok(new_address) =>
+							if let Some(retry_config) = maybe_retry_config {
+								TimedRetries::<T>::insert(new_address, retry_config);
+							},
+						Err((_, task)) => {
+							T::Preimages::drop(&task.call);
+							Self::deposit_event(Event::TimedPeriodicFailed {
+								task: (when, agenda_index),
+								id: task.maybe_id,
+							});
+						},
+					}
+				} else {
+					T::Preimages::drop(&task.call);
+				}
+				Ok(())
+			},
+		}
+	}
+
+	/// Check if a timed task has a retry configuration in place and, if so, try to reschedule it.
+	///
+	/// The retry is placed at least one unit of time after `now`, so that it is never placed
+	/// into an agenda which is currently being serviced.
+	pub(crate) fn schedule_timed_retry(
+		weight: &mut WeightMeter,
+		now: MomentOf<T>,
+		when: MomentOf<T>,
+		agenda_index: u32,
+		task: &TimedScheduledOf<T>,
+		retry_config: RetryConfig<MomentOf<T>>,
+	) {
+		if weight
+			.try_consume(T::WeightInfo::schedule_timed_retry(T::MaxScheduledPerBlock::get()))
+			.is_err()
+		{
+			Self::deposit_event(Event::TimedRetryFailed {
+				task: (when, agenda_index),
+				id: task.maybe_id,
+			});
+			return;
+		}
+
+		let RetryConfig { total_retries, mut remaining, period } = retry_config;
+		remaining = match remaining.checked_sub(1) {
+			Some(n) => n,
+			None => return,
+		};
+		let wake = now.saturating_add(period.max(One::one()));
+		match Self::place_timed_task(wake, task.as_retry()) {
+			Ok(address) => {
+				// Reinsert the retry config to the new address of the task after it was
+				// placed.
+				TimedRetries::<T>::insert(
+					address,
+					RetryConfig { total_retries, remaining, period },
+				);
+			},
+			Err((_, task)) => {
+				T::Preimages::drop(&task.call);
+				Self::deposit_event(Event::TimedRetryFailed {
+					task: (when, agenda_index),
+					id: task.maybe_id,
+				});
+			},
+		}
+	}
+
+	/// Returns up to `max` timed tasks which are yet to be executed, earliest first.
+	pub fn upcoming_timed_tasks(max: u32) -> Vec<TimedTaskInfo<MomentOf<T>, T::Hash>> {
+		TimedAgendaIndex::<T>::get()
+			.into_iter()
+			.flat_map(|when| {
+				TimedAgenda::<T>::get(when).into_iter().enumerate().filter_map(
+					move |(index, maybe_task)| {
+						maybe_task.map(|task| {
+							let task_address = (when, index as u32);
+							TimedTaskInfo {
+								task: task_address,
+								id: task.maybe_id,
+								priority: task.priority,
+								call_hash: task.call.hash(),
+								maybe_periodic: task.maybe_periodic,
+								remaining_retries: TimedRetries::<T>::get(task_address)
+									.map(|config| config.remaining),
+							}
+						})
+					},
+				)
+			})
+			.take(max as usize)
+			.collect()
+	}
+}
@@ -89,6 +89,17 @@ pub trait WeightInfo {
 	fn set_retry_named() -> Weight;
 	fn cancel_retry() -> Weight;
 	fn cancel_retry_named() -> Weight;
+	fn service_timed_agendas_base() -> Weight;
+	fn service_timed_agenda_base(s: u32, ) -> Weight;
+	fn schedule_at_time(s: u32, ) -> Weight;
+	fn cancel_timed(s: u32, ) -> Weight;
+	fn schedule_named_at_time(s: u32, ) -> Weight;
+	fn cancel_named_timed(s: u32, ) -> Weight;
+	fn schedule_timed_retry(s: u32, ) -> Weight;
+	fn set_retry_timed() -> Weight;
+	fn set_retry_named_timed() -> Weight;
+	fn cancel_retry_timed() -> Weight;
+	fn cancel_retry_named_timed() -> Weight;
 }
 
 /// Weights for `pallet_scheduler` using the Substrate node and recommended hardware.
@@ -319,6 +330,183 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	fn service_timed_agendas_base() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 1_275_000 picoseconds.
+		Weight::from_parts(1_335_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn service_timed_agenda_base(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5 + s * (177 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 2_379_000 picoseconds.
+		Weight::from_parts(2_409_000, 110487)
+			// Standard Error: 990
+			.saturating_add(Weight::from_parts(486_046, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 511]`.
+	fn schedule_at_time(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5 + s * (177 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 7_853_000 picoseconds.
+		Weight::from_parts(3_815_234, 110487)
+			// Standard Error: 1_794
+			.saturating_add(Weight::from_parts(538_417, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedLookup` (r:0 w:1)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 512]`.
+	fn cancel_timed(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5 + s * (177 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 13_972_000 picoseconds.
+		Weight::from_parts(2_524_382, 110487)
+			// Standard Error: 2_303
+			.saturating_add(Weight::from_parts(777_710, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:0)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:1)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 511]`.
+	fn schedule_named_at_time(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `521 + s * (178 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 11_024_000 picoseconds.
+		Weight::from_parts(8_965_037, 110487)
+			// Standard Error: 1_998
+			.saturating_add(Weight::from_parts(559_963, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:1)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 512]`.
+	fn cancel_named_timed(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `634 + s * (177 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 16_476_000 picoseconds.
+		Weight::from_parts(7_403_622, 110487)
+			// Standard Error: 2_213
+			.saturating_add(Weight::from_parts(778_228, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 512]`.
+	fn schedule_timed_retry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `31`
+		//  Estimated: `110487`
+		// Minimum execution time: 8_844_000 picoseconds.
+		Weight::from_parts(10_762_174, 110487)
+			// Standard Error: 267
+			.saturating_add(Weight::from_parts(16_285, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn set_retry_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `90629`
+		//  Estimated: `110487`
+		// Minimum execution time: 160_504_000 picoseconds.
+		Weight::from_parts(169_783_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:0)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn set_retry_named_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `91672`
+		//  Estimated: `110487`
+		// Minimum execution time: 170_680_000 picoseconds.
+		Weight::from_parts(186_544_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn cancel_retry_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `90630`
+		//  Estimated: `110487`
+		// Minimum execution time: 161_130_000 picoseconds.
+		Weight::from_parts(169_076_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:0)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn cancel_retry_named_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `91672`
+		//  Estimated: `110487`
+		// Minimum execution time: 170_742_000 picoseconds.
+		Weight::from_parts(182_329_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -548,4 +736,181 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	fn service_timed_agendas_base() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 1_275_000 picoseconds.
+		Weight::from_parts(1_335_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 512]`.
+	fn service_timed_agenda_base(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5 + s * (177 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 2_379_000 picoseconds.
+		Weight::from_parts(2_409_000, 110487)
+			// Standard Error: 990
+			.saturating_add(Weight::from_parts(486_046, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 511]`.
+	fn schedule_at_time(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5 + s * (177 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 7_853_000 picoseconds.
+		Weight::from_parts(3_815_234, 110487)
+			// Standard Error: 1_794
+			.saturating_add(Weight::from_parts(538_417, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedLookup` (r:0 w:1)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 512]`.
+	fn cancel_timed(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5 + s * (177 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 13_972_000 picoseconds.
+		Weight::from_parts(2_524_382, 110487)
+			// Standard Error: 2_303
+			.saturating_add(Weight::from_parts(777_710, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Scheduler::Lookup` (r:1 w:0)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:1)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 511]`.
+	fn schedule_named_at_time(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `521 + s * (178 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 11_024_000 picoseconds.
+		Weight::from_parts(8_965_037, 110487)
+			// Standard Error: 1_998
+			.saturating_add(Weight::from_parts(559_963, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:1)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 512]`.
+	fn cancel_named_timed(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `634 + s * (177 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 16_476_000 picoseconds.
+		Weight::from_parts(7_403_622, 110487)
+			// Standard Error: 2_213
+			.saturating_add(Weight::from_parts(778_228, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgendaIndex` (r:1 w:1)
+	/// Proof: `Scheduler::TimedAgendaIndex` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 512]`.
+	fn schedule_timed_retry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `31`
+		//  Estimated: `110487`
+		// Minimum execution time: 8_844_000 picoseconds.
+		Weight::from_parts(10_762_174, 110487)
+			// Standard Error: 267
+			.saturating_add(Weight::from_parts(16_285, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn set_retry_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `90629`
+		//  Estimated: `110487`
+		// Minimum execution time: 160_504_000 picoseconds.
+		Weight::from_parts(169_783_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:0)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn set_retry_named_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `91672`
+		//  Estimated: `110487`
+		// Minimum execution time: 170_680_000 picoseconds.
+		Weight::from_parts(186_544_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn cancel_retry_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `90630`
+		//  Estimated: `110487`
+		// Minimum execution time: 161_130_000 picoseconds.
+		Weight::from_parts(169_076_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Scheduler::TimedLookup` (r:1 w:0)
+	/// Proof: `Scheduler::TimedLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedAgenda` (r:1 w:0)
+	/// Proof: `Scheduler::TimedAgenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimedRetries` (r:0 w:1)
+	/// Proof: `Scheduler::TimedRetries` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn cancel_retry_named_timed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `91672`
+		//  Estimated: `110487`
+		// Minimum execution time: 170_742_000 picoseconds.
+		Weight::from_parts(182_329_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
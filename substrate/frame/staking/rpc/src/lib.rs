@@ -0,0 +1,193 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the staking pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorCode, ErrorObject, ErrorObjectOwned},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
+use sp_staking::{EraIndex, EraReward, YieldEstimate};
+
+pub use pallet_staking_runtime_api::StakingRewardsApi as StakingRewardsRuntimeApi;
+
+/// The maximum number of eras which can be queried by a single `staking_rewardHistory` call.
+///
+/// Every era requires going through the exposures of all of its validators.
+pub const MAX_REWARD_HISTORY_ERAS: u32 = 84;
+
+#[rpc(client, server)]
+pub trait StakingApi<BlockHash, AccountId> {
+	/// Returns the rewards of `stash` in the eras `from_era` to `to_era`, both inclusive, one
+	/// entry per page of exposure. At most [`MAX_REWARD_HISTORY_ERAS`] eras can be queried at
+	/// once.
+	#[method(name = "staking_rewardHistory")]
+	fn reward_history(
+		&self,
+		stash: AccountId,
+		from_era: EraIndex,
+		to_era: EraIndex,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<EraReward<AccountId, NumberOrHex>>>;
+
+	/// Returns the projected yearly yield of bonding `stake` split evenly across `targets`.
+	#[method(name = "staking_estimateYield")]
+	fn estimate_yield(
+		&self,
+		stake: NumberOrHex,
+		targets: Vec<AccountId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<YieldEstimate<NumberOrHex>>;
+}
+
+/// Provides RPC methods to query the rewards and projected yield of stakers.
+pub struct Staking<C, Block, Balance> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<(Block, Balance)>,
+}
+
+impl<C, Block, Balance> Staking<C, Block, Balance> {
+	/// Creates a new instance of the Staking Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The queried range of eras is empty or too long.
+	InvalidEraRange,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::InvalidEraRange => 2,
+		}
+	}
+}
+
+fn map_err(error: impl ToString, desc: &'static str) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string()))
+}
+
+fn into_number_or_hex<Balance: TryInto<NumberOrHex> + MaybeDisplay + Copy>(
+	value: Balance,
+) -> RpcResult<NumberOrHex> {
+	value.try_into().map_err(|_| {
+		ErrorObject::owned(
+			ErrorCode::InvalidParams.code(),
+			format!("{} doesn't fit in NumberOrHex representation", value),
+			None::<()>,
+		)
+	})
+}
+
+impl<C, Block, AccountId, Balance> StakingApiServer<<Block as BlockT>::Hash, AccountId>
+	for Staking<C, Block, Balance>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: StakingRewardsRuntimeApi<Block, Balance, AccountId>,
+	AccountId: Codec + Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec
+		+ MaybeDisplay
+		+ Copy
+		+ TryInto<NumberOrHex>
+		+ TryFrom<NumberOrHex>
+		+ Send
+		+ Sync
+		+ 'static,
+{
+	fn reward_history(
+		&self,
+		stash: AccountId,
+		from_era: EraIndex,
+		to_era: EraIndex,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<EraReward<AccountId, NumberOrHex>>> {
+		if from_era > to_era || to_era - from_era >= MAX_REWARD_HISTORY_ERAS {
+			return Err(ErrorObject::owned(
+				Error::InvalidEraRange.into(),
+				"Invalid range of eras.",
+				Some(format!("at most {} eras can be queried at once", MAX_REWARD_HISTORY_ERAS)),
+			))
+		}
+
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let mut history = Vec::new();
+		for era in from_era..=to_era {
+			let rewards = api
+				.era_rewards(at_hash, era, stash.clone())
+				.map_err(|e| map_err(e, "Unable to query era rewards."))?;
+			for reward in rewards {
+				history.push(EraReward {
+					era: reward.era,
+					validator: reward.validator,
+					commission: reward.commission,
+					page: reward.page,
+					stake: into_number_or_hex(reward.stake)?,
+					reward: into_number_or_hex(reward.reward)?,
+					claimed: reward.claimed,
+				});
+			}
+		}
+
+		Ok(history)
+	}
+
+	fn estimate_yield(
+		&self,
+		stake: NumberOrHex,
+		targets: Vec<AccountId>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<YieldEstimate<NumberOrHex>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let stake = Balance::try_from(stake).map_err(|_| {
+			ErrorObject::owned(
+				ErrorCode::InvalidParams.code(),
+				format!("{:?} doesn't fit in the balance type", stake),
+				None::<()>,
+			)
+		})?;
+		let estimate = api
+			.estimate_yield(at_hash, stake, targets)
+			.map_err(|e| map_err(e, "Unable to estimate yield."))?;
+
+		Ok(YieldEstimate {
+			yearly_reward: into_number_or_hex(estimate.yearly_reward)?,
+			apy: estimate.apy,
+		})
+	}
+}
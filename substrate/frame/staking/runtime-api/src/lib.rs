@@ -19,6 +19,9 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
 use codec::Codec;
 
 sp_api::decl_runtime_apis! {
@@ -36,4 +39,21 @@ sp_api::decl_runtime_apis! {
 		/// Returns true if validator `account` has pages to be claimed for the given era.
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool;
 	}
+
+	pub trait StakingRewardsApi<Balance, AccountId>
+		where
+			Balance: Codec,
+			AccountId: Codec,
+	{
+		/// Returns the rewards of `stash`, as a validator or as a nominator, in `era`, one entry per
+		/// page of exposure. Empty if `era` has not been paid yet or is beyond the history depth.
+		///
+		/// Finding the exposures of a nominator goes through all validators of `era`, hence a
+		/// single era is covered per call.
+		fn era_rewards(era: sp_staking::EraIndex, stash: AccountId) -> Vec<sp_staking::EraReward<AccountId, Balance>>;
+
+		/// Returns the projected yearly yield of bonding `stake` split evenly across `targets`,
+		/// assuming the exposures and commissions of the active era and the current inflation.
+		fn estimate_yield(stake: Balance, targets: Vec<AccountId>) -> sp_staking::YieldEstimate<Balance>;
+	}
 }
@@ -22,6 +22,9 @@
 
 use sp_runtime::{curve::PiecewiseLinear, traits::AtLeast32BitUnsigned, Perbill};
 
+/// Milliseconds per year for the Julian year (365.25 days).
+pub const MILLISECONDS_PER_YEAR: u64 = 1000 * 3600 * 24 * 36525 / 100;
+
 /// The total payout to all validators (and their nominators) per era and maximum payout.
 ///
 /// Defined as such:
@@ -38,9 +41,6 @@ pub fn compute_total_payout<N>(
 where
 	N: AtLeast32BitUnsigned + Clone,
 {
-	// Milliseconds per year for the Julian year (365.25 days).
-	const MILLISECONDS_PER_YEAR: u64 = 1000 * 3600 * 24 * 36525 / 100;
-
 	let portion = Perbill::from_rational(era_duration as u64, MILLISECONDS_PER_YEAR);
 	let payout = portion *
 		yearly_inflation
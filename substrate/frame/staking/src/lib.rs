@@ -1349,6 +1349,57 @@ impl<T: Config> EraInfo<T> {
 		<ErasValidatorPrefs<T>>::get(&era, validator_stash).commission
 	}
 
+	/// Split the reward of `validator` in `era` for one page of its `exposure`.
+	///
+	/// Returns the payout of the validator itself, i.e. its share of the commission proportional
+	/// to the page stake plus the reward for its own stake, and the leftover payout that is
+	/// shared among all nominators pro rata to `exposure.total()`. Returns `None` if the
+	/// validator earned no reward points in `era`.
+	pub(crate) fn page_payout(
+		era: EraIndex,
+		era_payout: BalanceOf<T>,
+		validator: &T::AccountId,
+		exposure: &PagedExposure<T::AccountId, BalanceOf<T>>,
+	) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
+		// Get Era reward points. It has TOTAL and INDIVIDUAL
+		// Find the fraction of the era reward that belongs to the validator
+		// Take that fraction of the eras rewards to split to nominator and validator
+		//
+		// Then look at the validator, figure out the proportion of their reward
+		// which goes to them and each of their nominators.
+		let era_reward_points = <ErasRewardPoints<T>>::get(&era);
+		let total_reward_points = era_reward_points.total;
+		let validator_reward_points =
+			era_reward_points.individual.get(validator).copied().unwrap_or_else(Zero::zero);
+
+		if validator_reward_points.is_zero() {
+			return None
+		}
+
+		// This is the fraction of the total reward that the validator and the
+		// nominators will get.
+		let validator_total_reward_part =
+			Perbill::from_rational(validator_reward_points, total_reward_points);
+
+		// This is how much validator + nominators are entitled to.
+		let validator_total_payout = validator_total_reward_part * era_payout;
+
+		let validator_commission = Self::get_validator_commission(era, validator);
+		// total commission validator takes across all nominator pages
+		let validator_total_commission_payout = validator_commission * validator_total_payout;
+
+		let validator_leftover_payout =
+			validator_total_payout.defensive_saturating_sub(validator_total_commission_payout);
+		// Now let's calculate how this is split to the validator.
+		let validator_exposure_part = Perbill::from_rational(exposure.own(), exposure.total());
+		let validator_staking_payout = validator_exposure_part * validator_leftover_payout;
+		let page_stake_part = Perbill::from_rational(exposure.page_total(), exposure.total());
+		// validator commission is paid out in fraction across pages proportional to the page stake.
+		let validator_commission_payout = page_stake_part * validator_total_commission_payout;
+
+		Some((validator_staking_payout + validator_commission_payout, validator_leftover_payout))
+	}
+
 	/// Creates an entry to track validator reward has been claimed for a given era and page.
 	/// Noop if already claimed.
 	pub(crate) fn set_rewards_as_claimed(era: EraIndex, validator: &T::AccountId, page: Page) {
@@ -43,9 +43,9 @@ use sp_runtime::{
 use sp_staking::{
 	currency_to_vote::CurrencyToVote,
 	offence::{OffenceDetails, OnOffenceHandler},
-	EraIndex, OnStakingUpdate, Page, SessionIndex, Stake,
+	EraIndex, EraReward, OnStakingUpdate, Page, SessionIndex, Stake,
 	StakingAccount::{self, Controller, Stash},
-	StakingInterface,
+	StakingInterface, YieldEstimate,
 };
 
 use crate::{
@@ -310,43 +310,12 @@ impl<T: Config> Pallet<T> {
 
 		// Input data seems good, no errors allowed after this point
 
-		// Get Era reward points. It has TOTAL and INDIVIDUAL
-		// Find the fraction of the era reward that belongs to the validator
-		// Take that fraction of the eras rewards to split to nominator and validator
-		//
-		// Then look at the validator, figure out the proportion of their reward
-		// which goes to them and each of their nominators.
-
-		let era_reward_points = <ErasRewardPoints<T>>::get(&era);
-		let total_reward_points = era_reward_points.total;
-		let validator_reward_points =
-			era_reward_points.individual.get(&stash).copied().unwrap_or_else(Zero::zero);
-
-		// Nothing to do if they have no reward points.
-		if validator_reward_points.is_zero() {
+		let Some((validator_payout, validator_leftover_payout)) =
+			EraInfo::<T>::page_payout(era, era_payout, &stash, &exposure)
+		else {
+			// Nothing to do if they have no reward points.
 			return Ok(Some(T::WeightInfo::payout_stakers_alive_staked(0)).into())
-		}
-
-		// This is the fraction of the total reward that the validator and the
-		// nominators will get.
-		let validator_total_reward_part =
-			Perbill::from_rational(validator_reward_points, total_reward_points);
-
-		// This is how much validator + nominators are entitled to.
-		let validator_total_payout = validator_total_reward_part * era_payout;
-
-		let validator_commission = EraInfo::<T>::get_validator_commission(era, &ledger.stash);
-		// total commission validator takes across all nominator pages
-		let validator_total_commission_payout = validator_commission * validator_total_payout;
-
-		let validator_leftover_payout =
-			validator_total_payout.defensive_saturating_sub(validator_total_commission_payout);
-		// Now let's calculate how this is split to the validator.
-		let validator_exposure_part = Perbill::from_rational(exposure.own(), exposure.total());
-		let validator_staking_payout = validator_exposure_part * validator_leftover_payout;
-		let page_stake_part = Perbill::from_rational(exposure.page_total(), exposure.total());
-		// validator commission is paid out in fraction across pages proportional to the page stake.
-		let validator_commission_payout = page_stake_part * validator_total_commission_payout;
+		};
 
 		Self::deposit_event(Event::<T>::PayoutStarted {
 			era_index: era,
@@ -357,9 +326,7 @@ impl<T: Config> Pallet<T> {
 
 		let mut total_imbalance = PositiveImbalanceOf::<T>::zero();
 		// We can now make total validator payout:
-		if let Some((imbalance, dest)) =
-			Self::make_payout(&stash, validator_staking_payout + validator_commission_payout)
-		{
+		if let Some((imbalance, dest)) = Self::make_payout(&stash, validator_payout) {
 			Self::deposit_event(Event::<T>::Rewarded { stash, dest, amount: imbalance.peek() });
 			total_imbalance.subsume(imbalance);
 		}
@@ -578,15 +545,7 @@ impl<T: Config> Pallet<T> {
 			let issuance = asset::total_issuance::<T>();
 
 			let (validator_payout, remainder) =
-				T::EraPayout::era_payout(staked, issuance, era_duration);
-
-			let total_payout = validator_payout.saturating_add(remainder);
-			let max_staked_rewards =
-				MaxStakedRewards::<T>::get().unwrap_or(Percent::from_percent(100));
-
-			// apply cap to validators payout and add difference to remainder.
-			let validator_payout = validator_payout.min(max_staked_rewards * total_payout);
-			let remainder = total_payout.saturating_sub(validator_payout);
+				Self::capped_era_payout(staked, issuance, era_duration);
 
 			Self::deposit_event(Event::<T>::EraPaid {
 				era_index: active_era.index,
@@ -600,6 +559,24 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Compute the payout to stakers and the remainder for an era of `era_duration` milliseconds,
+	/// with the stakers payout capped by [`MaxStakedRewards`].
+	pub(crate) fn capped_era_payout(
+		staked: BalanceOf<T>,
+		issuance: BalanceOf<T>,
+		era_duration: u64,
+	) -> (BalanceOf<T>, BalanceOf<T>) {
+		let (validator_payout, remainder) =
+			T::EraPayout::era_payout(staked, issuance, era_duration);
+
+		let total_payout = validator_payout.saturating_add(remainder);
+		let max_staked_rewards = MaxStakedRewards::<T>::get().unwrap_or(Percent::from_percent(100));
+
+		// apply cap to validators payout and add difference to remainder.
+		let validator_payout = validator_payout.min(max_staked_rewards * total_payout);
+		(validator_payout, total_payout.saturating_sub(validator_payout))
+	}
+
 	/// Plan a new era.
 	///
 	/// * Bump the current era storage (which holds the latest planned era).
@@ -1400,6 +1377,124 @@ impl<T: Config> Pallet<T> {
 	pub fn api_pending_rewards(era: EraIndex, account: T::AccountId) -> bool {
 		EraInfo::<T>::pending_rewards(era, &account)
 	}
+
+	pub fn api_era_rewards(
+		era: EraIndex,
+		stash: T::AccountId,
+	) -> Vec<EraReward<T::AccountId, BalanceOf<T>>> {
+		let Some(current_era) = CurrentEra::<T>::get() else { return Vec::new() };
+		if era > current_era || era < current_era.saturating_sub(T::HistoryDepth::get()) {
+			return Vec::new()
+		}
+		// eras that have not been paid yet have nothing to show.
+		let Some(era_payout) = ErasValidatorReward::<T>::get(era) else { return Vec::new() };
+
+		// validators are only exposed to themselves, no need to look through everyone else.
+		if ErasValidatorPrefs::<T>::contains_key(era, &stash) {
+			return Self::era_rewards_from(era, era_payout, &stash, &stash)
+		}
+		ErasValidatorPrefs::<T>::iter_key_prefix(era)
+			.flat_map(|validator| Self::era_rewards_from(era, era_payout, &validator, &stash))
+			.collect()
+	}
+
+	/// The rewards of `stash` in `era` through its exposure to `validator`, one entry per page.
+	fn era_rewards_from(
+		era: EraIndex,
+		era_payout: BalanceOf<T>,
+		validator: &T::AccountId,
+		stash: &T::AccountId,
+	) -> Vec<EraReward<T::AccountId, BalanceOf<T>>> {
+		let mut rewards = Vec::new();
+		// a validator backed by its own stake only has no exposure pages, yet it is paid out
+		// in page 0 and `get_page_count` accounts for it.
+		let page_count = EraInfo::<T>::get_page_count(era, validator);
+		if page_count.is_zero() {
+			return rewards
+		}
+
+		let legacy_claimed =
+			Ledger::<T>::get(validator).is_some_and(|l| l.legacy_claimed_rewards.contains(&era));
+		let claimed_pages = ClaimedRewards::<T>::get(era, validator);
+		let commission = EraInfo::<T>::get_validator_commission(era, validator);
+
+		for page in 0..page_count {
+			let Some(exposure) = EraInfo::<T>::get_paged_exposure(era, validator, page) else {
+				continue
+			};
+			let payout = EraInfo::<T>::page_payout(era, era_payout, validator, &exposure);
+
+			let (stake, reward) = if validator == stash {
+				let validator_payout = payout.map(|(p, _)| p).unwrap_or_else(Zero::zero);
+				(exposure.own(), validator_payout)
+			} else if let Some(nominator) = exposure.others().iter().find(|n| &n.who == stash) {
+				let nominator_reward = payout
+					.map(|(_, leftover)| {
+						Perbill::from_rational(nominator.value, exposure.total()) * leftover
+					})
+					.unwrap_or_else(Zero::zero);
+				(nominator.value, nominator_reward)
+			} else {
+				continue
+			};
+
+			rewards.push(EraReward {
+				era,
+				validator: validator.clone(),
+				commission,
+				page,
+				stake,
+				reward,
+				claimed: legacy_claimed || claimed_pages.contains(&page),
+			});
+		}
+
+		rewards
+	}
+
+	pub fn api_estimate_yield(
+		stake: BalanceOf<T>,
+		targets: Vec<T::AccountId>,
+	) -> YieldEstimate<BalanceOf<T>> {
+		let mut estimate = YieldEstimate { yearly_reward: Zero::zero(), apy: Perbill::zero() };
+		let Some(active_era) = ActiveEra::<T>::get() else { return estimate };
+		if stake.is_zero() || targets.is_empty() {
+			return estimate
+		}
+
+		let staked = ErasTotalStake::<T>::get(active_era.index);
+		let (yearly_payout, _) = Self::capped_era_payout(
+			staked,
+			asset::total_issuance::<T>(),
+			crate::inflation::MILLISECONDS_PER_YEAR,
+		);
+
+		// reward points are assumed to be evenly spread across the active validators.
+		let validator_count = ErasValidatorPrefs::<T>::iter_key_prefix(active_era.index).count();
+		if validator_count.is_zero() {
+			return estimate
+		}
+		let validator_payout = yearly_payout / (validator_count as u32).into();
+		let stake_per_target = stake / (targets.len() as u32).into();
+
+		for target in targets {
+			// targets that are not active in this era would not earn anything.
+			let Some(overview) = ErasStakersOverview::<T>::get(active_era.index, &target) else {
+				continue
+			};
+			let commission = EraInfo::<T>::get_validator_commission(active_era.index, &target);
+			let leftover_payout = Perbill::one().saturating_sub(commission) * validator_payout;
+			let exposure_part = Perbill::from_rational(
+				stake_per_target,
+				overview.total.saturating_add(stake_per_target),
+			);
+			estimate.yearly_reward =
+				estimate.yearly_reward.saturating_add(exposure_part * leftover_payout);
+		}
+
+		estimate.apy = Perbill::from_rational(estimate.yearly_reward, stake);
+		estimate
+	}
 }
 
 impl<T: Config> ElectionDataProvider for Pallet<T> {
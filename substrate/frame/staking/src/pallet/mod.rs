@@ -2398,6 +2398,24 @@ pub mod pallet {
 			Ok(())
 		}
 	}
+
+	#[pallet::view_functions]
+	impl<T: Config> Pallet<T> {
+		/// Whether validator `stash` has rewards from `era` which are yet to be paid out.
+		pub fn pending_rewards(era: EraIndex, stash: T::AccountId) -> bool {
+			crate::EraInfo::<T>::pending_rewards(era, &stash)
+		}
+
+		/// The eras, still within `HistoryDepth`, in which validator `stash` has rewards which are
+		/// yet to be paid out.
+		pub fn unclaimed_eras(stash: T::AccountId) -> Vec<EraIndex> {
+			let Some(current_era) = CurrentEra::<T>::get() else { return Vec::new() };
+			(current_era.saturating_sub(T::HistoryDepth::get())..=current_era)
+				.filter(|era| ErasValidatorReward::<T>::contains_key(era))
+				.filter(|era| crate::EraInfo::<T>::pending_rewards(*era, &stash))
+				.collect()
+		}
+	}
 }
 
 /// Check that list is sorted and has no duplicates.
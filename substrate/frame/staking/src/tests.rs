@@ -6664,6 +6664,128 @@ fn test_runtime_api_pending_rewards() {
 	});
 }
 
+#[test]
+fn unclaimed_eras_view_function_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(1);
+		mock::start_active_era(2);
+
+		// Eras 0 and 1 are pending, while the active era 2 has no reward to pay out yet.
+		assert!(Staking::pending_rewards(0, 11));
+		assert_eq!(Staking::unclaimed_eras(11), vec![0, 1]);
+
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 0));
+		assert!(!Staking::pending_rewards(0, 11));
+		assert_eq!(Staking::unclaimed_eras(11), vec![1]);
+
+		// Nominators are paid out through their validators.
+		assert_eq!(Staking::unclaimed_eras(101), vec![]);
+	});
+}
+
+#[test]
+fn reward_history_api_works() {
+	ExtBuilder::default().nominate(true).build_and_execute(|| {
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		let total_payout_0 = current_total_payout_for_duration(reward_time_per_era());
+		mock::start_active_era(1);
+
+		// 101 backs both validators, but only 11 earned points in era 0.
+		let rewards = Staking::api_era_rewards(0, 101);
+		assert_eq!(rewards.len(), 2);
+		let from_11 = rewards.iter().find(|r| r.validator == 11).unwrap();
+		assert_eq!((from_11.era, from_11.page, from_11.stake), (0, 0, 125));
+		assert_eq!(from_11.reward, Perbill::from_rational::<u32>(125, 1125) * total_payout_0);
+		assert!(!from_11.claimed);
+		let from_21 = rewards.iter().find(|r| r.validator == 21).unwrap();
+		assert_eq!((from_21.stake, from_21.reward), (375, 0));
+
+		let rewards = Staking::api_era_rewards(0, 11);
+		assert_eq!(rewards.len(), 1);
+		assert_eq!(rewards[0].stake, 1000);
+		assert_eq!(rewards[0].reward, Perbill::from_rational::<u32>(1000, 1125) * total_payout_0);
+
+		// the active era is not paid out yet, hence has no rewards.
+		assert!(Staking::api_era_rewards(1, 11).is_empty());
+
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 0));
+		assert!(Staking::api_era_rewards(0, 101).iter().any(|r| r.validator == 11 && r.claimed));
+		assert!(Staking::api_era_rewards(0, 101).iter().any(|r| r.validator == 21 && !r.claimed));
+
+		// stashes that never staked have no rewards.
+		assert!(Staking::api_era_rewards(0, 1337).is_empty());
+	});
+}
+
+#[test]
+fn era_rewards_api_covers_validators_without_nominators() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		let total_payout_0 = current_total_payout_for_duration(reward_time_per_era());
+		mock::start_active_era(1);
+
+		// 11 is exposed with its own stake only, which has no exposure pages.
+		assert_eq!(ErasStakersOverview::<Test>::get(0, 11).unwrap().page_count, 0);
+
+		let rewards = Staking::api_era_rewards(0, 11);
+		assert_eq!(rewards.len(), 1);
+		assert_eq!((rewards[0].page, rewards[0].stake), (0, 1000));
+		assert_eq!(rewards[0].reward, total_payout_0);
+	});
+}
+
+#[test]
+fn era_rewards_api_is_bounded_by_history_depth() {
+	ExtBuilder::default().nominate(true).build_and_execute(|| {
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(1);
+		assert!(!Staking::api_era_rewards(0, 101).is_empty());
+
+		mock::start_active_era(HistoryDepth::get() + 1);
+		assert!(Staking::api_era_rewards(0, 101).is_empty());
+		// eras in the future have no rewards either.
+		assert!(Staking::api_era_rewards(HistoryDepth::get() + 2, 101).is_empty());
+	});
+}
+
+#[test]
+fn estimate_yield_api_works() {
+	ExtBuilder::default().nominate(true).build_and_execute(|| {
+		mock::start_active_era(1);
+
+		// nothing to earn without stake, targets, or with inactive targets.
+		assert_eq!(Staking::api_estimate_yield(0, vec![11]).yearly_reward, 0);
+		assert_eq!(Staking::api_estimate_yield(1000, vec![]).yearly_reward, 0);
+		assert_eq!(Staking::api_estimate_yield(1000, vec![31]).yearly_reward, 0);
+
+		let (yearly_payout, _) = Staking::capped_era_payout(
+			ErasTotalStake::<Test>::get(1),
+			asset::total_issuance::<Test>(),
+			crate::inflation::MILLISECONDS_PER_YEAR,
+		);
+		let total_11 = ErasStakersOverview::<Test>::get(1, 11).unwrap().total;
+
+		let estimate = Staking::api_estimate_yield(1000, vec![11]);
+		assert_eq!(
+			estimate.yearly_reward,
+			Perbill::from_rational(1000, total_11 + 1000) * (yearly_payout / 2)
+		);
+		assert_eq!(estimate.apy, Perbill::from_rational(estimate.yearly_reward, 1000));
+
+		// commission is taken off the projected reward.
+		ErasValidatorPrefs::<Test>::insert(
+			1,
+			11,
+			ValidatorPrefs { commission: Perbill::from_percent(50), ..Default::default() },
+		);
+		assert_eq!(
+			Staking::api_estimate_yield(1000, vec![11]).yearly_reward,
+			Perbill::from_rational(1000, total_11 + 1000) *
+				(Perbill::from_percent(50) * (yearly_payout / 2))
+		);
+	});
+}
+
 mod staking_interface {
 	use frame_support::storage::with_storage_layer;
 	use sp_staking::StakingInterface;
@@ -350,6 +350,198 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// This benchmark is short-circuited if `SpendOrigin` cannot provide
+	/// a successful origin, in which case `spend_recurring` is un-callable and can use weight=0.
+	#[benchmark]
+	fn spend_recurring() -> Result<(), BenchmarkError> {
+		let origin =
+			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+			create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		let period: BlockNumberFor<T, I> = 1u32.into();
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			Box::new(asset_kind.clone()),
+			amount,
+			Box::new(beneficiary_lookup),
+			None,
+			period,
+			RecurringSpendEnd::Instalments(1),
+		);
+
+		let valid_from = T::BlockNumberProvider::current_block_number();
+		assert_last_event::<T, I>(
+			Event::RecurringSpendApproved {
+				index: 0,
+				asset_kind,
+				amount,
+				beneficiary,
+				valid_from,
+				period,
+				instalments: 1,
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn payout_recurring() -> Result<(), BenchmarkError> {
+		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+			create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+
+		let spend_exists = if let Ok(origin) = T::SpendOrigin::try_successful_origin() {
+			Treasury::<T, _>::spend_recurring(
+				origin,
+				Box::new(asset_kind.clone()),
+				amount,
+				Box::new(beneficiary_lookup),
+				None,
+				1u32.into(),
+				RecurringSpendEnd::Instalments(1),
+			)?;
+
+			true
+		} else {
+			false
+		};
+
+		T::Paymaster::ensure_successful(&beneficiary, asset_kind, amount);
+		let caller: T::AccountId = account("caller", 0, SEED);
+
+		#[block]
+		{
+			let res =
+				Treasury::<T, _>::payout_recurring(RawOrigin::Signed(caller.clone()).into(), 0u32);
+
+			if spend_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		if spend_exists {
+			let id = match RecurringSpends::<T, I>::get(0).unwrap().status {
+				PaymentState::Attempted { id, .. } => {
+					assert_ne!(T::Paymaster::check_payment(id), PaymentStatus::Failure);
+					id
+				},
+				_ => panic!("No payout attempt made"),
+			};
+			assert_last_event::<T, I>(
+				Event::RecurringSpendPaid { index: 0, instalments: 1, payment_id: id }.into(),
+			);
+			assert!(
+				Treasury::<T, _>::payout_recurring(RawOrigin::Signed(caller).into(), 0u32).is_err()
+			);
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn check_recurring_status() -> Result<(), BenchmarkError> {
+		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+			create_spend_arguments::<T, _>(SEED);
+
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		T::Paymaster::ensure_successful(&beneficiary, asset_kind.clone(), amount);
+		let caller: T::AccountId = account("caller", 0, SEED);
+
+		let spend_exists = if let Ok(origin) = T::SpendOrigin::try_successful_origin() {
+			Treasury::<T, _>::spend_recurring(
+				origin,
+				Box::new(asset_kind),
+				amount,
+				Box::new(beneficiary_lookup),
+				None,
+				1u32.into(),
+				RecurringSpendEnd::Instalments(1),
+			)?;
+
+			Treasury::<T, _>::payout_recurring(RawOrigin::Signed(caller.clone()).into(), 0u32)?;
+			match RecurringSpends::<T, I>::get(0).unwrap().status {
+				PaymentState::Attempted { id, .. } => {
+					T::Paymaster::ensure_concluded(id);
+				},
+				_ => panic!("No payout attempt made"),
+			};
+
+			true
+		} else {
+			false
+		};
+
+		#[block]
+		{
+			let res = Treasury::<T, _>::check_recurring_status(
+				RawOrigin::Signed(caller.clone()).into(),
+				0u32,
+			);
+
+			if spend_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		if let Some(s) = RecurringSpends::<T, I>::get(0) {
+			assert!(!matches!(s.status, PaymentState::Attempted { .. }));
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_recurring_spend() -> Result<(), BenchmarkError> {
+		let (asset_kind, amount, _, beneficiary_lookup) = create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		let spend_exists = if let Ok(origin) = T::SpendOrigin::try_successful_origin() {
+			Treasury::<T, _>::spend_recurring(
+				origin,
+				Box::new(asset_kind.clone()),
+				amount,
+				Box::new(beneficiary_lookup),
+				None,
+				1u32.into(),
+				RecurringSpendEnd::Instalments(1),
+			)?;
+			assert!(RecurringSpends::<T, I>::get(0).is_some());
+
+			true
+		} else {
+			false
+		};
+
+		// Cancelling as `SpendOrigin` converts the spend to the native asset, which is the worst
+		// case.
+		let origin = match T::SpendOrigin::try_successful_origin() {
+			Ok(origin) => origin,
+			Err(_) =>
+				T::RejectOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+		};
+
+		#[block]
+		{
+			let res = Treasury::<T, _>::cancel_recurring_spend(origin as T::RuntimeOrigin, 0u32);
+
+			if spend_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		assert!(RecurringSpends::<T, I>::get(0).is_none());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Treasury,
 		crate::tests::ExtBuilder::default().build(),
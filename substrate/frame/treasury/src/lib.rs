@@ -84,14 +84,14 @@ pub use benchmarking::ArgumentsFactory;
 
 extern crate alloc;
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
 use alloc::{boxed::Box, collections::btree_map::BTreeMap};
 use sp_runtime::{
 	traits::{
-		AccountIdConversion, BlockNumberProvider, CheckedAdd, One, Saturating, StaticLookup,
-		UniqueSaturatedInto, Zero,
+		AccountIdConversion, AtLeast32BitUnsigned, BlockNumberProvider, CheckedAdd, One,
+		Saturating, StaticLookup, UniqueSaturatedInto, Zero,
 	},
 	PerThing, Permill, RuntimeDebug,
 };
@@ -124,6 +124,13 @@ type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup
 type BeneficiaryLookupOf<T, I> = <<T as Config<I>>::BeneficiaryLookup as StaticLookup>::Source;
 pub type BlockNumberFor<T, I = ()> =
 	<<T as Config<I>>::BlockNumberProvider as BlockNumberProvider>::BlockNumber;
+pub type RecurringSpendStatusOf<T, I = ()> = RecurringSpendStatus<
+	<T as Config<I>>::AssetKind,
+	AssetBalanceOf<T, I>,
+	<T as Config<I>>::Beneficiary,
+	BlockNumberFor<T, I>,
+	<<T as Config<I>>::Paymaster as Pay>::Id,
+>;
 
 /// A trait to allow the Treasury Pallet to spend it's funds for other purposes.
 /// There is an expectation that the implementer of this trait will correctly manage
@@ -179,7 +186,7 @@ pub enum PaymentState<Id> {
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
 pub struct SpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, PaymentId> {
-	// The kind of asset to be spent.
+	/// The kind of asset to be spent.
 	asset_kind: AssetKind,
 	/// The asset amount of the spend.
 	amount: AssetBalance,
@@ -193,6 +200,80 @@ pub struct SpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, Paymen
 	status: PaymentState<PaymentId>,
 }
 
+/// When a recurring treasury spend stops paying out.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+)]
+pub enum RecurringSpendEnd<BlockNumber> {
+	/// After the given number of instalments.
+	Instalments(u32),
+	/// With the last instalment which becomes claimable before the given block number.
+	At(BlockNumber),
+}
+
+/// Info regarding an approved recurring treasury spend.
+///
+/// Instalment `i` becomes claimable at `valid_from + i * period` and has to be claimed within
+/// [`Config::PayoutPeriod`] from then, after which it is forfeited.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct RecurringSpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, PaymentId> {
+	/// The kind of asset to be spent.
+	asset_kind: AssetKind,
+	/// The asset amount paid out per instalment.
+	amount: AssetBalance,
+	/// The beneficiary of the spend.
+	beneficiary: Beneficiary,
+	/// The block number from which the first instalment can be claimed.
+	valid_from: BlockNumber,
+	/// The number of blocks between successive instalments.
+	period: BlockNumber,
+	/// The total number of instalments.
+	instalments: u32,
+	/// The first instalment which has been neither paid out nor forfeited.
+	next: u32,
+	/// The number of instalments covered by the payment attempted in `status`.
+	paying: u32,
+	/// The status of the latest payout/claim.
+	status: PaymentState<PaymentId>,
+}
+
+impl<AssetKind, AssetBalance, Beneficiary, BlockNumber, PaymentId>
+	RecurringSpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, PaymentId>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// The number of instalments which became claimable at or before `now`.
+	fn due_by(&self, now: BlockNumber) -> u32 {
+		if now < self.valid_from {
+			return 0
+		}
+		let due: u32 = ((now - self.valid_from) / self.period).unique_saturated_into();
+		due.saturating_add(1).min(self.instalments)
+	}
+
+	/// The range of instalments which could be claimed at `now`, skipping over any which have
+	/// already been paid out or forfeited.
+	fn claimable(&self, now: BlockNumber, payout_period: BlockNumber) -> (u32, u32) {
+		let forfeited = if now < self.valid_from.saturating_add(payout_period) {
+			0
+		} else {
+			self.due_by(now - payout_period)
+		};
+		let first = self.next.max(forfeited);
+		(first, self.due_by(now).saturating_sub(first))
+	}
+}
+
 /// Index of an approved treasury spend.
 pub type SpendIndex = u32;
 
@@ -352,6 +433,14 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Recurring spends that have been approved and have instalments left to process.
+	///
+	/// Recurring spends share their index space, [`SpendCount`], with [`Spends`].
+	// Hasher: Twox safe since `SpendIndex` is an internal count based index.
+	#[pallet::storage]
+	pub type RecurringSpends<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, SpendIndex, RecurringSpendStatusOf<T, I>, OptionQuery>;
+
 	/// The blocknumber for the last triggered spend period.
 	#[pallet::storage]
 	pub type LastSpendPeriod<T, I = ()> = StorageValue<_, BlockNumberFor<T, I>, OptionQuery>;
@@ -414,6 +503,24 @@ pub mod pallet {
 		/// A spend was processed and removed from the storage. It might have been successfully
 		/// paid or it may have expired.
 		SpendProcessed { index: SpendIndex },
+		/// A new recurring asset spend has been approved.
+		RecurringSpendApproved {
+			index: SpendIndex,
+			asset_kind: T::AssetKind,
+			amount: AssetBalanceOf<T, I>,
+			beneficiary: T::Beneficiary,
+			valid_from: BlockNumberFor<T, I>,
+			period: BlockNumberFor<T, I>,
+			instalments: u32,
+		},
+		/// Some instalments of a recurring spend have been paid out in one payment.
+		RecurringSpendPaid {
+			index: SpendIndex,
+			instalments: u32,
+			payment_id: <T::Paymaster as Pay>::Id,
+		},
+		/// The remaining instalments of a recurring spend were cancelled.
+		RecurringSpendCancelled { index: SpendIndex },
 	}
 
 	/// Error for the treasury pallet.
@@ -442,6 +549,8 @@ pub mod pallet {
 		NotAttempted,
 		/// The payment has neither failed nor succeeded yet.
 		Inconclusive,
+		/// The recurring spend has a zero period or no instalments.
+		InvalidSchedule,
 	}
 
 	#[pallet::hooks]
@@ -496,6 +605,34 @@ pub mod pallet {
 		spend_in_context: BTreeMap<Balance, Balance>,
 	}
 
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Account `native_amount` against what origins with a spend limit of `max_amount` have
+		/// already approved in the current dispatch context.
+		fn note_spend_in_context(
+			max_amount: BalanceOf<T, I>,
+			native_amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
+				let context = v.or_default();
+				// We group based on `max_amount`, to distinguish between different kind of
+				// origins. (assumes that all origins have different `max_amount`)
+				//
+				// Worst case is that we reject some "valid" request.
+				let spend = context.spend_in_context.entry(max_amount).or_default();
+
+				// Ensure that we don't overflow nor use more than `max_amount`
+				if spend.checked_add(&native_amount).map(|s| s > max_amount).unwrap_or(true) {
+					Err(Error::<T, I>::InsufficientPermission)
+				} else {
+					*spend = spend.saturating_add(native_amount);
+					Ok(())
+				}
+			})
+			.unwrap_or(Ok(()))
+			.map_err(Into::into)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Propose and approve a spend of treasury funds.
@@ -663,25 +800,7 @@ pub mod pallet {
 				T::BalanceConverter::from_asset_balance(amount, *asset_kind.clone())
 					.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
 
-			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
-
-			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
-				let context = v.or_default();
-				// We group based on `max_amount`, to distinguish between different kind of
-				// origins. (assumes that all origins have different `max_amount`)
-				//
-				// Worst case is that we reject some "valid" request.
-				let spend = context.spend_in_context.entry(max_amount).or_default();
-
-				// Ensure that we don't overflow nor use more than `max_amount`
-				if spend.checked_add(&native_amount).map(|s| s > max_amount).unwrap_or(true) {
-					Err(Error::<T, I>::InsufficientPermission)
-				} else {
-					*spend = spend.saturating_add(native_amount);
-					Ok(())
-				}
-			})
-			.unwrap_or(Ok(()))?;
+			Self::note_spend_in_context(max_amount, native_amount)?;
 
 			let index = SpendCount::<T, I>::get();
 			Spends::<T, I>::insert(
@@ -839,6 +958,298 @@ pub mod pallet {
 			Self::deposit_event(Event::<T, I>::AssetSpendVoided { index });
 			Ok(())
 		}
+
+		/// Propose and approve a spend of treasury funds paid out in instalments.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::SpendOrigin`] with the `Success` value being at least the total of
+		/// all instalments of `asset_kind` in the native asset. The amount of `asset_kind` is
+		/// converted for assertion using the [`Config::BalanceConverter`].
+		///
+		/// ## Details
+		///
+		/// Create an approved spend of `amount` of `asset_kind` to a designated beneficiary every
+		/// `period` blocks. Each instalment must be claimed using the `payout_recurring`
+		/// dispatchable within the [`Config::PayoutPeriod`] from becoming claimable, after which
+		/// it is forfeited.
+		///
+		/// ### Parameters
+		/// - `asset_kind`: An indicator of the specific asset class to be spent.
+		/// - `amount`: The amount to be transferred to the `beneficiary` per instalment.
+		/// - `beneficiary`: The beneficiary of the spend.
+		/// - `valid_from`: The block number from which the first instalment can be claimed. If
+		///   `None`, it can be claimed immediately after approval.
+		/// - `period`: The number of blocks between successive instalments.
+		/// - `end`: The number of instalments, or the block number before which the last instalment
+		///   becomes claimable.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::RecurringSpendApproved`] if successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::spend_recurring())]
+		pub fn spend_recurring(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+			#[pallet::compact] amount: AssetBalanceOf<T, I>,
+			beneficiary: Box<BeneficiaryLookupOf<T, I>>,
+			valid_from: Option<BlockNumberFor<T, I>>,
+			period: BlockNumberFor<T, I>,
+			end: RecurringSpendEnd<BlockNumberFor<T, I>>,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let beneficiary = T::BeneficiaryLookup::lookup(*beneficiary)?;
+			ensure!(!period.is_zero(), Error::<T, I>::InvalidSchedule);
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let valid_from = valid_from.unwrap_or(now);
+			let instalments = match end {
+				RecurringSpendEnd::Instalments(n) => n,
+				RecurringSpendEnd::At(end) if end > valid_from => {
+					let n: u32 = ((end - valid_from - One::one()) / period).unique_saturated_into();
+					n.saturating_add(1)
+				},
+				RecurringSpendEnd::At(_) => 0,
+			};
+			ensure!(instalments > 0, Error::<T, I>::InvalidSchedule);
+
+			let spend = RecurringSpendStatus {
+				asset_kind: *asset_kind.clone(),
+				amount,
+				beneficiary: beneficiary.clone(),
+				valid_from,
+				period,
+				instalments,
+				next: 0,
+				paying: 0,
+				status: PaymentState::Pending,
+			};
+			let (first, _) = spend.claimable(now, T::PayoutPeriod::get());
+			ensure!(first < instalments, Error::<T, I>::SpendExpired);
+
+			let total = amount.saturating_mul(instalments.into());
+			let native_amount = T::BalanceConverter::from_asset_balance(total, *asset_kind.clone())
+				.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
+			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
+			Self::note_spend_in_context(max_amount, native_amount)?;
+
+			let index = SpendCount::<T, I>::get();
+			RecurringSpends::<T, I>::insert(index, spend);
+			SpendCount::<T, I>::put(index + 1);
+
+			Self::deposit_event(Event::RecurringSpendApproved {
+				index,
+				asset_kind: *asset_kind,
+				amount,
+				beneficiary,
+				valid_from,
+				period,
+				instalments,
+			});
+			Ok(())
+		}
+
+		/// Claim the instalments of a recurring spend which are due.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be signed.
+		///
+		/// ## Details
+		///
+		/// All claimable instalments are paid out together in a single payment. As with `payout`,
+		/// the status of the payment must be updated with the `check_recurring_status`
+		/// dispatchable before any further instalments can be claimed or a failed payment
+		/// retried.
+		///
+		/// ### Parameters
+		/// - `index`: The recurring spend index.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::RecurringSpendPaid`] if successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::payout_recurring())]
+		pub fn payout_recurring(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut spend =
+				RecurringSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			ensure!(
+				matches!(spend.status, PaymentState::Pending | PaymentState::Failed),
+				Error::<T, I>::AlreadyAttempted
+			);
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let (first, count) = spend.claimable(now, T::PayoutPeriod::get());
+			ensure!(first < spend.instalments, Error::<T, I>::SpendExpired);
+			ensure!(count > 0, Error::<T, I>::EarlyPayout);
+
+			let amount = spend.amount.saturating_mul(count.into());
+			let id = T::Paymaster::pay(&spend.beneficiary, spend.asset_kind.clone(), amount)
+				.map_err(|_| Error::<T, I>::PayoutError)?;
+
+			spend.next = first.saturating_add(count);
+			spend.paying = count;
+			spend.status = PaymentState::Attempted { id };
+			RecurringSpends::<T, I>::insert(index, spend);
+
+			Self::deposit_event(Event::<T, I>::RecurringSpendPaid {
+				index,
+				instalments: count,
+				payment_id: id,
+			});
+			Ok(())
+		}
+
+		/// Check the status of the latest payment of a recurring spend, and remove the spend from
+		/// the storage once all of its instalments are processed.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be signed.
+		///
+		/// ## Details
+		///
+		/// The status check is a prerequisite for claiming further instalments, or retrying a
+		/// failed payout. Instalments covered by a failed payment can be claimed again unless
+		/// they have been forfeited in the meantime. If the spend has no instalments left, it is
+		/// removed from the storage and transaction fees are refunded.
+		///
+		/// ### Parameters
+		/// - `index`: The recurring spend index.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::PaymentFailed`] if the latest payout has failed.
+		/// Emits [`Event::SpendProcessed`] if the spend has no instalments left.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::check_recurring_status())]
+		pub fn check_recurring_status(
+			origin: OriginFor<T>,
+			index: SpendIndex,
+		) -> DispatchResultWithPostInfo {
+			use PaymentState as State;
+			use PaymentStatus as Status;
+
+			ensure_signed(origin)?;
+			let mut spend =
+				RecurringSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let now = T::BlockNumberProvider::current_block_number();
+
+			match spend.status {
+				State::Attempted { id } => match T::Paymaster::check_payment(id) {
+					Status::Failure => {
+						spend.next = spend.next.saturating_sub(spend.paying);
+						spend.paying = 0;
+						spend.status = State::Failed;
+						RecurringSpends::<T, I>::insert(index, spend);
+						Self::deposit_event(Event::<T, I>::PaymentFailed { index, payment_id: id });
+						return Ok(Pays::Yes.into())
+					},
+					Status::Success | Status::Unknown => {
+						spend.paying = 0;
+						spend.status = State::Pending;
+					},
+					Status::InProgress => return Err(Error::<T, I>::Inconclusive.into()),
+				},
+				_ => {
+					// nothing left to claim, whether paid out or forfeited.
+					let (first, _) = spend.claimable(now, T::PayoutPeriod::get());
+					if first < spend.instalments {
+						return Err(Error::<T, I>::NotAttempted.into())
+					}
+					spend.next = first;
+				},
+			}
+
+			if spend.next >= spend.instalments {
+				RecurringSpends::<T, I>::remove(index);
+				Self::deposit_event(Event::<T, I>::SpendProcessed { index });
+			} else {
+				RecurringSpends::<T, I>::insert(index, spend);
+			}
+			Ok(Pays::No.into())
+		}
+
+		/// Cancel the remaining instalments of a recurring spend.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::RejectOrigin`], or [`Config::SpendOrigin`] with the `Success` value
+		/// being at least the total of all instalments of the spend in the native asset, i.e. an
+		/// origin which could have approved it.
+		///
+		/// ## Details
+		///
+		/// A payment which is already in progress is not affected, and the spend is removed once
+		/// its status has been checked with `check_recurring_status`.
+		///
+		/// ### Parameters
+		/// - `index`: The recurring spend index.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::RecurringSpendCancelled`] if successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::cancel_recurring_spend())]
+		pub fn cancel_recurring_spend(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+			let maybe_max_amount = match T::SpendOrigin::try_origin(origin) {
+				Ok(max_amount) => Some(max_amount),
+				Err(origin) => {
+					T::RejectOrigin::ensure_origin(origin)?;
+					None
+				},
+			};
+			let mut spend =
+				RecurringSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+
+			if let Some(max_amount) = maybe_max_amount {
+				let total = spend.amount.saturating_mul(spend.instalments.into());
+				let native_amount =
+					T::BalanceConverter::from_asset_balance(total, spend.asset_kind.clone())
+						.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
+				ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
+			}
+
+			if matches!(spend.status, PaymentState::Attempted { .. }) {
+				spend.instalments = spend.next;
+				RecurringSpends::<T, I>::insert(index, spend);
+			} else {
+				RecurringSpends::<T, I>::remove(index);
+			}
+			Self::deposit_event(Event::<T, I>::RecurringSpendCancelled { index });
+			Ok(())
+		}
+	}
+
+	#[pallet::view_functions]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// The funds in the treasury pot which are available for spending.
+		pub fn available_funds() -> BalanceOf<T, I> {
+			Self::pot()
+		}
+
+		/// The status of the approved spend `index`, if it has not yet been cleaned up.
+		pub fn spend_status(
+			index: SpendIndex,
+		) -> Option<
+			SpendStatus<
+				T::AssetKind,
+				AssetBalanceOf<T, I>,
+				T::Beneficiary,
+				BlockNumberFor<T, I>,
+				<T::Paymaster as Pay>::Id,
+			>,
+		> {
+			Spends::<T, I>::get(index)
+		}
+
+		/// The status of the approved recurring spend `index`, if it has instalments left to
+		/// process.
+		pub fn recurring_spend_status(index: SpendIndex) -> Option<RecurringSpendStatusOf<T, I>> {
+			RecurringSpends::<T, I>::get(index)
+		}
 	}
 }
 
@@ -1050,6 +1461,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// [`SpendCount`].
 	/// 3. For each spend entry contained in [`Spends`] we should have spend.expire_at
 	/// > spend.valid_from.
+	/// 4. Each entry in [`RecurringSpends`] should be saved under a key strictly less than current
+	/// [`SpendCount`] and not used in [`Spends`].
+	/// 5. For each recurring spend entry we should have a non-zero period and no more instalments
+	/// processed than it has.
 	#[cfg(any(feature = "try-runtime", test))]
 	fn try_state_spends() -> Result<(), sp_runtime::TryRuntimeError> {
 		let current_spend_count = SpendCount::<T, I>::get();
@@ -1074,6 +1489,23 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})?;
 
+		RecurringSpends::<T, I>::iter().try_for_each(|(index, spend)| -> DispatchResult {
+			ensure!(
+				current_spend_count > index,
+				"`SpendCount` should by strictly greater than any SpendIndex used as a key for `RecurringSpends`."
+			);
+			ensure!(
+				!Spends::<T, I>::contains_key(index),
+				"A SpendIndex cannot be used for both a spend and a recurring spend."
+			);
+			ensure!(!spend.period.is_zero(), "Recurring spend must have a non-zero period.");
+			ensure!(
+				spend.next <= spend.instalments,
+				"Recurring spend cannot have processed more instalments than it has."
+			);
+			Ok(())
+		})?;
+
 		Ok(())
 	}
 }
@@ -1046,3 +1046,204 @@ fn multiple_spend_periods_work() {
 		assert_eq!(LastSpendPeriod::<Test>::get(), Some(8));
 	});
 }
+
+fn get_recurring_payment_id(i: SpendIndex) -> Option<u64> {
+	let spend = RecurringSpends::<Test, _>::get(i).expect("no recurring spend");
+	match spend.status {
+		PaymentState::Attempted { id } => Some(id),
+		_ => None,
+	}
+}
+
+#[test]
+fn recurring_spend_payout_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(<Test as Config>::PayoutPeriod::get(), 5);
+		System::set_block_number(1);
+
+		// approve three instalments of `2` coins of asset `1` to beneficiary `6`, every 10
+		// blocks, the first valid from now.
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(12),
+			Box::new(1),
+			2,
+			Box::new(6),
+			None,
+			10,
+			RecurringSpendEnd::Instalments(3),
+		));
+		System::assert_last_event(
+			Event::<Test, _>::RecurringSpendApproved {
+				index: 0,
+				asset_kind: 1,
+				amount: 2,
+				beneficiary: 6,
+				valid_from: 1,
+				period: 10,
+				instalments: 3,
+			}
+			.into(),
+		);
+		assert_eq!(SpendCount::<Test, _>::get(), 1);
+
+		// the first instalment is claimable straight away.
+		assert_ok!(Treasury::payout_recurring(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 2);
+		let payment_id = get_recurring_payment_id(0).expect("no payment attempt");
+		System::assert_last_event(
+			Event::<Test, _>::RecurringSpendPaid { index: 0, instalments: 1, payment_id }.into(),
+		);
+		// cannot claim again while the payment is in progress.
+		assert_noop!(
+			Treasury::payout_recurring(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::AlreadyAttempted
+		);
+		set_status(payment_id, PaymentStatus::Success);
+		assert_ok!(Treasury::check_recurring_status(RuntimeOrigin::signed(1), 0));
+		assert_noop!(
+			Treasury::payout_recurring(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::EarlyPayout
+		);
+
+		// the second instalment (valid from block 11) expired at block 16 and is forfeited.
+		System::set_block_number(21);
+		assert_ok!(Treasury::payout_recurring(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 4);
+		let payment_id = get_recurring_payment_id(0).expect("no payment attempt");
+		System::assert_last_event(
+			Event::<Test, _>::RecurringSpendPaid { index: 0, instalments: 1, payment_id }.into(),
+		);
+
+		// with all instalments processed the spend is removed.
+		set_status(payment_id, PaymentStatus::Success);
+		assert_ok!(Treasury::check_recurring_status(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::<Test, _>::SpendProcessed { index: 0 }.into());
+		assert_eq!(RecurringSpends::<Test, _>::get(0), None);
+	});
+}
+
+#[test]
+fn recurring_spend_claims_due_instalments_together() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		// instalments valid from blocks 5, 7, 9 and 11.
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::root(),
+			Box::new(1),
+			3,
+			Box::new(6),
+			Some(5),
+			2,
+			RecurringSpendEnd::At(12),
+		));
+		assert_eq!(Treasury::recurring_spend_status(0).unwrap().instalments, 4);
+
+		System::set_block_number(9);
+		assert_ok!(Treasury::payout_recurring(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 9);
+
+		// a failed payment makes its instalments claimable again.
+		let payment_id = get_recurring_payment_id(0).expect("no payment attempt");
+		set_status(payment_id, PaymentStatus::Failure);
+		unpay(6, 1, 9);
+		assert_ok!(Treasury::check_recurring_status(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::<Test, _>::PaymentFailed { index: 0, payment_id }.into());
+
+		// by block 11 the first instalment has expired.
+		System::set_block_number(11);
+		assert_ok!(Treasury::payout_recurring(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 9);
+		let payment_id = get_recurring_payment_id(0).expect("no payment attempt");
+		System::assert_last_event(
+			Event::<Test, _>::RecurringSpendPaid { index: 0, instalments: 3, payment_id }.into(),
+		);
+	});
+}
+
+#[test]
+fn spend_recurring_checks_schedule_and_permission() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let spend_recurring = |origin, period, end| {
+			Treasury::spend_recurring(origin, Box::new(1), 2, Box::new(6), None, period, end)
+		};
+
+		assert_noop!(
+			spend_recurring(RuntimeOrigin::signed(1), 10, RecurringSpendEnd::Instalments(1)),
+			BadOrigin
+		);
+		assert_noop!(
+			spend_recurring(RuntimeOrigin::root(), 0, RecurringSpendEnd::Instalments(1)),
+			Error::<Test, _>::InvalidSchedule
+		);
+		assert_noop!(
+			spend_recurring(RuntimeOrigin::root(), 10, RecurringSpendEnd::Instalments(0)),
+			Error::<Test, _>::InvalidSchedule
+		);
+		assert_noop!(
+			spend_recurring(RuntimeOrigin::root(), 10, RecurringSpendEnd::At(1)),
+			Error::<Test, _>::InvalidSchedule
+		);
+		// the total of all instalments counts against the origin's allowance, `2` per coin.
+		assert_ok!(spend_recurring(
+			RuntimeOrigin::signed(11),
+			10,
+			RecurringSpendEnd::Instalments(2)
+		));
+		assert_noop!(
+			spend_recurring(RuntimeOrigin::signed(11), 10, RecurringSpendEnd::Instalments(3)),
+			Error::<Test, _>::InsufficientPermission
+		);
+	});
+}
+
+#[test]
+fn cancel_recurring_spend_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(13),
+			Box::new(1),
+			2,
+			Box::new(6),
+			None,
+			10,
+			RecurringSpendEnd::Instalments(5),
+		));
+
+		assert_noop!(Treasury::cancel_recurring_spend(RuntimeOrigin::signed(1), 0), BadOrigin);
+		// an origin which could not have approved the spend cannot cancel it.
+		assert_noop!(
+			Treasury::cancel_recurring_spend(RuntimeOrigin::signed(11), 0),
+			Error::<Test, _>::InsufficientPermission
+		);
+
+		// a payment in progress is left to complete.
+		assert_ok!(Treasury::payout_recurring(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Treasury::cancel_recurring_spend(RuntimeOrigin::signed(13), 0));
+		System::assert_last_event(Event::<Test, _>::RecurringSpendCancelled { index: 0 }.into());
+		let payment_id = get_recurring_payment_id(0).expect("no payment attempt");
+		set_status(payment_id, PaymentStatus::Success);
+		assert_ok!(Treasury::check_recurring_status(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::<Test, _>::SpendProcessed { index: 0 }.into());
+		assert_eq!(paid(6, 1), 2);
+
+		// otherwise the spend is removed straight away.
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(13),
+			Box::new(1),
+			2,
+			Box::new(6),
+			None,
+			10,
+			RecurringSpendEnd::Instalments(5),
+		));
+		assert_ok!(Treasury::cancel_recurring_spend(RuntimeOrigin::root(), 1));
+		assert_eq!(RecurringSpends::<Test, _>::get(1), None);
+		assert_noop!(
+			Treasury::payout_recurring(RuntimeOrigin::signed(1), 1),
+			Error::<Test, _>::InvalidIndex
+		);
+	});
+}
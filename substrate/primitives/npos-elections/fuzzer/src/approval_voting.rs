@@ -0,0 +1,156 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Fuzzing which ensures that approval voting elects the candidates with the highest approval
+//! stake, and distributes the stake of each voter equally among its winners, as computed by a naive
+//! reference implementation.
+//!
+//! ## Running a single iteration
+//!
+//! Simply run the program without the `fuzzing` configuration to run a single iteration:
+//! `cargo run --bin approval_voting`.
+//!
+//! ## Running
+//!
+//! Run with `cargo hfuzz run approval_voting`.
+//!
+//! ## Debugging a panic
+//!
+//! Once a panic is found, it can be debugged with
+//! `cargo hfuzz run-debug approval_voting hfuzz_workspace/approval_voting/*.fuzz`.
+
+#[cfg(fuzzing)]
+use honggfuzz::fuzz;
+
+#[cfg(not(fuzzing))]
+use clap::Parser;
+
+mod common;
+use common::{generate_random_npos_inputs, to_range, AccountId};
+use rand::{self, SeedableRng};
+use sp_npos_elections::{approval_voting, ElectionResult, ExtendedBalance, VoteWeight};
+use sp_runtime::Perbill;
+use std::collections::BTreeMap;
+
+const MIN_CANDIDATES: usize = 50;
+const MAX_CANDIDATES: usize = 200;
+const MIN_VOTERS: usize = 100;
+const MAX_VOTERS: usize = 500;
+
+#[cfg(fuzzing)]
+fn main() {
+	loop {
+		fuzz!(|data: (usize, usize, u64)| {
+			let (candidate_count, voter_count, seed) = data;
+			iteration(candidate_count, voter_count, seed);
+		});
+	}
+}
+
+#[cfg(not(fuzzing))]
+#[derive(Debug, Parser)]
+#[command(author, version, about)]
+struct Opt {
+	/// How many candidates participate in this election
+	#[arg(short, long)]
+	candidates: Option<usize>,
+
+	/// How many voters participate in this election
+	#[arg(short, long)]
+	voters: Option<usize>,
+
+	/// Random seed to use in this election
+	#[arg(long)]
+	seed: Option<u64>,
+}
+
+#[cfg(not(fuzzing))]
+fn main() {
+	let opt = Opt::parse();
+	iteration(
+		opt.candidates.unwrap_or(MAX_CANDIDATES - 1),
+		opt.voters.unwrap_or(MAX_VOTERS - 1),
+		opt.seed.unwrap_or_default(),
+	);
+}
+
+/// The approval stake of each candidate.
+fn reference_approvals(
+	voters: &[(AccountId, VoteWeight, Vec<AccountId>)],
+) -> BTreeMap<AccountId, ExtendedBalance> {
+	let mut approvals = BTreeMap::new();
+	for (_, stake, votes) in voters {
+		for target in votes {
+			*approvals.entry(*target).or_default() += *stake as ExtendedBalance;
+		}
+	}
+	approvals
+}
+
+/// The backing of each winner, if each voter splits its stake equally among the winners it
+/// approves.
+fn reference_backing(
+	voters: &[(AccountId, VoteWeight, Vec<AccountId>)],
+	winners: &[AccountId],
+) -> BTreeMap<AccountId, ExtendedBalance> {
+	let mut backing = BTreeMap::new();
+	for (_, stake, votes) in voters {
+		let elected = votes.iter().filter(|t| winners.contains(t)).collect::<Vec<_>>();
+		if elected.is_empty() {
+			continue
+		}
+		let stake = *stake as ExtendedBalance;
+		let share = stake / elected.len() as ExtendedBalance;
+		let remainder = stake % elected.len() as ExtendedBalance;
+		for (index, target) in elected.iter().enumerate() {
+			let extra = if index == elected.len() - 1 { remainder } else { 0 };
+			*backing.entry(**target).or_default() += share + extra;
+		}
+	}
+	backing
+}
+
+fn iteration(mut candidate_count: usize, mut voter_count: usize, seed: u64) {
+	let rng = rand::rngs::SmallRng::seed_from_u64(seed);
+	candidate_count = to_range(candidate_count, MIN_CANDIDATES, MAX_CANDIDATES);
+	voter_count = to_range(voter_count, MIN_VOTERS, MAX_VOTERS);
+
+	let (rounds, candidates, voters) =
+		generate_random_npos_inputs(candidate_count, voter_count, rng);
+
+	let ElectionResult::<AccountId, Perbill> { winners, assignments } =
+		approval_voting(rounds, candidates, voters.clone()).expect("approval voting must succeed");
+
+	// the approval stakes of the winners must be the highest ones.
+	let approvals = reference_approvals(&voters);
+	let mut expected = approvals.values().copied().filter(|a| *a > 0).collect::<Vec<_>>();
+	expected.sort_by(|a, b| b.cmp(a));
+	expected.truncate(rounds);
+	let actual = winners.iter().map(|(w, _)| approvals[w]).collect::<Vec<_>>();
+	assert_eq!(actual, expected, "winners must have the highest approval stakes");
+
+	// the backing of the winners must match an equal split.
+	let winner_ids = winners.iter().map(|(w, _)| *w).collect::<Vec<_>>();
+	let backing = reference_backing(&voters, &winner_ids);
+	for (winner, backed) in &winners {
+		assert_eq!(backing.get(winner).copied().unwrap_or_default(), *backed);
+	}
+
+	// assignments only point to winners.
+	assert!(assignments
+		.iter()
+		.all(|a| a.distribution.iter().all(|(t, _)| winner_ids.contains(t))));
+}
@@ -0,0 +1,149 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Fuzzing which ensures that the fast greedy method elects the same winners, with the same
+//! backing, as a naive reference implementation.
+//!
+//! ## Running a single iteration
+//!
+//! Simply run the program without the `fuzzing` configuration to run a single iteration:
+//! `cargo run --bin fast_greedy`.
+//!
+//! ## Running
+//!
+//! Run with `cargo hfuzz run fast_greedy`.
+//!
+//! ## Debugging a panic
+//!
+//! Once a panic is found, it can be debugged with
+//! `cargo hfuzz run-debug fast_greedy hfuzz_workspace/fast_greedy/*.fuzz`.
+
+#[cfg(fuzzing)]
+use honggfuzz::fuzz;
+
+#[cfg(not(fuzzing))]
+use clap::Parser;
+
+mod common;
+use common::{generate_random_npos_inputs, to_range, AccountId};
+use rand::{self, SeedableRng};
+use sp_npos_elections::{fast_greedy, ElectionResult, ExtendedBalance, VoteWeight};
+use sp_runtime::Perbill;
+use std::collections::BTreeMap;
+
+const MIN_CANDIDATES: usize = 250;
+const MAX_CANDIDATES: usize = 1000;
+const MIN_VOTERS: usize = 500;
+const MAX_VOTERS: usize = 2500;
+
+#[cfg(fuzzing)]
+fn main() {
+	loop {
+		fuzz!(|data: (usize, usize, u64)| {
+			let (candidate_count, voter_count, seed) = data;
+			iteration(candidate_count, voter_count, seed);
+		});
+	}
+}
+
+#[cfg(not(fuzzing))]
+#[derive(Debug, Parser)]
+#[command(author, version, about)]
+struct Opt {
+	/// How many candidates participate in this election
+	#[arg(short, long)]
+	candidates: Option<usize>,
+
+	/// How many voters participate in this election
+	#[arg(short, long)]
+	voters: Option<usize>,
+
+	/// Random seed to use in this election
+	#[arg(long)]
+	seed: Option<u64>,
+}
+
+#[cfg(not(fuzzing))]
+fn main() {
+	let opt = Opt::parse();
+	iteration(
+		opt.candidates.unwrap_or(MAX_CANDIDATES - 1),
+		opt.voters.unwrap_or(MAX_VOTERS - 1),
+		opt.seed.unwrap_or_default(),
+	);
+}
+
+/// The winners of the fast greedy method, zipped with their backing.
+fn reference_fast_greedy(
+	to_elect: usize,
+	candidates: &[AccountId],
+	voters: &[(AccountId, VoteWeight, Vec<AccountId>)],
+) -> Vec<(AccountId, ExtendedBalance)> {
+	let mut support = BTreeMap::<AccountId, ExtendedBalance>::new();
+	for (_, stake, votes) in voters {
+		let share = *stake as ExtendedBalance / votes.len() as ExtendedBalance;
+		for target in votes {
+			*support.entry(*target).or_default() += share;
+		}
+	}
+
+	let mut ranked = candidates
+		.iter()
+		.enumerate()
+		.map(|(index, c)| (support.get(c).copied().unwrap_or_default(), index, *c))
+		.filter(|(s, _, _)| *s > 0)
+		.collect::<Vec<_>>();
+	// highest support first, then the order of the input.
+	ranked.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+	let winners = ranked.into_iter().take(to_elect).map(|(_, _, c)| c).collect::<Vec<_>>();
+
+	let mut backing = BTreeMap::<AccountId, ExtendedBalance>::new();
+	for (_, stake, votes) in voters {
+		let elected = votes.iter().filter(|t| winners.contains(t)).collect::<Vec<_>>();
+		if elected.is_empty() {
+			continue
+		}
+		let stake = *stake as ExtendedBalance;
+		let share = stake / elected.len() as ExtendedBalance;
+		let remainder = stake % elected.len() as ExtendedBalance;
+		for (index, target) in elected.iter().enumerate() {
+			let extra = if index == elected.len() - 1 { remainder } else { 0 };
+			*backing.entry(**target).or_default() += share + extra;
+		}
+	}
+
+	winners.into_iter().map(|w| (w, backing[&w])).collect()
+}
+
+fn iteration(mut candidate_count: usize, mut voter_count: usize, seed: u64) {
+	let rng = rand::rngs::SmallRng::seed_from_u64(seed);
+	candidate_count = to_range(candidate_count, MIN_CANDIDATES, MAX_CANDIDATES);
+	voter_count = to_range(voter_count, MIN_VOTERS, MAX_VOTERS);
+
+	let (rounds, candidates, voters) =
+		generate_random_npos_inputs(candidate_count, voter_count, rng);
+
+	let expected = reference_fast_greedy(rounds, &candidates, &voters);
+	let ElectionResult::<AccountId, Perbill> { winners, assignments } =
+		fast_greedy(rounds, candidates, voters, None).expect("fast greedy must succeed");
+
+	assert_eq!(winners, expected, "fast greedy must match the reference implementation");
+
+	// assignments only point to winners.
+	assert!(assignments
+		.iter()
+		.all(|a| a.distribution.iter().all(|(t, _)| winners.iter().any(|(w, _)| w == t))));
+}
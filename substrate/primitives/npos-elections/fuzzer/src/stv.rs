@@ -0,0 +1,203 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Fuzzing which ensures that the single transferable vote elects the same winners, with the same
+//! counts, as a naive reference implementation.
+//!
+//! ## Running a single iteration
+//!
+//! Simply run the program without the `fuzzing` configuration to run a single iteration:
+//! `cargo run --bin stv`.
+//!
+//! ## Running
+//!
+//! Run with `HFUZZ_RUN_ARGS="-t 10" cargo hfuzz run stv`.
+//!
+//! Note the environment variable: by default, `cargo hfuzz` shuts down each iteration after 1
+//! second of runtime. The number of rounds of STV is bounded by the number of candidates rather
+//! than the number of winners, thus iterations can take longer than that.
+//!
+//! ## Debugging a panic
+//!
+//! Once a panic is found, it can be debugged with
+//! `HFUZZ_RUN_ARGS="-t 10" cargo hfuzz run-debug stv hfuzz_workspace/stv/*.fuzz`.
+
+#[cfg(fuzzing)]
+use honggfuzz::fuzz;
+
+#[cfg(not(fuzzing))]
+use clap::Parser;
+
+mod common;
+use common::{generate_random_npos_inputs, to_range, AccountId};
+use rand::{self, seq::SliceRandom, SeedableRng};
+use sp_npos_elections::{droop_quota, stv, ElectionResult, ExtendedBalance, VoteWeight};
+use sp_runtime::{helpers_128bit::multiply_by_rational_with_rounding, Perbill, Rounding};
+
+const MIN_CANDIDATES: usize = 50;
+const MAX_CANDIDATES: usize = 200;
+const MIN_VOTERS: usize = 100;
+const MAX_VOTERS: usize = 500;
+
+#[cfg(fuzzing)]
+fn main() {
+	loop {
+		fuzz!(|data: (usize, usize, u64)| {
+			let (candidate_count, voter_count, seed) = data;
+			iteration(candidate_count, voter_count, seed);
+		});
+	}
+}
+
+#[cfg(not(fuzzing))]
+#[derive(Debug, Parser)]
+#[command(author, version, about)]
+struct Opt {
+	/// How many candidates participate in this election
+	#[arg(short, long)]
+	candidates: Option<usize>,
+
+	/// How many voters participate in this election
+	#[arg(short, long)]
+	voters: Option<usize>,
+
+	/// Random seed to use in this election
+	#[arg(long)]
+	seed: Option<u64>,
+}
+
+#[cfg(not(fuzzing))]
+fn main() {
+	let opt = Opt::parse();
+	iteration(
+		opt.candidates.unwrap_or(MAX_CANDIDATES - 1),
+		opt.voters.unwrap_or(MAX_VOTERS - 1),
+		opt.seed.unwrap_or_default(),
+	);
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Status {
+	Hopeful,
+	Elected,
+	Eliminated,
+}
+
+/// The winners of the single transferable vote, zipped with their count.
+///
+/// Candidates and preferences are handled by index, without any of the shared pointers used by the
+/// actual implementation.
+fn reference_stv(
+	to_elect: usize,
+	candidates: &[AccountId],
+	voters: &[(AccountId, VoteWeight, Vec<AccountId>)],
+) -> Vec<(AccountId, ExtendedBalance)> {
+	let to_elect = to_elect.min(candidates.len());
+	let preferences = voters
+		.iter()
+		.map(|(_, _, votes)| {
+			votes
+				.iter()
+				.map(|v| candidates.iter().position(|c| c == v).unwrap())
+				.collect::<Vec<_>>()
+		})
+		.collect::<Vec<_>>();
+	let mut remaining =
+		voters.iter().map(|(_, stake, _)| *stake as ExtendedBalance).collect::<Vec<_>>();
+	let quota = droop_quota(to_elect, remaining.iter().sum());
+	let mut status = vec![Status::Hopeful; candidates.len()];
+	let mut winners = Vec::new();
+
+	let top = |prefs: &[usize], status: &[Status]| {
+		prefs.iter().copied().find(|c| status[*c] == Status::Hopeful)
+	};
+
+	while winners.len() < to_elect {
+		let hopeful = (0..candidates.len())
+			.filter(|c| status[*c] == Status::Hopeful)
+			.collect::<Vec<_>>();
+		if hopeful.is_empty() {
+			break
+		}
+
+		let mut count = vec![0 as ExtendedBalance; candidates.len()];
+		for (prefs, r) in preferences.iter().zip(remaining.iter()) {
+			if let Some(c) = top(prefs, &status) {
+				count[c] += *r;
+			}
+		}
+
+		if hopeful.len() <= to_elect - winners.len() {
+			let mut rest = hopeful.into_iter().filter(|c| count[*c] > 0).collect::<Vec<_>>();
+			rest.sort_by(|a, b| count[*b].cmp(&count[*a]));
+			winners.extend(rest.into_iter().map(|c| (candidates[c], count[c])));
+			break
+		}
+
+		let best =
+			hopeful
+				.iter()
+				.copied()
+				.fold(hopeful[0], |b, c| if count[c] > count[b] { c } else { b });
+		let worst =
+			hopeful
+				.iter()
+				.copied()
+				.fold(hopeful[0], |w, c| if count[c] <= count[w] { c } else { w });
+
+		if count[best] >= quota {
+			let mut counted = 0;
+			for (prefs, r) in preferences.iter().zip(remaining.iter_mut()) {
+				if top(prefs, &status) == Some(best) {
+					let used =
+						multiply_by_rational_with_rounding(*r, quota, count[best], Rounding::Down)
+							.unwrap_or(ExtendedBalance::MAX)
+							.min(*r);
+					*r -= used;
+					counted += used;
+				}
+			}
+			status[best] = Status::Elected;
+			winners.push((candidates[best], counted));
+		} else {
+			status[worst] = Status::Eliminated;
+		}
+	}
+
+	winners
+}
+
+fn iteration(mut candidate_count: usize, mut voter_count: usize, seed: u64) {
+	let mut rng = rand::rngs::SmallRng::seed_from_u64(seed);
+	candidate_count = to_range(candidate_count, MIN_CANDIDATES, MAX_CANDIDATES);
+	voter_count = to_range(voter_count, MIN_VOTERS, MAX_VOTERS);
+
+	let (rounds, candidates, mut voters) =
+		generate_random_npos_inputs(candidate_count, voter_count, &mut rng);
+	// the generated votes are sorted, shuffle them into some random preferences.
+	voters.iter_mut().for_each(|(_, _, votes)| votes.shuffle(&mut rng));
+
+	let expected = reference_stv(rounds, &candidates, &voters);
+	let ElectionResult::<AccountId, Perbill> { winners, assignments } =
+		stv(rounds, candidates, voters).expect("stv must succeed");
+
+	assert_eq!(winners, expected, "stv must match the reference implementation");
+
+	// assignments only point to winners.
+	assert!(assignments
+		.iter()
+		.all(|a| a.distribution.iter().all(|(t, _)| winners.iter().any(|(w, _)| w == t))));
+}
@@ -0,0 +1,244 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation of (multi-winner) approval voting.
+//!
+//! Each voter approves of a set of candidates and lends its full stake to every single one of them.
+//! The `to_elect` candidates with the highest approval stake win. Ties between candidates with the
+//! same approval stake are broken in favour of the candidate whose election maximizes the minimum
+//! backing of the elected set (hence _max-min_ tie breaking), and then by the order in which the
+//! candidates were provided.
+
+use crate::{
+	setup_inputs, CandidatePtr, ElectionResult, ExtendedBalance, IdentifierT, PerThing128,
+	VoteWeight, Voter,
+};
+use alloc::{collections::btree_map::BTreeMap, rc::Rc, vec::Vec};
+use sp_arithmetic::traits::Zero;
+
+/// Execute approval voting with max-min tie breaking.
+///
+/// - The algorithm is a _best-effort_ to elect `to_elect`. Candidates with zero approval stake are
+///   never elected, thus less winners might be returned, without an error.
+/// - Returning winners are sorted based on the round in which they have been elected, which is
+///   equivalent to their approval stake, in descending order.
+/// - Each voter's stake is distributed equally among the winners that it has approved. The
+///   returning winners are zipped with the backing stake that they receive from this distribution.
+/// - The returning weight distribution is _normalized_, meaning that it is guaranteed that the sum
+///   of the ratios in each voter's distribution sums up to exactly `P::one()`.
+///
+/// This can only fail if the normalization fails.
+pub fn approval_voting<AccountId: IdentifierT, P: PerThing128>(
+	to_elect: usize,
+	candidates: Vec<AccountId>,
+	voters: Vec<(AccountId, VoteWeight, impl IntoIterator<Item = AccountId>)>,
+) -> Result<ElectionResult<AccountId, P>, crate::Error> {
+	let (candidates, mut voters) = setup_inputs(candidates, voters);
+
+	let mut winners = Vec::with_capacity(to_elect.min(candidates.len()));
+	for round in 0..to_elect {
+		if let Some(round_winner) = select_winner(&candidates, &voters) {
+			round_winner.borrow_mut().round = round;
+			round_winner.borrow_mut().elected = true;
+			winners.push(round_winner);
+		} else {
+			break
+		}
+	}
+
+	distribute_equally(&mut voters);
+
+	let mut assignments =
+		voters.into_iter().filter_map(|v| v.into_assignment()).collect::<Vec<_>>();
+	assignments
+		.iter_mut()
+		.try_for_each(|a| a.try_normalize())
+		.map_err(crate::Error::ArithmeticError)?;
+	let winners = winners
+		.into_iter()
+		.map(|w_ptr| (w_ptr.borrow().who.clone(), w_ptr.borrow().backed_stake))
+		.collect();
+
+	Ok(ElectionResult { winners, assignments })
+}
+
+/// Select the winner of the next round, if any.
+///
+/// This is an internal part of [`approval_voting`].
+fn select_winner<AccountId: IdentifierT>(
+	candidates: &[CandidatePtr<AccountId>],
+	voters: &[Voter<AccountId>],
+) -> Option<CandidatePtr<AccountId>> {
+	let best_approval = candidates
+		.iter()
+		.map(|c| c.borrow())
+		.filter(|c| !c.elected)
+		.map(|c| c.approval_stake)
+		.max()
+		.filter(|a| !a.is_zero())?;
+
+	let mut tied = candidates.iter().filter(|c| {
+		let c = c.borrow();
+		!c.elected && c.approval_stake == best_approval
+	});
+	let first = tied.next()?;
+	let mut best = (Rc::clone(first), min_backing_with(first, voters));
+	for c_ptr in tied {
+		let min_backing = min_backing_with(c_ptr, voters);
+		// strictly better only, such that equal candidates are resolved by their order.
+		if min_backing > best.1 {
+			best = (Rc::clone(c_ptr), min_backing);
+		}
+	}
+
+	Some(best.0)
+}
+
+/// The minimum backing of the elected set, if `candidate` were to be elected as well, and each
+/// voter distributes its budget equally among the elected candidates that it approves.
+fn min_backing_with<AccountId: IdentifierT>(
+	candidate: &CandidatePtr<AccountId>,
+	voters: &[Voter<AccountId>],
+) -> ExtendedBalance {
+	let who = candidate.borrow().who.clone();
+	let mut backing = BTreeMap::<AccountId, ExtendedBalance>::new();
+	backing.insert(who.clone(), Zero::zero());
+
+	for voter in voters {
+		let elected = voter
+			.edges
+			.iter()
+			.filter(|e| e.who == who || e.candidate.borrow().elected)
+			.map(|e| &e.who)
+			.collect::<Vec<_>>();
+		if elected.is_empty() {
+			continue
+		}
+
+		let share = voter.budget / elected.len() as ExtendedBalance;
+		for target in elected {
+			let b = backing.entry(target.clone()).or_default();
+			*b = b.saturating_add(share);
+		}
+	}
+
+	backing.values().copied().min().unwrap_or_default()
+}
+
+/// Distribute the budget of each voter equally among all of its elected edges, and update the
+/// `backed_stake` of the corresponding candidates accordingly. Edges to unelected candidates are
+/// removed.
+///
+/// Any remainder of the integer division is given to the last elected edge, such that the budget of
+/// each voter is always distributed in full.
+pub(crate) fn distribute_equally<AccountId: IdentifierT>(voters: &mut Vec<Voter<AccountId>>) {
+	for voter in voters.iter_mut() {
+		voter.edges.retain(|e| e.candidate.borrow().elected);
+		let count = voter.edges.len() as ExtendedBalance;
+		if count.is_zero() {
+			continue
+		}
+
+		let share = voter.budget / count;
+		let remainder = voter.budget % count;
+		let last = voter.edges.len() - 1;
+		for (index, edge) in voter.edges.iter_mut().enumerate() {
+			edge.weight = if index == last { share.saturating_add(remainder) } else { share };
+			let mut candidate = edge.candidate.borrow_mut();
+			candidate.backed_stake = candidate.backed_stake.saturating_add(edge.weight);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Assignment, ElectionResult};
+	use sp_runtime::Perbill;
+
+	#[test]
+	fn basic_election_works() {
+		let candidates = vec![1, 2, 3];
+		let voters = vec![(10, 10, vec![1, 2]), (20, 20, vec![1, 3]), (30, 30, vec![2, 3])];
+
+		let ElectionResult::<_, Perbill> { winners, assignments } =
+			approval_voting(2, candidates, voters).unwrap();
+
+		// approval stakes are 1: 30, 2: 40, 3: 50.
+		assert_eq!(winners, vec![(3, 35), (2, 25)]);
+		assert_eq!(
+			assignments,
+			vec![
+				Assignment { who: 10u64, distribution: vec![(2, Perbill::one())] },
+				Assignment { who: 20, distribution: vec![(3, Perbill::one())] },
+				Assignment {
+					who: 30,
+					distribution: vec![
+						(2, Perbill::from_percent(50)),
+						(3, Perbill::from_percent(50)),
+					],
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn ties_are_broken_by_max_min() {
+		// 1, 2 and 3 all have an approval stake of 20, yet electing 3 along 1 yields a better
+		// minimum backing, since 1 and 2 share their only voter.
+		let candidates = vec![1, 2, 3];
+		let voters = vec![(10, 20, vec![1, 2]), (20, 20, vec![3])];
+
+		let ElectionResult::<_, Perbill> { winners, assignments: _ } =
+			approval_voting(2, candidates, voters).unwrap();
+		assert_eq!(winners, vec![(1, 20), (3, 20)]);
+	}
+
+	#[test]
+	fn ties_are_broken_by_order_as_last_resort() {
+		let candidates = vec![3, 1, 2];
+		let voters = vec![(10, 20, vec![1]), (20, 20, vec![2]), (30, 20, vec![3])];
+
+		let ElectionResult::<_, Perbill> { winners, assignments: _ } =
+			approval_voting(2, candidates, voters).unwrap();
+		assert_eq!(winners, vec![(3, 20), (1, 20)]);
+	}
+
+	#[test]
+	fn does_not_elect_unapproved_candidates() {
+		let candidates = vec![1, 2, 3];
+		let voters = vec![(10, 10, vec![1])];
+
+		let ElectionResult::<_, Perbill> { winners, assignments } =
+			approval_voting(3, candidates, voters).unwrap();
+		assert_eq!(winners, vec![(1, 10)]);
+		assert_eq!(
+			assignments,
+			vec![Assignment { who: 10u64, distribution: vec![(1, Perbill::one())] }]
+		);
+	}
+
+	#[test]
+	fn remainder_is_not_lost() {
+		let candidates = vec![1, 2, 3];
+		let voters = vec![(10, 10, vec![1, 2, 3])];
+
+		let ElectionResult::<_, Perbill> { winners, assignments: _ } =
+			approval_voting(3, candidates, voters).unwrap();
+		assert_eq!(winners, vec![(1, 3), (2, 3), (3, 4)]);
+	}
+}
@@ -0,0 +1,163 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation of a fast, single-pass greedy election method.
+//!
+//! Each voter splits its stake equally among all of its votes, and the `to_elect` candidates with
+//! the highest resulting support are elected at once. Contrary to [`crate::seq_phragmen`] and
+//! [`crate::phragmms()`], the support of the remaining candidates is not re-computed after each
+//! election, which makes this method run in `O(E + C log C)`, where `E` is the number of edges and
+//! `C` the number of candidates. This makes it suitable for very large sets, at the expense of the
+//! quality of the solution, which can be partially recovered with some rounds of balancing.
+
+use crate::{
+	approval_voting::distribute_equally, balance, setup_inputs, BalancingConfig, ElectionResult,
+	ExtendedBalance, IdentifierT, PerThing128, VoteWeight,
+};
+use alloc::{rc::Rc, vec::Vec};
+use core::cmp::Reverse;
+use sp_arithmetic::traits::Zero;
+
+/// Execute the fast greedy method with potentially some rounds of `balancing`.
+///
+/// - The algorithm is a _best-effort_ to elect `to_elect`. Candidates with zero support are never
+///   elected, thus less winners might be returned, without an error.
+/// - Returning winners are sorted based on their initial, equally split support, in descending
+///   order. Ties are broken by the order in which the candidates were provided.
+/// - The returning winners are zipped with their final backing stake, after each voter has
+///   distributed its stake equally among its elected votes, and after the optional balancing.
+/// - The returning weight distribution is _normalized_, meaning that it is guaranteed that the sum
+///   of the ratios in each voter's distribution sums up to exactly `P::one()`.
+///
+/// This can only fail if the normalization fails.
+pub fn fast_greedy<AccountId: IdentifierT, P: PerThing128>(
+	to_elect: usize,
+	candidates: Vec<AccountId>,
+	voters: Vec<(AccountId, VoteWeight, impl IntoIterator<Item = AccountId>)>,
+	balancing: Option<BalancingConfig>,
+) -> Result<ElectionResult<AccountId, P>, crate::Error> {
+	let (candidates, mut voters) = setup_inputs(candidates, voters);
+
+	// compute the equally split support of all candidates, temporarily stored in `backed_stake`.
+	for voter in &voters {
+		let share = voter.budget / voter.edges.len() as ExtendedBalance;
+		for edge in &voter.edges {
+			let mut candidate = edge.candidate.borrow_mut();
+			candidate.backed_stake = candidate.backed_stake.saturating_add(share);
+		}
+	}
+
+	// stable sort, thus ties remain in the order of the input.
+	let mut winners = candidates
+		.iter()
+		.filter(|c| !c.borrow().backed_stake.is_zero())
+		.map(Rc::clone)
+		.collect::<Vec<_>>();
+	winners.sort_by_key(|c| Reverse(c.borrow().backed_stake));
+	winners.truncate(to_elect);
+
+	for (round, w_ptr) in winners.iter().enumerate() {
+		let mut winner = w_ptr.borrow_mut();
+		winner.elected = true;
+		winner.round = round;
+	}
+	for c_ptr in &candidates {
+		c_ptr.borrow_mut().backed_stake = Zero::zero();
+	}
+
+	distribute_equally(&mut voters);
+
+	if let Some(ref config) = balancing {
+		let _iters = balance::<AccountId>(&mut voters, config);
+	}
+
+	let mut assignments =
+		voters.into_iter().filter_map(|v| v.into_assignment()).collect::<Vec<_>>();
+	assignments
+		.iter_mut()
+		.try_for_each(|a| a.try_normalize())
+		.map_err(crate::Error::ArithmeticError)?;
+	let winners = winners
+		.into_iter()
+		.map(|w_ptr| (w_ptr.borrow().who.clone(), w_ptr.borrow().backed_stake))
+		.collect();
+
+	Ok(ElectionResult { winners, assignments })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Assignment, ElectionResult};
+	use sp_runtime::Perbill;
+
+	#[test]
+	fn basic_election_works() {
+		let candidates = vec![1, 2, 3];
+		let voters = vec![(10, 10, vec![1, 2]), (20, 20, vec![1, 3]), (30, 30, vec![2, 3])];
+
+		// equally split supports are 1: 15, 2: 20, 3: 25.
+		let ElectionResult::<_, Perbill> { winners, assignments } =
+			fast_greedy(2, candidates, voters, None).unwrap();
+		assert_eq!(winners, vec![(3, 35), (2, 25)]);
+		assert_eq!(
+			assignments,
+			vec![
+				Assignment { who: 10u64, distribution: vec![(2, Perbill::one())] },
+				Assignment { who: 20, distribution: vec![(3, Perbill::one())] },
+				Assignment {
+					who: 30,
+					distribution: vec![
+						(2, Perbill::from_percent(50)),
+						(3, Perbill::from_percent(50)),
+					],
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn balancing_works() {
+		let candidates = vec![1, 2, 3];
+		let voters = vec![(10, 10, vec![1, 2]), (20, 20, vec![1, 3]), (30, 30, vec![2, 3])];
+
+		let config = BalancingConfig { iterations: 10, tolerance: 0 };
+		let ElectionResult::<_, Perbill> { winners, assignments: _ } =
+			fast_greedy(2, candidates, voters, Some(config)).unwrap();
+		assert_eq!(winners, vec![(3, 30), (2, 30)]);
+	}
+
+	#[test]
+	fn ties_are_broken_by_order() {
+		let candidates = vec![3, 2, 1];
+		let voters = vec![(10, 10, vec![1]), (20, 10, vec![2]), (30, 10, vec![3])];
+
+		let ElectionResult::<_, Perbill> { winners, assignments: _ } =
+			fast_greedy(2, candidates, voters, None).unwrap();
+		assert_eq!(winners, vec![(3, 10), (2, 10)]);
+	}
+
+	#[test]
+	fn does_not_elect_unsupported_candidates() {
+		let candidates = vec![1, 2, 3];
+		let voters = vec![(10, 10, vec![1])];
+
+		let ElectionResult::<_, Perbill> { winners, assignments: _ } =
+			fast_greedy(3, candidates, voters, None).unwrap();
+		assert_eq!(winners, vec![(1, 10)]);
+	}
+}
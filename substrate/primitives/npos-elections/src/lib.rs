@@ -22,6 +22,12 @@
 //! - [`ghragmms`](phragmms::phragmms()): Implements a hybrid approach inspired by Phragmén which is
 //!   executed faster but it can achieve a constant factor approximation of the maximin problem,
 //!   similar to that of the MMS algorithm.
+//! - [`approval_voting()`]: Implements multi-winner approval voting, where ties are broken in
+//!   favour of the candidate that maximizes the minimum backing of the elected set.
+//! - [`stv()`]: Implements the single transferable vote, where the votes of each voter are
+//!   interpreted as a ranked list of preferences.
+//! - [`fast_greedy`]: Implements a single-pass greedy method, suitable for very large sets where
+//!   the quality of the solution can be traded for speed.
 //! - [`balance`]: Implements the star balancing algorithm. This iterative process can push a
 //!   solution toward being more "balanced", which in turn can increase its score.
 //!
@@ -90,23 +96,29 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod approval_voting;
 mod assignments;
 pub mod balancing;
+pub mod greedy;
 pub mod helpers;
 pub mod node;
 pub mod phragmen;
 pub mod phragmms;
 pub mod pjr;
 pub mod reduce;
+pub mod stv;
 pub mod traits;
 
+pub use approval_voting::approval_voting;
 pub use assignments::{Assignment, StakedAssignment};
 pub use balancing::*;
+pub use greedy::fast_greedy;
 pub use helpers::*;
 pub use phragmen::*;
 pub use phragmms::*;
 pub use pjr::*;
 pub use reduce::reduce;
+pub use stv::{droop_quota, stv, stv_core};
 pub use traits::{IdentifierT, PerThing128};
 
 /// The errors that might occur in this crate and `frame-election-provider-solution-type`.
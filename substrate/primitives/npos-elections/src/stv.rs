@@ -0,0 +1,276 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation of the single transferable vote (STV) election method.
+//!
+//! Contrary to the other methods of this crate, the votes of each voter are interpreted as a
+//! _ranked_ list of preferences, the most preferred target being first. Each voter's stake is
+//! counted towards its most preferred candidate that is neither elected nor eliminated yet. A
+//! candidate whose count reaches the Droop quota is elected, and the surplus is transferred to the
+//! next preferences of its voters, in proportion to their contribution (also known as the weighted
+//! Gregory method). If no candidate reaches the quota, the candidate with the lowest count is
+//! eliminated and its votes are transferred in full.
+
+use crate::{
+	setup_inputs, CandidatePtr, ElectionResult, ExtendedBalance, IdentifierT, PerThing128,
+	VoteWeight, Voter,
+};
+use alloc::{collections::btree_set::BTreeSet, rc::Rc, vec::Vec};
+use sp_arithmetic::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{Bounded, Zero},
+	Rounding,
+};
+
+/// Execute the single transferable vote method.
+///
+/// - The votes of each voter are interpreted as its preferences, in descending order.
+/// - The algorithm is a _best-effort_ to elect `to_elect`. Candidates that are not ranked by any
+///   voter are never elected, thus less winners might be returned, without an error.
+/// - Returning winners are sorted based on the round in which they have been elected. They are
+///   zipped with the stake that has been counted towards them. This is the Droop quota (modulo
+///   rounding) for those who have reached it, and their full count for those who have filled the
+///   remaining seats once no more candidates could be eliminated.
+/// - Stake that cannot be transferred anymore, since all the remaining preferences of a voter are
+///   either elected or eliminated, is _exhausted_. The exhausted stake is not counted towards any
+///   winner, yet since the returning weight distribution is _normalized_, it is proportionally
+///   spread over the winners of each such voter in the assignments.
+///
+/// This can only fail if the normalization fails.
+pub fn stv<AccountId: IdentifierT, P: PerThing128>(
+	to_elect: usize,
+	candidates: Vec<AccountId>,
+	voters: Vec<(AccountId, VoteWeight, impl IntoIterator<Item = AccountId>)>,
+) -> Result<ElectionResult<AccountId, P>, crate::Error> {
+	let (candidates, voters) = setup_inputs(candidates, voters);
+
+	let (winners, voters) = stv_core(to_elect, candidates, voters);
+
+	let mut assignments =
+		voters.into_iter().filter_map(|v| v.into_assignment()).collect::<Vec<_>>();
+	assignments
+		.iter_mut()
+		.try_for_each(|a| a.try_normalize())
+		.map_err(crate::Error::ArithmeticError)?;
+	let winners = winners
+		.into_iter()
+		.map(|w_ptr| (w_ptr.borrow().who.clone(), w_ptr.borrow().backed_stake))
+		.collect();
+
+	Ok(ElectionResult { winners, assignments })
+}
+
+/// The Droop quota, i.e. the smallest count that can be reached by at most `to_elect` candidates.
+pub fn droop_quota(to_elect: usize, total_stake: ExtendedBalance) -> ExtendedBalance {
+	(total_stake / (to_elect as ExtendedBalance).saturating_add(1)).saturating_add(1)
+}
+
+/// Core implementation of the single transferable vote.
+///
+/// Returns the winners, sorted by the round in which they have been elected, and the voters, the
+/// edge weights of which reflect the stake counted towards each winner.
+///
+/// This function is left public in case a crate needs to use the implementation in a custom way.
+// To create the inputs needed for this function, see [`crate::setup_inputs`].
+pub fn stv_core<AccountId: IdentifierT>(
+	to_elect: usize,
+	candidates: Vec<CandidatePtr<AccountId>>,
+	mut voters: Vec<Voter<AccountId>>,
+) -> (Vec<CandidatePtr<AccountId>>, Vec<Voter<AccountId>>) {
+	let to_elect = to_elect.min(candidates.len());
+	let quota = droop_quota(to_elect, voters.iter().fold(0, |acc, v| acc.saturating_add(v.budget)));
+
+	// the part of the budget of each voter that is yet to be counted towards a winner.
+	let mut remaining = voters.iter().map(|v| v.budget).collect::<Vec<_>>();
+	let mut eliminated = BTreeSet::<AccountId>::new();
+	let mut winners = Vec::with_capacity(to_elect);
+
+	// the index of the most preferred edge of a voter that is still in the running.
+	let top_preference = |voter: &Voter<AccountId>, eliminated: &BTreeSet<AccountId>| {
+		voter
+			.edges
+			.iter()
+			.position(|e| !e.candidate.borrow().elected && !eliminated.contains(&e.who))
+	};
+
+	while winners.len() < to_elect {
+		// count the votes. The count of hopeful candidates is kept in their `backed_stake`.
+		let hopeful = candidates
+			.iter()
+			.filter(|c| {
+				let c = c.borrow();
+				!c.elected && !eliminated.contains(&c.who)
+			})
+			.collect::<Vec<_>>();
+		if hopeful.is_empty() {
+			break
+		}
+		hopeful.iter().for_each(|c| c.borrow_mut().backed_stake = Zero::zero());
+		for (voter, remaining) in voters.iter().zip(remaining.iter()) {
+			if let Some(index) = top_preference(voter, &eliminated) {
+				let mut candidate = voter.edges[index].candidate.borrow_mut();
+				candidate.backed_stake = candidate.backed_stake.saturating_add(*remaining);
+			}
+		}
+
+		// if there are not more hopeful candidates than seats left, all of them with a non-zero
+		// count are elected, in the order of their count.
+		if hopeful.len() <= to_elect - winners.len() {
+			let mut rest = hopeful
+				.into_iter()
+				.filter(|c| !c.borrow().backed_stake.is_zero())
+				.map(Rc::clone)
+				.collect::<Vec<_>>();
+			rest.sort_by(|a, b| b.borrow().backed_stake.cmp(&a.borrow().backed_stake));
+
+			for (voter, remaining) in voters.iter_mut().zip(remaining.iter_mut()) {
+				if let Some(index) = top_preference(&*voter, &eliminated) {
+					let edge = &mut voter.edges[index];
+					edge.weight = edge.weight.saturating_add(*remaining);
+					*remaining = Zero::zero();
+				}
+			}
+			for c_ptr in rest {
+				let round = winners.len();
+				let mut candidate = c_ptr.borrow_mut();
+				candidate.elected = true;
+				candidate.round = round;
+				drop(candidate);
+				winners.push(c_ptr);
+			}
+			break
+		}
+
+		// the first of the highest counts, and the last of the lowest.
+		let mut best = hopeful[0];
+		let mut worst = hopeful[0];
+		for c_ptr in hopeful.iter().skip(1).copied() {
+			let count = c_ptr.borrow().backed_stake;
+			if count > best.borrow().backed_stake {
+				best = c_ptr;
+			}
+			if count <= worst.borrow().backed_stake {
+				worst = c_ptr;
+			}
+		}
+
+		let count = best.borrow().backed_stake;
+		if count >= quota {
+			// elect `best`, and only keep the surplus of its voters for their next preferences.
+			let best_who = best.borrow().who.clone();
+			let mut counted: ExtendedBalance = Zero::zero();
+			for (voter, remaining) in voters.iter_mut().zip(remaining.iter_mut()) {
+				let Some(index) = top_preference(&*voter, &eliminated) else { continue };
+				if voter.edges[index].who != best_who {
+					continue
+				}
+
+				let used =
+					multiply_by_rational_with_rounding(*remaining, quota, count, Rounding::Down)
+						.unwrap_or_else(Bounded::max_value)
+						.min(*remaining);
+				let edge = &mut voter.edges[index];
+				edge.weight = edge.weight.saturating_add(used);
+				*remaining = remaining.saturating_sub(used);
+				counted = counted.saturating_add(used);
+			}
+
+			let round = winners.len();
+			let mut winner = best.borrow_mut();
+			winner.elected = true;
+			winner.round = round;
+			winner.backed_stake = counted;
+			drop(winner);
+			winners.push(Rc::clone(best));
+		} else {
+			// eliminate `worst`, its votes are transferred in full in the next round.
+			eliminated.insert(worst.borrow().who.clone());
+		}
+	}
+
+	(winners, voters)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Assignment, ElectionResult};
+	use sp_runtime::Perbill;
+
+	#[test]
+	fn droop_quota_works() {
+		assert_eq!(droop_quota(1, 100), 51);
+		assert_eq!(droop_quota(2, 100), 34);
+		assert_eq!(droop_quota(3, 100), 26);
+		assert_eq!(droop_quota(0, 100), 101);
+	}
+
+	#[test]
+	fn surplus_is_transferred() {
+		// quota is 34.
+		let candidates = vec![1, 2, 3];
+		let voters = vec![(10, 60, vec![1, 2]), (20, 32, vec![3]), (30, 8, vec![2, 3])];
+
+		let ElectionResult::<_, Perbill> { winners, assignments } =
+			stv(2, candidates, voters).unwrap();
+
+		// 1 is elected with a surplus of 26, which goes to 2, now at 34 as well.
+		assert_eq!(winners, vec![(1, 34), (2, 34)]);
+		assert_eq!(assignments.iter().map(|a| a.who).collect::<Vec<_>>(), vec![10, 30],);
+		assert_eq!(
+			assignments[0].distribution.iter().map(|(t, _)| *t).collect::<Vec<_>>(),
+			vec![1, 2],
+		);
+		assert_eq!(assignments[1], Assignment { who: 30, distribution: vec![(2, Perbill::one())] });
+	}
+
+	#[test]
+	fn lowest_is_eliminated_and_transferred() {
+		// quota is 31, nobody reaches it in the first round.
+		let candidates = vec![1, 2, 3];
+		let voters =
+			vec![(10, 30, vec![1]), (20, 30, vec![2]), (30, 15, vec![3, 1]), (40, 15, vec![3, 2])];
+
+		let ElectionResult::<_, Perbill> { winners, assignments: _ } =
+			stv(2, candidates, voters).unwrap();
+
+		// 3 is eliminated, its votes go to 1 and 2 which fill the remaining seats.
+		assert_eq!(winners, vec![(1, 45), (2, 45)]);
+	}
+
+	#[test]
+	fn remaining_seats_are_filled() {
+		let candidates = vec![1, 2, 3, 4];
+		let voters = vec![(10, 10, vec![1]), (20, 20, vec![2]), (30, 30, vec![3])];
+
+		let ElectionResult::<_, Perbill> { winners, assignments: _ } =
+			stv(3, candidates, voters).unwrap();
+
+		// 3 and 2 reach the quota of 16, then 4 is eliminated and 1 fills the last seat.
+		assert_eq!(winners, vec![(3, 16), (2, 16), (1, 10)]);
+	}
+
+	#[test]
+	fn unranked_candidates_are_never_elected() {
+		let candidates = vec![1, 2, 3];
+		let voters = vec![(10, 10, vec![1])];
+
+		let ElectionResult::<_, Perbill> { winners, assignments: _ } =
+			stv(3, candidates, voters).unwrap();
+		assert_eq!(winners, vec![(1, 10)]);
+	}
+}
@@ -95,6 +95,7 @@ pub mod testing;
 pub mod traits;
 pub mod transaction_validity;
 pub mod type_with_default;
+pub mod view_functions;
 
 // Re-export Multiaddress
 pub use multiaddress::MultiAddress;
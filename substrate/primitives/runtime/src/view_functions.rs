@@ -0,0 +1,68 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Types shared by the runtime and the client for dispatching pallet view functions.
+
+use crate::{Decode, Encode, RuntimeDebug, TypeInfo};
+
+/// The unique identifier for a view function.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ViewFunctionId {
+	/// The part of the id for dispatching view functions from the top level of the runtime.
+	///
+	/// Specifies which view function grouping this view function belongs to. This could be a group
+	/// of view functions associated with a pallet, or a pallet agnostic group of view functions.
+	pub prefix: [u8; 16],
+	/// The part of the id for dispatching to a view function within a group.
+	pub suffix: [u8; 16],
+}
+
+impl From<ViewFunctionId> for [u8; 32] {
+	fn from(value: ViewFunctionId) -> Self {
+		let mut output = [0u8; 32];
+		output[..16].copy_from_slice(&value.prefix);
+		output[16..].copy_from_slice(&value.suffix);
+		output
+	}
+}
+
+impl From<[u8; 32]> for ViewFunctionId {
+	fn from(value: [u8; 32]) -> Self {
+		let mut prefix = [0u8; 16];
+		let mut suffix = [0u8; 16];
+		prefix.copy_from_slice(&value[..16]);
+		suffix.copy_from_slice(&value[16..]);
+		ViewFunctionId { prefix, suffix }
+	}
+}
+
+/// Error type for view function dispatching.
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum ViewFunctionDispatchError {
+	/// View functions are not implemented for this runtime.
+	NotImplemented,
+	/// A view function with the given `ViewFunctionId` was not found
+	NotFound(ViewFunctionId),
+	/// Failed to decode the view function input.
+	Codec,
+}
+
+impl From<codec::Error> for ViewFunctionDispatchError {
+	fn from(_: codec::Error) -> Self {
+		ViewFunctionDispatchError::Codec
+	}
+}
@@ -486,6 +486,40 @@ pub struct PagedExposureMetadata<Balance: HasCompact + codec::MaxEncodedLen> {
 	pub page_count: Page,
 }
 
+/// The reward of a staker for one page of a validator's exposure in a given era.
+///
+/// If the staker is the validator itself, `reward` includes the commission due for the page.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct EraReward<AccountId, Balance> {
+	/// The era in which the reward was earned.
+	pub era: EraIndex,
+	/// The validator whose exposure the staker is part of.
+	pub validator: AccountId,
+	/// The commission of `validator` in `era`.
+	pub commission: Perbill,
+	/// The page of the validator's exposure the staker is in.
+	pub page: Page,
+	/// The stake of the staker exposed to `validator`.
+	pub stake: Balance,
+	/// The reward of the staker for this page.
+	pub reward: Balance,
+	/// Whether the page has already been paid out.
+	pub claimed: bool,
+}
+
+/// The projected yearly yield of a hypothetical stake, at the current inflation.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct YieldEstimate<Balance> {
+	/// The expected reward over a year.
+	pub yearly_reward: Balance,
+	/// The expected reward over a year, relative to the stake.
+	pub apy: Perbill,
+}
+
 /// A type that belongs only in the context of an `Agent`.
 ///
 /// `Agent` is someone that manages delegated funds from [`Delegator`] accounts. It can
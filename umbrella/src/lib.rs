@@ -661,6 +661,10 @@ pub use pallet_staking_reward_curve;
 #[cfg(feature = "pallet-staking-reward-fn")]
 pub use pallet_staking_reward_fn;
 
+/// RPC interface for the staking pallet.
+#[cfg(feature = "pallet-staking-rpc")]
+pub use pallet_staking_rpc;
+
 /// RPC runtime API for transaction payment FRAME pallet.
 #[cfg(feature = "pallet-staking-runtime-api")]
 pub use pallet_staking_runtime_api;